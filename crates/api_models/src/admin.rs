@@ -128,6 +128,10 @@ pub struct MerchantAccountResponse {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// The secret used to sign the webhooks sent to the merchant
+    #[schema(value_type = Option<String>, example = "whsec_3d1ab5f0c0a54cd3a31e8bd2c2d4b4e0")]
+    pub webhook_signing_secret: Option<StrongSecret<String>>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -176,6 +180,88 @@ pub struct MerchantDetails {
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithm {
     Single(api_enums::RoutableConnectors),
+    /// Routes payments to the first connector of the list, the connectors that follow are used
+    /// when a payment is retried after a failure
+    Priority(Vec<api_enums::RoutableConnectors>),
+    RuleBased(RuleBasedRoutingAlgorithm),
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+    SuccessRate(SuccessRateRoutingAlgorithm),
+}
+
+/// Routes a payment to the connector with the highest success rate over the recent payments of
+/// the merchant made with the same payment method and currency
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SuccessRateRoutingAlgorithm {
+    /// The connectors to choose from, in order of preference when their success rates are equal
+    pub connectors: Vec<api_enums::RoutableConnectors>,
+    /// The percentage of payments routed to a random connector instead of the best scoring one,
+    /// so that the success rates of all connectors are kept up to date. Defaults to 5
+    pub exploration_percentage: Option<u8>,
+}
+
+/// The share of payments to be routed to a connector, relative to the weights of the other
+/// connectors in the volume split
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplit {
+    pub connector: api_enums::RoutableConnectors,
+    pub weight: u32,
+}
+
+/// Routes a payment to the connector of the first rule whose conditions are all satisfied by
+/// the payment, falling back to the default connector when no rule matches
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleBasedRoutingAlgorithm {
+    /// The rules to be evaluated, in order of priority
+    pub rules: Vec<RoutingRule>,
+    /// The connector to be used when none of the rules match
+    pub default_connector: api_enums::RoutableConnectors,
+    /// The BIN prefixes of the cards issued in each country, keyed by the ISO 3166-1 alpha-2
+    /// country code. Used for resolving the issuing country of a card in `card_issuing_countries`
+    /// conditions, the longest matching prefix taking precedence.
+    pub bin_countries: Option<std::collections::HashMap<String, Vec<String>>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRule {
+    /// A name for the rule, used for identifying the rule in logs and errors
+    pub name: Option<String>,
+    /// The conditions which must all be satisfied for the rule to match
+    pub conditions: RoutingConditions,
+    /// The connector to which the payment is routed when the rule matches
+    pub connector: api_enums::RoutableConnectors,
+}
+
+/// Conditions of a routing rule. Conditions which are not specified match every payment.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingConditions {
+    /// The range in which the payment amount must lie
+    pub amount: Option<AmountRange>,
+    /// The currencies of which the payment must be in one
+    pub currencies: Option<Vec<api_enums::Currency>>,
+    /// The payment method types of which the payment must use one
+    pub payment_methods: Option<Vec<api_enums::PaymentMethodType>>,
+    /// The card networks of which the card used for the payment must belong to one
+    pub card_networks: Option<Vec<api_enums::CardNetwork>>,
+    /// The BIN prefixes of which the card number must start with one
+    pub card_bins: Option<Vec<String>>,
+    /// The ISO 3166-1 alpha-2 country codes of which the card must be issued in one, as resolved
+    /// from the `bin_countries` of the algorithm
+    pub card_issuing_countries: Option<Vec<String>>,
+    /// The metadata keys and values which must all be present in the payment metadata
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+}
+
+/// An inclusive range of amounts, in the lowest denomination of the currency
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AmountRange {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -287,6 +373,10 @@ pub struct PaymentConnectorCreate {
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>,max_length = 255,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<serde_json::Value>,
+    /// Number of days after which the authorizations made with this connector are voided if they
+    /// were not captured. Defaults to the expiry configured for the connector.
+    #[schema(example = 7)]
+    pub authorization_expiry_days: Option<i32>,
 }
 /// Details of all the payment methods enabled for the connector for the given merchant account
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -351,6 +441,33 @@ pub struct ToggleKVResponse {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookSigningSecretResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// The new secret used to sign the webhooks sent to the merchant
+    #[schema(value_type = String, example = "whsec_3d1ab5f0c0a54cd3a31e8bd2c2d4b4e0")]
+    pub webhook_signing_secret: StrongSecret<String>,
+    /// Time until which webhooks are also signed with the previous secret. The signature header
+    /// then holds the signatures by both secrets, separated by a comma.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_webhook_signing_secret_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ConnectorCredentialsKeyRotationResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// The version of the new key encrypting the connector account details of the merchant. The
+    /// connector accounts encrypted with previous keys are re-encrypted in the background, and
+    /// remain usable meanwhile.
+    #[schema(example = 2)]
+    pub key_version: i32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ToggleKVRequest {
     /// Status of KV for the specific merchant
@@ -23,6 +23,11 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The privileged operations the API Key may perform, in addition to the operations
+    /// available to every API Key.
+    #[serde(default)]
+    pub scopes: Vec<ApiKeyScope>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +65,9 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The privileged operations the API Key may perform.
+    pub scopes: Vec<ApiKeyScope>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +110,9 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The privileged operations the API Key may perform.
+    pub scopes: Vec<ApiKeyScope>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -129,6 +140,10 @@ pub struct UpdateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
+
+    /// The privileged operations the API Key may perform, replacing the ones it was allowed to
+    /// perform before.
+    pub scopes: Option<Vec<ApiKeyScope>>,
 }
 
 /// The response body for revoking an API Key.
@@ -154,6 +169,26 @@ pub struct ListApiKeyConstraints {
     pub skip: Option<i64>,
 }
 
+/// A privileged operation that an API Key may be allowed to perform.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ApiKeyScope {
+    /// Retrieve the sensitive data behind a token created through the tokens API.
+    Detokenize,
+}
+
 /// The expiration date and time for an API Key.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
@@ -1,8 +1,11 @@
 use common_utils::{consts, custom_serde, pii};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::{enums as api_enums, payments};
+
 /// The customer details
 #[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
 pub struct CustomerRequest {
@@ -114,6 +117,123 @@ pub struct CustomerDeleteResponse {
     pub payment_methods_deleted: bool,
 }
 
+/// Every record held about a customer, returned for data subject access requests
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerExportResponse {
+    /// The customer details
+    pub customer: CustomerResponse,
+    /// The addresses of the customer, including the shipping and billing addresses of payments
+    pub addresses: Vec<payments::Address>,
+    /// The payment methods saved for the customer
+    pub payment_methods: Vec<CustomerPaymentMethodExport>,
+    /// The mandates given by the customer
+    pub mandates: Vec<CustomerMandateExport>,
+    /// The payments made by the customer
+    pub payments: Vec<CustomerPaymentExport>,
+    /// Time at which the export was generated
+    #[serde(with = "custom_serde::iso8601")]
+    pub exported_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerPaymentMethodExport {
+    /// The identifier for the payment method
+    pub payment_method_id: String,
+    /// The type of payment method
+    #[schema(value_type = PaymentMethodType)]
+    pub payment_method: api_enums::PaymentMethodType,
+    /// The sub type of the payment method
+    #[schema(value_type = Option<PaymentMethodSubType>)]
+    pub payment_method_type: Option<api_enums::PaymentMethodSubType>,
+    /// The name of the bank or the card network issuing the payment method
+    pub payment_method_issuer: Option<String>,
+    /// The card scheme of the payment method
+    pub scheme: Option<String>,
+    /// The country of the issuer of the payment method
+    pub issuer_country: Option<String>,
+    /// Metadata stored with the payment method
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    /// Time at which the payment method was saved
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerMandateExport {
+    /// The identifier for the mandate
+    pub mandate_id: String,
+    /// The status of the mandate
+    #[schema(value_type = MandateStatus)]
+    pub status: api_enums::MandateStatus,
+    /// The identifier for the payment method of the mandate
+    pub payment_method_id: String,
+    /// Time at which the customer accepted the mandate
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub customer_accepted_at: Option<PrimitiveDateTime>,
+    /// The IP address from which the customer accepted the mandate
+    #[schema(value_type = Option<String>)]
+    pub customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+    /// The user agent with which the customer accepted the mandate
+    pub customer_user_agent: Option<String>,
+    /// Time at which the mandate was created
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerPaymentExport {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The status of the payment
+    #[schema(value_type = IntentStatus)]
+    pub status: api_enums::IntentStatus,
+    /// The amount of the payment, in the lowest denomination of the currency
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<api_enums::Currency>,
+    /// The description of the payment
+    pub description: Option<String>,
+    /// Metadata provided with the payment
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    /// Time at which the payment was created
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct CustomerErasureId {
+    pub customer_id: String,
+    pub erasure_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerErasureResponse {
+    /// The identifier for the erasure
+    pub erasure_id: String,
+    /// The identifier for the customer whose personal data is erased
+    pub customer_id: String,
+    /// The status of the erasure
+    #[schema(value_type = CustomerErasureStatus)]
+    pub status: api_enums::CustomerErasureStatus,
+    /// The number of addresses which were redacted
+    pub addresses_redacted: i32,
+    /// The number of payments which were redacted
+    pub payments_redacted: i32,
+    /// The number of payment methods which were deleted, along with their locker entries
+    pub payment_methods_deleted: i32,
+    /// The reason for which the erasure failed
+    pub failure_reason: Option<String>,
+    /// Time at which the erasure completed
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+    /// Time at which the erasure was requested
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
 pub fn generate_customer_id() -> String {
     common_utils::generate_id(consts::ID_LENGTH, "cus")
 }
@@ -1 +1,147 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums;
+
+#[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
+pub struct DisputeResponse {
+    /// The identifier for dispute
+    pub dispute_id: String,
+    /// The identifier for payment_intent
+    pub payment_id: String,
+    /// The identifier for payment_attempt
+    pub attempt_id: String,
+    /// The dispute amount in the lowest denomination of the currency
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency)]
+    pub currency: enums::Currency,
+    /// Stage of the dispute
+    #[schema(value_type = DisputeStage)]
+    pub dispute_stage: enums::DisputeStage,
+    /// Status of the dispute
+    #[schema(value_type = DisputeStatus)]
+    pub dispute_status: enums::DisputeStatus,
+    /// The connector through which the disputed payment was processed
+    pub connector: String,
+    /// Status of the dispute sent by connector
+    pub connector_status: String,
+    /// Dispute id sent by connector
+    pub connector_dispute_id: String,
+    /// Reason of dispute sent by connector
+    pub connector_reason: Option<String>,
+    /// Reason code of dispute sent by connector
+    pub connector_reason_code: Option<String>,
+    /// Evidence deadline of dispute sent by connector
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    /// Dispute created time sent by connector
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub dispute_created_at: Option<PrimitiveDateTime>,
+    /// Dispute updated time sent by connector
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub updated_at: Option<PrimitiveDateTime>,
+    /// Time at which dispute is received
+    #[serde(with = "custom_serde::iso8601")]
+    pub received_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeListConstraints {
+    /// The identifier for the payment
+    pub payment_id: Option<String>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// Status of the dispute
+    #[schema(value_type = Option<DisputeStatus>)]
+    pub dispute_status: Option<enums::DisputeStatus>,
+    /// Stage of the dispute
+    #[schema(value_type = Option<DisputeStage>)]
+    pub dispute_stage: Option<enums::DisputeStage>,
+    /// The connector through which the disputed payment was processed
+    pub connector: Option<String>,
+    /// Time greater than or equals to the dispute received time
+    #[serde(
+        default,
+        rename = "received_time.gte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub received_time_gte: Option<PrimitiveDateTime>,
+    /// Time less than or equals to the dispute received time
+    #[serde(
+        default,
+        rename = "received_time.lte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub received_time_lte: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DisputeListResponse {
+    /// The list of disputes
+    pub data: Vec<DisputeResponse>,
+}
+
+/// Dispute details extracted by a connector from its chargeback webhook
+#[derive(Clone, Debug)]
+pub struct DisputePayload {
+    pub amount: i64,
+    pub dispute_stage: enums::DisputeStage,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub updated_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitEvidenceRequest {
+    /// The identifier for the dispute
+    pub dispute_id: String,
+    /// File ID of a receipt or message sent to the customer notifying them of the charge
+    pub receipt: Option<String>,
+    /// File ID of documentation showing proof that the product was shipped to the customer
+    pub shipping_documentation: Option<String>,
+    /// File ID of any communication with the customer showing their verification of the purchase
+    pub customer_communication: Option<String>,
+    /// File ID of the refund policy that was shown to the customer
+    pub refund_policy: Option<String>,
+    /// File ID of the subscription cancellation policy that was shown to the customer
+    pub cancellation_policy: Option<String>,
+    /// File ID of documentation showing proof that the service was provided to the customer
+    pub service_documentation: Option<String>,
+    /// File ID of the agreement for the recurring transaction
+    pub recurring_transaction_agreement: Option<String>,
+    /// File ID of an invoice showing that the disputed charges are distinct transactions
+    pub invoice_showing_distinct_transactions: Option<String>,
+    /// File ID of any additional evidence or statements
+    pub uncategorized_file: Option<String>,
+    /// The name of the customer
+    pub customer_name: Option<String>,
+    /// The email address of the customer
+    pub customer_email_address: Option<String>,
+    /// A description of the product or service that was sold
+    pub product_description: Option<String>,
+    /// The address to which the physical product was shipped
+    pub shipping_address: Option<String>,
+    /// The delivery service that shipped the physical product
+    pub shipping_carrier: Option<String>,
+    /// The tracking number of the shipment of the physical product
+    pub shipping_tracking_number: Option<String>,
+    /// The date on which the physical product was shipped, in YYYY-MM-DD format
+    pub shipping_date: Option<String>,
+    /// An explanation of how and when the customer was shown the refund policy
+    pub refund_policy_disclosure: Option<String>,
+    /// An explanation of why the customer is not entitled to a refund
+    pub refund_refusal_explanation: Option<String>,
+    /// An explanation of why the customer's subscription was not cancelled
+    pub cancellation_rebuttal: Option<String>,
+    /// Any additional evidence or statements
+    pub uncategorized_text: Option<String>,
+}
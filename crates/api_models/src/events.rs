@@ -0,0 +1,76 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventListConstraints {
+    /// The identifier for the merchant whose events are listed
+    pub merchant_id: String,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventResponse {
+    /// The identifier for the event
+    pub event_id: String,
+    /// The identifier for the merchant the event belongs to
+    pub merchant_id: Option<String>,
+    /// The type of the event
+    #[schema(value_type = String, example = "payment_succeeded")]
+    pub event_type: enums::EventType,
+    /// The class of the event
+    #[schema(example = "payments")]
+    pub event_class: String,
+    /// Whether the webhook of the event was delivered to the merchant
+    pub is_webhook_notified: bool,
+    /// The identifier for the payment the event refers to
+    pub intent_reference_id: Option<String>,
+    /// The identifier for the object the event refers to
+    pub primary_object_id: String,
+    /// The type of the object the event refers to
+    #[schema(example = "payment_details")]
+    pub primary_object_type: String,
+    /// Time at which the event was created
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventListResponse {
+    /// The list of events
+    pub data: Vec<EventResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventDeliveryAttemptResponse {
+    /// The identifier for the delivery attempt
+    pub attempt_id: String,
+    /// The identifier for the event which was delivered
+    pub event_id: String,
+    /// The body of the request sent to the merchant
+    pub request_body: String,
+    /// The HTTP status code of the response of the merchant, if one was received
+    pub response_status_code: Option<i32>,
+    /// The beginning of the body of the response of the merchant
+    pub response_body: Option<String>,
+    /// Time taken by the merchant to respond, in milliseconds
+    pub latency_ms: Option<i64>,
+    /// Whether the merchant acknowledged the webhook with a successful status code
+    pub is_success: bool,
+    /// The error which prevented the request from reaching the merchant
+    pub error_message: Option<String>,
+    /// Time at which the delivery was attempted
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventDeliveryAttemptListResponse {
+    /// The list of delivery attempts, latest first
+    pub data: Vec<EventDeliveryAttemptResponse>,
+}
@@ -1 +1,8 @@
+use serde::Serialize;
+use utoipa::ToSchema;
 
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CreateFileResponse {
+    /// ID of the file created
+    pub file_id: String,
+}
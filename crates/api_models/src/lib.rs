@@ -7,10 +7,14 @@ pub mod customers;
 pub mod disputes;
 pub mod enums;
 pub mod errors;
+pub mod events;
 pub mod files;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
+pub mod proxy;
 pub mod refunds;
+pub mod subscriptions;
+pub mod tokens;
 pub mod webhooks;
@@ -1,5 +1,6 @@
 use masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
 use crate::{enums as api_enums, payments};
@@ -34,6 +35,20 @@ pub struct MandateResponse {
     /// Details about the customer’s acceptance
     #[schema(value_type = Option<CustomerAcceptance>)]
     pub customer_acceptance: Option<payments::CustomerAcceptance>,
+    /// The time from which the mandate can be used for payments
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_date: Option<PrimitiveDateTime>,
+    /// The time after which the mandate expires
+    #[schema(example = "2023-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_date: Option<PrimitiveDateTime>,
+    /// The maximum number of payments which can be made with the mandate
+    #[schema(example = 12)]
+    pub max_usage_count: Option<i32>,
+    /// The number of payments which have been made with the mandate
+    #[schema(example = 1)]
+    pub usage_count: i32,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize, ToSchema)]
@@ -50,7 +50,7 @@ pub struct PaymentsRequest {
     #[schema(example = 6540)]
     pub amount_to_capture: Option<i64>,
     /// A timestamp (ISO 8601 code) that determines when the payment should be captured.
-    /// Providing this field will automatically set `capture` to true
+    /// Required, and must be in the future, when the `capture_method` is `scheduled`
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub capture_on: Option<PrimitiveDateTime>,
@@ -132,6 +132,10 @@ pub struct PaymentsRequest {
         "java_script_enabled":true
     }"#)]
     pub browser_info: Option<serde_json::Value>,
+    /// Whether the authorized amount of the payment can be increased later through incremental
+    /// authorizations, on the connectors which support them
+    #[schema(example = true)]
+    pub request_incremental_authorization: Option<bool>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
@@ -233,6 +237,17 @@ pub struct MandateData {
     pub customer_acceptance: CustomerAcceptance,
     /// A way to select the type of mandate used
     pub mandate_type: MandateType,
+    /// The time from which the mandate can be used for payments
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_date: Option<PrimitiveDateTime>,
+    /// The time after which the mandate expires and can no longer be used for payments
+    #[schema(example = "2023-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_date: Option<PrimitiveDateTime>,
+    /// The maximum number of payments which can be made with the mandate
+    #[schema(example = 12)]
+    pub max_usage_count: Option<i32>,
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -531,7 +546,7 @@ pub struct PhoneDetails {
     pub country_code: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, ToSchema)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentsCaptureRequest {
     /// The unique identifier for the payment
     pub payment_id: Option<String>,
@@ -541,12 +556,31 @@ pub struct PaymentsCaptureRequest {
     pub amount_to_capture: Option<i64>,
     /// Decider to refund the uncaptured amount
     pub refund_uncaptured_amount: Option<bool>,
+    /// Whether this is the last capture of the payment, releasing the amount which remains
+    /// uncaptured. Defaults to `false` for payments with the `manual_multiple` capture method and
+    /// to `true` otherwise.
+    #[schema(example = false)]
+    pub final_capture: Option<bool>,
     /// Provides information about a card payment that customers see on their statements.
     pub statement_descriptor_suffix: Option<String>,
     /// Concatenated with the statement descriptor suffix that’s set on the account to form the complete statement descriptor.
     pub statement_descriptor_prefix: Option<String>,
 }
 
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, ToSchema)]
+pub struct PaymentsIncrementalAuthorizationRequest {
+    /// The unique identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The total amount to be authorized on the payment after the increment, in the lowest
+    /// denomination of the currency. It must be greater than the amount currently authorized.
+    #[schema(example = 8000)]
+    pub amount: i64,
+    /// The reason for increasing the authorized amount
+    #[schema(max_length = 255, example = "Extended hotel stay")]
+    pub reason: Option<String>,
+}
+
 #[derive(Default, Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct UrlDetails {
     pub url: String,
@@ -574,6 +608,62 @@ pub struct NextAction {
     pub redirect_to_url: Option<String>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct CaptureResponse {
+    /// The identifier for the capture
+    #[schema(example = "cap_mbabizu24mvu3mela5njyhpit4")]
+    pub capture_id: String,
+    /// The status of the capture
+    #[schema(value_type = CaptureStatus, example = "charged")]
+    pub status: api_enums::CaptureStatus,
+    /// The amount captured by the capture, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The currency of the amount of the capture
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// Whether the capture released the amount which remained uncaptured
+    pub final_capture: bool,
+    /// The error message, if the capture failed
+    pub error_message: Option<String>,
+    /// The error code, if the capture failed
+    pub error_code: Option<String>,
+    /// Time when the capture was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct IncrementalAuthorizationResponse {
+    /// The identifier for the incremental authorization
+    #[schema(example = "auth_mbabizu24mvu3mela5njyhpit4")]
+    pub authorization_id: String,
+    /// The status of the incremental authorization
+    #[schema(value_type = AuthorizationStatus, example = "success")]
+    pub status: api_enums::AuthorizationStatus,
+    /// The total amount authorized on the payment after the increment, in the lowest
+    /// denomination of the currency
+    #[schema(example = 8000)]
+    pub amount: i64,
+    /// The amount which was authorized on the payment before the increment
+    #[schema(example = 6540)]
+    pub previously_authorized_amount: i64,
+    /// The currency of the amounts of the incremental authorization
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// The reason for increasing the authorized amount
+    pub reason: Option<String>,
+    /// The error message, if the incremental authorization failed
+    pub error_message: Option<String>,
+    /// The error code, if the incremental authorization failed
+    pub error_code: Option<String>,
+    /// Time when the incremental authorization was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
 #[derive(Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
 pub struct PaymentsResponse {
     /// Unique identifier for the payment. This ensures impotency for multiple payments
@@ -622,6 +712,12 @@ pub struct PaymentsResponse {
     /// List of refund that happened on this intent
     #[schema(value_type = Option<Vec<RefundResponse>>)]
     pub refunds: Option<Vec<refunds::RefundResponse>>,
+    /// List of captures that happened on this intent
+    #[schema(value_type = Option<Vec<CaptureResponse>>)]
+    pub captures: Option<Vec<CaptureResponse>>,
+    /// List of incremental authorizations that happened on this intent
+    #[schema(value_type = Option<Vec<IncrementalAuthorizationResponse>>)]
+    pub incremental_authorizations: Option<Vec<IncrementalAuthorizationResponse>>,
     /// A unique identifier to link the payment to a mandate, can be use instead of payment_method_data
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
@@ -652,6 +748,10 @@ pub struct PaymentsResponse {
     /// Provide a reference to a stored payment method
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payment_token: Option<String>,
+    /// Fingerprint of the card used for the payment, which is the same for all the payments of the
+    /// merchant made with the same card
+    #[schema(example = "8a5c5e5d9f1b4ab59d3f6c6c8e2b0f1a7d4e9c3b2a1f0e9d8c7b6a5f4e3d2c1b")]
+    pub card_fingerprint: Option<String>,
     /// The shipping address for the payment
     pub shipping: Option<Address>,
     /// The billing address for the payment
@@ -932,6 +1032,16 @@ impl From<PaymentsCaptureRequest> for PaymentsResponse {
     }
 }
 
+impl From<PaymentsIncrementalAuthorizationRequest> for PaymentsResponse {
+    // After removing the request from the payments_to_payments_response this will no longer be needed
+    fn from(item: PaymentsIncrementalAuthorizationRequest) -> Self {
+        Self {
+            payment_id: Some(item.payment_id),
+            ..Self::default()
+        }
+    }
+}
+
 impl From<Card> for CardResponse {
     fn from(card: Card) -> Self {
         let card_number_length = card.card_number.peek().clone().len();
@@ -1 +1,180 @@
+use common_utils::{custom_serde, pii};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutCreateRequest {
+    /// Unique identifier for the payout. This ensures idempotency for multiple payouts that have been done by a single merchant. If not provided, it is auto generated and returned in the API response.
+    #[schema(max_length = 30, example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: Option<String>,
+
+    /// The payout amount in the lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc.
+    #[schema(example = 6540)]
+    pub amount: i64,
+
+    /// The currency of the payout
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The connector through which the payout is to be processed. If not provided, the connector configured in the merchant's routing algorithm is used
+    #[schema(value_type = Option<Connector>, example = "adyen")]
+    pub connector: Option<api_enums::Connector>,
+
+    /// The type of the payout destination
+    #[schema(value_type = PayoutType, example = "bank")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The details of the payout destination
+    pub payout_method_data: PayoutMethodData,
+
+    /// The identifier for the customer receiving the payout
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The email address of the customer receiving the payout
+    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+
+    /// Whether the payout should be fulfilled right after it is created. If set to false, the payout stays in `requires_fulfillment` until the fulfill endpoint is called
+    #[schema(default = false, example = true)]
+    pub auto_fulfill: Option<bool>,
+
+    /// A description of the payout
+    #[schema(example = "Seller payout for January")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMethodData {
+    Card(CardPayout),
+    Bank(BankPayout),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CardPayout {
+    /// The card number
+    #[schema(value_type = String, example = "4111111111111111")]
+    pub card_number: Secret<String, pii::CardNumber>,
+    /// The card's expiry month
+    #[schema(value_type = String, example = "03")]
+    pub expiry_month: Secret<String>,
+    /// The card's expiry year
+    #[schema(value_type = String, example = "2030")]
+    pub expiry_year: Secret<String>,
+    /// The card holder's name
+    #[schema(value_type = String, example = "John Test")]
+    pub card_holder_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct BankPayout {
+    /// The International Bank Account Number of the beneficiary
+    #[schema(value_type = String, example = "NL13TEST0123456789")]
+    pub iban: Secret<String>,
+    /// The name of the account holder
+    #[schema(value_type = String, example = "John Test")]
+    pub owner_name: Secret<String>,
+    /// The two-letter ISO country code of the bank
+    #[schema(example = "NL")]
+    pub country_code: String,
+    /// The name of the bank
+    #[schema(example = "Deutsche Bank")]
+    pub bank_name: Option<String>,
+    /// The city in which the bank is located
+    #[schema(example = "Amsterdam")]
+    pub bank_city: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutUpdateRequest {
+    /// A description of the payout
+    #[schema(example = "Seller payout for January")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct PayoutResponse {
+    /// The identifier for the payout
+    pub payout_id: String,
+    /// The identifier for the merchant account
+    pub merchant_id: String,
+    /// The identifier for the customer receiving the payout
+    pub customer_id: Option<String>,
+    /// The connector through which the payout was processed
+    pub connector: String,
+    /// The payout amount in the lowest denomination of the currency
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    /// The type of the payout destination
+    #[schema(value_type = PayoutType)]
+    pub payout_type: api_enums::PayoutType,
+    /// The status of the payout
+    #[schema(value_type = PayoutStatus)]
+    pub status: api_enums::PayoutStatus,
+    /// A description of the payout
+    pub description: Option<String>,
+    /// Metadata attached to the payout
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    /// The error message, if the payout failed
+    pub error_message: Option<String>,
+    /// The error code, if the payout failed
+    pub error_code: Option<String>,
+    /// The timestamp at which the payout was created
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutListRequest {
+    /// The identifier for the customer
+    pub customer_id: Option<String>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutListResponse {
+    /// The number of payouts included in the list
+    pub size: usize,
+    /// The list of payouts
+    pub data: Vec<PayoutResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutAccount {
+    /// The connector through which payouts can be made
+    #[schema(example = "adyen")]
+    pub connector: String,
+    /// The identifier for the merchant connector account
+    pub merchant_connector_id: String,
+    /// Whether the connector account is in test mode
+    pub test_mode: Option<bool>,
+    /// Whether the connector account is disabled
+    pub disabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutAccountsResponse {
+    /// The number of payout accounts included in the list
+    pub size: usize,
+    /// The list of payout accounts
+    pub data: Vec<PayoutAccount>,
+}
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The HTTP method of the request forwarded to the destination
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ProxyMethod {
+    Get,
+    #[default]
+    Post,
+    Put,
+    Delete,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProxyRequest {
+    /// The URL the request is forwarded to. It must be within one of the destinations allowed for
+    /// the application.
+    #[schema(example = "https://api.fraud-vendor.com/v1/screen")]
+    pub destination_url: String,
+    /// The HTTP method of the forwarded request, defaults to `POST`
+    #[serde(default)]
+    pub method: ProxyMethod,
+    /// Headers sent along with the forwarded request. Placeholders are not substituted in
+    /// headers. Headers describing the connection, such as `Host`, `Content-Length`,
+    /// `Transfer-Encoding` and `Connection`, are set for the forwarded request and are not taken
+    /// from these headers.
+    #[schema(value_type = Option<Object>, example = json!({ "Authorization": "Bearer sk_vendor" }))]
+    pub headers: Option<HashMap<String, String>>,
+    /// The body of the forwarded request. Placeholders of the form
+    /// `{{<payment_method_id>.<field>}}` in string values are substituted with the card data of
+    /// the payment method. The supported fields are `card_number`, `card_exp_month`,
+    /// `card_exp_year`, `card_holder_name` and `card_cvc`.
+    #[schema(value_type = Option<Object>, example = json!({
+        "card": {
+            "number": "{{pm_mbabizu24mvu3mela5njyhpit4.card_number}}",
+            "exp_month": "{{pm_mbabizu24mvu3mela5njyhpit4.card_exp_month}}",
+            "exp_year": "{{pm_mbabizu24mvu3mela5njyhpit4.card_exp_year}}"
+        }
+    }))]
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProxyResponse {
+    /// The HTTP status code returned by the destination
+    #[schema(example = 200)]
+    pub status_code: u16,
+    /// The body returned by the destination, with the card data substituted in the request
+    /// masked. A body which is not JSON is returned as a string.
+    #[schema(value_type = Object, example = json!({ "risk_score": 12 }))]
+    pub response: serde_json::Value,
+}
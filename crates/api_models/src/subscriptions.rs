@@ -0,0 +1,97 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier for the customer being billed
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The identifier for the mandate through which the customer is charged at every cycle. The
+    /// mandate must be active, belong to the customer and allow the currency of the subscription.
+    #[schema(example = "mandate_end38934n12s923d0")]
+    pub mandate_id: String,
+    /// The amount charged at every cycle, in the lowest denomination of the currency
+    #[schema(minimum = 1, example = 6540)]
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// The unit of the billing interval
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub interval: enums::SubscriptionInterval,
+    /// The number of intervals between two cycles, defaults to 1
+    #[schema(minimum = 1, example = 1)]
+    pub interval_count: Option<i32>,
+    /// The end of the trial period, the customer is first charged at this time. The customer is
+    /// charged right away when no trial end is provided.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+    /// The time after which the customer is no longer charged and the subscription ends
+    #[schema(example = "2023-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub end_date: Option<PrimitiveDateTime>,
+    /// A description of the subscription, passed on to the payments of every cycle
+    #[schema(max_length = 255, example = "Monthly plan")]
+    pub description: Option<String>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to
+    /// 500 characters long. Metadata is useful for storing additional, structured information on
+    /// an object.
+    #[schema(value_type = Option<Object>, example = json!({ "plan": "premium" }))]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// The identifier for the subscription
+    pub subscription_id: String,
+    /// The identifier for the customer being billed
+    pub customer_id: String,
+    /// The identifier for the mandate through which the customer is charged
+    pub mandate_id: String,
+    /// Status of the subscription
+    #[schema(value_type = SubscriptionStatus)]
+    pub status: enums::SubscriptionStatus,
+    /// The amount charged at every cycle, in the lowest denomination of the currency
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency)]
+    pub currency: enums::Currency,
+    /// The unit of the billing interval
+    #[schema(value_type = SubscriptionInterval)]
+    pub interval: enums::SubscriptionInterval,
+    /// The number of intervals between two cycles
+    pub interval_count: i32,
+    /// The end of the trial period
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+    /// The time after which the customer is no longer charged
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub end_date: Option<PrimitiveDateTime>,
+    /// Start of the current period
+    #[serde(with = "custom_serde::iso8601")]
+    pub current_period_start: PrimitiveDateTime,
+    /// End of the current period, the customer is charged for the next period at this time
+    #[serde(with = "custom_serde::iso8601")]
+    pub current_period_end: PrimitiveDateTime,
+    /// Number of consecutive failed payments of the current cycle
+    pub failed_payment_attempts: i32,
+    /// The identifier for the payment of the latest cycle
+    pub latest_payment_id: Option<String>,
+    /// A description of the subscription
+    pub description: Option<String>,
+    /// Metadata provided while creating the subscription
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    /// Time at which the subscription was cancelled
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    /// Time at which the subscription was created
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,75 @@
+use common_utils::custom_serde;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// Whether a token can be detokenized once or until it expires.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenUsage {
+    /// The token is deleted once it is detokenized.
+    SingleUse,
+    /// The token can be detokenized any number of times until it expires.
+    #[default]
+    MultiUse,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TokenCreateRequest {
+    /// The sensitive data to tokenize, e.g. a bank account number or a national identifier
+    #[schema(value_type = Object, example = json!({ "account_number": "000123456789" }))]
+    pub data: Secret<serde_json::Value>,
+    /// A label describing the kind of data being tokenized
+    #[schema(max_length = 64, example = "bank_account_number")]
+    pub data_type: Option<String>,
+    /// Time for which the token can be detokenized, in seconds. Defaults to the TTL configured
+    /// for the application and cannot exceed the maximum TTL configured for the application.
+    #[schema(minimum = 1, example = 3600)]
+    pub ttl: Option<i64>,
+    /// Whether the token can be detokenized once or until it expires, defaults to `multi_use`
+    #[serde(default)]
+    pub usage: TokenUsage,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TokenResponse {
+    /// The identifier for the token
+    #[schema(example = "tok_mbabizu24mvu3mela5njyhpit4")]
+    pub token_id: String,
+    /// The label describing the kind of data tokenized
+    #[schema(example = "bank_account_number")]
+    pub data_type: Option<String>,
+    /// Whether the token can be detokenized once or until it expires
+    pub usage: TokenUsage,
+    /// The time at which the token was created
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    /// The time after which the token can no longer be detokenized
+    #[serde(with = "custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DetokenizeResponse {
+    /// The identifier for the token
+    #[schema(example = "tok_mbabizu24mvu3mela5njyhpit4")]
+    pub token_id: String,
+    /// The label describing the kind of data tokenized
+    #[schema(example = "bank_account_number")]
+    pub data_type: Option<String>,
+    /// The sensitive data behind the token
+    #[schema(value_type = Object, example = json!({ "account_number": "000123456789" }))]
+    pub data: Secret<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TokenDeleteResponse {
+    /// The identifier for the token
+    #[schema(example = "tok_mbabizu24mvu3mela5njyhpit4")]
+    pub token_id: String,
+    /// Whether the token was deleted
+    #[schema(example = true)]
+    pub deleted: bool,
+}
@@ -2,19 +2,34 @@ use common_utils::custom_serde;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{enums as api_enums, payments};
+use crate::{disputes, enums as api_enums, payments, refunds, subscriptions};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum IncomingWebhookEvent {
     PaymentIntentFailure,
     PaymentIntentSuccess,
+    RefundSuccess,
+    RefundFailure,
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+    PayoutSuccess,
+    PayoutFailure,
+    PayoutCancelled,
+    PayoutReversed,
 }
 
 pub enum WebhookFlow {
     Payment,
     Refund,
+    Dispute,
     Subscription,
+    Payout,
 }
 
 impl From<IncomingWebhookEvent> for WebhookFlow {
@@ -22,6 +37,20 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
         match evt {
             IncomingWebhookEvent::PaymentIntentFailure => Self::Payment,
             IncomingWebhookEvent::PaymentIntentSuccess => Self::Payment,
+            IncomingWebhookEvent::RefundSuccess | IncomingWebhookEvent::RefundFailure => {
+                Self::Refund
+            }
+            IncomingWebhookEvent::DisputeOpened
+            | IncomingWebhookEvent::DisputeAccepted
+            | IncomingWebhookEvent::DisputeExpired
+            | IncomingWebhookEvent::DisputeCancelled
+            | IncomingWebhookEvent::DisputeChallenged
+            | IncomingWebhookEvent::DisputeWon
+            | IncomingWebhookEvent::DisputeLost => Self::Dispute,
+            IncomingWebhookEvent::PayoutSuccess
+            | IncomingWebhookEvent::PayoutFailure
+            | IncomingWebhookEvent::PayoutCancelled
+            | IncomingWebhookEvent::PayoutReversed => Self::Payout,
         }
     }
 }
@@ -33,6 +62,15 @@ pub struct IncomingWebhookDetails {
     pub resource_object: Vec<u8>,
 }
 
+/// Webhook sent to the merchant on the occurrence of an event.
+///
+/// When the merchant has a webhook signing secret, every delivery carries the
+/// `X-Webhook-Timestamp` header, holding the UNIX time of the delivery in seconds, and the
+/// `X-Webhook-Signature` header, holding the hex encoded HMAC-SHA512 of
+/// `{timestamp}.{request body}` keyed with the secret. To protect against replayed deliveries,
+/// receivers should recompute the signature over the raw request body, compare it in constant
+/// time, reject deliveries whose timestamp is more than a few minutes old, and ignore
+/// `event_id`s which were already processed, as retried deliveries carry the same `event_id`.
 #[derive(Debug, Clone, Serialize)]
 pub struct OutgoingWebhook {
     pub merchant_id: String,
@@ -47,4 +85,7 @@ pub struct OutgoingWebhook {
 #[serde(tag = "type", content = "object", rename_all = "snake_case")]
 pub enum OutgoingWebhookContent {
     PaymentDetails(payments::PaymentsResponse),
+    RefundDetails(refunds::RefundResponse),
+    DisputeDetails(Box<disputes::DisputeResponse>),
+    SubscriptionDetails(Box<subscriptions::SubscriptionResponse>),
 }
@@ -15,7 +15,10 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{
+        HashesInterface, KeysInterface, ListInterface, LuaInterface, SetsInterface,
+        StreamsInterface,
+    },
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse,
@@ -29,6 +32,26 @@ use crate::{
     types::{HsetnxReply, MsetnxReply, RedisEntryId, SetnxReply},
 };
 
+const DELETE_KEY_IF_VALUE_MATCHES_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("DEL", KEYS[1])
+end
+return 0
+"#;
+
+const SET_EXPIRY_IF_VALUE_MATCHES_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("EXPIRE", KEYS[1], ARGV[2])
+end
+return 0
+"#;
+
+const PUSH_TO_CAPPED_LIST_WITH_EXPIRY_SCRIPT: &str = r#"
+redis.call("LPUSH", KEYS[1], ARGV[1])
+redis.call("LTRIM", KEYS[1], 0, ARGV[2])
+return redis.call("EXPIRE", KEYS[1], ARGV[3])
+"#;
+
 impl super::RedisConnectionPool {
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_key<V>(&self, key: &str, value: V) -> CustomResult<(), errors::RedisError>
@@ -171,6 +194,55 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
+    /// Deletes the key if it holds the given value, returning whether the key was deleted.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_key_if_value_matches(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> CustomResult<bool, errors::RedisError> {
+        let deleted_keys: i64 = self
+            .pool
+            .eval(DELETE_KEY_IF_VALUE_MATCHES_SCRIPT, vec![key], vec![value])
+            .await
+            .into_report()
+            .change_context(errors::RedisError::DeleteFailed)?;
+
+        Ok(deleted_keys > 0)
+    }
+
+    /// Sets the expiry of the key if it holds the given value, returning whether the expiry was
+    /// set.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_expiry_if_value_matches(
+        &self,
+        key: &str,
+        value: &str,
+        seconds: i64,
+    ) -> CustomResult<bool, errors::RedisError> {
+        let is_expiry_set: i64 = self
+            .pool
+            .eval(
+                SET_EXPIRY_IF_VALUE_MATCHES_SCRIPT,
+                vec![key],
+                vec![value.to_owned(), seconds.to_string()],
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetExpiryFailed)?;
+
+        Ok(is_expiry_set > 0)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -370,6 +442,81 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::JsonDeserializationFailed)
     }
 
+    /// Prepends the element to the list, trims the list to its first `max_length` elements and
+    /// sets the expiry of the list, atomically.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn push_to_capped_list_with_expiry(
+        &self,
+        key: &str,
+        element: &str,
+        max_length: usize,
+        seconds: i64,
+    ) -> CustomResult<(), errors::RedisError> {
+        let last_index = i64::try_from(max_length)
+            .unwrap_or(i64::MAX)
+            .saturating_sub(1);
+        let _: i64 = self
+            .pool
+            .eval(
+                PUSH_TO_CAPPED_LIST_WITH_EXPIRY_SCRIPT,
+                vec![key],
+                vec![
+                    element.to_owned(),
+                    last_index.to_string(),
+                    seconds.to_string(),
+                ],
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::AppendElementsToListFailed)?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_list_elements(
+        &self,
+        key: &str,
+        start: i64,
+        stop: i64,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        self.pool
+            .lrange(key, start, stop)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetListElementsFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn add_member_to_set(
+        &self,
+        key: &str,
+        member: &str,
+    ) -> CustomResult<(), errors::RedisError> {
+        let _: i64 = self
+            .pool
+            .sadd(key, member.to_owned())
+            .await
+            .into_report()
+            .change_context(errors::RedisError::AddMembersToSetFailed)?;
+
+        // the set expires along with the hashes of its members
+        self.set_expiry(key, self.config.default_hash_ttl.into())
+            .await
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_set_members(
+        &self,
+        key: &str,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        self.pool
+            .smembers(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetSetMembersFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
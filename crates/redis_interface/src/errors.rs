@@ -12,6 +12,8 @@ pub enum RedisError {
     SetExFailed,
     #[error("Failed to set expiry for key value in Redis")]
     SetExpiryFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to get key value in Redis")]
     GetFailed,
     #[error("Failed to delete key value in Redis")]
@@ -48,6 +50,14 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to append elements to Redis list")]
+    AppendElementsToListFailed,
+    #[error("Failed to get elements of Redis list")]
+    GetListElementsFailed,
+    #[error("Failed to add members to Redis set")]
+    AddMembersToSetFailed,
+    #[error("Failed to get members of Redis set")]
+    GetSetMembersFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
@@ -97,6 +97,30 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such API key")]
     ApiKeyNotFound,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payout")]
+    PayoutNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such dispute")]
+    DisputeNotFound { dispute_id: String },
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such subscription")]
+    SubscriptionNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such customer erasure")]
+    CustomerErasureNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such event")]
+    EventNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such token")]
+    TokenNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such file")]
+    FileNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "File not available")]
+    FileNotAvailable,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "parameter_missing", message = "Return url is not available")]
     ReturnUrlUnavailable,
 
@@ -168,6 +192,28 @@ pub enum StripeErrorCode {
     PaymentIntentMandateInvalid { message: String },
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payment with the specified payment_id '{payment_id}' already exists in our records.")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payout with the specified payout_id '{payout_id}' already exists in our records.")]
+    DuplicatePayout { payout_id: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_error", message = "Keys for idempotent requests can only be used with the same parameters they were first used with.")]
+    IdempotencyKeyMismatch,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "There is currently another in-progress request using this idempotency key.")]
+    IdempotencyKeyInUse,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "lock_timeout", message = "This object cannot be accessed right now because another API request is currently accessing it.")]
+    LockTimeout,
+    #[error(error_type = StripeErrorType::ApiError, code = "payout_failed", message = "payout has failed")]
+    PayoutFailed,
+    #[error(error_type = StripeErrorType::ApiError, code = "dispute_failure", message = "Dispute operation failed")]
+    DisputeFailed,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "{reason}")]
+    DisputeStatusValidationFailed { reason: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "File validation failed: {reason}")]
+    FileValidationFailed { reason: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "parameter_missing", message = "Missing file")]
+    MissingFile,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "parameter_missing", message = "Missing purpose")]
+    MissingFilePurpose,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "parameter_missing", message = "Missing file content type")]
+    MissingFileContentType,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -210,7 +256,6 @@ pub enum StripeErrorCode {
         DebitNotAuthorized,
         EmailInvalid,
         ExpiredCard,
-        IdempotencyKeyInUse,
         IncorrectAddress,
         IncorrectCvc,
         IncorrectNumber,
@@ -238,7 +283,6 @@ pub enum StripeErrorCode {
         InvoicePaymentIntentRequiresAction,
         InvoiceUpcomingNone,
         LivemodeMismatch,
-        LockTimeout,
         Missing,
         NoAccount,
         NotAllowedOnStandardAccount,
@@ -367,6 +411,8 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::CardExpired { data } => Self::ExpiredCard,
             errors::ApiErrorResponse::RefundNotPossible { connector } => Self::RefundFailed,
             errors::ApiErrorResponse::RefundFailed { data } => Self::RefundFailed, // Nothing at stripe to map
+            errors::ApiErrorResponse::PayoutFailed { data } => Self::PayoutFailed,
+            errors::ApiErrorResponse::DisputeFailed { data } => Self::DisputeFailed,
 
             errors::ApiErrorResponse::InternalServerError => Self::InternalServerError, // not a stripe code
             errors::ApiErrorResponse::ExternalConnectorError { .. } => Self::InternalServerError,
@@ -387,6 +433,28 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             }
             errors::ApiErrorResponse::MandateNotFound => Self::MandateNotFound,
             errors::ApiErrorResponse::ApiKeyNotFound => Self::ApiKeyNotFound,
+            errors::ApiErrorResponse::PayoutNotFound => Self::PayoutNotFound,
+            errors::ApiErrorResponse::DisputeNotFound { dispute_id } => {
+                Self::DisputeNotFound { dispute_id }
+            }
+            errors::ApiErrorResponse::SubscriptionNotFound => Self::SubscriptionNotFound,
+            errors::ApiErrorResponse::CustomerErasureNotFound => Self::CustomerErasureNotFound,
+            errors::ApiErrorResponse::EventNotFound => Self::EventNotFound,
+            errors::ApiErrorResponse::TokenNotFound => Self::TokenNotFound,
+            errors::ApiErrorResponse::IdempotencyKeyMismatch => Self::IdempotencyKeyMismatch,
+            errors::ApiErrorResponse::IdempotencyKeyInUse => Self::IdempotencyKeyInUse,
+            errors::ApiErrorResponse::ResourceBusy => Self::LockTimeout,
+            errors::ApiErrorResponse::DisputeStatusValidationFailed { reason } => {
+                Self::DisputeStatusValidationFailed { reason }
+            }
+            errors::ApiErrorResponse::FileNotFound => Self::FileNotFound,
+            errors::ApiErrorResponse::FileNotAvailable => Self::FileNotAvailable,
+            errors::ApiErrorResponse::FileValidationFailed { reason } => {
+                Self::FileValidationFailed { reason }
+            }
+            errors::ApiErrorResponse::MissingFile => Self::MissingFile,
+            errors::ApiErrorResponse::MissingFilePurpose => Self::MissingFilePurpose,
+            errors::ApiErrorResponse::MissingFileContentType => Self::MissingFileContentType,
             errors::ApiErrorResponse::MandateValidationFailed { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
             }
@@ -429,6 +497,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::DuplicatePayment { payment_id } => {
                 Self::DuplicatePayment { payment_id }
             }
+            errors::ApiErrorResponse::DuplicatePayout { payout_id } => {
+                Self::DuplicatePayout { payout_id }
+            }
         }
     }
 }
@@ -458,6 +529,19 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::MerchantConnectorAccountNotFound
             | Self::MandateNotFound
             | Self::ApiKeyNotFound
+            | Self::PayoutNotFound
+            | Self::DisputeNotFound { .. }
+            | Self::SubscriptionNotFound
+            | Self::CustomerErasureNotFound
+            | Self::EventNotFound
+            | Self::TokenNotFound
+            | Self::DisputeStatusValidationFailed { .. }
+            | Self::FileNotFound
+            | Self::FileNotAvailable
+            | Self::FileValidationFailed { .. }
+            | Self::MissingFile
+            | Self::MissingFilePurpose
+            | Self::MissingFileContentType
             | Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
@@ -474,11 +558,17 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::ResourceIdNotFound
             | Self::PaymentIntentMandateInvalid { .. }
             | Self::PaymentIntentUnexpectedState { .. }
-            | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST,
+            | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. } => StatusCode::BAD_REQUEST,
             Self::RefundFailed
+            | Self::PayoutFailed
+            | Self::DisputeFailed
             | Self::InternalServerError
             | Self::MandateActive
             | Self::CustomerRedacted => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::IdempotencyKeyMismatch => StatusCode::BAD_REQUEST,
+            Self::IdempotencyKeyInUse => StatusCode::CONFLICT,
+            Self::LockTimeout => StatusCode::TOO_MANY_REQUESTS,
             Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
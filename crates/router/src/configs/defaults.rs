@@ -29,6 +29,7 @@ impl Default for super::settings::Secrets {
         Self {
             jwt_secret: "secret".into(),
             admin_api_key: "test_admin".into(),
+            card_fingerprint_secret: "card_fingerprint_secret".into(),
         }
     }
 }
@@ -72,6 +73,84 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::FileUploadConfig {
+    fn default() -> Self {
+        Self {
+            file_storage_backend: super::settings::FileStorageBackend::Local,
+            local_storage_path: "files".into(),
+            max_file_size: 5 * 1024 * 1024, // Uploaded files are limited to 5MiB
+        }
+    }
+}
+
+impl Default for super::settings::PaymentRetry {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_attempts: 3,
+        }
+    }
+}
+
+impl Default for super::settings::SuccessRateRouting {
+    fn default() -> Self {
+        Self {
+            window_size: 100,
+            window_ttl: 24 * 60 * 60, // Outcomes are discarded after a day without payments
+        }
+    }
+}
+
+impl Default for super::settings::LockSettings {
+    fn default() -> Self {
+        Self {
+            lock_ttl: 30,
+            lock_retries: 5,
+            retry_delay: 200,
+        }
+    }
+}
+
+impl Default for super::settings::AuthorizationExpiry {
+    fn default() -> Self {
+        Self {
+            default_days: 7,
+            connectors: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::SubscriptionSettings {
+    fn default() -> Self {
+        Self {
+            dunning_max_retries: 3,
+            dunning_retry_interval: 24 * 60 * 60, // Failed payments are retried daily
+        }
+    }
+}
+
+impl Default for super::settings::LocalVault {
+    fn default() -> Self {
+        Self {
+            key_file: String::new(),
+            cvc_ttl: 15 * 60,
+            token_ttl: 15 * 60,
+            data_token_ttl: 60 * 60,
+            data_token_max_ttl: 90 * 24 * 60 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::WebhooksSettings {
+    fn default() -> Self {
+        Self {
+            outgoing_enabled: false,
+            outgoing_retry_initial_delay: 60,
+            outgoing_max_retries: 10,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
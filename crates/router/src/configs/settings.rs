@@ -51,6 +51,15 @@ pub struct Settings {
     pub drainer: DrainerSettings,
     pub jwekey: Jwekey,
     pub webhooks: WebhooksSettings,
+    pub file_upload: FileUploadConfig,
+    pub payment_retry: PaymentRetry,
+    pub success_rate_routing: SuccessRateRouting,
+    pub lock_settings: LockSettings,
+    pub authorization_expiry: AuthorizationExpiry,
+    pub subscriptions: SubscriptionSettings,
+    pub connector_credentials: ConnectorCredentials,
+    pub local_vault: LocalVault,
+    pub vault_proxy: VaultProxy,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -58,6 +67,8 @@ pub struct Settings {
 pub struct Secrets {
     pub jwt_secret: String,
     pub admin_api_key: String,
+    /// Key of the HMAC computing card fingerprints
+    pub card_fingerprint_secret: String,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -75,6 +86,113 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentRetry {
+    /// Whether failed authorizations are retried with the next connector of the merchant
+    pub enabled: bool,
+    /// Maximum number of attempts made for a payment, including the first one
+    pub max_attempts: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct LockSettings {
+    /// Time after which a lock which is not extended by its holder expires, in seconds
+    pub lock_ttl: i64,
+    /// Number of times the acquisition of a held lock is retried before giving up
+    pub lock_retries: u32,
+    /// Time waited between two attempts of acquiring a held lock, in milliseconds
+    pub retry_delay: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AuthorizationExpiry {
+    /// Time after which uncaptured authorizations are voided, for connectors without a specific
+    /// expiry, in days
+    pub default_days: i64,
+    /// Time after which uncaptured authorizations are voided, by connector name, in days
+    pub connectors: std::collections::HashMap<String, i64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SubscriptionSettings {
+    /// Number of times the failed payment of a cycle is retried before the subscription is
+    /// cancelled
+    pub dunning_max_retries: i32,
+    /// Time waited between two retries of the failed payment of a cycle, in seconds
+    pub dunning_retry_interval: i64,
+}
+
+/// Master key wrapping the data keys which encrypt the connector account details of merchants
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorCredentials {
+    /// Path of the file holding the hex encoded 256 bit master key
+    #[cfg(not(feature = "kms"))]
+    pub master_key_file: String,
+    /// Identifier of the KMS key used as the master key
+    #[cfg(feature = "kms")]
+    pub kms_key_id: String,
+    /// Region of the KMS key used as the master key
+    #[cfg(feature = "kms")]
+    pub kms_region: String,
+}
+
+/// Vault storing card data in the application itself, used when the mock locker is enabled
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct LocalVault {
+    /// Path of the file holding the hex encoded 256 bit vault key. It must differ from the keys
+    /// protecting the rest of the application data.
+    pub key_file: String,
+    /// Time for which the card security code of a saved card is kept, in seconds
+    pub cvc_ttl: i64,
+    /// Time for which a temporary payment method token is kept, in seconds
+    pub token_ttl: i64,
+    /// Time for which data tokenized through the tokens API is kept when no TTL is requested, in
+    /// seconds
+    pub data_token_ttl: i64,
+    /// Maximum time for which data tokenized through the tokens API may be kept, in seconds
+    pub data_token_max_ttl: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct VaultProxy {
+    /// Base URLs of the destinations to which requests can be forwarded by the vault proxy
+    pub allowed_destinations: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SuccessRateRouting {
+    /// Number of recent payment outcomes on which the success rate of a connector is computed
+    pub window_size: usize,
+    /// Time after which the outcomes of a connector are discarded if no payment is made, in seconds
+    pub window_ttl: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FileUploadConfig {
+    pub file_storage_backend: FileStorageBackend,
+    /// Directory in which the local file storage backend stores uploaded files
+    pub local_storage_path: String,
+    /// Maximum size of an uploaded file, in bytes
+    pub max_file_size: usize,
+}
+
+#[derive(Debug, Deserialize, Clone, Default, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FileStorageBackend {
+    #[default]
+    Local,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -159,6 +277,9 @@ pub struct Connectors {
 #[serde(default)]
 pub struct ConnectorParams {
     pub base_url: String,
+    /// Base URL for connector APIs that are hosted separately from the primary API, such as payouts
+    /// or file uploads.
+    pub secondary_base_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -198,10 +319,15 @@ pub struct DrainerSettings {
     pub loop_interval: u32,     // in milliseconds
 }
 
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
+    /// Delay before the first retry of an outgoing webhook which was not delivered, in seconds.
+    /// The delay is doubled for every subsequent retry.
+    pub outgoing_retry_initial_delay: i32,
+    /// Maximum number of times the delivery of an outgoing webhook is retried
+    pub outgoing_max_retries: i32,
 }
 
 impl Settings {
@@ -234,7 +360,8 @@ impl Settings {
                     .separator("__")
                     .list_separator(",")
                     .with_list_parse_key("redis.cluster_urls")
-                    .with_list_parse_key("connectors.supported.wallets"),
+                    .with_list_parse_key("connectors.supported.wallets")
+                    .with_list_parse_key("vault_proxy.allowed_destinations"),
             )
             .build()?;
 
@@ -278,6 +405,16 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.jwekey.validate()?;
+        self.file_upload.validate()?;
+        self.payment_retry.validate()?;
+        self.success_rate_routing.validate()?;
+        self.webhooks.validate()?;
+        self.lock_settings.validate()?;
+        self.authorization_expiry.validate()?;
+        self.subscriptions.validate()?;
+        self.connector_credentials.validate()?;
+        self.local_vault.validate()?;
+        self.vault_proxy.validate()?;
 
         Ok(())
     }
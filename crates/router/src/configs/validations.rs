@@ -16,6 +16,12 @@ impl super::settings::Secrets {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "admin API key must not be empty".into(),
             ))
+        })?;
+
+        when(self.card_fingerprint_secret.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card fingerprint secret must not be empty".into(),
+            ))
         })
     }
 }
@@ -61,6 +67,35 @@ impl super::settings::Jwekey {
     }
 }
 
+impl super::settings::ConnectorCredentials {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        #[cfg(not(feature = "kms"))]
+        common_utils::fp_utils::when(self.master_key_file.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector credentials master key file must not be empty".into(),
+            ))
+        })?;
+
+        #[cfg(feature = "kms")]
+        common_utils::fp_utils::when(self.kms_key_id.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector credentials KMS key ID must not be empty when KMS feature is enabled"
+                    .into(),
+            ))
+        })?;
+
+        #[cfg(feature = "kms")]
+        common_utils::fp_utils::when(self.kms_region.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector credentials KMS region must not be empty when KMS feature is enabled"
+                    .into(),
+            ))
+        })?;
+
+        Ok(())
+    }
+}
+
 impl super::settings::Server {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         common_utils::fp_utils::when(self.host.is_default_or_empty(), || {
@@ -184,3 +219,171 @@ impl super::settings::DrainerSettings {
         })
     }
 }
+
+impl super::settings::FileUploadConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            matches!(
+                self.file_storage_backend,
+                super::settings::FileStorageBackend::Local
+            ) && self.local_storage_path.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "local storage path must not be empty when the local file storage backend is used"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(self.max_file_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "maximum file size must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::PaymentRetry {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.max_attempts < 2, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "maximum payment attempts must be at least 2 when payment retries are enabled"
+                    .into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::SuccessRateRouting {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.window_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "success rate window size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.window_ttl <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "success rate window TTL must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::WebhooksSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.outgoing_retry_initial_delay <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "outgoing webhook retry delay must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.outgoing_max_retries < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "maximum outgoing webhook retries cannot be negative".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::LockSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.lock_ttl <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "lock TTL must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::AuthorizationExpiry {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.default_days <= 0 || self.connectors.values().any(|days| *days <= 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "authorization expiry must be greater than zero".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::LocalVault {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.key_file.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "local vault key file must not be empty".into(),
+            ))
+        })?;
+
+        when(self.cvc_ttl <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "local vault CVC TTL must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.token_ttl <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "local vault token TTL must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.data_token_ttl <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "local vault data token TTL must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.data_token_max_ttl < self.data_token_ttl, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "local vault data token max TTL must not be less than the data token TTL".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::VaultProxy {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.allowed_destinations
+            .iter()
+            .try_for_each(|destination| match url::Url::parse(destination) {
+                Ok(url) if url.has_host() && matches!(url.scheme(), "http" | "https") => Ok(()),
+                _ => Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "vault proxy destination {destination} is not a valid HTTP URL"
+                ))),
+            })
+    }
+}
+
+impl super::settings::SubscriptionSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.dunning_max_retries < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "dunning max retries must not be negative".into(),
+            ))
+        })?;
+
+        when(self.dunning_retry_interval <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "dunning retry interval must be greater than zero".into(),
+            ))
+        })
+    }
+}
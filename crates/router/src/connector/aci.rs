@@ -95,6 +95,18 @@ impl
     // Not Implemented (R)
 }
 
+impl api::PaymentIncrementalAuthorization for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Aci
@@ -412,6 +424,98 @@ impl
     }
 }
 
+impl api::Payouts for Aci {}
+impl api::PayoutCreate for Aci {}
+impl api::PayoutFulfill for Aci {}
+impl api::PayoutCancel for Aci {}
+impl api::PayoutReverse for Aci {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Aci {}
+impl api::AcceptDispute for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Aci {}
+impl api::UploadFile for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Aci {}
 impl api::RefundExecute for Aci {}
 impl api::RefundSync for Aci {}
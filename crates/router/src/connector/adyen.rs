@@ -45,6 +45,11 @@ impl ConnectorCommon for Adyen {
     fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
         connectors.adyen.base_url.as_ref()
     }
+
+    fn is_retryable_error(&self, error: &types::ErrorResponse) -> bool {
+        // Refusal reason codes 4 (Acquirer Error) and 9 (Issuer Unavailable) are soft declines
+        matches!(error.code.as_str(), "4" | "9")
+    }
 }
 
 impl api::Payment for Adyen {}
@@ -184,6 +189,113 @@ impl
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Adyen {
+    fn supports_incremental_authorization(&self) -> bool {
+        true
+    }
+}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let id = req.request.connector_transaction_id.as_str();
+        Ok(format!(
+            "{}{}/{}/amountUpdates",
+            self.base_url(connectors),
+            "v68/payments",
+            id
+        ))
+    }
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenAmountUpdateRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenAmountUpdateRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+    fn build_request(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsIncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsIncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsIncrementalAuthorizationType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+    fn handle_response(
+        &self,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
+    {
+        let response: adyen::AdyenAmountUpdateResponse = res
+            .response
+            .parse_struct("AdyenAmountUpdateResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("adyen::ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Adyen
@@ -530,6 +642,384 @@ impl
     }
 }
 
+impl api::Payouts for Adyen {
+    fn supports_payouts(&self) -> bool {
+        true
+    }
+}
+impl api::PayoutCreate for Adyen {}
+impl api::PayoutFulfill for Adyen {}
+impl api::PayoutCancel for Adyen {}
+impl api::PayoutReverse for Adyen {}
+
+/// Adyen serves the payouts API from a different host than the checkout API.
+fn get_payout_base_url(
+    connectors: &settings::Connectors,
+) -> CustomResult<&str, errors::ConnectorError> {
+    connectors
+        .adyen
+        .secondary_base_url
+        .as_deref()
+        .ok_or(errors::ConnectorError::FailedToObtainIntegrationUrl)
+        .into_report()
+        .attach_printable("Adyen payout base URL is not configured")
+}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PayoutCreateType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/storeDetailAndSubmitThirdParty",
+            get_payout_base_url(connectors)?,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutCreateRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutCreateRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCreateType::get_url(self, req, connectors)?)
+                .headers(types::PayoutCreateType::get_headers(self, req, connectors)?)
+                .body(types::PayoutCreateType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCreate>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCreate>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PayoutFulfillType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/confirmThirdParty",
+            get_payout_base_url(connectors)?,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutReviewRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutReviewRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutFulfillType::get_url(self, req, connectors)?)
+                .headers(types::PayoutFulfillType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PayoutFulfillType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoFulfill>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoFulfill>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PayoutCancelType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/declineThirdParty",
+            get_payout_base_url(connectors)?,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutReviewRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutReviewRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCancelType::get_url(self, req, connectors)?)
+                .headers(types::PayoutCancelType::get_headers(self, req, connectors)?)
+                .body(types::PayoutCancelType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCancel>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCancel>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Adyen
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Adyen {}
+impl api::AcceptDispute for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Adyen
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Adyen
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Adyen
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Adyen {}
+impl api::UploadFile for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Adyen
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Adyen {}
 impl api::RefundExecute for Adyen {}
 impl api::RefundSync for Adyen {}
@@ -726,6 +1216,23 @@ impl api::IncomingWebhook for Adyen {
         let notif = get_webhook_object_from_body(body)
             .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
 
+        if adyen::is_chargeback_event(&notif.event_code)
+            || adyen::is_refund_event(&notif.event_code)
+        {
+            // For dispute and refund notifications the psp reference identifies the dispute or
+            // the refund, while the original reference is the psp reference of the payment
+            return notif
+                .original_reference
+                .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)
+                .into_report();
+        }
+
+        if adyen::is_payout_event(&notif.event_code) {
+            // Payout notifications which relate to a modification of the payout carry the psp
+            // reference of the payout as the original reference
+            return Ok(notif.original_reference.unwrap_or(notif.psp_reference));
+        }
+
         Ok(notif.psp_reference)
     }
 
@@ -738,6 +1245,21 @@ impl api::IncomingWebhook for Adyen {
 
         Ok(match notif.event_code.as_str() {
             "AUTHORISATION" => api::IncomingWebhookEvent::PaymentIntentSuccess,
+            "REFUND" if notif.success == "true" => api::IncomingWebhookEvent::RefundSuccess,
+            "REFUND" | "REFUND_FAILED" | "REFUNDED_REVERSED" => {
+                api::IncomingWebhookEvent::RefundFailure
+            }
+            "REQUEST_FOR_INFORMATION" | "NOTIFICATION_OF_CHARGEBACK" | "CHARGEBACK" => {
+                api::IncomingWebhookEvent::DisputeOpened
+            }
+            "CHARGEBACK_REVERSED" | "PREARBITRATION_WON" => api::IncomingWebhookEvent::DisputeWon,
+            "SECOND_CHARGEBACK" | "PREARBITRATION_LOST" => api::IncomingWebhookEvent::DisputeLost,
+            "PAYOUT_THIRDPARTY" if notif.success == "true" => {
+                api::IncomingWebhookEvent::PayoutSuccess
+            }
+            "PAYOUT_THIRDPARTY" => api::IncomingWebhookEvent::PayoutFailure,
+            "PAYOUT_DECLINE" | "PAYOUT_EXPIRE" => api::IncomingWebhookEvent::PayoutCancelled,
+            "PAIDOUT_REVERSED" => api::IncomingWebhookEvent::PayoutReversed,
             _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
         })
     }
@@ -766,6 +1288,26 @@ impl api::IncomingWebhook for Adyen {
             "[accepted]".to_string(),
         ))
     }
+
+    fn get_dispute_details(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<api::DisputePayload, errors::ConnectorError> {
+        let notif = get_webhook_object_from_body(body)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        Ok(notif.into())
+    }
+
+    fn get_webhook_connector_refund_id(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let notif = get_webhook_object_from_body(body)
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(notif.psp_reference)
+    }
 }
 
 impl services::ConnectorRedirectResponse for Adyen {
@@ -776,3 +1318,318 @@ impl services::ConnectorRedirectResponse for Adyen {
         Ok(payments::CallConnectorAction::Trigger)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::{api::IncomingWebhook, storage::enums as storage_enums};
+
+    fn notification_item(event_code: &str, success: &str) -> serde_json::Value {
+        serde_json::json!({
+            "additionalData": { "hmacSignature": "c2lnbmF0dXJl" },
+            "amount": { "value": 6540, "currency": "EUR" },
+            "pspReference": "8816789012345678",
+            "eventCode": event_code,
+            "merchantAccountCode": "TestMerchant",
+            "merchantReference": "pay_mbabizu24mvu3mela5njyhpit4",
+            "success": success
+        })
+    }
+
+    fn notification_body(item: serde_json::Value) -> Vec<u8> {
+        serde_json::json!({
+            "live": "false",
+            "notificationItems": [{ "NotificationRequestItem": item }]
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_chargeback_webhook() {
+        let mut item = notification_item("NOTIFICATION_OF_CHARGEBACK", "true");
+        item["originalReference"] = serde_json::json!("8816789012340000");
+        item["reason"] = serde_json::json!("Merchandise/Services Not Received");
+        item["eventDate"] = serde_json::json!("2023-02-13T10:15:30+01:00");
+        item["additionalData"]["chargebackReasonCode"] = serde_json::json!("13.1");
+        item["additionalData"]["defensePeriodEndsAt"] =
+            serde_json::json!("2023-02-27T10:15:30+01:00");
+        item["additionalData"]["disputeStatus"] = serde_json::json!("Undefended");
+        let body = notification_body(item);
+
+        assert_eq!(
+            Adyen.get_webhook_event_type(&body).unwrap(),
+            api::IncomingWebhookEvent::DisputeOpened
+        );
+        // Disputes are looked up through the payment they were raised against
+        assert_eq!(
+            Adyen.get_webhook_object_reference_id(&body).unwrap(),
+            "8816789012340000"
+        );
+
+        let dispute = Adyen.get_dispute_details(&body).unwrap();
+        assert_eq!(dispute.amount, 6540);
+        assert_eq!(
+            dispute.dispute_stage,
+            api_models::enums::DisputeStage::Dispute
+        );
+        assert_eq!(dispute.connector_dispute_id, "8816789012345678");
+        assert_eq!(dispute.connector_status, "Undefended");
+        assert_eq!(dispute.connector_reason_code.as_deref(), Some("13.1"));
+        assert!(dispute.challenge_required_by.is_some());
+        assert!(dispute.created_at.is_some());
+    }
+
+    #[test]
+    fn test_chargeback_webhook_dispute_stage() {
+        let mut item = notification_item("REQUEST_FOR_INFORMATION", "true");
+        item["originalReference"] = serde_json::json!("8816789012340000");
+        let dispute = Adyen.get_dispute_details(&notification_body(item)).unwrap();
+        assert_eq!(
+            dispute.dispute_stage,
+            api_models::enums::DisputeStage::PreDispute
+        );
+        // Without a dispute status, the event code is kept as the connector status
+        assert_eq!(dispute.connector_status, "REQUEST_FOR_INFORMATION");
+
+        let event_type = |event_code| {
+            Adyen
+                .get_webhook_event_type(&notification_body(notification_item(event_code, "true")))
+                .unwrap()
+        };
+        assert_eq!(
+            event_type("CHARGEBACK_REVERSED"),
+            api::IncomingWebhookEvent::DisputeWon
+        );
+        assert_eq!(
+            event_type("PREARBITRATION_LOST"),
+            api::IncomingWebhookEvent::DisputeLost
+        );
+    }
+
+    #[test]
+    fn test_payout_status_from_result_code() {
+        assert_eq!(
+            adyen::get_payout_status("[payout-submit-received]"),
+            storage_enums::PayoutStatus::RequiresFulfillment
+        );
+        // A confirmation is only acknowledged, the outcome is sent in the PAYOUT_THIRDPARTY webhook
+        assert_eq!(
+            adyen::get_payout_status("[payout-confirm-received]"),
+            storage_enums::PayoutStatus::Pending
+        );
+        assert_eq!(
+            adyen::get_payout_status("[payout-decline-received]"),
+            storage_enums::PayoutStatus::Cancelled
+        );
+        assert_eq!(
+            adyen::get_payout_status("Refused"),
+            storage_enums::PayoutStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_payout_webhook_event_type() {
+        let event_type = |event_code, success| {
+            Adyen
+                .get_webhook_event_type(&notification_body(notification_item(event_code, success)))
+                .unwrap()
+        };
+
+        assert_eq!(
+            event_type("PAYOUT_THIRDPARTY", "true"),
+            api::IncomingWebhookEvent::PayoutSuccess
+        );
+        assert_eq!(
+            event_type("PAYOUT_THIRDPARTY", "false"),
+            api::IncomingWebhookEvent::PayoutFailure
+        );
+        assert_eq!(
+            event_type("PAYOUT_DECLINE", "true"),
+            api::IncomingWebhookEvent::PayoutCancelled
+        );
+        assert_eq!(
+            event_type("PAYOUT_EXPIRE", "true"),
+            api::IncomingWebhookEvent::PayoutCancelled
+        );
+        assert_eq!(
+            event_type("PAIDOUT_REVERSED", "true"),
+            api::IncomingWebhookEvent::PayoutReversed
+        );
+    }
+
+    #[test]
+    fn test_payout_webhook_object_reference_id() {
+        let body = notification_body(notification_item("PAYOUT_THIRDPARTY", "true"));
+        assert_eq!(
+            Adyen.get_webhook_object_reference_id(&body).unwrap(),
+            "8816789012345678"
+        );
+
+        let mut item = notification_item("PAIDOUT_REVERSED", "true");
+        item["originalReference"] = serde_json::json!("8816789012340000");
+        assert_eq!(
+            Adyen
+                .get_webhook_object_reference_id(&notification_body(item))
+                .unwrap(),
+            "8816789012340000"
+        );
+    }
+
+    fn incremental_authorization_router_data() -> types::PaymentsIncrementalAuthorizationRouterData
+    {
+        types::RouterData {
+            flow: std::marker::PhantomData,
+            merchant_id: "merchant_1".to_string(),
+            connector: "adyen".to_string(),
+            payment_id: "pay_123".to_string(),
+            attempt_id: None,
+            status: storage_enums::AttemptStatus::Authorized,
+            payment_method: storage_enums::PaymentMethodType::Card,
+            connector_auth_type: types::ConnectorAuthType::BodyKey {
+                api_key: "api_key".to_string(),
+                key1: "TestMerchant".to_string(),
+            },
+            description: None,
+            return_url: None,
+            router_return_url: None,
+            address: Default::default(),
+            auth_type: storage_enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            request: types::PaymentsIncrementalAuthorizationData {
+                total_amount: 1500,
+                additional_amount: 500,
+                currency: storage_enums::Currency::USD,
+                connector_transaction_id: "8816789012345678".to_string(),
+                reason: None,
+            },
+            response: Err(types::ErrorResponse {
+                code: String::new(),
+                message: String::new(),
+                reason: None,
+                status_code: 500,
+            }),
+            payment_method_id: None,
+        }
+    }
+
+    #[test]
+    fn test_incremental_authorization_request() {
+        let router_data = incremental_authorization_router_data();
+        let request_body =
+            types::PaymentsIncrementalAuthorizationType::get_request_body(&Adyen, &router_data)
+                .unwrap()
+                .unwrap();
+        let request_body: serde_json::Value = serde_json::from_str(&request_body).unwrap();
+        // Adyen expects the total amount to be authorized, not the increment
+        assert_eq!(
+            request_body,
+            serde_json::json!({
+                "merchantAccount": "TestMerchant",
+                "amount": { "currency": "USD", "value": 1500 },
+                "reference": "pay_123"
+            })
+        );
+    }
+
+    #[test]
+    fn test_incremental_authorization_response() {
+        let response = |status: &str| types::Response {
+            response: serde_json::json!({
+                "merchantAccount": "TestMerchant",
+                "paymentPspReference": "8816789012345678",
+                "pspReference": "8816789012340000",
+                "reference": "pay_123",
+                "status": status,
+                "amount": { "currency": "USD", "value": 1500 }
+            })
+            .to_string()
+            .into(),
+            status_code: 201,
+        };
+
+        let router_data = types::PaymentsIncrementalAuthorizationType::handle_response(
+            &Adyen,
+            &incremental_authorization_router_data(),
+            response("received"),
+        )
+        .unwrap();
+        assert_eq!(router_data.status, storage_enums::AttemptStatus::Authorized);
+        assert!(matches!(
+            router_data.response,
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(ref id),
+                ..
+            }) if id == "8816789012345678"
+        ));
+
+        let router_data = types::PaymentsIncrementalAuthorizationType::handle_response(
+            &Adyen,
+            &incremental_authorization_router_data(),
+            response("pending"),
+        )
+        .unwrap();
+        assert_eq!(router_data.status, storage_enums::AttemptStatus::Pending);
+    }
+
+    fn error_response(code: &str, status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            message: String::new(),
+            reason: None,
+            status_code,
+        }
+    }
+
+    #[test]
+    fn test_is_retryable_error() {
+        // Acquirer error and issuer unavailable refusals are soft declines
+        assert!(Adyen.is_retryable_error(&error_response("4", 200)));
+        assert!(Adyen.is_retryable_error(&error_response("9", 200)));
+        // Refused and insufficient funds refusals are hard declines
+        assert!(!Adyen.is_retryable_error(&error_response("2", 200)));
+        assert!(!Adyen.is_retryable_error(&error_response("12", 200)));
+        // The payment may have been processed in spite of a server error
+        assert!(!Adyen.is_retryable_error(&error_response("000", 500)));
+    }
+
+    #[test]
+    fn test_refund_webhook() {
+        let refund_webhook_body = |event_code, success| {
+            let mut item = notification_item(event_code, success);
+            item["originalReference"] = serde_json::json!("8816789012340000");
+            notification_body(item)
+        };
+        let body = refund_webhook_body("REFUND", "true");
+
+        assert_eq!(
+            Adyen.get_webhook_event_type(&body).unwrap(),
+            api::IncomingWebhookEvent::RefundSuccess
+        );
+        // Refunds are looked up through the payment they were made against
+        assert_eq!(
+            Adyen.get_webhook_object_reference_id(&body).unwrap(),
+            "8816789012340000"
+        );
+        assert_eq!(
+            Adyen.get_webhook_connector_refund_id(&body).unwrap(),
+            "8816789012345678"
+        );
+
+        for (event_code, success) in [
+            ("REFUND", "false"),
+            ("REFUND_FAILED", "true"),
+            ("REFUNDED_REVERSED", "true"),
+        ] {
+            assert_eq!(
+                Adyen
+                    .get_webhook_event_type(&refund_webhook_body(event_code, success))
+                    .unwrap(),
+                api::IncomingWebhookEvent::RefundFailure
+            );
+        }
+    }
+}
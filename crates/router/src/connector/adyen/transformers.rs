@@ -865,17 +865,26 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
     fn try_from(
         item: types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>,
     ) -> Result<Self, Self::Error> {
+        // Adyen accepts several partial captures of an authorization without any flag, the
+        // uncaptured amount is released when the authorization expires
         let (status, amount_captured) = match item.response.status.as_str() {
-            "received" => (
+            "received" if item.data.request.final_capture => (
                 storage_enums::AttemptStatus::Charged,
                 Some(item.response.amount.value),
             ),
+            "received" => (
+                storage_enums::AttemptStatus::PartialCharged,
+                Some(item.response.amount.value),
+            ),
             _ => (storage_enums::AttemptStatus::Pending, None),
         };
         Ok(Self {
             status,
             response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(item.response.psp_reference),
+                // Later captures and refunds are made on the payment, not on this capture
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.payment_psp_reference,
+                ),
                 redirect: false,
                 redirection_data: None,
                 mandate_reference: None,
@@ -887,6 +896,71 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
     }
 }
 
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAmountUpdateRequest {
+    merchant_account: String,
+    amount: Amount,
+    reference: String,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData> for AdyenAmountUpdateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            reference: item.payment_id.to_string(),
+            // The amount of an amount update is the total amount to be authorized, not the
+            // increment
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.total_amount,
+            },
+        })
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAmountUpdateResponse {
+    merchant_account: String,
+    payment_psp_reference: String,
+    psp_reference: String,
+    reference: String,
+    status: String,
+    amount: Amount,
+}
+
+impl TryFrom<types::PaymentsIncrementalAuthorizationResponseRouterData<AdyenAmountUpdateResponse>>
+    for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<AdyenAmountUpdateResponse>,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.status.as_str() {
+            "received" => storage_enums::AttemptStatus::Authorized,
+            _ => storage_enums::AttemptStatus::Pending,
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.payment_psp_reference,
+                ),
+                redirect: false,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
 /*
 // This is a repeated code block from Stripe inegration. Can we avoid the repetition in every integration
 #[derive(Debug, Serialize, Deserialize)]
@@ -995,6 +1069,10 @@ pub struct ErrorResponse {
 #[serde(rename_all = "camelCase")]
 pub struct AdyenAdditionalDataWH {
     pub hmac_signature: String,
+    pub chargeback_reason_code: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub defense_period_ends_at: Option<time::PrimitiveDateTime>,
+    pub dispute_status: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1014,6 +1092,59 @@ pub struct AdyenNotificationRequestItemWH {
     pub merchant_account_code: String,
     pub merchant_reference: String,
     pub success: String,
+    pub reason: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub event_date: Option<time::PrimitiveDateTime>,
+}
+
+pub fn is_chargeback_event(event_code: &str) -> bool {
+    matches!(
+        event_code,
+        "REQUEST_FOR_INFORMATION"
+            | "NOTIFICATION_OF_CHARGEBACK"
+            | "CHARGEBACK"
+            | "CHARGEBACK_REVERSED"
+            | "SECOND_CHARGEBACK"
+            | "PREARBITRATION_WON"
+            | "PREARBITRATION_LOST"
+    )
+}
+
+pub fn is_refund_event(event_code: &str) -> bool {
+    matches!(event_code, "REFUND" | "REFUND_FAILED" | "REFUNDED_REVERSED")
+}
+
+pub fn is_payout_event(event_code: &str) -> bool {
+    matches!(
+        event_code,
+        "PAYOUT_THIRDPARTY" | "PAYOUT_DECLINE" | "PAYOUT_EXPIRE" | "PAIDOUT_REVERSED"
+    )
+}
+
+impl From<AdyenNotificationRequestItemWH> for api::DisputePayload {
+    fn from(notif: AdyenNotificationRequestItemWH) -> Self {
+        let dispute_stage = match notif.event_code.as_str() {
+            "REQUEST_FOR_INFORMATION" => api_enums::DisputeStage::PreDispute,
+            "SECOND_CHARGEBACK" | "PREARBITRATION_WON" | "PREARBITRATION_LOST" => {
+                api_enums::DisputeStage::PreArbitration
+            }
+            _ => api_enums::DisputeStage::Dispute,
+        };
+        Self {
+            amount: notif.amount.value,
+            dispute_stage,
+            connector_status: notif
+                .additional_data
+                .dispute_status
+                .unwrap_or(notif.event_code),
+            connector_dispute_id: notif.psp_reference,
+            connector_reason: notif.reason,
+            connector_reason_code: notif.additional_data.chargeback_reason_code,
+            challenge_required_by: notif.additional_data.defense_period_ends_at,
+            created_at: notif.event_date,
+            updated_at: notif.event_date,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -1046,3 +1177,179 @@ impl From<AdyenNotificationRequestItemWH> for AdyenResponse {
         }
     }
 }
+
+// Payouts Request and Response
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCreateRequest {
+    amount: Amount,
+    merchant_account: String,
+    recurring: AdyenPayoutRecurring,
+    reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank: Option<AdyenPayoutBank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<AdyenPayoutCard>,
+    shopper_email: Option<Secret<String, Email>>,
+    shopper_reference: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenPayoutRecurring {
+    contract: AdyenPayoutContract,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdyenPayoutContract {
+    Payout,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutBank {
+    iban: Secret<String>,
+    owner_name: Secret<String>,
+    country_code: String,
+    bank_name: Option<String>,
+    bank_city: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCard {
+    number: Secret<String, pii::CardNumber>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    holder_name: Secret<String>,
+}
+
+/// Request body for confirming or declining a payout that was previously submitted.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutReviewRequest {
+    merchant_account: String,
+    original_reference: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutResponse {
+    psp_reference: String,
+    result_code: String,
+    refusal_reason: Option<String>,
+}
+
+impl TryFrom<&types::PayoutsRouterData<api::PoCreate>> for AdyenPayoutCreateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<api::PoCreate>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        let payout_method_data = item.request.payout_method_data.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "payout_method_data",
+            },
+        )?;
+        let (bank, card) = match payout_method_data {
+            api::PayoutMethodData::Bank(bank) => (
+                Some(AdyenPayoutBank {
+                    iban: bank.iban,
+                    owner_name: bank.owner_name,
+                    country_code: bank.country_code,
+                    bank_name: bank.bank_name,
+                    bank_city: bank.bank_city,
+                }),
+                None,
+            ),
+            api::PayoutMethodData::Card(card) => (
+                None,
+                Some(AdyenPayoutCard {
+                    number: card.card_number,
+                    expiry_month: card.expiry_month,
+                    expiry_year: card.expiry_year,
+                    holder_name: card.card_holder_name,
+                }),
+            ),
+        };
+        Ok(Self {
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.amount,
+            },
+            merchant_account: auth_type.merchant_account,
+            recurring: AdyenPayoutRecurring {
+                contract: AdyenPayoutContract::Payout,
+            },
+            reference: item.request.payout_id.clone(),
+            bank,
+            card,
+            shopper_email: item.request.email.clone(),
+            shopper_reference: item.request.customer_id.clone(),
+        })
+    }
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutReviewRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        let original_reference = item.request.connector_payout_id.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_payout_id",
+            },
+        )?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            original_reference,
+        })
+    }
+}
+
+pub fn get_payout_status(result_code: &str) -> storage_enums::PayoutStatus {
+    // Adyen only acknowledges the confirmation of a payout synchronously, the payout stays
+    // pending until its outcome is communicated through the PAYOUT_THIRDPARTY notification
+    match result_code {
+        "[payout-submit-received]" => storage_enums::PayoutStatus::RequiresFulfillment,
+        "[payout-confirm-received]" => storage_enums::PayoutStatus::Pending,
+        "[payout-decline-received]" => storage_enums::PayoutStatus::Cancelled,
+        "Refused" => storage_enums::PayoutStatus::Failed,
+        _ => storage_enums::PayoutStatus::Pending,
+    }
+}
+
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, AdyenPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        let status = get_payout_status(&item.response.result_code);
+        let response = match status {
+            storage_enums::PayoutStatus::Failed => Err(types::ErrorResponse {
+                code: item.response.result_code,
+                message: item
+                    .response
+                    .refusal_reason
+                    .clone()
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: item.response.refusal_reason,
+                status_code: item.http_code,
+            }),
+            // The psp reference of a confirmation or decline identifies the modification, the
+            // payout itself stays identified by the psp reference returned when submitting it
+            _ => Ok(types::PayoutsResponseData {
+                connector_payout_id: item
+                    .data
+                    .request
+                    .connector_payout_id
+                    .clone()
+                    .unwrap_or(item.response.psp_reference),
+                status,
+            }),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
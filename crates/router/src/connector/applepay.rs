@@ -67,6 +67,18 @@ impl
 {
 }
 
+impl api::PaymentIncrementalAuthorization for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Applepay
@@ -222,6 +234,98 @@ impl
     }
 }
 
+impl api::Payouts for Applepay {}
+impl api::PayoutCreate for Applepay {}
+impl api::PayoutFulfill for Applepay {}
+impl api::PayoutCancel for Applepay {}
+impl api::PayoutReverse for Applepay {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Applepay {}
+impl api::AcceptDispute for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Applepay {}
+impl api::UploadFile for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Applepay {}
 impl api::RefundExecute for Applepay {}
 impl api::RefundSync for Applepay {}
@@ -86,6 +86,18 @@ impl
     // Not Implemented (R)
 }
 
+impl api::PaymentIncrementalAuthorization for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Authorizedotnet
@@ -379,6 +391,98 @@ impl
     }
 }
 
+impl api::Payouts for Authorizedotnet {}
+impl api::PayoutCreate for Authorizedotnet {}
+impl api::PayoutFulfill for Authorizedotnet {}
+impl api::PayoutCancel for Authorizedotnet {}
+impl api::PayoutReverse for Authorizedotnet {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Authorizedotnet {}
+impl api::AcceptDispute for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Authorizedotnet {}
+impl api::UploadFile for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Authorizedotnet {}
 impl api::RefundExecute for Authorizedotnet {}
 impl api::RefundSync for Authorizedotnet {}
@@ -199,6 +199,18 @@ impl
     // Not Implemented (R)
 }
 
+impl api::PaymentIncrementalAuthorization for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Braintree
+{
+    // Not Implemented (R)
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Braintree
@@ -515,6 +527,98 @@ impl
     }
 }
 
+impl api::Payouts for Braintree {}
+impl api::PayoutCreate for Braintree {}
+impl api::PayoutFulfill for Braintree {}
+impl api::PayoutCancel for Braintree {}
+impl api::PayoutReverse for Braintree {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Braintree
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Braintree {}
+impl api::AcceptDispute for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Braintree
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Braintree
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Braintree
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Braintree {}
+impl api::UploadFile for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Braintree
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Braintree {}
 impl api::RefundExecute for Braintree {}
 impl api::RefundSync for Braintree {}
@@ -20,7 +20,7 @@ use crate::{
         self,
         api::{self, ConnectorCommon},
     },
-    utils::{self, BytesExt},
+    utils::{self, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -59,6 +59,11 @@ impl api::PaymentAuthorize for Checkout {}
 impl api::PaymentSync for Checkout {}
 impl api::PaymentVoid for Checkout {}
 impl api::PaymentCapture for Checkout {}
+impl api::PaymentIncrementalAuthorization for Checkout {
+    fn supports_incremental_authorization(&self) -> bool {
+        true
+    }
+}
 impl api::PaymentSession for Checkout {}
 impl api::ConnectorAccessToken for Checkout {}
 
@@ -199,6 +204,114 @@ impl
     }
 }
 
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Checkout
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let id = req.request.connector_transaction_id.as_str();
+        Ok(format!(
+            "{}payments/{id}/authorizations",
+            self.base_url(connectors)
+        ))
+    }
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = checkout::IncrementalAuthorizationRequest::try_from(req)?;
+        let checkout_req =
+            utils::Encode::<checkout::IncrementalAuthorizationRequest>::encode_to_string_of_json(
+                &connector_req,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(checkout_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsIncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsIncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsIncrementalAuthorizationType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
+    {
+        let response: checkout::IncrementalAuthorizationResponse = res
+            .response
+            .parse_struct("IncrementalAuthorizationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: checkout::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error_codes
+                .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
+                .join(" & "),
+            message: response
+                .error_type
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Checkout
@@ -509,6 +622,443 @@ impl
     }
 }
 
+impl api::Payouts for Checkout {}
+impl api::PayoutCreate for Checkout {}
+impl api::PayoutFulfill for Checkout {}
+impl api::PayoutCancel for Checkout {}
+impl api::PayoutReverse for Checkout {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Checkout {}
+impl api::AcceptDispute for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Checkout
+{
+    fn get_headers(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::AcceptDisputeType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}disputes/{}/accept",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::AcceptDisputeType::get_url(self, req, connectors)?)
+                .headers(types::AcceptDisputeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::AcceptDisputeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::AcceptDisputeRouterData, errors::ConnectorError> {
+        logger::debug!(accept_dispute_response=?res);
+        // Checkout responds with `204 No Content` when the dispute is accepted
+        types::RouterData::try_from(types::ResponseRouterData {
+            response: (),
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: checkout::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error_codes
+                .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
+                .join(" & "),
+            message: response
+                .error_type
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::SubmitEvidence for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Checkout
+{
+    fn get_headers(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::SubmitEvidenceType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}disputes/{}/evidence",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let checkout_req = utils::Encode::<checkout::Evidence>::convert_and_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(checkout_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Put)
+                .url(&types::SubmitEvidenceType::get_url(self, req, connectors)?)
+                .headers(types::SubmitEvidenceType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::SubmitEvidenceType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::SubmitEvidenceRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::SubmitEvidenceRouterData, errors::ConnectorError> {
+        logger::debug!(submit_evidence_response=?res);
+        // Checkout responds with `204 No Content` once the evidence is saved
+        types::RouterData::try_from(types::ResponseRouterData {
+            response: (),
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: checkout::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error_codes
+                .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
+                .join(" & "),
+            message: response
+                .error_type
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::DefendDispute for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Checkout
+{
+    fn get_headers(
+        &self,
+        req: &types::DefendDisputeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::DefendDisputeType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::DefendDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}disputes/{}/evidence",
+            self.base_url(connectors),
+            req.request.connector_dispute_id,
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::DefendDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::DefendDisputeType::get_url(self, req, connectors)?)
+                .headers(types::DefendDisputeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::DefendDisputeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::DefendDisputeRouterData, errors::ConnectorError> {
+        logger::debug!(defend_dispute_response=?res);
+        // Checkout responds with `204 No Content` once the saved evidence is submitted
+        types::RouterData::try_from(types::ResponseRouterData {
+            response: (),
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: checkout::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error_codes
+                .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
+                .join(" & "),
+            message: response
+                .error_type
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::FileUpload for Checkout {}
+impl api::UploadFile for Checkout {}
+
+/// Checkout serves the files API from a different host than the rest of its API.
+fn get_file_upload_base_url(
+    connectors: &settings::Connectors,
+) -> CustomResult<&str, errors::ConnectorError> {
+    connectors
+        .checkout
+        .secondary_base_url
+        .as_deref()
+        .ok_or(errors::ConnectorError::FailedToObtainIntegrationUrl)
+        .into_report()
+        .attach_printable("Checkout file upload base URL is not configured")
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Checkout
+{
+    fn get_headers(
+        &self,
+        req: &types::UploadFileRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        // The multipart content type along with its boundary is set when the form is attached
+        self.get_auth_header(&req.connector_auth_type)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "multipart/form-data"
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}files", get_file_upload_base_url(connectors)?))
+    }
+
+    fn get_request_form_data(
+        &self,
+        req: &types::UploadFileRouterData,
+    ) -> CustomResult<Option<reqwest::multipart::Form>, errors::ConnectorError> {
+        let checkout_req = checkout::construct_file_upload_request(req)?;
+        Ok(Some(checkout_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::UploadFileType::get_url(self, req, connectors)?)
+                .headers(types::UploadFileType::get_headers(self, req, connectors)?)
+                .form_data(types::UploadFileType::get_request_form_data(self, req)?)
+                .content_type(services::ContentType::FormData)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::UploadFileRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::UploadFileRouterData, errors::ConnectorError> {
+        logger::debug!(upload_file_response=?res);
+        let response: checkout::FileUploadResponse = res
+            .response
+            .parse_struct("Checkout FileUploadResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: checkout::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error_codes
+                .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
+                .join(" & "),
+            message: response
+                .error_type
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
 impl api::Refund for Checkout {}
 impl api::RefundExecute for Checkout {}
 impl api::RefundSync for Checkout {}
@@ -721,23 +1271,71 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 impl api::IncomingWebhook for Checkout {
     fn get_webhook_object_reference_id(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let event: checkout::CheckoutWebhookEventType = body
+            .parse_struct("CheckoutWebhookEventType")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        if event.event_type.is_refund_event() {
+            // The data of refund events holds the id of the refunded payment and the action id
+            // of the refund
+            let details: checkout::CheckoutRefundWebhookBody = body
+                .parse_struct("CheckoutRefundWebhookBody")
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+            return Ok(details.data.id);
+        }
+
+        let details: checkout::CheckoutDisputeWebhookBody = body
+            .parse_struct("CheckoutDisputeWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(details.data.payment_id)
     }
 
     fn get_webhook_event_type(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let details: checkout::CheckoutWebhookEventType = body
+            .parse_struct("CheckoutWebhookEventType")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        api::IncomingWebhookEvent::try_from(details.event_type)
     }
 
     fn get_webhook_resource_object(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let details: checkout::CheckoutWebhookObjectResource = body
+            .parse_struct("CheckoutWebhookObjectResource")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        Ok(details.data)
+    }
+
+    fn get_dispute_details(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<api::DisputePayload, errors::ConnectorError> {
+        let details: checkout::CheckoutDisputeWebhookBody = body
+            .parse_struct("CheckoutDisputeWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        Ok(details.into())
+    }
+
+    fn get_webhook_connector_refund_id(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let details: checkout::CheckoutRefundWebhookBody = body
+            .parse_struct("CheckoutRefundWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(details.data.action_id)
     }
 }
 
@@ -758,3 +1356,129 @@ impl services::ConnectorRedirectResponse for Checkout {
             .unwrap_or(payments::CallConnectorAction::Trigger))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::{api::IncomingWebhook, storage::enums as storage_enums};
+
+    fn incremental_authorization_router_data() -> types::PaymentsIncrementalAuthorizationRouterData
+    {
+        types::RouterData {
+            flow: std::marker::PhantomData,
+            merchant_id: "merchant_1".to_string(),
+            connector: "checkout".to_string(),
+            payment_id: "pay_123".to_string(),
+            attempt_id: None,
+            status: storage_enums::AttemptStatus::Authorized,
+            payment_method: storage_enums::PaymentMethodType::Card,
+            connector_auth_type: types::ConnectorAuthType::BodyKey {
+                api_key: "sk_test".to_string(),
+                key1: "pk_test".to_string(),
+            },
+            description: None,
+            return_url: None,
+            router_return_url: None,
+            address: Default::default(),
+            auth_type: storage_enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            request: types::PaymentsIncrementalAuthorizationData {
+                total_amount: 1500,
+                additional_amount: 500,
+                currency: storage_enums::Currency::USD,
+                connector_transaction_id: "pay_mbabizu24mvu3mela5njyhpit4".to_string(),
+                reason: None,
+            },
+            response: Err(types::ErrorResponse {
+                code: String::new(),
+                message: String::new(),
+                reason: None,
+                status_code: 500,
+            }),
+            payment_method_id: None,
+        }
+    }
+
+    #[test]
+    fn test_incremental_authorization_request() {
+        let router_data = incremental_authorization_router_data();
+        let request_body =
+            types::PaymentsIncrementalAuthorizationType::get_request_body(&Checkout, &router_data)
+                .unwrap()
+                .unwrap();
+        let request_body: serde_json::Value = serde_json::from_str(&request_body).unwrap();
+        // Checkout expects the amount to be added to the authorization, not the new total
+        assert_eq!(
+            request_body,
+            serde_json::json!({ "amount": 500, "reference": "pay_123" })
+        );
+    }
+
+    #[test]
+    fn test_incremental_authorization_response() {
+        let response = |status_code: u16| types::Response {
+            response: serde_json::json!({ "action_id": "act_123", "amount": 500 })
+                .to_string()
+                .into(),
+            status_code,
+        };
+
+        let router_data = types::PaymentsIncrementalAuthorizationType::handle_response(
+            &Checkout,
+            &incremental_authorization_router_data(),
+            response(201),
+        )
+        .unwrap();
+        assert_eq!(router_data.status, storage_enums::AttemptStatus::Authorized);
+
+        let router_data = types::PaymentsIncrementalAuthorizationType::handle_response(
+            &Checkout,
+            &incremental_authorization_router_data(),
+            response(202),
+        )
+        .unwrap();
+        assert_eq!(router_data.status, storage_enums::AttemptStatus::Pending);
+    }
+
+    #[test]
+    fn test_refund_webhook() {
+        let refund_webhook_body = |event_type: &str| {
+            serde_json::json!({
+                "id": "evt_az5sblvku4ge3dwpztvyizgcau",
+                "type": event_type,
+                "data": {
+                    "id": "pay_mbabizu24mvu3mela5njyhpit4",
+                    "action_id": "act_y3oqhf46pyzuxjbcn2giaqnb44",
+                    "amount": 6540
+                }
+            })
+            .to_string()
+            .into_bytes()
+        };
+        let body = refund_webhook_body("payment_refunded");
+
+        assert_eq!(
+            Checkout.get_webhook_event_type(&body).unwrap(),
+            api::IncomingWebhookEvent::RefundSuccess
+        );
+        // Refunds are looked up through the payment they were made against
+        assert_eq!(
+            Checkout.get_webhook_object_reference_id(&body).unwrap(),
+            "pay_mbabizu24mvu3mela5njyhpit4"
+        );
+        assert_eq!(
+            Checkout.get_webhook_connector_refund_id(&body).unwrap(),
+            "act_y3oqhf46pyzuxjbcn2giaqnb44"
+        );
+
+        assert_eq!(
+            Checkout
+                .get_webhook_event_type(&refund_webhook_body("payment_refund_declined"))
+                .unwrap(),
+            api::IncomingWebhookEvent::RefundFailure
+        );
+    }
+}
@@ -49,6 +49,14 @@ pub struct PaymentsRequest {
     #[serde(flatten)]
     pub return_url: ReturnUrl,
     pub capture: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_type: Option<CheckoutAuthorizationType>,
+}
+
+#[derive(Debug, Serialize)]
+pub enum CheckoutAuthorizationType {
+    Final,
+    Estimated,
 }
 
 #[derive(Debug, Serialize)]
@@ -125,6 +133,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             three_ds,
             return_url,
             capture,
+            // Only estimated authorizations can be incremented later on
+            authorization_type: item
+                .request
+                .request_incremental_authorization
+                .then_some(CheckoutAuthorizationType::Estimated),
         })
     }
 }
@@ -138,6 +151,8 @@ pub enum CheckoutPaymentStatus {
     CardVerified,
     Declined,
     Captured,
+    #[serde(rename = "Partially Captured")]
+    PartiallyCaptured,
 }
 
 impl From<transformers::Foreign<(CheckoutPaymentStatus, Option<enums::CaptureMethod>)>>
@@ -159,6 +174,7 @@ impl From<transformers::Foreign<(CheckoutPaymentStatus, Option<enums::CaptureMet
                 }
             }
             CheckoutPaymentStatus::Captured => enums::AttemptStatus::Charged,
+            CheckoutPaymentStatus::PartiallyCaptured => enums::AttemptStatus::PartialCharged,
             CheckoutPaymentStatus::Declined => enums::AttemptStatus::Failure,
             CheckoutPaymentStatus::Pending => enums::AttemptStatus::AuthenticationPending,
             CheckoutPaymentStatus::CardVerified => enums::AttemptStatus::Pending,
@@ -182,6 +198,7 @@ impl From<transformers::Foreign<(CheckoutPaymentStatus, Balances)>>
                 }
             }
             CheckoutPaymentStatus::Captured => enums::AttemptStatus::Charged,
+            CheckoutPaymentStatus::PartiallyCaptured => enums::AttemptStatus::PartialCharged,
             CheckoutPaymentStatus::Declined => enums::AttemptStatus::Failure,
             CheckoutPaymentStatus::Pending => enums::AttemptStatus::AuthenticationPending,
             CheckoutPaymentStatus::CardVerified => enums::AttemptStatus::Pending,
@@ -373,12 +390,75 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for PaymentCaptureRequest {
         let processing_channel_id = auth_type.processing_channel_id;
         Ok(Self {
             amount: item.request.amount_to_capture,
-            capture_type: Some(CaptureType::Final),
+            capture_type: Some(if item.request.final_capture {
+                CaptureType::Final
+            } else {
+                CaptureType::NonFinal
+            }),
             processing_channel_id,
         })
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct IncrementalAuthorizationRequest {
+    pub amount: i64,
+    pub reference: String,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for IncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            // Checkout expects the amount to be added to the authorization, not the new total
+            amount: item.request.additional_amount,
+            reference: item.payment_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IncrementalAuthorizationResponse {
+    pub action_id: String,
+    pub amount: i64,
+}
+
+impl
+    TryFrom<
+        types::PaymentsIncrementalAuthorizationResponseRouterData<IncrementalAuthorizationResponse>,
+    > for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<
+            IncrementalAuthorizationResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = if item.http_code == 201 {
+            enums::AttemptStatus::Authorized
+        } else {
+            enums::AttemptStatus::Pending
+        };
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.to_owned(),
+                ),
+                redirect: false,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            status,
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PaymentCaptureResponse {
     pub action_id: String,
@@ -393,7 +473,11 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
     ) -> Result<Self, Self::Error> {
         let (status, amount_captured) = if item.http_code == 202 {
             (
-                enums::AttemptStatus::Charged,
+                if item.data.request.final_capture {
+                    enums::AttemptStatus::Charged
+                } else {
+                    enums::AttemptStatus::PartialCharged
+                },
                 item.data.request.amount_to_capture,
             )
         } else {
@@ -592,3 +676,258 @@ impl From<CheckoutRedirectResponseStatus> for enums::AttemptStatus {
         }
     }
 }
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookEventType {
+    #[serde(rename = "type")]
+    pub event_type: CheckoutWebhookEvent,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutWebhookEvent {
+    PaymentRefunded,
+    PaymentRefundDeclined,
+    DisputeReceived,
+    DisputeEvidenceRequired,
+    DisputeEvidenceSubmitted,
+    DisputeEvidenceAcknowledgedByScheme,
+    DisputeAccepted,
+    DisputeCanceled,
+    DisputeExpired,
+    DisputeWon,
+    DisputeLost,
+    DisputeArbitrationWon,
+    DisputeArbitrationLost,
+    #[serde(other)]
+    Unknown,
+}
+
+impl TryFrom<CheckoutWebhookEvent> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(event: CheckoutWebhookEvent) -> Result<Self, Self::Error> {
+        match event {
+            CheckoutWebhookEvent::PaymentRefunded => Ok(Self::RefundSuccess),
+            CheckoutWebhookEvent::PaymentRefundDeclined => Ok(Self::RefundFailure),
+            CheckoutWebhookEvent::DisputeReceived
+            | CheckoutWebhookEvent::DisputeEvidenceRequired => Ok(Self::DisputeOpened),
+            CheckoutWebhookEvent::DisputeEvidenceSubmitted
+            | CheckoutWebhookEvent::DisputeEvidenceAcknowledgedByScheme => {
+                Ok(Self::DisputeChallenged)
+            }
+            CheckoutWebhookEvent::DisputeAccepted => Ok(Self::DisputeAccepted),
+            CheckoutWebhookEvent::DisputeCanceled => Ok(Self::DisputeCancelled),
+            CheckoutWebhookEvent::DisputeExpired => Ok(Self::DisputeExpired),
+            CheckoutWebhookEvent::DisputeWon | CheckoutWebhookEvent::DisputeArbitrationWon => {
+                Ok(Self::DisputeWon)
+            }
+            CheckoutWebhookEvent::DisputeLost | CheckoutWebhookEvent::DisputeArbitrationLost => {
+                Ok(Self::DisputeLost)
+            }
+            CheckoutWebhookEvent::Unknown => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()
+            }
+        }
+    }
+}
+
+impl CheckoutWebhookEvent {
+    pub fn is_refund_event(&self) -> bool {
+        matches!(self, Self::PaymentRefunded | Self::PaymentRefundDeclined)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutRefundWebhookData {
+    pub id: String,
+    pub action_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutRefundWebhookBody {
+    pub data: CheckoutRefundWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutDisputeWebhookData {
+    pub id: String,
+    pub payment_id: String,
+    pub amount: i64,
+    pub status: Option<String>,
+    pub reason_code: Option<String>,
+    pub category: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub evidence_required_by: Option<time::PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub received_on: Option<time::PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_update: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutDisputeWebhookBody {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub data: CheckoutDisputeWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookObjectResource {
+    pub data: serde_json::Value,
+}
+
+impl From<CheckoutDisputeWebhookBody> for api::DisputePayload {
+    fn from(webhook: CheckoutDisputeWebhookBody) -> Self {
+        let dispute_stage = if webhook.event_type.starts_with("dispute_arbitration") {
+            api_models::enums::DisputeStage::PreArbitration
+        } else {
+            api_models::enums::DisputeStage::Dispute
+        };
+        Self {
+            amount: webhook.data.amount,
+            dispute_stage,
+            connector_status: webhook.data.status.unwrap_or(webhook.event_type),
+            connector_dispute_id: webhook.data.id,
+            connector_reason: webhook.data.category,
+            connector_reason_code: webhook.data.reason_code,
+            challenge_required_by: webhook.data.evidence_required_by,
+            created_at: webhook.data.received_on,
+            updated_at: webhook.data.last_update,
+        }
+    }
+}
+
+impl TryFrom<types::AcceptDisputeResponseRouterData<()>> for types::AcceptDisputeRouterData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: types::AcceptDisputeResponseRouterData<()>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AcceptDisputeResponse {
+                dispute_status: enums::DisputeStatus::DisputeAccepted,
+                connector_status: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Evidence {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof_of_delivery_or_service_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof_of_delivery_or_service_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_or_receipt_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_or_receipt_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_communication_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_communication_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_or_cancellation_policy_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_or_cancellation_policy_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_transaction_agreement_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_showing_distinct_transactions_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_evidence_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_evidence_text: Option<String>,
+}
+
+impl TryFrom<&types::SubmitEvidenceRouterData> for Evidence {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SubmitEvidenceRouterData) -> Result<Self, Self::Error> {
+        let submit_evidence_request_data = item.request.clone();
+        Ok(Self {
+            proof_of_delivery_or_service_file: submit_evidence_request_data
+                .shipping_documentation
+                .or(submit_evidence_request_data.service_documentation),
+            proof_of_delivery_or_service_text: submit_evidence_request_data
+                .shipping_tracking_number
+                .or(submit_evidence_request_data.product_description),
+            invoice_or_receipt_file: submit_evidence_request_data.receipt,
+            invoice_or_receipt_text: None,
+            customer_communication_file: submit_evidence_request_data.customer_communication,
+            customer_communication_text: None,
+            refund_or_cancellation_policy_file: submit_evidence_request_data
+                .refund_policy
+                .or(submit_evidence_request_data.cancellation_policy),
+            refund_or_cancellation_policy_text: submit_evidence_request_data
+                .refund_refusal_explanation
+                .or(submit_evidence_request_data.cancellation_rebuttal),
+            recurring_transaction_agreement_file: submit_evidence_request_data
+                .recurring_transaction_agreement,
+            invoice_showing_distinct_transactions_file: submit_evidence_request_data
+                .invoice_showing_distinct_transactions,
+            additional_evidence_file: submit_evidence_request_data.uncategorized_file,
+            additional_evidence_text: submit_evidence_request_data.uncategorized_text,
+        })
+    }
+}
+
+impl TryFrom<types::SubmitEvidenceResponseRouterData<()>> for types::SubmitEvidenceRouterData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: types::SubmitEvidenceResponseRouterData<()>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::SubmitEvidenceResponse {
+                dispute_status: enums::DisputeStatus::DisputeOpened,
+                connector_status: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<types::DefendDisputeResponseRouterData<()>> for types::DefendDisputeRouterData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: types::DefendDisputeResponseRouterData<()>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::DefendDisputeResponse {
+                dispute_status: enums::DisputeStatus::DisputeChallenged,
+                connector_status: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+pub fn construct_file_upload_request(
+    item: &types::UploadFileRouterData,
+) -> Result<reqwest::multipart::Form, error_stack::Report<errors::ConnectorError>> {
+    let request = &item.request;
+    let file_part = reqwest::multipart::Part::bytes(request.file.clone())
+        .file_name(request.file_key.clone())
+        .mime_str(request.file_type.as_ref())
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+        .attach_printable("Failure in constructing file data")?;
+    Ok(reqwest::multipart::Form::new()
+        .text("purpose", "dispute_evidence")
+        .part("file", file_part))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FileUploadResponse {
+    #[serde(rename = "id")]
+    pub file_id: String,
+}
+
+impl TryFrom<types::UploadFileResponseRouterData<FileUploadResponse>>
+    for types::UploadFileRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::UploadFileResponseRouterData<FileUploadResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::UploadFileResponse {
+                provider_file_id: item.response.file_id,
+            }),
+            ..item.data
+        })
+    }
+}
@@ -284,6 +284,18 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Cybersource {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Cybersource
+{
+    // Not Implemented (R)
+}
+
 impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Cybersource
 {
@@ -537,6 +549,88 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
     }
 }
 
+impl api::Payouts for Cybersource {}
+impl api::PayoutCreate for Cybersource {}
+impl api::PayoutFulfill for Cybersource {}
+impl api::PayoutCancel for Cybersource {}
+impl api::PayoutReverse for Cybersource {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Cybersource {}
+impl api::AcceptDispute for Cybersource {}
+
+impl
+    ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::AcceptDisputeResponse>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Cybersource {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Cybersource {}
+
+impl
+    ConnectorIntegration<api::Defend, types::DefendDisputeRequestData, types::DefendDisputeResponse>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Cybersource {}
+impl api::UploadFile for Cybersource {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Cybersource {}
 impl api::RefundExecute for Cybersource {}
 impl api::RefundSync for Cybersource {}
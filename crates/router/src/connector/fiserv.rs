@@ -245,6 +245,18 @@ impl
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Fiserv {}
 
 #[allow(dead_code)]
@@ -396,6 +408,98 @@ impl
     }
 }
 
+impl api::Payouts for Fiserv {}
+impl api::PayoutCreate for Fiserv {}
+impl api::PayoutFulfill for Fiserv {}
+impl api::PayoutCancel for Fiserv {}
+impl api::PayoutReverse for Fiserv {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Fiserv {}
+impl api::AcceptDispute for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Fiserv {}
+impl api::UploadFile for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Fiserv {}
 impl api::RefundExecute for Fiserv {}
 impl api::RefundSync for Fiserv {}
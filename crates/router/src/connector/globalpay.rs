@@ -437,6 +437,18 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Globalpay {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Globalpay
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Globalpay {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -526,6 +538,88 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::Payouts for Globalpay {}
+impl api::PayoutCreate for Globalpay {}
+impl api::PayoutFulfill for Globalpay {}
+impl api::PayoutCancel for Globalpay {}
+impl api::PayoutReverse for Globalpay {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Globalpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Globalpay {}
+impl api::AcceptDispute for Globalpay {}
+
+impl
+    ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::AcceptDisputeResponse>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Globalpay {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Globalpay {}
+
+impl
+    ConnectorIntegration<api::Defend, types::DefendDisputeRequestData, types::DefendDisputeResponse>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Globalpay {}
+impl api::UploadFile for Globalpay {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Globalpay {}
 impl api::RefundExecute for Globalpay {}
 impl api::RefundSync for Globalpay {}
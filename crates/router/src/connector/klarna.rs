@@ -191,6 +191,18 @@ impl
     // Not Implemented (R)
 }
 
+impl api::PaymentIncrementalAuthorization for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Klarna
@@ -326,6 +338,98 @@ impl
 {
 }
 
+impl api::Payouts for Klarna {}
+impl api::PayoutCreate for Klarna {}
+impl api::PayoutFulfill for Klarna {}
+impl api::PayoutCancel for Klarna {}
+impl api::PayoutReverse for Klarna {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Klarna {}
+impl api::AcceptDispute for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Klarna {}
+impl api::UploadFile for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Klarna {}
 impl api::RefundExecute for Klarna {}
 impl api::RefundSync for Klarna {}
@@ -429,6 +429,18 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Payu {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Payu
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Payu {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -527,6 +539,80 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::Payouts for Payu {}
+impl api::PayoutCreate for Payu {}
+impl api::PayoutFulfill for Payu {}
+impl api::PayoutCancel for Payu {}
+impl api::PayoutReverse for Payu {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData> for Payu {
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData> for Payu {
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData> for Payu {
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData> for Payu {
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Payu
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Payu {}
+impl api::AcceptDispute for Payu {}
+
+impl
+    ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::AcceptDisputeResponse>
+    for Payu
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Payu {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Payu
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Payu {}
+
+impl
+    ConnectorIntegration<api::Defend, types::DefendDisputeRequestData, types::DefendDisputeResponse>
+    for Payu
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Payu {}
+impl api::UploadFile for Payu {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Payu
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Payu {}
 impl api::RefundExecute for Payu {}
 impl api::RefundSync for Payu {}
@@ -507,6 +507,18 @@ impl
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Rapyd {}
 
 impl
@@ -519,6 +531,98 @@ impl
     //TODO: implement sessions flow
 }
 
+impl api::Payouts for Rapyd {}
+impl api::PayoutCreate for Rapyd {}
+impl api::PayoutFulfill for Rapyd {}
+impl api::PayoutCancel for Rapyd {}
+impl api::PayoutReverse for Rapyd {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Rapyd {}
+impl api::AcceptDispute for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Rapyd {}
+impl api::UploadFile for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Rapyd {}
 impl api::RefundExecute for Rapyd {}
 impl api::RefundSync for Rapyd {}
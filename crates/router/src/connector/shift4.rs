@@ -263,6 +263,18 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Shift4 {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Shift4
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Shift4 {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -350,6 +362,88 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::Payouts for Shift4 {}
+impl api::PayoutCreate for Shift4 {}
+impl api::PayoutFulfill for Shift4 {}
+impl api::PayoutCancel for Shift4 {}
+impl api::PayoutReverse for Shift4 {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Shift4 {}
+impl api::AcceptDispute for Shift4 {}
+
+impl
+    ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::AcceptDisputeResponse>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Shift4 {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Shift4 {}
+
+impl
+    ConnectorIntegration<api::Defend, types::DefendDisputeRequestData, types::DefendDisputeResponse>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Shift4 {}
+impl api::UploadFile for Shift4 {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Shift4 {}
 impl api::RefundExecute for Shift4 {}
 impl api::RefundSync for Shift4 {}
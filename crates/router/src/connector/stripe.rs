@@ -52,6 +52,14 @@ impl ConnectorCommon for Stripe {
             format!("Bearer {}", auth.api_key),
         )])
     }
+
+    fn is_retryable_error(&self, error: &types::ErrorResponse) -> bool {
+        // Soft declines, identified by the decline code set as the reason of the error
+        matches!(
+            error.reason.as_deref(),
+            Some("issuer_not_available" | "try_again_later")
+        )
+    }
 }
 
 impl api::Payment for Stripe {}
@@ -163,6 +171,131 @@ impl
     where
         types::PaymentsCaptureData: Clone,
         types::PaymentsResponseData: Clone,
+    {
+        let response: stripe::PaymentIntentResponse = res
+            .response
+            .parse_struct("PaymentIntentResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let router_data: types::PaymentsCaptureRouterData =
+            types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            })
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        Ok(types::PaymentsCaptureRouterData {
+            status: stripe::get_capture_status(router_data.status, data.request.final_capture),
+            ..router_data
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::PaymentIncrementalAuthorization for Stripe {
+    fn supports_incremental_authorization(&self) -> bool {
+        true
+    }
+}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                Self::common_get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let id = req.request.connector_transaction_id.as_str();
+
+        Ok(format!(
+            "{}{}/{}/increment_authorization",
+            self.base_url(connectors),
+            "v1/payment_intents",
+            id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let stripe_req =
+            utils::Encode::<stripe::IncrementalAuthorizationRequest>::convert_and_url_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(stripe_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsIncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsIncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsIncrementalAuthorizationType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
     {
         let response: stripe::PaymentIntentResponse = res
             .response
@@ -408,7 +541,7 @@ impl
                 .error
                 .message
                 .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
-            reason: None,
+            reason: response.error.decline_code,
         })
     }
 }
@@ -647,6 +780,469 @@ impl
     }
 }
 
+impl api::Payouts for Stripe {}
+impl api::PayoutCreate for Stripe {}
+impl api::PayoutFulfill for Stripe {}
+impl api::PayoutCancel for Stripe {}
+impl api::PayoutReverse for Stripe {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Stripe {
+    fn supports_mandate_revoke(&self) -> bool {
+        true
+    }
+}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::MandateRevokeType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    // Stripe deactivates the mandates of a payment method once it is detached from its customer
+    fn get_url(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/payment_methods/{}/detach",
+            self.base_url(connectors),
+            req.request.connector_mandate_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::MandateRevokeType::get_url(self, req, connectors)?)
+                .headers(types::MandateRevokeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::MandateRevokeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::MandateRevokeRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeDetachPaymentMethodResponse = res
+            .response
+            .parse_struct("Stripe DetachPaymentMethodResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::Dispute for Stripe {}
+impl api::AcceptDispute for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::AcceptDisputeType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/disputes/{}/close",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::AcceptDisputeType::get_url(self, req, connectors)?)
+                .headers(types::AcceptDisputeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::AcceptDisputeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::AcceptDisputeRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeDisputeResponse = res
+            .response
+            .parse_struct("Stripe DisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::SubmitEvidence for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::SubmitEvidenceType::get_content_type(self).to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/disputes/{}",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let stripe_req = utils::Encode::<stripe::Evidence>::convert_and_url_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(stripe_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::SubmitEvidenceType::get_url(self, req, connectors)?)
+                .headers(types::SubmitEvidenceType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::SubmitEvidenceType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::SubmitEvidenceRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::SubmitEvidenceRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeDisputeResponse = res
+            .response
+            .parse_struct("Stripe DisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::DefendDispute for Stripe {}
+
+// Stripe submits the evidence for review along with the evidence itself
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Stripe
+{
+}
+
+impl api::FileUpload for Stripe {}
+impl api::UploadFile for Stripe {}
+
+/// Stripe serves the files API from a different host than the rest of its API.
+fn get_file_upload_base_url(
+    connectors: &settings::Connectors,
+) -> CustomResult<&str, errors::ConnectorError> {
+    connectors
+        .stripe
+        .secondary_base_url
+        .as_deref()
+        .ok_or(errors::ConnectorError::FailedToObtainIntegrationUrl)
+        .into_report()
+        .attach_printable("Stripe file upload base URL is not configured")
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::UploadFileRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        // The multipart content type along with its boundary is set when the form is attached
+        self.get_auth_header(&req.connector_auth_type)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "multipart/form-data"
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}v1/files", get_file_upload_base_url(connectors)?))
+    }
+
+    fn get_request_form_data(
+        &self,
+        req: &types::UploadFileRouterData,
+    ) -> CustomResult<Option<reqwest::multipart::Form>, errors::ConnectorError> {
+        let stripe_req = stripe::construct_file_upload_request(req)?;
+        Ok(Some(stripe_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::UploadFileType::get_url(self, req, connectors)?)
+                .headers(types::UploadFileType::get_headers(self, req, connectors)?)
+                .form_data(types::UploadFileType::get_request_form_data(self, req)?)
+                .content_type(services::ContentType::FormData)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::UploadFileRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::UploadFileRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeFileUploadResponse = res
+            .response
+            .parse_struct("Stripe FileUploadResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
 impl api::Refund for Stripe {}
 impl api::RefundExecute for Stripe {}
 impl api::RefundSync for Stripe {}
@@ -943,6 +1539,38 @@ impl api::IncomingWebhook for Stripe {
         &self,
         body: &[u8],
     ) -> CustomResult<String, errors::ConnectorError> {
+        let details: stripe::StripeWebhookObjectEventType = body
+            .parse_struct("StripeWebhookObjectEventType")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        if is_dispute_event(&details.event_type) {
+            // Disputes are looked up through the payment intent they were raised against
+            let dispute: stripe::StripeDisputeWebhookObject = body
+                .parse_struct("StripeDisputeWebhookObject")
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+            return dispute
+                .data
+                .object
+                .payment_intent
+                .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)
+                .into_report();
+        }
+
+        if is_refund_event(&details.event_type) {
+            // Refunds are looked up through the payment intent they were made against
+            let refund: stripe::StripeRefundWebhookObject = body
+                .parse_struct("StripeRefundWebhookObject")
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+            return refund
+                .data
+                .object
+                .payment_intent
+                .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)
+                .into_report();
+        }
+
         let details: stripe::StripeWebhookObjectId = body
             .parse_struct("StripeWebhookObjectId")
             .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
@@ -961,6 +1589,26 @@ impl api::IncomingWebhook for Stripe {
         Ok(match details.event_type.as_str() {
             "payment_intent.payment_failed" => api::IncomingWebhookEvent::PaymentIntentFailure,
             "payment_intent.succeeded" => api::IncomingWebhookEvent::PaymentIntentSuccess,
+            event_type if is_dispute_event(event_type) => {
+                let dispute: stripe::StripeDisputeWebhookObject = body
+                    .parse_struct("StripeDisputeWebhookObject")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+                dispute.data.object.status.into()
+            }
+            event_type if is_refund_event(event_type) => {
+                let refund: stripe::StripeRefundWebhookObject = body
+                    .parse_struct("StripeRefundWebhookObject")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+                match refund.data.object.status {
+                    stripe::RefundStatus::Succeeded => api::IncomingWebhookEvent::RefundSuccess,
+                    stripe::RefundStatus::Failed | stripe::RefundStatus::Canceled => {
+                        api::IncomingWebhookEvent::RefundFailure
+                    }
+                    _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+                }
+            }
             _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
         })
     }
@@ -975,6 +1623,39 @@ impl api::IncomingWebhook for Stripe {
 
         Ok(details.data.object)
     }
+
+    fn get_dispute_details(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<api::DisputePayload, errors::ConnectorError> {
+        let dispute: stripe::StripeDisputeWebhookObject = body
+            .parse_struct("StripeDisputeWebhookObject")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        Ok(dispute.data.object.into())
+    }
+
+    fn get_webhook_connector_refund_id(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let refund: stripe::StripeRefundWebhookObject = body
+            .parse_struct("StripeRefundWebhookObject")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(refund.data.object.id)
+    }
+}
+
+fn is_dispute_event(event_type: &str) -> bool {
+    matches!(
+        event_type,
+        "charge.dispute.created" | "charge.dispute.updated" | "charge.dispute.closed"
+    )
+}
+
+fn is_refund_event(event_type: &str) -> bool {
+    matches!(event_type, "charge.refund.updated" | "refund.updated")
 }
 
 impl services::ConnectorRedirectResponse for Stripe {
@@ -994,3 +1675,230 @@ impl services::ConnectorRedirectResponse for Stripe {
             }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::{api::IncomingWebhook, storage::enums as storage_enums};
+
+    fn dispute_webhook_body(event_type: &str, status: &str) -> Vec<u8> {
+        serde_json::json!({
+            "id": "evt_1MaqRLD5R7gDAGffgM8n4HEv",
+            "type": event_type,
+            "data": {
+                "object": {
+                    "id": "dp_1MaqRKD5R7gDAGffsxuFCqFW",
+                    "object": "dispute",
+                    "amount": 6540,
+                    "currency": "usd",
+                    "reason": "product_not_received",
+                    "status": status,
+                    "payment_intent": "pi_3MaqQkD5R7gDAGff1JsOhZJF",
+                    "evidence_details": { "due_by": 1677542399 },
+                    "created": 1676373626
+                }
+            }
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_dispute_webhook() {
+        let body = dispute_webhook_body("charge.dispute.created", "needs_response");
+
+        assert_eq!(
+            Stripe.get_webhook_event_type(&body).unwrap(),
+            api::IncomingWebhookEvent::DisputeOpened
+        );
+        // Disputes are looked up through the payment intent they were raised against
+        assert_eq!(
+            Stripe.get_webhook_object_reference_id(&body).unwrap(),
+            "pi_3MaqQkD5R7gDAGff1JsOhZJF"
+        );
+
+        let dispute = Stripe.get_dispute_details(&body).unwrap();
+        assert_eq!(dispute.amount, 6540);
+        assert_eq!(dispute.connector_dispute_id, "dp_1MaqRKD5R7gDAGffsxuFCqFW");
+        assert_eq!(dispute.connector_status, "needs_response");
+        assert_eq!(
+            dispute.connector_reason.as_deref(),
+            Some("product_not_received")
+        );
+        assert_eq!(
+            dispute
+                .challenge_required_by
+                .map(|due_by| due_by.assume_utc().unix_timestamp()),
+            Some(1677542399)
+        );
+    }
+
+    #[test]
+    fn test_dispute_webhook_event_type_from_status() {
+        let event_type = |status| {
+            Stripe
+                .get_webhook_event_type(&dispute_webhook_body("charge.dispute.updated", status))
+                .unwrap()
+        };
+
+        assert_eq!(
+            event_type("warning_needs_response"),
+            api::IncomingWebhookEvent::DisputeOpened
+        );
+        assert_eq!(
+            event_type("under_review"),
+            api::IncomingWebhookEvent::DisputeChallenged
+        );
+        assert_eq!(
+            event_type("charge_refunded"),
+            api::IncomingWebhookEvent::DisputeCancelled
+        );
+        assert_eq!(event_type("won"), api::IncomingWebhookEvent::DisputeWon);
+        assert_eq!(event_type("lost"), api::IncomingWebhookEvent::DisputeLost);
+    }
+
+    fn incremental_authorization_router_data() -> types::PaymentsIncrementalAuthorizationRouterData
+    {
+        types::RouterData {
+            flow: std::marker::PhantomData,
+            merchant_id: "merchant_1".to_string(),
+            connector: "stripe".to_string(),
+            payment_id: "pay_123".to_string(),
+            attempt_id: None,
+            status: storage_enums::AttemptStatus::Authorized,
+            payment_method: storage_enums::PaymentMethodType::Card,
+            connector_auth_type: types::ConnectorAuthType::HeaderKey {
+                api_key: "sk_test".to_string(),
+            },
+            description: None,
+            return_url: None,
+            router_return_url: None,
+            address: Default::default(),
+            auth_type: storage_enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            request: types::PaymentsIncrementalAuthorizationData {
+                total_amount: 1500,
+                additional_amount: 500,
+                currency: storage_enums::Currency::USD,
+                connector_transaction_id: "pi_123".to_string(),
+                reason: None,
+            },
+            response: Err(types::ErrorResponse {
+                code: String::new(),
+                message: String::new(),
+                reason: None,
+                status_code: 500,
+            }),
+            payment_method_id: None,
+        }
+    }
+
+    #[test]
+    fn test_incremental_authorization_request() {
+        let router_data = incremental_authorization_router_data();
+        let request_body =
+            types::PaymentsIncrementalAuthorizationType::get_request_body(&Stripe, &router_data)
+                .unwrap();
+        // Stripe expects the total amount to be authorized, not the increment
+        assert_eq!(request_body.as_deref(), Some("amount=1500"));
+
+        let url = types::PaymentsIncrementalAuthorizationType::get_url(
+            &Stripe,
+            &router_data,
+            &settings::Connectors::default(),
+        )
+        .unwrap();
+        assert!(url.ends_with("v1/payment_intents/pi_123/increment_authorization"));
+    }
+
+    fn error_response(
+        code: &str,
+        decline_code: Option<&str>,
+        status_code: u16,
+    ) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            message: String::new(),
+            reason: decline_code.map(str::to_string),
+            status_code,
+        }
+    }
+
+    #[test]
+    fn test_is_retryable_error() {
+        assert!(Stripe.is_retryable_error(&error_response(
+            "card_declined",
+            Some("issuer_not_available"),
+            402
+        )));
+        assert!(Stripe.is_retryable_error(&error_response(
+            "card_declined",
+            Some("try_again_later"),
+            402
+        )));
+        assert!(!Stripe.is_retryable_error(&error_response(
+            "card_declined",
+            Some("insufficient_funds"),
+            402
+        )));
+        // The payment may have been processed in spite of a server error
+        assert!(!Stripe.is_retryable_error(&error_response("api_error", None, 500)));
+        assert!(!Stripe.is_retryable_error(&error_response("rate_limit", None, 429)));
+    }
+
+    #[test]
+    fn test_refund_webhook() {
+        let refund_webhook_body = |status: &str| {
+            serde_json::json!({
+                "id": "evt_3MaqSvD5R7gDAGff0nZ8xY3L",
+                "type": "refund.updated",
+                "data": {
+                    "object": {
+                        "id": "re_3MaqQkD5R7gDAGff1Cr1V3Ub",
+                        "object": "refund",
+                        "amount": 6540,
+                        "payment_intent": "pi_3MaqQkD5R7gDAGff1JsOhZJF",
+                        "status": status
+                    }
+                }
+            })
+            .to_string()
+            .into_bytes()
+        };
+        let body = refund_webhook_body("succeeded");
+
+        assert_eq!(
+            Stripe.get_webhook_event_type(&body).unwrap(),
+            api::IncomingWebhookEvent::RefundSuccess
+        );
+        // Refunds are looked up through the payment intent they were made against
+        assert_eq!(
+            Stripe.get_webhook_object_reference_id(&body).unwrap(),
+            "pi_3MaqQkD5R7gDAGff1JsOhZJF"
+        );
+        assert_eq!(
+            Stripe.get_webhook_connector_refund_id(&body).unwrap(),
+            "re_3MaqQkD5R7gDAGff1Cr1V3Ub"
+        );
+
+        assert_eq!(
+            Stripe
+                .get_webhook_event_type(&refund_webhook_body("failed"))
+                .unwrap(),
+            api::IncomingWebhookEvent::RefundFailure
+        );
+        assert_eq!(
+            Stripe
+                .get_webhook_event_type(&refund_webhook_body("canceled"))
+                .unwrap(),
+            api::IncomingWebhookEvent::RefundFailure
+        );
+        // A refund which is still pending carries no outcome
+        assert!(Stripe
+            .get_webhook_event_type(&refund_webhook_body("pending"))
+            .is_err());
+    }
+}
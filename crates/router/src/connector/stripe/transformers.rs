@@ -88,6 +88,17 @@ pub struct PaymentIntentRequest {
     #[serde(flatten)]
     pub payment_data: Option<StripePaymentMethodData>,
     pub capture_method: StripeCaptureMethod,
+    #[serde(rename = "payment_method_options[card][request_multicapture]")]
+    pub request_multicapture: Option<StripeCardFeatureRequest>,
+    #[serde(rename = "payment_method_options[card][request_incremental_authorization]")]
+    pub request_incremental_authorization: Option<StripeCardFeatureRequest>,
+}
+
+/// Requests a card feature, which is used only if the card supports it
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCardFeatureRequest {
+    IfAvailable,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -273,6 +284,15 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             description: item.description.clone(),
             shipping: shipping_address,
             capture_method: StripeCaptureMethod::from(item.request.capture_method),
+            request_multicapture: matches!(
+                item.request.capture_method,
+                Some(enums::CaptureMethod::ManualMultiple)
+            )
+            .then_some(StripeCardFeatureRequest::IfAvailable),
+            request_incremental_authorization: item
+                .request
+                .request_incremental_authorization
+                .then_some(StripeCardFeatureRequest::IfAvailable),
             payment_data,
             off_session,
             mandate,
@@ -557,13 +577,14 @@ pub enum RefundStatus {
     #[default]
     Pending,
     RequiresAction,
+    Canceled,
 }
 
 impl From<RefundStatus> for enums::RefundStatus {
     fn from(item: RefundStatus) -> Self {
         match item {
             self::RefundStatus::Succeeded => Self::Success,
-            self::RefundStatus::Failed => Self::Failure,
+            self::RefundStatus::Failed | self::RefundStatus::Canceled => Self::Failure,
             self::RefundStatus::Pending => Self::Pending,
             self::RefundStatus::RequiresAction => Self::ManualReview,
         }
@@ -622,6 +643,7 @@ pub struct ErrorDetails {
     pub error_type: Option<String>,
     pub message: Option<String>,
     pub param: Option<String>,
+    pub decline_code: Option<String>,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
@@ -713,6 +735,9 @@ pub struct StripeMandateOptions {
 pub struct CaptureRequest {
     /// If amount_to_capture is None stripe captures the amount in the payment intent.
     amount_to_capture: Option<i64>,
+    /// If final_capture is false the payment intent can be captured again, this requires the
+    /// payment intent to have been confirmed with multicapture requested.
+    final_capture: Option<bool>,
 }
 
 impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
@@ -720,10 +745,42 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
     fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
             amount_to_capture: item.request.amount_to_capture,
+            final_capture: (!item.request.final_capture).then_some(false),
+        })
+    }
+}
+
+/// Represents the incremental authorization request body for stripe connector.
+#[derive(Debug, Serialize, Clone, Copy)]
+pub struct IncrementalAuthorizationRequest {
+    /// The total amount to be authorized on the payment intent after the increment
+    amount: i64,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for IncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.total_amount,
         })
     }
 }
 
+/// Status of a capture, the payment intent remains capturable after a capture which is not final.
+pub fn get_capture_status(
+    status: enums::AttemptStatus,
+    final_capture: bool,
+) -> enums::AttemptStatus {
+    match status {
+        enums::AttemptStatus::Authorized if !final_capture => enums::AttemptStatus::PartialCharged,
+        status => status,
+    }
+}
+
 // #[cfg(test)]
 // mod test_stripe_transformers {
 //     use super::*;
@@ -792,6 +849,275 @@ pub struct StripeWebhookObjectId {
     pub data: StripeWebhookDataId,
 }
 
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StripeDisputeStatus {
+    WarningNeedsResponse,
+    WarningUnderReview,
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    ChargeRefunded,
+    Won,
+    Lost,
+}
+
+impl From<StripeDisputeStatus> for api::IncomingWebhookEvent {
+    fn from(status: StripeDisputeStatus) -> Self {
+        match status {
+            StripeDisputeStatus::WarningNeedsResponse | StripeDisputeStatus::NeedsResponse => {
+                Self::DisputeOpened
+            }
+            StripeDisputeStatus::WarningUnderReview | StripeDisputeStatus::UnderReview => {
+                Self::DisputeChallenged
+            }
+            StripeDisputeStatus::WarningClosed | StripeDisputeStatus::ChargeRefunded => {
+                Self::DisputeCancelled
+            }
+            StripeDisputeStatus::Won => Self::DisputeWon,
+            StripeDisputeStatus::Lost => Self::DisputeLost,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeEvidenceDetails {
+    #[serde(default, with = "time::serde::timestamp::option")]
+    pub due_by: Option<time::OffsetDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeObject {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub reason: Option<String>,
+    pub status: StripeDisputeStatus,
+    pub payment_intent: Option<String>,
+    pub evidence_details: Option<StripeDisputeEvidenceDetails>,
+    #[serde(with = "time::serde::timestamp")]
+    pub created: time::OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeWebhookData {
+    pub object: StripeDisputeObject,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeWebhookObject {
+    pub data: StripeDisputeWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeRefundObject {
+    pub id: String,
+    pub payment_intent: Option<String>,
+    pub status: RefundStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeRefundWebhookData {
+    pub object: StripeRefundObject,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeRefundWebhookObject {
+    pub data: StripeRefundWebhookData,
+}
+
+impl From<StripeDisputeObject> for api::DisputePayload {
+    fn from(dispute: StripeDisputeObject) -> Self {
+        let challenge_required_by = dispute
+            .evidence_details
+            .and_then(|details| details.due_by)
+            .map(common_utils::date_time::convert_to_pdt);
+        Self {
+            amount: dispute.amount,
+            dispute_stage: api_models::enums::DisputeStage::Dispute,
+            connector_status: dispute.status.to_string(),
+            connector_dispute_id: dispute.id,
+            connector_reason: dispute.reason,
+            connector_reason_code: None,
+            challenge_required_by,
+            created_at: Some(common_utils::date_time::convert_to_pdt(dispute.created)),
+            updated_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeResponse {
+    pub id: String,
+    pub status: StripeDisputeStatus,
+}
+
+impl TryFrom<types::AcceptDisputeResponseRouterData<StripeDisputeResponse>>
+    for types::AcceptDisputeRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::AcceptDisputeResponseRouterData<StripeDisputeResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AcceptDisputeResponse {
+                dispute_status: enums::DisputeStatus::DisputeAccepted,
+                connector_status: Some(item.response.status.to_string()),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDetachPaymentMethodResponse {
+    pub id: String,
+    pub customer: Option<String>,
+}
+
+impl TryFrom<types::MandateRevokeResponseRouterData<StripeDetachPaymentMethodResponse>>
+    for types::MandateRevokeRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::MandateRevokeResponseRouterData<StripeDetachPaymentMethodResponse>,
+    ) -> Result<Self, Self::Error> {
+        // A detached payment method is no longer attached to any customer
+        let mandate_status = match item.response.customer {
+            None => enums::MandateStatus::Revoked,
+            Some(_) => enums::MandateStatus::Active,
+        };
+        Ok(Self {
+            response: Ok(types::MandateRevokeResponseData { mandate_status }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Evidence {
+    #[serde(rename = "evidence[cancellation_policy]")]
+    pub cancellation_policy: Option<String>,
+    #[serde(rename = "evidence[customer_communication]")]
+    pub customer_communication: Option<String>,
+    #[serde(rename = "evidence[customer_email_address]")]
+    pub customer_email_address: Option<String>,
+    #[serde(rename = "evidence[customer_name]")]
+    pub customer_name: Option<String>,
+    #[serde(rename = "evidence[product_description]")]
+    pub product_description: Option<String>,
+    #[serde(rename = "evidence[receipt]")]
+    pub receipt: Option<String>,
+    #[serde(rename = "evidence[refund_policy]")]
+    pub refund_policy: Option<String>,
+    #[serde(rename = "evidence[refund_policy_disclosure]")]
+    pub refund_policy_disclosure: Option<String>,
+    #[serde(rename = "evidence[refund_refusal_explanation]")]
+    pub refund_refusal_explanation: Option<String>,
+    #[serde(rename = "evidence[service_documentation]")]
+    pub service_documentation: Option<String>,
+    #[serde(rename = "evidence[shipping_address]")]
+    pub shipping_address: Option<String>,
+    #[serde(rename = "evidence[shipping_carrier]")]
+    pub shipping_carrier: Option<String>,
+    #[serde(rename = "evidence[shipping_date]")]
+    pub shipping_date: Option<String>,
+    #[serde(rename = "evidence[shipping_documentation]")]
+    pub shipping_documentation: Option<String>,
+    #[serde(rename = "evidence[shipping_tracking_number]")]
+    pub shipping_tracking_number: Option<String>,
+    #[serde(rename = "evidence[uncategorized_file]")]
+    pub uncategorized_file: Option<String>,
+    #[serde(rename = "evidence[uncategorized_text]")]
+    pub uncategorized_text: Option<String>,
+    #[serde(rename = "evidence[cancellation_rebuttal]")]
+    pub cancellation_rebuttal: Option<String>,
+    pub submit: bool,
+}
+
+impl TryFrom<&types::SubmitEvidenceRouterData> for Evidence {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SubmitEvidenceRouterData) -> Result<Self, Self::Error> {
+        let submit_evidence_request_data = item.request.clone();
+        Ok(Self {
+            cancellation_policy: submit_evidence_request_data.cancellation_policy,
+            customer_communication: submit_evidence_request_data.customer_communication,
+            customer_email_address: submit_evidence_request_data.customer_email_address,
+            customer_name: submit_evidence_request_data.customer_name,
+            product_description: submit_evidence_request_data.product_description,
+            receipt: submit_evidence_request_data.receipt,
+            refund_policy: submit_evidence_request_data.refund_policy,
+            refund_policy_disclosure: submit_evidence_request_data.refund_policy_disclosure,
+            refund_refusal_explanation: submit_evidence_request_data.refund_refusal_explanation,
+            service_documentation: submit_evidence_request_data.service_documentation,
+            shipping_address: submit_evidence_request_data.shipping_address,
+            shipping_carrier: submit_evidence_request_data.shipping_carrier,
+            shipping_date: submit_evidence_request_data.shipping_date,
+            shipping_documentation: submit_evidence_request_data.shipping_documentation,
+            shipping_tracking_number: submit_evidence_request_data.shipping_tracking_number,
+            uncategorized_file: submit_evidence_request_data.uncategorized_file,
+            uncategorized_text: submit_evidence_request_data.uncategorized_text,
+            cancellation_rebuttal: submit_evidence_request_data.cancellation_rebuttal,
+            submit: true,
+        })
+    }
+}
+
+impl TryFrom<types::SubmitEvidenceResponseRouterData<StripeDisputeResponse>>
+    for types::SubmitEvidenceRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::SubmitEvidenceResponseRouterData<StripeDisputeResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::SubmitEvidenceResponse {
+                dispute_status: enums::DisputeStatus::DisputeChallenged,
+                connector_status: Some(item.response.status.to_string()),
+            }),
+            ..item.data
+        })
+    }
+}
+
+pub fn construct_file_upload_request(
+    item: &types::UploadFileRouterData,
+) -> Result<reqwest::multipart::Form, error_stack::Report<errors::ConnectorError>> {
+    let request = &item.request;
+    let file_part = reqwest::multipart::Part::bytes(request.file.clone())
+        .file_name(request.file_key.clone())
+        .mime_str(request.file_type.as_ref())
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+        .attach_printable("Failure in constructing file data")?;
+    Ok(reqwest::multipart::Form::new()
+        .text("purpose", "dispute_evidence")
+        .part("file", file_part))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeFileUploadResponse {
+    #[serde(rename = "id")]
+    pub file_id: String,
+}
+
+impl TryFrom<types::UploadFileResponseRouterData<StripeFileUploadResponse>>
+    for types::UploadFileRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::UploadFileResponseRouterData<StripeFileUploadResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::UploadFileResponse {
+                provider_file_id: item.response.file_id,
+            }),
+            ..item.data
+        })
+    }
+}
+
 impl TryFrom<(api::PaymentMethod, enums::AuthenticationType)> for StripePaymentMethodData {
     type Error = errors::ConnectorError;
     fn try_from(
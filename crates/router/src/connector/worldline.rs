@@ -395,6 +395,18 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Worldline {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Worldline
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Worldline {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -492,6 +504,88 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::Payouts for Worldline {}
+impl api::PayoutCreate for Worldline {}
+impl api::PayoutFulfill for Worldline {}
+impl api::PayoutCancel for Worldline {}
+impl api::PayoutReverse for Worldline {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl api::mandates::ConnectorMandateRevoke for Worldline {}
+
+impl
+    services::ConnectorIntegration<
+        api::mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Worldline {}
+impl api::AcceptDispute for Worldline {}
+
+impl
+    ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::AcceptDisputeResponse>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for Worldline {}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl api::DefendDispute for Worldline {}
+
+impl
+    ConnectorIntegration<api::Defend, types::DefendDisputeRequestData, types::DefendDisputeResponse>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Worldline {}
+impl api::UploadFile for Worldline {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Worldline {}
 impl api::RefundExecute for Worldline {}
 impl api::RefundSync for Worldline {}
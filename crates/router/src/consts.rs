@@ -23,3 +23,13 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
+
+pub(crate) const WEBHOOK_SIGNING_SECRET_LENGTH: usize = 32;
+
+/// Time for which webhooks are also signed with the previous signing secret of the merchant once
+/// the secret is rotated (in seconds)
+pub(crate) const WEBHOOK_SIGNING_SECRET_ROTATION_GRACE_PERIOD: i64 = 24 * 60 * 60;
+
+/// Time for which the response of a request is replayed to the requests made with the same
+/// idempotency key (in seconds)
+pub(crate) const IDEMPOTENT_RESPONSE_TTL: i64 = 24 * 60 * 60;
@@ -5,9 +5,13 @@ use uuid::Uuid;
 
 use crate::{
     consts,
-    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments,
+    },
     db::StorageInterface,
-    pii::Secret,
+    pii::{Secret, StrongSecret},
+    scheduler::utils as pt_utils,
     services::api as service_api,
     types::{
         self, api,
@@ -17,6 +21,10 @@ use crate::{
     utils::{self, OptionExt},
 };
 
+const CONNECTOR_CREDENTIALS_REENCRYPTION_WORKFLOW_RUNNER: &str =
+    "CONNECTOR_CREDENTIALS_REENCRYPTION_WORKFLOW";
+const CONNECTOR_CREDENTIALS_REENCRYPTION_WORKFLOW_TASK: &str = "CONNECTOR_CREDENTIALS_REENCRYPTION";
+
 #[inline]
 pub fn create_merchant_api_key() -> String {
     format!(
@@ -26,6 +34,17 @@ pub fn create_merchant_api_key() -> String {
     )
 }
 
+#[inline]
+fn create_webhook_signing_secret() -> StrongSecret<String> {
+    format!(
+        "whsec_{}",
+        common_utils::crypto::generate_cryptographically_secure_random_string(
+            consts::WEBHOOK_SIGNING_SECRET_LENGTH
+        )
+    )
+    .into()
+}
+
 pub async fn create_merchant_account(
     db: &dyn StorageInterface,
     req: api::CreateMerchantAccount,
@@ -49,13 +68,14 @@ pub async fn create_merchant_account(
     );
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
+        let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
             .clone()
             .parse_value("RoutingAlgorithm")
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "routing_algorithm",
             })
             .attach_printable("Invalid routing algorithm given")?;
+        payments::routing::validate_routing_algorithm(&routing_algorithm)?;
     }
 
     let merchant_account = storage::MerchantAccountNew {
@@ -79,6 +99,7 @@ pub async fn create_merchant_account(
         publishable_key,
         locker_id: req.locker_id,
         metadata: req.metadata,
+        webhook_signing_secret: Some(create_webhook_signing_secret()),
     };
 
     let merchant_account = db
@@ -127,13 +148,14 @@ pub async fn merchant_account_update(
     }
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
+        let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
             .clone()
             .parse_value("RoutingAlgorithm")
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "routing_algorithm",
             })
             .attach_printable("Invalid routing algorithm given")?;
+        payments::routing::validate_routing_algorithm(&routing_algorithm)?;
     }
 
     let updated_merchant_account = storage::MerchantAccountUpdate::Update {
@@ -239,6 +261,18 @@ async fn validate_merchant_id<S: Into<String>>(
             error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
         })
 }
+fn validate_authorization_expiry_days(authorization_expiry_days: Option<i32>) -> RouterResult<()> {
+    utils::when(
+        authorization_expiry_days.map_or(false, |days| days <= 0),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "authorization_expiry_days".to_string(),
+                expected_format: "positive integer".to_string(),
+            }))
+        },
+    )
+}
+
 // Payment Connector API -  Every merchant and connector can have an instance of (merchant <> connector)
 //                          with unique merchant_connector_id for Create Operation
 
@@ -271,6 +305,8 @@ pub async fn create_payment_connector(
         None => None,
     };
 
+    validate_authorization_expiry_days(req.authorization_expiry_days)?;
+
     // Validate Merchant api details and return error if not in correct format
     let _: types::ConnectorAuthType = req
         .connector_account_details
@@ -291,6 +327,9 @@ pub async fn create_payment_connector(
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
+        authorization_expiry_days: req.authorization_expiry_days,
+        // Set by the storage layer, which encrypts the connector account details
+        key_version: None,
     };
 
     let mca = store
@@ -382,6 +421,8 @@ pub async fn update_payment_connector(
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
 
+    validate_authorization_expiry_days(req.authorization_expiry_days)?;
+
     let payment_methods_enabled = req.payment_methods_enabled.map(|pm_enabled| {
         pm_enabled
             .iter()
@@ -401,6 +442,9 @@ pub async fn update_payment_connector(
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
+        authorization_expiry_days: req.authorization_expiry_days,
+        // Set by the storage layer, which encrypts the connector account details
+        key_version: None,
     };
 
     let updated_mca = db
@@ -431,6 +475,7 @@ pub async fn update_payment_connector(
         disabled: updated_mca.disabled,
         payment_methods_enabled: updated_pm_enabled,
         metadata: updated_mca.metadata,
+        authorization_expiry_days: updated_mca.authorization_expiry_days,
     };
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -517,6 +562,131 @@ pub async fn kv_for_merchant(
     ))
 }
 
+pub async fn rotate_webhook_signing_secret(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+) -> RouterResponse<api::WebhookSigningSecretResponse> {
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    // The previous secret keeps being used for a while, so that webhooks already sent can still be
+    // verified with it
+    let previous_webhook_signing_secret_expires_at = common_utils::date_time::now().saturating_add(
+        time::Duration::seconds(consts::WEBHOOK_SIGNING_SECRET_ROTATION_GRACE_PERIOD),
+    );
+    let webhook_signing_secret_update =
+        merchant_account::MerchantAccountUpdate::WebhookSigningSecretUpdate {
+            webhook_signing_secret: create_webhook_signing_secret(),
+            previous_webhook_signing_secret: merchant_account.webhook_signing_secret.clone(),
+            previous_webhook_signing_secret_expires_at,
+        };
+    let updated_merchant_account = db
+        .update_merchant(merchant_account, webhook_signing_secret_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to rotate the webhook signing secret")?;
+
+    let webhook_signing_secret = updated_merchant_account
+        .webhook_signing_secret
+        .get_required_value("webhook_signing_secret")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::WebhookSigningSecretResponse {
+            merchant_id: updated_merchant_account.merchant_id,
+            webhook_signing_secret,
+            previous_webhook_signing_secret_expires_at: updated_merchant_account
+                .previous_webhook_signing_secret_expires_at,
+        },
+    ))
+}
+
+pub async fn rotate_connector_credentials_key(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+) -> RouterResponse<api::ConnectorCredentialsKeyRotationResponse> {
+    db.find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    // Connector account details are encrypted with the new data key from now on, while the ones
+    // encrypted with previous keys remain readable until they are re-encrypted in the background
+    let key_store = db
+        .create_merchant_key_store(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create a new data key for the merchant")?;
+
+    add_connector_credentials_reencryption_task(db, &key_store).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::ConnectorCredentialsKeyRotationResponse {
+            merchant_id: key_store.merchant_id,
+            key_version: key_store.key_version,
+        },
+    ))
+}
+
+async fn add_connector_credentials_reencryption_task(
+    db: &dyn StorageInterface,
+    key_store: &storage::MerchantKeyStore,
+) -> RouterResult<()> {
+    let tracking_data = storage::ConnectorCredentialsReencryptionTrackingData {
+        merchant_id: key_store.merchant_id.clone(),
+        key_version: key_store.key_version,
+    };
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: pt_utils::get_process_tracker_id(
+            CONNECTOR_CREDENTIALS_REENCRYPTION_WORKFLOW_RUNNER,
+            CONNECTOR_CREDENTIALS_REENCRYPTION_WORKFLOW_TASK,
+            &key_store.key_version.to_string(),
+            &key_store.merchant_id,
+        ),
+        name: Some(String::from(
+            CONNECTOR_CREDENTIALS_REENCRYPTION_WORKFLOW_TASK,
+        )),
+        tag: vec![
+            String::from("CONNECTOR_CREDENTIALS"),
+            String::from("REENCRYPTION"),
+        ],
+        runner: Some(String::from(
+            CONNECTOR_CREDENTIALS_REENCRYPTION_WORKFLOW_RUNNER,
+        )),
+        retry_count: 0,
+        schedule_time: Some(current_time),
+        rule: String::new(),
+        tracking_data:
+            utils::Encode::<storage::ConnectorCredentialsReencryptionTrackingData>::encode_to_value(
+                &tracking_data,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: merchant_id: {}",
+                key_store.merchant_id
+            )
+        })?;
+
+    Ok(())
+}
+
 pub async fn check_merchant_account_kv_status(
     db: &dyn StorageInterface,
     merchant_id: String,
@@ -5,7 +5,7 @@ use router_env::{instrument, tracing};
 
 use crate::{
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
     db::StorageInterface,
     services::ApplicationResponse,
     types::{api, storage, transformers::ForeignInto},
@@ -112,6 +112,7 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        scopes: api_key.scopes.iter().map(ToString::to_string).collect(),
     };
 
     let api_key = store
@@ -190,6 +191,58 @@ pub async fn list_api_keys(
     Ok(ApplicationResponse::Json(api_keys))
 }
 
+/// Verifies an API key created through the API keys API, returning the stored API key if it has
+/// not expired and has been granted the given scope.
+#[instrument(skip_all)]
+pub async fn verify_api_key_scope(
+    store: &dyn StorageInterface,
+    api_key: &str,
+    scope: api::ApiKeyScope,
+) -> RouterResult<storage::ApiKey> {
+    // API keys are handed out as `{key_id}-{plaintext_api_key}`
+    let (key_id, plaintext_api_key) = api_key
+        .rsplit_once('-')
+        .ok_or(errors::ApiErrorResponse::Unauthorized)
+        .into_report()
+        .attach_printable("API key is not in the expected format")?;
+
+    let stored_api_key = store
+        .find_api_key_optional(key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(errors::ApiErrorResponse::Unauthorized)
+        .into_report()
+        .attach_printable("API key does not exist")?;
+
+    let hash_key = hex::decode(stored_api_key.hash_key.peek())
+        .ok()
+        .and_then(|hash_key| <[u8; PlaintextApiKey::HASH_KEY_LEN]>::try_from(hash_key).ok())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Failed to decode the hash key of the API key")?;
+    PlaintextApiKey(plaintext_api_key.to_owned().into())
+        .verify_hash(
+            &hash_key,
+            &HashedApiKey(stored_api_key.hashed_api_key.as_str().to_owned()),
+        )
+        .change_context(errors::ApiErrorResponse::Unauthorized)?;
+
+    let is_expired = stored_api_key
+        .expires_at
+        .map_or(false, |expires_at| expires_at <= date_time::now());
+    fp_utils::when(is_expired, || {
+        Err(report!(errors::ApiErrorResponse::Unauthorized)).attach_printable("API key has expired")
+    })?;
+
+    fp_utils::when(!stored_api_key.scopes.contains(&scope.to_string()), || {
+        Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable_lazy(|| format!("API key has not been granted the {scope} scope"))
+    })?;
+
+    Ok(stored_api_key)
+}
+
 impl From<HashedApiKey> for storage::HashedApiKey {
     fn from(hashed_api_key: HashedApiKey) -> Self {
         hashed_api_key.0.into()
@@ -1,25 +1,32 @@
 use common_utils::ext_traits::ValueExt;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 use storage_models::errors as storage_errors;
 
 use crate::{
+    consts,
     core::{
-        errors::{self, RouterResponse, StorageErrorExt},
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::cards,
     },
     db::StorageInterface,
     pii::PeekInterface,
     routes::AppState,
+    scheduler::utils as pt_utils,
     services,
     types::{
         api::customers::{self, CustomerRequestExt},
         storage::{self, enums},
+        transformers::ForeignInto,
     },
+    utils::{generate_id, Encode},
 };
 
 pub const REDACTED: &str = "Redacted";
 
+const CUSTOMER_ERASURE_WORKFLOW_RUNNER: &str = "CUSTOMER_ERASURE_WORKFLOW";
+const CUSTOMER_ERASURE_WORKFLOW_TASK: &str = "CUSTOMER_ERASURE";
+
 #[instrument(skip(db))]
 pub async fn create_customer(
     db: &dyn StorageInterface,
@@ -163,53 +170,8 @@ pub async fn delete_customer(
         }?,
     };
 
-    let update_address = storage::AddressUpdate::Update {
-        city: Some(REDACTED.to_string()),
-        country: Some(REDACTED.to_string()),
-        line1: Some(REDACTED.to_string().into()),
-        line2: Some(REDACTED.to_string().into()),
-        line3: Some(REDACTED.to_string().into()),
-        state: Some(REDACTED.to_string().into()),
-        zip: Some(REDACTED.to_string().into()),
-        first_name: Some(REDACTED.to_string().into()),
-        last_name: Some(REDACTED.to_string().into()),
-        phone_number: Some(REDACTED.to_string().into()),
-        country_code: Some(REDACTED.to_string()),
-    };
-
-    match db
-        .update_address_by_merchant_id_customer_id(
-            &req.customer_id,
-            &merchant_account.merchant_id,
-            update_address,
-        )
-        .await
-    {
-        Ok(_) => Ok(()),
-        Err(error) => match error.current_context() {
-            errors::StorageError::DatabaseError(err) => match err.current_context() {
-                storage_errors::DatabaseError::NotFound => Ok(()),
-                _ => Err(errors::ApiErrorResponse::InternalServerError),
-            },
-            _ => Err(errors::ApiErrorResponse::InternalServerError),
-        },
-    }?;
-
-    let updated_customer = storage::CustomerUpdate::Update {
-        name: Some(REDACTED.to_string()),
-        email: Some(REDACTED.to_string().into()),
-        phone: Some(REDACTED.to_string().into()),
-        description: Some(REDACTED.to_string()),
-        phone_country_code: Some(REDACTED.to_string()),
-        metadata: None,
-    };
-    db.update_customer_by_customer_id_merchant_id(
-        req.customer_id.clone(),
-        merchant_account.merchant_id,
-        updated_customer,
-    )
-    .await
-    .change_context(errors::ApiErrorResponse::CustomerNotFound)?;
+    redact_customer_addresses(&**db, &merchant_account.merchant_id, &req.customer_id).await?;
+    redact_customer(&**db, &merchant_account.merchant_id, &req.customer_id).await?;
 
     let response = customers::CustomerDeleteResponse {
         customer_id: req.customer_id,
@@ -289,3 +251,509 @@ pub async fn update_customer(
         customer_update_response,
     ))
 }
+
+/// Redacts every address of the customer, returning the number of addresses redacted
+async fn redact_customer_addresses(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+) -> RouterResult<usize> {
+    let update_address = storage::AddressUpdate::Update {
+        city: Some(REDACTED.to_string()),
+        country: Some(REDACTED.to_string()),
+        line1: Some(REDACTED.to_string().into()),
+        line2: Some(REDACTED.to_string().into()),
+        line3: Some(REDACTED.to_string().into()),
+        state: Some(REDACTED.to_string().into()),
+        zip: Some(REDACTED.to_string().into()),
+        first_name: Some(REDACTED.to_string().into()),
+        last_name: Some(REDACTED.to_string().into()),
+        phone_number: Some(REDACTED.to_string().into()),
+        country_code: Some(REDACTED.to_string()),
+    };
+
+    match db
+        .update_address_by_merchant_id_customer_id(customer_id, merchant_id, update_address)
+        .await
+    {
+        Ok(addresses) => Ok(addresses.len()),
+        Err(error) => match error.current_context() {
+            errors::StorageError::DatabaseError(err) => match err.current_context() {
+                storage_errors::DatabaseError::NotFound => Ok(0),
+                _ => Err(errors::ApiErrorResponse::InternalServerError),
+            },
+            _ => Err(errors::ApiErrorResponse::InternalServerError),
+        },
+    }
+    .into_report()
+}
+
+async fn redact_customer(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+) -> RouterResult<()> {
+    let updated_customer = storage::CustomerUpdate::Update {
+        name: Some(REDACTED.to_string()),
+        email: Some(REDACTED.to_string().into()),
+        phone: Some(REDACTED.to_string().into()),
+        description: Some(REDACTED.to_string()),
+        phone_country_code: Some(REDACTED.to_string()),
+        metadata: None,
+    };
+    db.update_customer_by_customer_id_merchant_id(
+        customer_id.to_string(),
+        merchant_id.to_string(),
+        updated_customer,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    Ok(())
+}
+
+/// Lists the records of the customer, treating a missing record as an empty list
+fn get_customer_records<T>(
+    result: errors::CustomResult<Vec<T>, errors::StorageError>,
+) -> RouterResult<Vec<T>> {
+    match result {
+        Ok(records) => Ok(records),
+        Err(error) if error.current_context().is_db_not_found() => Ok(Vec::new()),
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError)),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn export_customer(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: customers::CustomerId,
+) -> RouterResponse<customers::CustomerExportResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(&req.customer_id, merchant_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
+    let addresses = get_customer_records(
+        db.find_address_by_merchant_id_customer_id(merchant_id, &req.customer_id)
+            .await,
+    )?;
+    let payment_methods = get_customer_records(
+        db.find_payment_method_by_customer_id_merchant_id_list(&req.customer_id, merchant_id)
+            .await,
+    )?;
+    let mandates = get_customer_records(
+        db.find_mandate_by_merchant_id_customer_id(merchant_id, &req.customer_id)
+            .await,
+    )?;
+    let payment_intents = get_customer_records(
+        db.find_payment_intent_by_merchant_id_customer_id(
+            merchant_id,
+            &req.customer_id,
+            merchant_account.storage_scheme,
+        )
+        .await,
+    )?;
+
+    let response = customers::CustomerExportResponse {
+        customer: customers::CustomerResponse::from(customer).into_inner(),
+        addresses: addresses.iter().map(ForeignInto::foreign_into).collect(),
+        payment_methods: payment_methods
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+        mandates: mandates
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+        payments: payment_intents
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+        exported_at: common_utils::date_time::now(),
+    };
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn create_customer_erasure(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: customers::CustomerId,
+) -> RouterResponse<customers::CustomerErasureResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    db.find_customer_by_customer_id_merchant_id(&req.customer_id, merchant_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
+    let customer_mandates = get_customer_records(
+        db.find_mandate_by_merchant_id_customer_id(merchant_id, &req.customer_id)
+            .await,
+    )?;
+    if customer_mandates
+        .iter()
+        .any(|mandate| mandate.mandate_status == enums::MandateStatus::Active)
+    {
+        Err(errors::ApiErrorResponse::MandateActive)?
+    }
+
+    let customer_erasure = db
+        .insert_customer_erasure(storage::CustomerErasureNew {
+            erasure_id: generate_id(consts::ID_LENGTH, "erasure"),
+            merchant_id: merchant_id.to_string(),
+            customer_id: req.customer_id,
+            status: enums::CustomerErasureStatus::Pending,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting the customer erasure")?;
+
+    add_customer_erasure_task(db, &customer_erasure).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        customer_erasure.foreign_into(),
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn retrieve_customer_erasure(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    req: customers::CustomerErasureId,
+) -> RouterResponse<customers::CustomerErasureResponse> {
+    let customer_erasure = db
+        .find_customer_erasure_by_merchant_id_erasure_id(
+            &merchant_account.merchant_id,
+            &req.erasure_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::CustomerErasureNotFound)
+        })?;
+
+    if customer_erasure.customer_id != req.customer_id {
+        Err(errors::ApiErrorResponse::CustomerErasureNotFound)?
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        customer_erasure.foreign_into(),
+    ))
+}
+
+/// Erases the personal data held about the customer: the customer and its addresses are
+/// redacted, its payment methods are deleted along with their locker entries, and the
+/// description and metadata of its payments are redacted. Every step can safely be repeated,
+/// so that a failed erasure can be retried.
+#[instrument(skip_all)]
+pub async fn erase_customer_data(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    customer_id: &str,
+) -> RouterResult<storage::CustomerErasureUpdate> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let payment_methods = get_customer_records(
+        db.find_payment_method_by_customer_id_merchant_id_list(customer_id, merchant_id)
+            .await,
+    )?;
+    // A previous attempt of the erasure may have deleted the card from the locker before failing
+    for payment_method in payment_methods.iter() {
+        if payment_method.payment_method == enums::PaymentMethodType::Card {
+            cards::delete_card_if_present(state, merchant_id, &payment_method.payment_method_id)
+                .await?;
+        }
+        db.delete_payment_method_by_merchant_id_payment_method_id(
+            merchant_id,
+            &payment_method.payment_method_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+    }
+
+    let addresses_redacted = redact_customer_addresses(db, merchant_id, customer_id).await?;
+    redact_customer(db, merchant_id, customer_id).await?;
+
+    let payment_intents = get_customer_records(
+        db.find_payment_intent_by_merchant_id_customer_id(
+            merchant_id,
+            customer_id,
+            merchant_account.storage_scheme,
+        )
+        .await,
+    )?;
+    let mut payments_redacted = 0;
+    for payment_intent in payment_intents {
+        if payment_intent.description.is_none() && payment_intent.metadata.is_none() {
+            continue;
+        }
+        let pii_redaction_update = storage::PaymentIntentUpdate::PiiRedactionUpdate {
+            description: payment_intent
+                .description
+                .as_ref()
+                .map(|_| REDACTED.to_string()),
+            metadata: payment_intent
+                .metadata
+                .as_ref()
+                .map(|_| serde_json::Value::Null),
+        };
+        db.update_payment_intent(
+            payment_intent,
+            pii_redaction_update,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while redacting the payment intent")?;
+        payments_redacted += 1;
+    }
+
+    Ok(storage::CustomerErasureUpdate::CompletedUpdate {
+        addresses_redacted: count_to_i32(addresses_redacted)?,
+        payments_redacted,
+        payment_methods_deleted: count_to_i32(payment_methods.len())?,
+    })
+}
+
+fn count_to_i32(count: usize) -> RouterResult<i32> {
+    i32::try_from(count)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Record count does not fit in i32")
+}
+
+pub async fn add_customer_erasure_task(
+    db: &dyn StorageInterface,
+    customer_erasure: &storage::CustomerErasure,
+) -> RouterResult<()> {
+    let tracking_data = storage::CustomerErasureTrackingData {
+        merchant_id: customer_erasure.merchant_id.clone(),
+        erasure_id: customer_erasure.erasure_id.clone(),
+    };
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: pt_utils::get_process_tracker_id(
+            CUSTOMER_ERASURE_WORKFLOW_RUNNER,
+            CUSTOMER_ERASURE_WORKFLOW_TASK,
+            &customer_erasure.erasure_id,
+            &customer_erasure.merchant_id,
+        ),
+        name: Some(String::from(CUSTOMER_ERASURE_WORKFLOW_TASK)),
+        tag: vec![String::from("CUSTOMER"), String::from("ERASURE")],
+        runner: Some(String::from(CUSTOMER_ERASURE_WORKFLOW_RUNNER)),
+        retry_count: 0,
+        schedule_time: Some(current_time),
+        rule: String::new(),
+        tracking_data: Encode::<storage::CustomerErasureTrackingData>::encode_to_value(
+            &tracking_data,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: erasure_id: {}",
+                customer_erasure.erasure_id
+            )
+        })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl};
+
+    const MERCHANT_ID: &str = "merchant_1";
+    const CUSTOMER_ID: &str = "cus_1";
+
+    /// Creates a customer with an address, a card and a bank debit payment method and a payment.
+    /// The card is not stored in the locker, as if a previous erasure had already deleted it.
+    async fn create_customer_records(state: &AppState) -> storage::MerchantAccount {
+        let db = &*state.store;
+        let merchant_account = db
+            .insert_merchant(storage::MerchantAccountNew {
+                merchant_id: MERCHANT_ID.to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        db.insert_customer(storage::CustomerNew {
+            customer_id: CUSTOMER_ID.to_string(),
+            merchant_id: MERCHANT_ID.to_string(),
+            name: Some("John Doe".to_string()),
+            email: Some("john@example.com".to_string().into()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        db.insert_address(storage::AddressNew {
+            city: Some("Berlin".to_string()),
+            line1: Some("Alexanderplatz 1".to_string().into()),
+            customer_id: CUSTOMER_ID.to_string(),
+            merchant_id: MERCHANT_ID.to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        for (payment_method_id, payment_method) in [
+            ("pm_card", enums::PaymentMethodType::Card),
+            ("pm_bank_debit", enums::PaymentMethodType::BankDebit),
+        ] {
+            db.insert_payment_method(storage::PaymentMethodNew {
+                customer_id: CUSTOMER_ID.to_string(),
+                merchant_id: MERCHANT_ID.to_string(),
+                payment_method_id: payment_method_id.to_string(),
+                payment_method,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        }
+
+        db.insert_payment_intent(
+            storage::PaymentIntentNew {
+                payment_id: "pay_1".to_string(),
+                merchant_id: MERCHANT_ID.to_string(),
+                amount: 100,
+                customer_id: Some(CUSTOMER_ID.to_string()),
+                description: Some("Order for John Doe".to_string()),
+                metadata: Some(serde_json::json!({ "order_id": "order_1" })),
+                ..Default::default()
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .unwrap();
+
+        merchant_account
+    }
+
+    #[actix_rt::test]
+    async fn test_export_customer() {
+        let conf = Settings::new().expect("invalid settings");
+        let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+        let merchant_account = create_customer_records(&state).await;
+
+        let response = export_customer(
+            &state,
+            merchant_account,
+            customers::CustomerId {
+                customer_id: CUSTOMER_ID.to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        let export = match response {
+            services::ApplicationResponse::Json(export) => export,
+            _ => panic!("Unexpected response"),
+        };
+
+        assert_eq!(export.customer.customer_id, CUSTOMER_ID);
+        assert_eq!(export.customer.name.as_deref(), Some("John Doe"));
+        assert_eq!(export.addresses.len(), 1);
+        assert_eq!(
+            export.addresses[0]
+                .address
+                .as_ref()
+                .and_then(|address| address.city.as_deref()),
+            Some("Berlin")
+        );
+        assert_eq!(export.payment_methods.len(), 2);
+        assert!(export.mandates.is_empty());
+        assert_eq!(export.payments.len(), 1);
+        assert_eq!(
+            export.payments[0].description.as_deref(),
+            Some("Order for John Doe")
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_erase_customer_data() {
+        let conf = Settings::new().expect("invalid settings");
+        let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+        let merchant_account = create_customer_records(&state).await;
+        let db = &*state.store;
+
+        let erasure_update = erase_customer_data(&state, &merchant_account, CUSTOMER_ID)
+            .await
+            .unwrap();
+        match erasure_update {
+            storage::CustomerErasureUpdate::CompletedUpdate {
+                addresses_redacted,
+                payments_redacted,
+                payment_methods_deleted,
+            } => {
+                assert_eq!(addresses_redacted, 1);
+                assert_eq!(payments_redacted, 1);
+                assert_eq!(payment_methods_deleted, 2);
+            }
+            _ => panic!("Unexpected erasure update"),
+        }
+
+        let customer = db
+            .find_customer_by_customer_id_merchant_id(CUSTOMER_ID, MERCHANT_ID)
+            .await;
+        assert!(matches!(
+            customer.unwrap_err().current_context(),
+            errors::StorageError::CustomerRedacted
+        ));
+
+        let addresses = db
+            .find_address_by_merchant_id_customer_id(MERCHANT_ID, CUSTOMER_ID)
+            .await
+            .unwrap();
+        assert_eq!(addresses[0].city.as_deref(), Some(REDACTED));
+        assert_eq!(
+            addresses[0]
+                .line1
+                .as_ref()
+                .map(|line1| line1.peek().as_str()),
+            Some(REDACTED)
+        );
+
+        let payment_methods = db
+            .find_payment_method_by_customer_id_merchant_id_list(CUSTOMER_ID, MERCHANT_ID)
+            .await
+            .unwrap();
+        assert!(payment_methods.is_empty());
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                "pay_1",
+                MERCHANT_ID,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(payment_intent.description.as_deref(), Some(REDACTED));
+        assert_eq!(payment_intent.metadata, Some(serde_json::Value::Null));
+
+        // The erasure can be retried once it has completed
+        assert!(erase_customer_data(&state, &merchant_account, CUSTOMER_ID)
+            .await
+            .is_ok());
+    }
+}
@@ -0,0 +1,518 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as files_helpers,
+        payments, utils as core_utils,
+    },
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, disputes},
+        storage::{self, enums},
+        transformers::{Foreign, ForeignInto},
+    },
+    utils,
+};
+
+// ********************************************** DISPUTE RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn retrieve_dispute(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+) -> RouterResponse<disputes::DisputeResponse> {
+    let dispute = find_dispute(state, &merchant_account, &dispute_id).await?;
+    Ok(services::ApplicationResponse::Json(dispute.foreign_into()))
+}
+
+async fn find_dispute(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    dispute_id: &str,
+) -> RouterResult<storage::Dispute> {
+    state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, dispute_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+                dispute_id: dispute_id.to_string(),
+            })
+        })
+}
+
+// ********************************************** DISPUTE LIST **********************************************
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn retrieve_disputes_list(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: disputes::DisputeListConstraints,
+) -> RouterResponse<disputes::DisputeListResponse> {
+    let disputes = state
+        .store
+        .find_disputes_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve disputes")?;
+
+    let data: Vec<disputes::DisputeResponse> = disputes
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        disputes::DisputeListResponse { data },
+    ))
+}
+
+// ********************************************** DISPUTE ACCEPT **********************************************
+
+#[instrument(skip_all)]
+pub async fn accept_dispute(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+) -> RouterResponse<disputes::DisputeResponse> {
+    let db = &*state.store;
+    let dispute = find_dispute(state, &merchant_account, &dispute_id).await?;
+    validate_dispute_can_be_accepted(&dispute)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &dispute.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data = core_utils::construct_accept_dispute_router_data(
+        state,
+        &payment_intent,
+        &payment_attempt,
+        &merchant_account,
+        &dispute,
+    )
+    .await?;
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_dispute_failed_response())
+    .attach_printable("Failed while calling accept dispute connector api")?;
+
+    let accept_dispute_response =
+        response
+            .response
+            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: dispute.connector.clone(),
+                status_code: err.status_code,
+            })?;
+
+    let dispute_update = storage::DisputeUpdate::StatusUpdate {
+        dispute_status: accept_dispute_response.dispute_status,
+        connector_status: accept_dispute_response.connector_status,
+    };
+
+    let updated_dispute = db
+        .update_dispute(dispute, dispute_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        updated_dispute.foreign_into(),
+    ))
+}
+
+/// Disputes can be accepted until they are closed, even after evidence has been submitted
+fn validate_dispute_can_be_accepted(dispute: &storage::Dispute) -> RouterResult<()> {
+    utils::when(
+        !matches!(
+            dispute.dispute_status,
+            enums::DisputeStatus::DisputeOpened | enums::DisputeStatus::DisputeChallenged
+        ),
+        || {
+            Err(report!(
+                errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                    reason: format!(
+                        "This dispute cannot be accepted because the dispute is in {} stage",
+                        dispute.dispute_status
+                    ),
+                }
+            ))
+        },
+    )
+}
+
+// ********************************************** DISPUTE EVIDENCE **********************************************
+
+#[instrument(skip_all)]
+pub async fn submit_evidence(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: disputes::SubmitEvidenceRequest,
+) -> RouterResponse<disputes::DisputeResponse> {
+    let db = &*state.store;
+    let dispute_id = req.dispute_id.clone();
+    let dispute = find_dispute(state, &merchant_account, &dispute_id).await?;
+    validate_dispute_can_be_challenged(&dispute)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &dispute.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let get_connector_file_id = |file_id: Option<String>| {
+        let (merchant_account, payment_intent, payment_attempt, connector) = (
+            &merchant_account,
+            &payment_intent,
+            &payment_attempt,
+            &dispute.connector,
+        );
+        async move {
+            match file_id {
+                Some(file_id) => files_helpers::get_connector_file_id(
+                    state,
+                    merchant_account,
+                    payment_intent,
+                    payment_attempt,
+                    connector,
+                    &file_id,
+                )
+                .await
+                .map(Some),
+                None => Ok(None),
+            }
+        }
+    };
+
+    let submit_evidence_request_data = types::SubmitEvidenceRequestData {
+        dispute_id: dispute.dispute_id.clone(),
+        connector_dispute_id: dispute.connector_dispute_id.clone(),
+        receipt: get_connector_file_id(req.receipt).await?,
+        shipping_documentation: get_connector_file_id(req.shipping_documentation).await?,
+        customer_communication: get_connector_file_id(req.customer_communication).await?,
+        refund_policy: get_connector_file_id(req.refund_policy).await?,
+        cancellation_policy: get_connector_file_id(req.cancellation_policy).await?,
+        service_documentation: get_connector_file_id(req.service_documentation).await?,
+        recurring_transaction_agreement: get_connector_file_id(req.recurring_transaction_agreement)
+            .await?,
+        invoice_showing_distinct_transactions: get_connector_file_id(
+            req.invoice_showing_distinct_transactions,
+        )
+        .await?,
+        uncategorized_file: get_connector_file_id(req.uncategorized_file).await?,
+        customer_name: req.customer_name,
+        customer_email_address: req.customer_email_address,
+        product_description: req.product_description,
+        shipping_address: req.shipping_address,
+        shipping_carrier: req.shipping_carrier,
+        shipping_tracking_number: req.shipping_tracking_number,
+        shipping_date: req.shipping_date,
+        refund_policy_disclosure: req.refund_policy_disclosure,
+        refund_refusal_explanation: req.refund_refusal_explanation,
+        cancellation_rebuttal: req.cancellation_rebuttal,
+        uncategorized_text: req.uncategorized_text,
+    };
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data = core_utils::construct_submit_evidence_router_data(
+        state,
+        &payment_intent,
+        &payment_attempt,
+        &merchant_account,
+        &dispute.connector,
+        submit_evidence_request_data,
+    )
+    .await?;
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_dispute_failed_response())
+    .attach_printable("Failed while calling submit evidence connector api")?;
+
+    let submit_evidence_response =
+        response
+            .response
+            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: dispute.connector.clone(),
+                status_code: err.status_code,
+            })?;
+
+    // Some connectors only save the evidence in the previous step and need a separate call to
+    // submit it for review
+    let connector_integration_defend_dispute: services::BoxedConnectorIntegration<
+        '_,
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > = connector_data.connector.get_connector_integration();
+
+    let defend_dispute_router_data = core_utils::construct_defend_dispute_router_data(
+        state,
+        &payment_intent,
+        &payment_attempt,
+        &merchant_account,
+        &dispute,
+        submit_evidence_response,
+    )
+    .await?;
+
+    let defend_response = services::execute_connector_processing_step(
+        state,
+        connector_integration_defend_dispute,
+        &defend_dispute_router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_dispute_failed_response())
+    .attach_printable("Failed while calling defend dispute connector api")?;
+
+    let defend_dispute_response = defend_response.response.map_err(|err| {
+        errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: dispute.connector.clone(),
+            status_code: err.status_code,
+        }
+    })?;
+
+    let dispute_update = storage::DisputeUpdate::StatusUpdate {
+        dispute_status: defend_dispute_response.dispute_status,
+        connector_status: defend_dispute_response.connector_status,
+    };
+
+    let updated_dispute = db
+        .update_dispute(dispute, dispute_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        updated_dispute.foreign_into(),
+    ))
+}
+
+/// Evidence can only be submitted once, while the dispute is open
+fn validate_dispute_can_be_challenged(dispute: &storage::Dispute) -> RouterResult<()> {
+    utils::when(
+        !matches!(dispute.dispute_status, enums::DisputeStatus::DisputeOpened),
+        || {
+            Err(report!(
+                errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                    reason: format!(
+                        "Evidence cannot be submitted because the dispute is in {} stage",
+                        dispute.dispute_status
+                    ),
+                }
+            ))
+        },
+    )
+}
+
+impl From<Foreign<storage::Dispute>> for Foreign<disputes::DisputeResponse> {
+    fn from(dispute: Foreign<storage::Dispute>) -> Self {
+        let dispute = dispute.0;
+        disputes::DisputeResponse {
+            dispute_id: dispute.dispute_id,
+            payment_id: dispute.payment_id,
+            attempt_id: dispute.attempt_id,
+            amount: dispute.amount,
+            currency: dispute.currency.foreign_into(),
+            dispute_stage: dispute.dispute_stage.foreign_into(),
+            dispute_status: dispute.dispute_status.foreign_into(),
+            connector: dispute.connector,
+            connector_status: dispute.connector_status,
+            connector_dispute_id: dispute.connector_dispute_id,
+            connector_reason: dispute.connector_reason,
+            connector_reason_code: dispute.connector_reason_code,
+            challenge_required_by: dispute.challenge_required_by,
+            dispute_created_at: dispute.dispute_created_at,
+            updated_at: dispute.updated_at,
+            received_at: dispute.created_at,
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::transformers::ForeignTryInto;
+
+    fn dispute(dispute_status: enums::DisputeStatus) -> storage::Dispute {
+        let now = common_utils::date_time::now();
+        storage::Dispute {
+            id: 1,
+            dispute_id: "dp_mbabizu24mvu3mela5njyhpit4".to_string(),
+            amount: 6540,
+            currency: enums::Currency::USD,
+            dispute_stage: enums::DisputeStage::Dispute,
+            dispute_status,
+            payment_id: "pay_mbabizu24mvu3mela5njyhpit4".to_string(),
+            attempt_id: "pay_mbabizu24mvu3mela5njyhpit4_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            connector: "stripe".to_string(),
+            connector_status: "needs_response".to_string(),
+            connector_dispute_id: "dp_1MaqRKD5R7gDAGffsxuFCqFW".to_string(),
+            connector_reason: Some("product_not_received".to_string()),
+            connector_reason_code: None,
+            challenge_required_by: Some(now),
+            dispute_created_at: Some(now),
+            updated_at: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_validate_dispute_can_be_accepted() {
+        assert!(
+            validate_dispute_can_be_accepted(&dispute(enums::DisputeStatus::DisputeOpened)).is_ok()
+        );
+        assert!(validate_dispute_can_be_accepted(&dispute(
+            enums::DisputeStatus::DisputeChallenged
+        ))
+        .is_ok());
+        assert!(
+            validate_dispute_can_be_accepted(&dispute(enums::DisputeStatus::DisputeWon)).is_err()
+        );
+        assert!(
+            validate_dispute_can_be_accepted(&dispute(enums::DisputeStatus::DisputeAccepted))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_dispute_can_be_challenged() {
+        assert!(
+            validate_dispute_can_be_challenged(&dispute(enums::DisputeStatus::DisputeOpened))
+                .is_ok()
+        );
+        // Evidence has already been submitted for disputes which are being challenged
+        assert!(validate_dispute_can_be_challenged(&dispute(
+            enums::DisputeStatus::DisputeChallenged
+        ))
+        .is_err());
+        assert!(
+            validate_dispute_can_be_challenged(&dispute(enums::DisputeStatus::DisputeExpired))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_dispute_status_from_webhook_event() {
+        let dispute_status: enums::DisputeStatus =
+            api_models::webhooks::IncomingWebhookEvent::DisputeOpened
+                .foreign_try_into()
+                .unwrap();
+        assert_eq!(dispute_status, enums::DisputeStatus::DisputeOpened);
+        let event_type: enums::EventType = dispute_status.foreign_into();
+        assert_eq!(event_type, enums::EventType::DisputeOpened);
+
+        let dispute_status: enums::DisputeStatus =
+            api_models::webhooks::IncomingWebhookEvent::DisputeLost
+                .foreign_try_into()
+                .unwrap();
+        assert_eq!(dispute_status, enums::DisputeStatus::DisputeLost);
+        let event_type: enums::EventType = dispute_status.foreign_into();
+        assert_eq!(event_type, enums::EventType::DisputeLost);
+
+        let dispute_status: Result<enums::DisputeStatus, _> =
+            api_models::webhooks::IncomingWebhookEvent::PaymentIntentSuccess.foreign_try_into();
+        assert!(dispute_status.is_err());
+    }
+
+    #[test]
+    fn test_dispute_response_from_dispute() {
+        let dispute = dispute(enums::DisputeStatus::DisputeChallenged);
+        let response: disputes::DisputeResponse = dispute.clone().foreign_into();
+
+        assert_eq!(response.dispute_id, dispute.dispute_id);
+        assert_eq!(response.payment_id, dispute.payment_id);
+        assert_eq!(response.amount, 6540);
+        assert_eq!(
+            response.dispute_status,
+            api_models::enums::DisputeStatus::DisputeChallenged
+        );
+        assert_eq!(response.connector_dispute_id, "dp_1MaqRKD5R7gDAGffsxuFCqFW");
+        assert_eq!(response.received_at, dispute.created_at);
+    }
+}
@@ -69,6 +69,10 @@ pub enum StorageError {
     CustomerRedacted,
     #[error("Deserialization failure")]
     DeserializationFailed,
+    #[error("Encryption failure")]
+    EncryptionFailed,
+    #[error("Decryption failure")]
+    DecryptionFailed,
 }
 
 impl From<error_stack::Report<storage_errors::DatabaseError>> for StorageError {
@@ -268,6 +272,8 @@ pub enum VaultError {
     SaveCardFailed,
     #[error("Failed to fetch card details from card vault")]
     FetchCardFailed,
+    #[error("The card was not found in the card vault")]
+    CardNotFound,
     #[error("Failed to encode card vault request")]
     RequestEncodingFailed,
     #[error("Failed to deserialize card vault response")]
@@ -326,6 +332,10 @@ pub enum ProcessTrackerError {
     EParsingError(error_stack::Report<ParsingError>),
     #[error("Validation Error Received: {0}")]
     EValidationError(error_stack::Report<ValidationError>),
+    #[error("Received Error WebhooksFlowError: {0}")]
+    EWebhooksFlowError(error_stack::Report<WebhooksFlowError>),
+    #[error("Received Error VaultError: {0}")]
+    EVaultError(error_stack::Report<VaultError>),
 }
 
 macro_rules! error_to_process_tracker_error {
@@ -371,6 +381,16 @@ error_to_process_tracker_error!(
     ProcessTrackerError::EValidationError(error_stack::Report<ValidationError>)
 );
 
+error_to_process_tracker_error!(
+    error_stack::Report<WebhooksFlowError>,
+    ProcessTrackerError::EWebhooksFlowError(error_stack::Report<WebhooksFlowError>)
+);
+
+error_to_process_tracker_error!(
+    error_stack::Report<VaultError>,
+    ProcessTrackerError::EVaultError(error_stack::Report<VaultError>)
+);
+
 #[derive(Debug, thiserror::Error)]
 pub enum WebhooksFlowError {
     #[error("Merchant webhook config not found")]
@@ -381,6 +401,12 @@ pub enum WebhooksFlowError {
     MerchantWebhookURLNotConfigured,
     #[error("Payments core flow failed")]
     PaymentsCoreFailed,
+    #[error("Refunds core flow failed")]
+    RefundsCoreFailed,
+    #[error("Dispute core flow failed")]
+    DisputeCoreFailed,
+    #[error("Payouts core flow failed")]
+    PayoutsCoreFailed,
     #[error("Webhook event creation failed")]
     WebhookEventCreationFailed,
     #[error("Unable to fork webhooks flow for outgoing webhooks")]
@@ -389,6 +415,44 @@ pub enum WebhooksFlowError {
     CallToMerchantFailed,
     #[error("Webhook not received by merchant")]
     NotReceivedByMerchant,
+    #[error("Failed to encode the outgoing webhook")]
+    OutgoingWebhookEncodingFailed,
+    #[error("Failed to sign the outgoing webhook")]
+    OutgoingWebhookSigningFailed,
+    #[error("Merchant does not have a webhook signing secret")]
+    MerchantWebhookSigningSecretNotFound,
+    #[error("Failed to schedule the retry of the webhook")]
+    RetrySchedulingFailed,
+    #[error("Failed to update the webhook event")]
+    WebhookEventUpdateFailed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LockError {
+    #[error("Resource is locked by another holder")]
+    ResourceLocked,
+    #[error("Failed to acquire the lock")]
+    AcquireFailed,
+    #[error("Failed to extend the lock")]
+    ExtendFailed,
+    #[error("Failed to release the lock")]
+    ReleaseFailed,
+    #[error("Failed to check the lock")]
+    CheckFailed,
+    #[error("Lock has been acquired by another holder")]
+    LockLost,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FileStorageError {
+    #[error("Failed to create the directory for storing files")]
+    DirectoryCreationFailed,
+    #[error("Failed to store the file")]
+    FileUploadFailed,
+    #[error("Failed to retrieve the file")]
+    FileRetrievalFailed,
+    #[error("Failed to delete the file")]
+    FileDeletionFailed,
 }
 
 #[derive(Debug, thiserror::Error)]
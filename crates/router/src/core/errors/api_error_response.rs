@@ -78,6 +78,12 @@ pub enum ApiErrorResponse {
         message = "{message}",
     )]
     GenericUnauthorized { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_19", message = "File not found in the request")]
+    MissingFile,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_20", message = "File purpose not found in the request or is invalid")]
+    MissingFilePurpose,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_21", message = "File content type not found")]
+    MissingFileContentType,
 
     #[error(error_type = ErrorType::ConnectorError, code = "CE_00", message = "{code}: {message}", ignore = "status_code")]
     ExternalConnectorError {
@@ -100,6 +106,10 @@ pub enum ApiErrorResponse {
     RefundFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_07", message = "Verification failed while processing with connector. Retry operation")]
     VerificationFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "Payout failed while processing with connector. Retry payout")]
+    PayoutFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_09", message = "Dispute operation failed while processing with connector. Retry operation")]
+    DisputeFailed { data: Option<serde_json::Value> },
 
     #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "Something went wrong")]
     InternalServerError,
@@ -115,6 +125,14 @@ pub enum ApiErrorResponse {
     DuplicatePaymentMethod,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payment with the specified payment_id '{payment_id}' already exists in our records")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payout with the specified payout_id '{payout_id}' already exists in our records")]
+    DuplicatePayout { payout_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The idempotency key was already used with a different request")]
+    IdempotencyKeyMismatch,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A request with the same idempotency key is still being processed")]
+    IdempotencyKeyInUse,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The resource is being modified by another request, retry after some time")]
+    ResourceBusy,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Refund does not exist in our records")]
     RefundNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Customer does not exist in our records")]
@@ -135,6 +153,22 @@ pub enum ApiErrorResponse {
     MandateNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "API Key does not exist in our records")]
     ApiKeyNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout does not exist in our records")]
+    PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Dispute does not exist in our records")]
+    DisputeNotFound { dispute_id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Subscription does not exist in our records")]
+    SubscriptionNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Customer erasure does not exist in our records")]
+    CustomerErasureNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Event does not exist in our records")]
+    EventNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Token does not exist in our records")]
+    TokenNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "File does not exist in our records")]
+    FileNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "File not available")]
+    FileNotAvailable,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Return URL is not configured and not passed in payments request")]
     ReturnUrlUnavailable,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "This refund is not possible through Hyperswitch. Please raise the refund through {connector} dashboard")]
@@ -143,6 +177,10 @@ pub enum ApiErrorResponse {
     MandateValidationFailed { reason: String },
     #[error(error_type= ErrorType::ValidationError, code = "HE_03", message = "The payment has not succeeded yet. Please pass a successful payment to initiate refund")]
     PaymentNotSucceeded,
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Dispute status validation failed: {reason}")]
+    DisputeStatusValidationFailed { reason: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "File validation failed: {reason}")]
+    FileValidationFailed { reason: String },
     #[error(error_type= ErrorType::ObjectNotFound, code = "HE_04", message = "Successful payment not found for the given payment id")]
     SuccessfulPaymentNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "The connector provided in the request is incorrect or not available")]
@@ -213,11 +251,22 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::RefundFailed { .. }
             | Self::RefundNotPossible { .. }
             | Self::VerificationFailed { .. }
+            | Self::PayoutFailed { .. }
+            | Self::DisputeFailed { .. }
+            | Self::DisputeStatusValidationFailed { .. }
+            | Self::FileValidationFailed { .. }
+            | Self::MissingFile
+            | Self::MissingFilePurpose
+            | Self::MissingFileContentType
             | Self::PaymentUnexpectedState { .. }
             | Self::MandateValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
 
             Self::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR, // 500
-            Self::DuplicateRefundRequest | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::DuplicateRefundRequest
+            | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::IdempotencyKeyMismatch => StatusCode::UNPROCESSABLE_ENTITY, // 422
+            Self::IdempotencyKeyInUse | Self::ResourceBusy => StatusCode::CONFLICT, // 409
             Self::RefundNotFound
             | Self::CustomerNotFound
             | Self::MandateActive
@@ -234,14 +283,22 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::ResourceIdNotFound
             | Self::ConfigNotFound
             | Self::AddressNotFound
-            | Self::ApiKeyNotFound => StatusCode::BAD_REQUEST, // 400
+            | Self::ApiKeyNotFound
+            | Self::PayoutNotFound
+            | Self::DisputeNotFound { .. }
+            | Self::SubscriptionNotFound
+            | Self::CustomerErasureNotFound
+            | Self::EventNotFound
+            | Self::TokenNotFound
+            | Self::FileNotFound
+            | Self::FileNotAvailable => StatusCode::BAD_REQUEST, // 400
             Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
             | Self::DuplicateMandate => StatusCode::BAD_REQUEST, // 400
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE, // 503
-            Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,          // 400
-            Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,    // 501
+            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,  // 503
+            Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,           // 400
+            Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,     // 501
         }
     }
 
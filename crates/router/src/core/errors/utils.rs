@@ -44,6 +44,8 @@ pub trait ConnectorErrorExt {
     fn to_refund_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
     fn to_payment_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
     fn to_verify_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
+    fn to_payout_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
+    fn to_dispute_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
 }
 
 impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
@@ -118,6 +120,52 @@ impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
         };
         self.change_context(errors::ApiErrorResponse::PaymentAuthorizationFailed { data })
     }
+
+    fn to_payout_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse> {
+        let error = match self.current_context() {
+            errors::ConnectorError::ProcessingStepFailed(Some(bytes)) => {
+                let response_str = std::str::from_utf8(bytes);
+                let data = match response_str {
+                    Ok(s) => serde_json::from_str(s)
+                        .map_err(|err| logger::error!(%err, "Failed to convert response to JSON"))
+                        .ok(),
+                    Err(err) => {
+                        logger::error!(%err, "Failed to convert response to UTF8 string");
+                        None
+                    }
+                };
+                errors::ApiErrorResponse::PayoutFailed { data }
+            }
+            errors::ConnectorError::MissingRequiredField { field_name } => {
+                errors::ApiErrorResponse::MissingRequiredField { field_name }
+            }
+            _ => errors::ApiErrorResponse::PayoutFailed { data: None },
+        };
+        self.change_context(error)
+    }
+
+    fn to_dispute_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse> {
+        let error = match self.current_context() {
+            errors::ConnectorError::ProcessingStepFailed(Some(bytes)) => {
+                let response_str = std::str::from_utf8(bytes);
+                let data = match response_str {
+                    Ok(s) => serde_json::from_str(s)
+                        .map_err(|err| logger::error!(%err, "Failed to convert response to JSON"))
+                        .ok(),
+                    Err(err) => {
+                        logger::error!(%err, "Failed to convert response to UTF8 string");
+                        None
+                    }
+                };
+                errors::ApiErrorResponse::DisputeFailed { data }
+            }
+            errors::ConnectorError::MissingRequiredField { field_name } => {
+                errors::ApiErrorResponse::MissingRequiredField { field_name }
+            }
+            _ => errors::ApiErrorResponse::DisputeFailed { data: None },
+        };
+        self.change_context(error)
+    }
 }
 
 pub trait RedisErrorExt {
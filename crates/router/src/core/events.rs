@@ -0,0 +1,257 @@
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        api::events,
+        storage,
+        transformers::{Foreign, ForeignInto},
+    },
+};
+
+// ********************************************** EVENT LIST **********************************************
+
+#[instrument(skip_all)]
+pub async fn list_events(
+    state: &AppState,
+    constraints: events::EventListConstraints,
+) -> RouterResponse<events::EventListResponse> {
+    let merchant_events = state
+        .store
+        .list_events_by_merchant_id(&constraints.merchant_id, constraints.limit)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve events")?;
+
+    let data: Vec<events::EventResponse> = merchant_events
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        events::EventListResponse { data },
+    ))
+}
+
+async fn find_event(state: &AppState, event_id: &str) -> RouterResult<storage::Event> {
+    state
+        .store
+        .find_event_by_event_id(event_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::EventNotFound))
+}
+
+async fn find_delivery_attempts(
+    state: &AppState,
+    event_id: &str,
+) -> RouterResult<Vec<storage::EventDeliveryAttempt>> {
+    state
+        .store
+        .find_event_delivery_attempts_by_event_id(event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve event delivery attempts")
+}
+
+// ********************************************** EVENT DELIVERY ATTEMPT LIST **********************************************
+
+#[instrument(skip_all)]
+pub async fn list_event_delivery_attempts(
+    state: &AppState,
+    event_id: String,
+) -> RouterResponse<events::EventDeliveryAttemptListResponse> {
+    let event = find_event(state, &event_id).await?;
+    let data: Vec<events::EventDeliveryAttemptResponse> =
+        find_delivery_attempts(state, &event.event_id)
+            .await?
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        events::EventDeliveryAttemptListResponse { data },
+    ))
+}
+
+// ********************************************** EVENT RETRY **********************************************
+
+/// Redelivers the webhook of an event as it was sent in its latest delivery attempt. A delivery
+/// which is not acknowledged by the merchant is not an error, the failed attempt is returned.
+#[instrument(skip_all)]
+pub async fn retry_event_delivery(
+    state: &AppState,
+    event_id: String,
+) -> RouterResponse<events::EventDeliveryAttemptResponse> {
+    let db = &*state.store;
+    let event = find_event(state, &event_id).await?;
+
+    let merchant_id =
+        event
+            .merchant_id
+            .clone()
+            .ok_or_else(|| errors::ApiErrorResponse::PreconditionFailed {
+                message: "The event does not record the merchant it belongs to".to_string(),
+            })?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let latest_attempt = find_delivery_attempts(state, &event_id)
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| errors::ApiErrorResponse::PreconditionFailed {
+            message: "The webhook of the event was never sent".to_string(),
+        })?;
+    let webhook: serde_json::Value = serde_json::from_str(&latest_attempt.request_body)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the recorded webhook")?;
+
+    match webhooks::send_webhook_to_merchant(db, &merchant_account, &event_id, &webhook).await {
+        Ok(()) if !event.is_webhook_notified => {
+            webhooks::mark_event_as_notified(db, event)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        }
+        Ok(()) => (),
+        Err(error) => match error.current_context() {
+            errors::WebhooksFlowError::CallToMerchantFailed
+            | errors::WebhooksFlowError::NotReceivedByMerchant => {
+                logger::info!(
+                    ?error,
+                    "Webhook redelivery was not acknowledged by the merchant"
+                );
+            }
+            errors::WebhooksFlowError::MerchantWebhookDetailsNotFound
+            | errors::WebhooksFlowError::MerchantWebhookURLNotConfigured => {
+                return Err(
+                    error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "The merchant does not have a webhook URL configured".to_string(),
+                    }),
+                );
+            }
+            errors::WebhooksFlowError::MerchantWebhookSigningSecretNotFound => {
+                return Err(
+                    error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "The merchant does not have a webhook signing secret".to_string(),
+                    }),
+                );
+            }
+            _ => return Err(error.change_context(errors::ApiErrorResponse::InternalServerError)),
+        },
+    }
+
+    let attempt = find_delivery_attempts(state, &event_id)
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Failed to record the redelivery attempt")?;
+
+    Ok(services::ApplicationResponse::Json(attempt.foreign_into()))
+}
+
+impl From<Foreign<storage::Event>> for Foreign<events::EventResponse> {
+    fn from(event: Foreign<storage::Event>) -> Self {
+        let event = event.0;
+        events::EventResponse {
+            event_id: event.event_id,
+            merchant_id: event.merchant_id,
+            event_type: event.event_type.foreign_into(),
+            event_class: event.event_class.to_string(),
+            is_webhook_notified: event.is_webhook_notified,
+            intent_reference_id: event.intent_reference_id,
+            primary_object_id: event.primary_object_id,
+            primary_object_type: event.primary_object_type.to_string(),
+            created_at: event.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<Foreign<storage::EventDeliveryAttempt>>
+    for Foreign<events::EventDeliveryAttemptResponse>
+{
+    fn from(attempt: Foreign<storage::EventDeliveryAttempt>) -> Self {
+        let attempt = attempt.0;
+        events::EventDeliveryAttemptResponse {
+            attempt_id: attempt.attempt_id,
+            event_id: attempt.event_id,
+            request_body: attempt.request_body,
+            response_status_code: attempt.response_status_code,
+            response_body: attempt.response_body,
+            latency_ms: attempt.latency_ms,
+            is_success: attempt.is_success,
+            error_message: attempt.error_message,
+            created_at: attempt.created_at,
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, types::storage::enums};
+
+    fn event_new(event_id: &str, merchant_id: &str) -> storage::EventNew {
+        storage::EventNew {
+            event_id: event_id.to_string(),
+            event_type: enums::EventType::PaymentSucceeded,
+            event_class: enums::EventClass::Payments,
+            is_webhook_notified: false,
+            intent_reference_id: None,
+            primary_object_id: "pay_123".to_string(),
+            primary_object_type: enums::EventObjectType::PaymentDetails,
+            merchant_id: Some(merchant_id.to_string()),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_list_events() {
+        let conf = Settings::new().expect("invalid settings");
+        let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+
+        for event in [
+            event_new("evt_1", "merchant_1"),
+            event_new("evt_2", "merchant_2"),
+            event_new("evt_3", "merchant_1"),
+        ] {
+            state.store.insert_event(event).await.unwrap();
+        }
+
+        let response = list_events(
+            &state,
+            events::EventListConstraints {
+                merchant_id: "merchant_1".to_string(),
+                limit: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let event_ids: Vec<_> = match response {
+            services::ApplicationResponse::Json(response) => response
+                .data
+                .into_iter()
+                .map(|event| event.event_id)
+                .collect(),
+            _ => panic!("Unexpected response"),
+        };
+        assert_eq!(event_ids, vec!["evt_3", "evt_1"]);
+    }
+}
@@ -0,0 +1,180 @@
+pub mod helpers;
+
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::AppState,
+    services::{self, file_storage},
+    types::{api, storage, transformers::ForeignInto},
+    utils,
+};
+
+const SUPPORTED_FILE_TYPES: [mime::Mime; 3] =
+    [mime::IMAGE_JPEG, mime::IMAGE_PNG, mime::APPLICATION_PDF];
+
+// ********************************************** FILE CREATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn files_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    create_file_request: api::CreateFileRequest,
+) -> RouterResponse<api::CreateFileResponse> {
+    let file_upload_config = &state.conf.file_upload;
+
+    utils::when(
+        usize::try_from(create_file_request.file_size).unwrap_or(usize::MAX)
+            > file_upload_config.max_file_size,
+        || {
+            Err(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!(
+                    "file size exceeds the maximum allowed size of {} bytes",
+                    file_upload_config.max_file_size
+                ),
+            })
+            .into_report()
+        },
+    )?;
+    utils::when(
+        !SUPPORTED_FILE_TYPES.contains(&create_file_request.file_type),
+        || {
+            Err(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!(
+                    "file type {} is not supported",
+                    create_file_request.file_type
+                ),
+            })
+            .into_report()
+        },
+    )?;
+
+    let file_id = utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!("{}/{}", merchant_account.merchant_id, file_id);
+    let file_new = storage::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        file_name: create_file_request.file_name,
+        file_size: create_file_request.file_size,
+        file_type: create_file_request.file_type.to_string(),
+        purpose: create_file_request.purpose.foreign_into(),
+        file_upload_provider: file_upload_config.file_storage_backend.to_string(),
+        provider_file_id: None,
+        available: false,
+    };
+    let file_metadata = state
+        .store
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert file metadata")?;
+
+    file_storage::get_file_storage(file_upload_config)
+        .upload_file(&file_key, create_file_request.file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to store the file")?;
+
+    // The file is made available only after its contents have been stored successfully
+    let file_update = storage::FileMetadataUpdate::StorageUpdate {
+        provider_file_id: file_key,
+        available: true,
+    };
+    state
+        .store
+        .update_file_metadata(file_metadata, file_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update file metadata with file_id: {file_id}")
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::CreateFileResponse { file_id },
+    ))
+}
+
+// ********************************************** FILE RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn files_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    file_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let file_metadata = find_available_file(state, &merchant_account, &file_id).await?;
+    let provider_file_id = file_metadata
+        .provider_file_id
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Missing provider file id for an available file")?;
+
+    let file = file_storage::get_file_storage(&state.conf.file_upload)
+        .retrieve_file(&provider_file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve the file")?;
+    let content_type = file_metadata
+        .file_type
+        .parse::<mime::Mime>()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the file type of the stored file")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        file,
+        content_type,
+    )))
+}
+
+// ********************************************** FILE DELETE **********************************************
+
+#[instrument(skip_all)]
+pub async fn files_delete_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    file_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let file_metadata = state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::FileNotFound))?;
+
+    if let Some(provider_file_id) = file_metadata.provider_file_id {
+        file_storage::get_file_storage(&state.conf.file_upload)
+            .delete_file(&provider_file_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to delete the file")?;
+    }
+
+    state
+        .store
+        .delete_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to delete file metadata with file_id: {file_id}")
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+pub async fn find_available_file(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    file_id: &str,
+) -> errors::RouterResult<storage::FileMetadata> {
+    let file_metadata = state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, file_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::FileNotFound))?;
+    utils::when(!file_metadata.available, || {
+        Err(errors::ApiErrorResponse::FileNotAvailable).into_report()
+    })?;
+    Ok(file_metadata)
+}
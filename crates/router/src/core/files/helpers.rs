@@ -0,0 +1,254 @@
+use std::str::FromStr;
+
+use actix_multipart::MultipartError;
+use error_stack::{IntoReport, ResultExt};
+use futures::{Stream, TryStreamExt};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResult},
+        files, payments, utils as core_utils,
+    },
+    routes::AppState,
+    services::{self, file_storage},
+    types::{self, api, storage},
+};
+
+/// The purpose is a short enum value, larger values are rejected without being buffered
+const PURPOSE_FIELD_MAX_SIZE: usize = 64;
+
+/// Reads a multipart field, aborting as soon as the field is larger than `max_size` bytes so
+/// that oversized uploads are never buffered in memory.
+async fn read_field_bytes<S>(
+    field: &mut S,
+    field_name: &str,
+    max_size: usize,
+) -> RouterResult<Vec<u8>>
+where
+    S: Stream<Item = Result<bytes::Bytes, MultipartError>> + Unpin,
+{
+    let mut bytes = Vec::new();
+    while let Some(chunk) = field.try_next().await.into_report().change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Failed to read the multipart form data".to_string(),
+        },
+    )? {
+        if bytes.len() + chunk.len() > max_size {
+            return Err(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!(
+                    "{field_name} exceeds the maximum allowed size of {max_size} bytes"
+                ),
+            })
+            .into_report();
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(bytes)
+}
+
+pub async fn get_create_file_request(
+    mut payload: actix_multipart::Multipart,
+    max_file_size: usize,
+) -> RouterResult<api::CreateFileRequest> {
+    let mut option_purpose: Option<api_models::enums::FilePurpose> = None;
+    let mut file_name: Option<String> = None;
+    let mut file_content: Option<Vec<u8>> = None;
+    let mut option_file_type: Option<mime::Mime> = None;
+
+    while let Some(mut field) = payload.try_next().await.into_report().change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Failed to read the multipart form data".to_string(),
+        },
+    )? {
+        let field_name = field.content_disposition().get_name().map(str::to_owned);
+        match field_name.as_deref() {
+            Some("purpose") => {
+                let purpose =
+                    read_field_bytes(&mut field, "purpose", PURPOSE_FIELD_MAX_SIZE).await?;
+                option_purpose = String::from_utf8(purpose)
+                    .ok()
+                    .and_then(|purpose| api_models::enums::FilePurpose::from_str(&purpose).ok());
+            }
+            Some("file") => {
+                file_name = field
+                    .content_disposition()
+                    .get_filename()
+                    .map(str::to_owned);
+                option_file_type = field.content_type().cloned();
+                file_content = Some(read_field_bytes(&mut field, "file", max_file_size).await?);
+            }
+            // Unknown fields are ignored, their contents are skipped without being buffered
+            _ => (),
+        }
+    }
+
+    let purpose = option_purpose.ok_or(errors::ApiErrorResponse::MissingFilePurpose)?;
+    let file = file_content.ok_or(errors::ApiErrorResponse::MissingFile)?;
+    let file_type = option_file_type.ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
+    let file_size = i32::try_from(file.len()).into_report().change_context(
+        errors::ApiErrorResponse::FileValidationFailed {
+            reason: "file size is too large".to_string(),
+        },
+    )?;
+
+    Ok(api::CreateFileRequest {
+        file,
+        file_name,
+        file_size,
+        file_type,
+        purpose,
+    })
+}
+
+/// Returns the connector's identifier for the file, uploading the file to the connector first
+/// if it has not been uploaded to that connector already.
+pub async fn get_connector_file_id(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    connector: &str,
+    file_id: &str,
+) -> RouterResult<String> {
+    let file_metadata = files::find_available_file(state, merchant_account, file_id).await?;
+
+    if let (Some(file_connector), Some(connector_file_id)) =
+        (&file_metadata.connector, &file_metadata.connector_file_id)
+    {
+        if file_connector == connector {
+            return Ok(connector_file_id.clone());
+        }
+    }
+
+    let provider_file_id = file_metadata
+        .provider_file_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Missing provider file id for an available file")?;
+    let file = file_storage::get_file_storage(&state.conf.file_upload)
+        .retrieve_file(&provider_file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve the file")?;
+    let file_type = file_metadata
+        .file_type
+        .parse::<mime::Mime>()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the file type of the stored file")?;
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data = core_utils::construct_upload_file_router_data(
+        state,
+        payment_intent,
+        payment_attempt,
+        merchant_account,
+        connector,
+        types::UploadFileRequestData {
+            file_key: file_metadata.file_id.clone(),
+            file,
+            file_type,
+            file_size: file_metadata.file_size,
+        },
+    )
+    .await?;
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_dispute_failed_response())
+    .attach_printable("Failed while calling upload file connector api")?;
+
+    let upload_file_response =
+        response
+            .response
+            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: connector.to_string(),
+                status_code: err.status_code,
+            })?;
+
+    let file_update = storage::FileMetadataUpdate::ConnectorUpdate {
+        connector: connector.to_string(),
+        connector_file_id: upload_file_response.provider_file_id.clone(),
+    };
+    state
+        .store
+        .update_file_metadata(file_metadata, file_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update file metadata with file_id: {file_id}")
+        })?;
+
+    Ok(upload_file_response.provider_file_id)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn field(
+        chunks: Vec<Result<&'static str, MultipartError>>,
+    ) -> impl Stream<Item = Result<bytes::Bytes, MultipartError>> + Unpin {
+        futures::stream::iter(
+            chunks
+                .into_iter()
+                .map(|chunk| chunk.map(|chunk| bytes::Bytes::from_static(chunk.as_bytes()))),
+        )
+    }
+
+    #[actix_rt::test]
+    async fn test_read_field_bytes() {
+        let mut file = field(vec![Ok("%PDF-1.4 "), Ok("evidence")]);
+        let bytes = read_field_bytes(&mut file, "file", 17).await.unwrap();
+        assert_eq!(bytes, b"%PDF-1.4 evidence");
+    }
+
+    #[actix_rt::test]
+    async fn test_read_field_bytes_aborts_on_oversized_field() {
+        let mut file = field(vec![Ok("%PDF-1.4 "), Ok("evidence"), Ok("never read")]);
+        let error = read_field_bytes(&mut file, "file", 16).await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::FileValidationFailed { .. }
+        ));
+
+        // The chunk after the one exceeding the limit is never read
+        assert_eq!(
+            file.try_next().await.unwrap(),
+            Some(bytes::Bytes::from_static(b"never read"))
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_read_field_bytes_propagates_multipart_errors() {
+        let mut file = field(vec![Ok("%PDF-1.4 "), Err(MultipartError::Incomplete)]);
+        let error = read_field_bytes(&mut file, "file", 1024).await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+    }
+}
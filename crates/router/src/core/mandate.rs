@@ -4,21 +4,29 @@ use storage_models::enums as storage_enums;
 
 use super::payments::helpers;
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils,
+    },
     db::StorageInterface,
     routes::AppState,
+    scheduler::utils as pt_utils,
     services,
     types::{
         self,
         api::{
-            customers,
+            self, customers,
             mandates::{self, MandateResponseExt},
         },
         storage,
         transformers::ForeignInto,
     },
+    utils::{self, Encode},
 };
 
+const MANDATE_EXPIRY_WORKFLOW_RUNNER: &str = "MANDATE_EXPIRY_WORKFLOW";
+const MANDATE_EXPIRY_WORKFLOW_TASK: &str = "MANDATE_EXPIRY";
+
 #[instrument(skip(state))]
 pub async fn get_mandate(
     state: &AppState,
@@ -35,12 +43,32 @@ pub async fn get_mandate(
     ))
 }
 
-#[instrument(skip(db))]
+#[instrument(skip(state))]
 pub async fn revoke_mandate(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_account: storage::MerchantAccount,
     req: mandates::MandateId,
 ) -> RouterResponse<mandates::MandateRevokedResponse> {
+    let db = &*state.store;
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
+
+    utils::when(
+        mandate.mandate_status == storage_enums::MandateStatus::Revoked,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "mandate has already been revoked".into()
+            }))
+        },
+    )?;
+
+    if let Some(connector_mandate_id) = mandate.connector_mandate_id.clone() {
+        revoke_mandate_at_connector(state, &merchant_account, &mandate, connector_mandate_id)
+            .await?;
+    }
+
     let mandate = db
         .update_mandate_by_merchant_id_mandate_id(
             &merchant_account.merchant_id,
@@ -60,6 +88,85 @@ pub async fn revoke_mandate(
     ))
 }
 
+/// Revokes the mandate at the connector it was set up with. Mandates of connectors which do not
+/// support revoking mandates are only revoked locally.
+async fn revoke_mandate_at_connector(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: &storage::Mandate,
+    connector_mandate_id: String,
+) -> RouterResult<()> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &mandate.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    if !connector_data.connector.supports_mandate_revoke() {
+        logger::info!(
+            mandate_id = %mandate.mandate_id,
+            connector = %mandate.connector,
+            "Connector does not support revoking mandates, revoking the mandate locally"
+        );
+        return Ok(());
+    }
+
+    let payment_method = state
+        .store
+        .find_payment_method(&mandate.payment_method_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        mandates::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data = core_utils::construct_mandate_revoke_router_data(
+        state,
+        merchant_account,
+        mandate,
+        payment_method.payment_method,
+        connector_mandate_id,
+    )
+    .await?;
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling revoke mandate connector api")?;
+
+    let revoke_response =
+        response
+            .response
+            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: mandate.connector.clone(),
+                status_code: err.status_code,
+            })?;
+
+    utils::when(
+        revoke_response.mandate_status != storage_enums::MandateStatus::Revoked,
+        || {
+            Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(
+                "Connector did not revoke the mandate, it is still active at the connector",
+            )
+        },
+    )
+}
+
 #[instrument(skip(state))]
 pub async fn get_customer_mandates(
     state: &AppState,
@@ -104,37 +211,20 @@ where
     match resp.request.get_mandate_id() {
         Some(mandate_id) => {
             let mandate_id = &mandate_id.mandate_id;
-            let mandate = state
-                .store
-                .find_mandate_by_merchant_id_mandate_id(resp.merchant_id.as_ref(), mandate_id)
-                .await
-                .change_context(errors::ApiErrorResponse::MandateNotFound)?;
-            let mandate = match mandate.mandate_type {
-                storage_enums::MandateType::SingleUse => state
-                    .store
-                    .update_mandate_by_merchant_id_mandate_id(
-                        &resp.merchant_id,
-                        mandate_id,
-                        storage::MandateUpdate::StatusUpdate {
-                            mandate_status: storage_enums::MandateStatus::Revoked,
-                        },
-                    )
-                    .await
-                    .change_context(errors::ApiErrorResponse::MandateNotFound),
-                storage_enums::MandateType::MultiUse => state
-                    .store
-                    .update_mandate_by_merchant_id_mandate_id(
-                        &resp.merchant_id,
-                        mandate_id,
-                        storage::MandateUpdate::CaptureAmountUpdate {
-                            amount_captured: Some(
-                                mandate.amount_captured.unwrap_or(0) + resp.request.get_amount(),
-                            ),
-                        },
-                    )
-                    .await
-                    .change_context(errors::ApiErrorResponse::MandateNotFound),
-            }?;
+
+            // Only the payments which went through count towards the limits of the mandate, the
+            // usage reserved for a payment which failed is released
+            let mandate = if is_payment_failed(&resp) {
+                release_mandate_usage(
+                    state,
+                    &resp.merchant_id,
+                    mandate_id,
+                    resp.request.get_amount(),
+                )
+                .await?
+            } else {
+                update_mandate_status_after_usage(state, &resp.merchant_id, mandate_id).await?
+            };
 
             resp.payment_method_id = Some(mandate.payment_method_id);
         }
@@ -173,15 +263,20 @@ where
                             mandate_id: new_mandate_data.mandate_id.clone(),
                             connector_mandate_id: new_mandate_data.connector_mandate_id.clone(),
                         });
-                    state
-                        .store
-                        .insert_mandate(new_mandate_data)
-                        .await
-                        .map_err(|err| {
-                            err.to_duplicate_response(
-                                errors::ApiErrorResponse::DuplicateRefundRequest,
-                            )
-                        })?;
+                    let mandate =
+                        state
+                            .store
+                            .insert_mandate(new_mandate_data)
+                            .await
+                            .map_err(|err| {
+                                err.to_duplicate_response(
+                                    errors::ApiErrorResponse::DuplicateRefundRequest,
+                                )
+                            })?;
+
+                    if let Some(end_date) = mandate.end_date {
+                        add_mandate_expiry_task(&*state.store, &mandate, end_date).await?;
+                    }
                 };
             } else if resp.request.get_setup_future_usage().is_some() {
                 helpers::call_payment_method(
@@ -199,6 +294,166 @@ where
     Ok(resp)
 }
 
+fn is_payment_failed<F, FData>(
+    resp: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> bool {
+    resp.response.is_err()
+        || matches!(
+            resp.status,
+            storage_enums::AttemptStatus::Failure
+                | storage_enums::AttemptStatus::AuthorizationFailed
+                | storage_enums::AttemptStatus::AuthenticationFailed
+                | storage_enums::AttemptStatus::RouterDeclined
+        )
+}
+
+/// Reserves one usage and the amount of the payment on the mandate it is made with, before the
+/// payment is sent to the connector. The usage is checked against the limits of the mandate in the
+/// same statement, so a payment which raced another one for the last usage or amount of the
+/// mandate is rejected before reaching the connector. The usage stays reserved when the outcome of
+/// the payment is unknown.
+pub async fn reserve_mandate_usage<F, FData>(
+    state: &AppState,
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> RouterResult<()>
+where
+    FData: MandateBehaviour,
+{
+    let mandate_id = match router_data.request.get_mandate_id() {
+        Some(mandate_id) => &mandate_id.mandate_id,
+        None => return Ok(()),
+    };
+
+    state
+        .store
+        .reserve_mandate_usage_by_merchant_id_mandate_id(
+            &router_data.merchant_id,
+            mandate_id,
+            router_data.request.get_amount(),
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, %mandate_id, "Failed to reserve the usage of the mandate");
+            error.to_not_found_response(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate has reached its maximum usage count or amount".to_string(),
+            })
+        })?;
+    Ok(())
+}
+
+/// Releases the usage and amount reserved on the mandate for a payment which failed
+async fn release_mandate_usage(
+    state: &AppState,
+    merchant_id: &str,
+    mandate_id: &str,
+    amount: i64,
+) -> RouterResult<storage::Mandate> {
+    state
+        .store
+        .release_mandate_usage_by_merchant_id_mandate_id(merchant_id, mandate_id, amount)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed to release the usage of the mandate {mandate_id}")
+        })
+}
+
+/// Updates the status of the mandate once the usage reserved for a payment has been used
+async fn update_mandate_status_after_usage(
+    state: &AppState,
+    merchant_id: &str,
+    mandate_id: &str,
+) -> RouterResult<storage::Mandate> {
+    let db = &*state.store;
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
+        .await
+        .change_context(errors::ApiErrorResponse::MandateNotFound)?;
+
+    match get_mandate_status_after_usage(&mandate) {
+        Some(mandate_status) => db
+            .update_mandate_by_merchant_id_mandate_id(
+                merchant_id,
+                mandate_id,
+                storage::MandateUpdate::StatusUpdate { mandate_status },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::MandateNotFound),
+        None => Ok(mandate),
+    }
+}
+
+/// Single use mandates are revoked once used, and multi use mandates become inactive once their
+/// usage count or amount is exhausted.
+pub fn get_mandate_status_after_usage(
+    mandate: &storage::Mandate,
+) -> Option<storage_enums::MandateStatus> {
+    match mandate.mandate_type {
+        storage_enums::MandateType::SingleUse => Some(storage_enums::MandateStatus::Revoked),
+        storage_enums::MandateType::MultiUse => {
+            let usage_exhausted = mandate
+                .max_usage_count
+                .map(|max_usage_count| mandate.usage_count >= max_usage_count)
+                .unwrap_or(false);
+            let amount_exhausted = mandate
+                .mandate_amount
+                .zip(mandate.amount_captured)
+                .map(|(mandate_amount, amount_captured)| amount_captured >= mandate_amount)
+                .unwrap_or(false);
+            (usage_exhausted || amount_exhausted).then_some(storage_enums::MandateStatus::Inactive)
+        }
+    }
+}
+
+/// Schedules the expiry of the mandate at its end date
+#[instrument(skip_all)]
+async fn add_mandate_expiry_task(
+    db: &dyn StorageInterface,
+    mandate: &storage::Mandate,
+    end_date: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let tracking_data = storage::MandateExpiryTrackingData {
+        merchant_id: mandate.merchant_id.clone(),
+        mandate_id: mandate.mandate_id.clone(),
+    };
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: pt_utils::get_process_tracker_id(
+            MANDATE_EXPIRY_WORKFLOW_RUNNER,
+            MANDATE_EXPIRY_WORKFLOW_TASK,
+            &mandate.mandate_id,
+            &mandate.merchant_id,
+        ),
+        name: Some(String::from(MANDATE_EXPIRY_WORKFLOW_TASK)),
+        tag: vec![String::from("MANDATE")],
+        runner: Some(String::from(MANDATE_EXPIRY_WORKFLOW_RUNNER)),
+        retry_count: 0,
+        schedule_time: Some(end_date),
+        rule: String::new(),
+        tracking_data: Encode::<storage::MandateExpiryTrackingData>::encode_to_value(
+            &tracking_data,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        business_status: String::from("Pending"),
+        status: storage_enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: mandate_id: {}",
+                mandate.mandate_id
+            )
+        })?;
+
+    Ok(())
+}
+
 pub trait MandateBehaviour {
     fn get_amount(&self) -> i64;
     fn get_setup_future_usage(&self) -> Option<storage_models::enums::FutureUsage>;
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use common_utils::{consts, ext_traits::AsyncExt, generate_id};
 use error_stack::{report, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     core::{
@@ -28,6 +28,7 @@ pub async fn create_payment_method(
     customer_id: &str,
     payment_method_id: &str,
     merchant_id: &str,
+    card_fingerprint: Option<String>,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::StorageError> {
     let response = db
         .insert_payment_method(storage::PaymentMethodNew {
@@ -38,6 +39,7 @@ pub async fn create_payment_method(
             payment_method_type: req.payment_method_type.map(ForeignInto::foreign_into),
             payment_method_issuer: req.payment_method_issuer.clone(),
             metadata: req.metadata.clone(),
+            card_fingerprint,
             ..storage::PaymentMethodNew::default()
         })
         .await?;
@@ -67,6 +69,7 @@ pub async fn add_payment_method(
                 &customer_id,
                 &payment_method_id,
                 merchant_id,
+                None,
             )
             .await
             .map_err(|error| {
@@ -143,6 +146,38 @@ pub async fn add_card(
         .get_required_value("locker_id")
         .change_context(errors::VaultError::SaveCardFailed)?;
 
+    let card_fingerprint = payment_methods::get_card_fingerprint(
+        &state.conf.secrets.card_fingerprint_secret,
+        merchant_id,
+        card.card_number.peek(),
+    )?;
+
+    // A card already saved for the customer is returned, instead of being saved again
+    match db
+        .find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+            &customer_id,
+            merchant_id,
+            &card_fingerprint,
+        )
+        .await
+    {
+        Ok(pm) => {
+            return get_saved_card(
+                state,
+                req,
+                card,
+                &customer_id,
+                &locker_id,
+                merchant_id,
+                pm,
+                card_fingerprint,
+            )
+            .await;
+        }
+        Err(error) if error.current_context().is_db_not_found() => (),
+        Err(error) => Err(error).change_context(errors::VaultError::SaveCardFailed)?,
+    }
+
     let request = payment_methods::mk_add_card_request(
         locker,
         &card,
@@ -151,7 +186,7 @@ pub async fn add_card(
         &locker_id,
         merchant_id,
     )?;
-    let response = if !locker.mock_locker {
+    let mut response = if !locker.mock_locker {
         let response = services::call_connector_api(state, request)
             .await
             .change_context(errors::VaultError::SaveCardFailed)?;
@@ -168,75 +203,188 @@ pub async fn add_card(
         response
     } else {
         let card_id = generate_id(consts::ID_LENGTH, "card");
-        mock_add_card(db, &card_id, &card, None, None, Some(&customer_id)).await?
+        vault::LocalVault::add_card(
+            state,
+            &card_id,
+            merchant_id,
+            &card,
+            None,
+            None,
+            Some(&customer_id),
+        )
+        .await?
     };
-
-    if let Some(false) = response.duplicate {
-        create_payment_method(db, &req, &customer_id, &response.card_id, merchant_id)
-            .await
-            .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
+    response.card_fingerprint = card_fingerprint.clone().into();
+
+    let payment_method_result = if let Some(false) = response.duplicate {
+        create_payment_method(
+            db,
+            &req,
+            &customer_id,
+            &response.card_id,
+            merchant_id,
+            Some(card_fingerprint.clone()),
+        )
+        .await
+        .map(|_| ())
     } else {
         match db.find_payment_method(&response.card_id).await {
-            Ok(_) => (),
-            Err(err) => {
-                if err.current_context().is_db_not_found() {
-                    create_payment_method(db, &req, &customer_id, &response.card_id, merchant_id)
-                        .await
-                        .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
-                } else {
-                    Err(errors::VaultError::PaymentMethodCreationFailed)?;
+            Ok(_) => Ok(()),
+            Err(err) if err.current_context().is_db_not_found() => create_payment_method(
+                db,
+                &req,
+                &customer_id,
+                &response.card_id,
+                merchant_id,
+                Some(card_fingerprint.clone()),
+            )
+            .await
+            .map(|_| ()),
+            Err(err) => Err(err),
+        }
+    };
+
+    match payment_method_result {
+        Ok(()) => (),
+        // The same card was saved for the customer by a concurrent request
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            let pm = db
+                .find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+                    &customer_id,
+                    merchant_id,
+                    &card_fingerprint,
+                )
+                .await
+                .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
+            if pm.payment_method_id != response.card_id {
+                if let Err(error) = delete_card(state, merchant_id, &response.card_id).await {
+                    logger::error!(
+                        ?error,
+                        "Failed to delete the duplicate card from the locker"
+                    );
                 }
             }
+            return get_saved_card(
+                state,
+                req,
+                card,
+                &customer_id,
+                &locker_id,
+                merchant_id,
+                pm,
+                card_fingerprint,
+            )
+            .await;
         }
+        Err(error) => Err(error).change_context(errors::VaultError::PaymentMethodCreationFailed)?,
     }
+
     let payment_method_resp =
         payment_methods::mk_add_card_response(card, response, req, merchant_id);
     Ok(payment_method_resp)
 }
 
-#[instrument(skip_all)]
-pub async fn mock_add_card(
-    db: &dyn db::StorageInterface,
-    card_id: &str,
+/// Returns a card already saved for the customer. When the card is saved again with another
+/// expiry date, such as after the card was renewed, the saved card is updated with it.
+#[allow(clippy::too_many_arguments)]
+async fn get_saved_card(
+    state: &routes::AppState,
+    req: api::CreatePaymentMethod,
+    card: api::CardDetail,
+    customer_id: &str,
+    locker_id: &str,
+    merchant_id: &str,
+    pm: storage::PaymentMethod,
+    card_fingerprint: String,
+) -> errors::CustomResult<api::PaymentMethodResponse, errors::VaultError> {
+    let mut response = get_card_from_legacy_locker(state, locker_id, &pm.payment_method_id)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)?
+        .card;
+
+    let is_expiry_changed = response.card_exp_month.as_ref().map(PeekInterface::peek)
+        != Some(card.card_exp_month.peek())
+        || response.card_exp_year.as_ref().map(PeekInterface::peek)
+            != Some(card.card_exp_year.peek());
+    if is_expiry_changed {
+        update_card_in_locker(
+            state,
+            &req,
+            &card,
+            customer_id,
+            locker_id,
+            merchant_id,
+            &pm.payment_method_id,
+        )
+        .await?;
+    }
+
+    response.card_id = pm.payment_method_id;
+    response.card_fingerprint = card_fingerprint.into();
+    response.duplicate = Some(true);
+    Ok(payment_methods::mk_add_card_response(
+        card,
+        response,
+        req,
+        merchant_id,
+    ))
+}
+
+/// Replaces the data of a card saved in the locker with the given card data
+async fn update_card_in_locker(
+    state: &routes::AppState,
+    req: &api::CreatePaymentMethod,
     card: &api::CardDetail,
-    card_cvc: Option<String>,
-    payment_method_id: Option<String>,
-    customer_id: Option<&str>,
-) -> errors::CustomResult<payment_methods::AddCardResponse, errors::VaultError> {
-    let locker_mock_up = storage::LockerMockUpNew {
-        card_id: card_id.to_string(),
-        external_id: uuid::Uuid::new_v4().to_string(),
-        card_fingerprint: uuid::Uuid::new_v4().to_string(),
-        card_global_fingerprint: uuid::Uuid::new_v4().to_string(),
-        merchant_id: "mm01".to_string(),
-        card_number: card.card_number.peek().to_string(),
-        card_exp_year: card.card_exp_year.peek().to_string(),
-        card_exp_month: card.card_exp_month.peek().to_string(),
-        card_cvc,
-        payment_method_id,
-        customer_id: customer_id.map(str::to_string),
-    };
+    customer_id: &str,
+    locker_id: &str,
+    merchant_id: &str,
+    card_id: &str,
+) -> errors::CustomResult<(), errors::VaultError> {
+    let locker = &state.conf.locker;
+    if locker.mock_locker {
+        return vault::LocalVault::update_card(
+            state,
+            card_id,
+            merchant_id,
+            card,
+            Some(customer_id),
+        )
+        .await;
+    }
 
-    let response = db
-        .insert_locker_mock_up(locker_mock_up)
+    // The locker keeps a single entry per card number of a customer, which is updated when the
+    // card is added again
+    let request = payment_methods::mk_add_card_request(
+        locker,
+        card,
+        customer_id,
+        req,
+        locker_id,
+        merchant_id,
+    )?;
+    let response = services::call_connector_api(state, request)
         .await
         .change_context(errors::VaultError::SaveCardFailed)?;
-    Ok(payment_methods::AddCardResponse {
-        card_id: response.card_id,
-        external_id: response.external_id,
-        card_fingerprint: response.card_fingerprint.into(),
-        card_global_fingerprint: response.card_global_fingerprint.into(),
-        merchant_id: Some(response.merchant_id),
-        card_number: Some(response.card_number.into()),
-        card_exp_year: Some(response.card_exp_year.into()),
-        card_exp_month: Some(response.card_exp_month.into()),
-        name_on_card: None,
-        nickname: response.nickname,
-        customer_id: response.customer_id,
-        duplicate: response.duplicate,
-    })
+    let response: payment_methods::AddCardResponse = match response {
+        Ok(card) => card
+            .response
+            .parse_struct("AddCardResponse")
+            .change_context(errors::VaultError::ResponseDeserializationFailed),
+        Err(err) => Err(report!(errors::VaultError::UnexpectedResponseError(
+            err.response
+        ))),
+    }?;
+    if response.card_id != card_id {
+        logger::warn!(
+            %card_id,
+            locker_card_id = %response.card_id,
+            "The locker saved the updated card as a new card"
+        );
+    }
+    Ok(())
 }
 
+/// Fetches a card saved in the mock locker, before the local vault was introduced
 #[instrument(skip_all)]
 pub async fn mock_get_card<'a>(
     db: &dyn db::StorageInterface,
@@ -270,15 +418,19 @@ pub async fn mock_get_card<'a>(
     ))
 }
 
+/// Deletes a card saved in the mock locker, before the local vault was introduced
 #[instrument(skip_all)]
 pub async fn mock_delete_card<'a>(
     db: &dyn db::StorageInterface,
     card_id: &'a str,
 ) -> errors::CustomResult<payment_methods::DeleteCardResponse, errors::VaultError> {
-    let locker_mock_up = db
-        .delete_locker_mock_up(card_id)
-        .await
-        .change_context(errors::VaultError::FetchCardFailed)?;
+    let locker_mock_up = db.delete_locker_mock_up(card_id).await.map_err(|error| {
+        if error.current_context().is_db_not_found() {
+            error.change_context(errors::VaultError::CardNotFound)
+        } else {
+            error.change_context(errors::VaultError::FetchCardFailed)
+        }
+    })?;
     Ok(payment_methods::DeleteCardResponse {
         card_id: Some(locker_mock_up.card_id),
         external_id: Some(locker_mock_up.external_id),
@@ -305,10 +457,10 @@ pub async fn get_card_from_legacy_locker<'a>(
 
         response.get_response_inner("AddCardResponse")?
     } else {
-        let (get_card_response, _) = mock_get_card(&*state.store, card_id)
+        let (get_card_response, _) = vault::LocalVault::get_card(state, card_id)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while fetching card from mock_locker")?;
+            .attach_printable("Failed while fetching card from local vault")?;
         get_card_response
     };
 
@@ -332,7 +484,7 @@ pub async fn delete_card<'a>(
             .await
             .get_response_inner("DeleteCardResponse")?
     } else {
-        mock_delete_card(&*state.store, card_id)
+        vault::LocalVault::delete_card(state, card_id)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable(card_delete_failure_message)?
@@ -341,6 +493,47 @@ pub async fn delete_card<'a>(
     Ok(delete_card_resp)
 }
 
+/// Deletes the card from the locker, treating a card which is no longer in the locker as deleted,
+/// so that a deletion which failed midway can be retried.
+#[instrument(skip_all)]
+pub async fn delete_card_if_present<'a>(
+    state: &'a routes::AppState,
+    merchant_id: &'a str,
+    card_id: &'a str,
+) -> errors::RouterResult<()> {
+    let card_delete_failure_message = "Failed while deleting card from card_locker";
+    if state.conf.locker.mock_locker {
+        return match vault::LocalVault::delete_card(state, card_id).await {
+            Ok(_) => Ok(()),
+            Err(error) if matches!(error.current_context(), errors::VaultError::CardNotFound) => {
+                logger::info!(%card_id, "Card was already deleted from the locker");
+                Ok(())
+            }
+            Err(error) => Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(card_delete_failure_message)),
+        };
+    }
+
+    let request = payment_methods::mk_delete_card_request(&state.conf.locker, merchant_id, card_id)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Making Delete card request Failed")?;
+    let response = services::call_connector_api(state, request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(card_delete_failure_message)?;
+
+    match response {
+        Ok(_) => Ok(()),
+        Err(response) if response.status_code == 404 => {
+            logger::info!(%card_id, "Card was already deleted from the locker");
+            Ok(())
+        }
+        Err(response) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable(format!("{card_delete_failure_message}: {response:?}")),
+    }
+}
+
 pub async fn list_payment_methods(
     db: &dyn db::StorageInterface,
     merchant_account: storage::MerchantAccount,
@@ -733,37 +926,10 @@ impl BasiliskCardSupport {
 
         let db_value = vault::MockTokenizeDBValue { value1, value2 };
 
-        let value_string =
-            utils::Encode::<vault::MockTokenizeDBValue>::encode_to_string_of_json(&db_value)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(
-                    "Mock tokenize value construction failed when saving card to locker",
-                )?;
-
-        let db = &*state.store;
-
-        let already_present = db.find_config_by_key(payment_token).await;
-
-        if already_present.is_err() {
-            let config = storage::ConfigNew {
-                key: payment_token.to_string(),
-                config: value_string,
-            };
-
-            db.insert_config(config)
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Mock tokenization save to db failed")?;
-        } else {
-            let config_update = storage::ConfigUpdate::Update {
-                config: Some(value_string),
-            };
-
-            db.update_config_by_key(payment_token, config_update)
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Mock tokenization db update failed")?;
-        }
+        vault::LocalVault::store_token(state, payment_token, &db_value)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to save card in local vault")?;
 
         Ok(card)
     }
@@ -925,3 +1091,102 @@ pub async fn delete_payment_method(
         },
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl};
+
+    fn card_detail(card_exp_year: &str) -> api::CardDetail {
+        api::CardDetail {
+            card_number: masking::Secret::new("4111111111111111".to_string()),
+            card_exp_month: masking::Secret::new("10".to_string()),
+            card_exp_year: masking::Secret::new(card_exp_year.to_string()),
+            card_holder_name: Some(masking::Secret::new("John Doe".to_string())),
+        }
+    }
+
+    fn create_payment_method_request(card: api::CardDetail) -> api::CreatePaymentMethod {
+        api::CreatePaymentMethod {
+            payment_method: api_models::enums::PaymentMethodType::Card,
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            card: Some(card),
+            metadata: None,
+            customer_id: Some("cus_1".to_string()),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_add_saved_card_with_new_expiry() {
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.locker.mock_locker = true;
+        conf.local_vault.key_file = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../config/development_vault_key.hex"
+        )
+        .to_string();
+        let state = routes::AppState::with_storage(conf, StorageImpl::Mock).await;
+        let merchant_account = state
+            .store
+            .insert_merchant(storage::MerchantAccountNew {
+                merchant_id: "merchant_1".to_string(),
+                locker_id: Some("m0010".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let card = card_detail("2030");
+        let saved_card = add_card(
+            &state,
+            create_payment_method_request(card.clone()),
+            card,
+            "cus_1".to_string(),
+            &merchant_account,
+        )
+        .await
+        .unwrap();
+
+        // The renewed card is saved with the payment method of the card saved before
+        let card = card_detail("2034");
+        let renewed_card = add_card(
+            &state,
+            create_payment_method_request(card.clone()),
+            card,
+            "cus_1".to_string(),
+            &merchant_account,
+        )
+        .await
+        .unwrap();
+        assert_eq!(renewed_card.payment_method_id, saved_card.payment_method_id);
+        assert_eq!(
+            renewed_card
+                .card
+                .and_then(|card| card.expiry_year)
+                .map(|year| year.peek().to_owned()),
+            Some("2034".to_string())
+        );
+
+        let payment_methods = state
+            .store
+            .find_payment_method_by_customer_id_merchant_id_list("cus_1", "merchant_1")
+            .await
+            .unwrap();
+        assert_eq!(payment_methods.len(), 1);
+
+        let (locker_card, _) = vault::LocalVault::get_card(&state, &saved_card.payment_method_id)
+            .await
+            .unwrap();
+        assert_eq!(
+            locker_card
+                .card
+                .card_exp_year
+                .map(|year| year.peek().to_owned()),
+            Some("2034".to_string())
+        );
+    }
+}
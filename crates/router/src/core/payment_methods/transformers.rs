@@ -1,3 +1,4 @@
+use common_utils::crypto::{self, SignMessage};
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 
@@ -191,12 +192,55 @@ pub fn get_card_detail(
         expiry_month: response.card_exp_month,
         expiry_year: response.card_exp_year,
         card_token: Some(response.external_id.into()), //TODO ?
-        card_fingerprint: Some(response.card_fingerprint),
+        card_fingerprint: Some(
+            pm.card_fingerprint
+                .clone()
+                .map_or(response.card_fingerprint, Into::into),
+        ),
         card_holder_name: None,
     };
     Ok(card_detail)
 }
 
+fn hmac_card_number(
+    secret: &str,
+    scope: &str,
+    card_number: &str,
+) -> CustomResult<String, errors::VaultError> {
+    let card_number: String = card_number
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect();
+    let signature = crypto::HmacSha256
+        .sign_message(
+            secret.as_bytes(),
+            format!("{scope}:{card_number}").as_bytes(),
+        )
+        .change_context(errors::VaultError::RequestEncodingFailed)
+        .attach_printable("Failed to compute the card fingerprint")?;
+    Ok(hex::encode(signature))
+}
+
+/// Computes the fingerprint of a card for a merchant, a keyed HMAC of the card number which
+/// identifies the card without revealing it. The same card has different fingerprints for
+/// different merchants.
+pub fn get_card_fingerprint(
+    secret: &str,
+    merchant_id: &str,
+    card_number: &str,
+) -> CustomResult<String, errors::VaultError> {
+    hmac_card_number(secret, &format!("merchant_{merchant_id}"), card_number)
+}
+
+/// Computes the fingerprint of a card which is the same for all merchants. It is only meant for
+/// internal use and must not be shown to merchants.
+pub fn get_card_global_fingerprint(
+    secret: &str,
+    card_number: &str,
+) -> CustomResult<String, errors::VaultError> {
+    hmac_card_number(secret, "global", card_number)
+}
+
 //------------------------------------------------TokenizeService------------------------------------------------
 pub fn mk_crud_locker_request(
     locker: &Locker,
@@ -255,3 +299,47 @@ pub fn mk_card_value2(
         .change_context(errors::VaultError::FetchCardFailed)?;
     Ok(value2_req)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_card_fingerprint_ignores_formatting() {
+        let fingerprint = get_card_fingerprint("secret", "merchant_1", "4242424242424242").unwrap();
+
+        assert_eq!(fingerprint.len(), 64);
+        assert_eq!(
+            get_card_fingerprint("secret", "merchant_1", "4242 4242 4242 4242").unwrap(),
+            fingerprint
+        );
+        assert_eq!(
+            get_card_fingerprint("secret", "merchant_1", "4242-4242-4242-4242").unwrap(),
+            fingerprint
+        );
+    }
+
+    #[test]
+    fn test_card_fingerprint_is_scoped_and_keyed() {
+        let fingerprint = get_card_fingerprint("secret", "merchant_1", "4242424242424242").unwrap();
+
+        assert_ne!(
+            get_card_fingerprint("secret", "merchant_2", "4242424242424242").unwrap(),
+            fingerprint
+        );
+        assert_ne!(
+            get_card_fingerprint("another_secret", "merchant_1", "4242424242424242").unwrap(),
+            fingerprint
+        );
+        assert_ne!(
+            get_card_fingerprint("secret", "merchant_1", "4000056655665556").unwrap(),
+            fingerprint
+        );
+        assert_ne!(
+            get_card_global_fingerprint("secret", "4242424242424242").unwrap(),
+            fingerprint
+        );
+    }
+}
@@ -5,21 +5,23 @@ use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
 use router_env::{instrument, tracing};
 
-#[cfg(not(feature = "basilisk"))]
-use crate::types::storage;
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult},
-        payment_methods::transformers as payment_methods,
+        payment_methods::{cards, transformers as payment_methods},
     },
-    logger, routes, services,
-    types::api,
+    logger, routes,
+    services::{self, encryption, key_manager},
+    types::{api, storage},
     utils::{self, BytesExt, StringExt},
 };
 
 const VAULT_SERVICE_NAME: &str = "CARD";
 const VAULT_VERSION: &str = "0";
 
+const MOCK_LOCKER_MIGRATION_WORKFLOW_RUNNER: &str = "MOCK_LOCKER_MIGRATION_WORKFLOW";
+const MOCK_LOCKER_MIGRATION_WORKFLOW_TASK: &str = "MOCK_LOCKER_MIGRATION";
+
 pub struct SupplementaryVaultData {
     pub customer_id: Option<String>,
     pub payment_method_id: Option<String>,
@@ -230,19 +232,11 @@ impl Vault {
         state: &routes::AppState,
         lookup_key: &str,
     ) -> RouterResult<(Option<api::PaymentMethod>, SupplementaryVaultData)> {
-        let config = state
-            .store
-            .find_config_by_key(lookup_key)
+        let tokenize_value = LocalVault::get_token(state, lookup_key)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Could not find payment method in vault")?;
 
-        let tokenize_value: MockTokenizeDBValue = config
-            .config
-            .parse_struct("MockTokenizeDBValue")
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Unable to deserialize Mock tokenize db value")?;
-
         let (payment_method, supp_data) =
             api::PaymentMethod::from_values(tokenize_value.value1, tokenize_value.value2)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -272,36 +266,10 @@ impl Vault {
 
         let db_value = MockTokenizeDBValue { value1, value2 };
 
-        let value_string =
-            utils::Encode::<MockTokenizeDBValue>::encode_to_string_of_json(&db_value)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to encode payment method as mock tokenize db value")?;
-
-        let already_present = state.store.find_config_by_key(&lookup_key).await;
-
-        if already_present.is_err() {
-            let config = storage::ConfigNew {
-                key: lookup_key.clone(),
-                config: value_string,
-            };
-
-            state
-                .store
-                .insert_config(config)
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Mock tokenization save to db failed insert")?;
-        } else {
-            let config_update = storage::ConfigUpdate::Update {
-                config: Some(value_string),
-            };
-            state
-                .store
-                .update_config_by_key(&lookup_key, config_update)
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Mock tokenization save to db failed update")?;
-        }
+        LocalVault::store_token(state, &lookup_key, &db_value)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to save payment method in local vault")?;
 
         Ok(lookup_key)
     }
@@ -311,11 +279,13 @@ impl Vault {
         state: &routes::AppState,
         lookup_key: &Option<String>,
     ) {
-        let db = &*state.store;
         if let Some(id) = lookup_key {
-            match db.delete_config_by_key(id).await {
-                Ok(_) => logger::info!("Card Deleted from locker mock up"),
-                Err(err) => logger::error!("Err: Card Delete from locker Failed : {}", err),
+            match LocalVault::delete_token(state, id).await {
+                Ok(_) => logger::info!("Payment method deleted from local vault"),
+                Err(err) => logger::error!(
+                    "Err: Payment method delete from local vault failed : {}",
+                    err
+                ),
             }
         }
     }
@@ -380,6 +350,459 @@ impl Vault {
     }
 }
 
+//------------------------------------------------LocalVault------------------------------------------------
+/// Vault keeping payment method data within the application, in place of the external locker,
+/// when the mock locker is enabled.
+///
+/// Saved cards are stored in the database, encrypted with a data key of their own which is wrapped
+/// by the vault key. The vault key is read from its own key file, so a dump of the database alone
+/// is not enough to recover card data. Card security codes and temporary payment method tokens are
+/// never written to the database: they are kept in Redis, encrypted with the vault key, and expire
+/// after their TTL. Deleting a saved card deletes its data key along with it.
+///
+/// Data tokenized through the tokens API is encrypted in the same way, with a data key per token.
+pub struct LocalVault;
+
+impl LocalVault {
+    fn get_vault_key(state: &routes::AppState) -> CustomResult<&[u8], errors::EncryptionError> {
+        state.local_vault_key_manager.get_key()
+    }
+
+    fn get_card_cvc_key(card_id: &str) -> String {
+        format!("vault_cvc_{card_id}")
+    }
+
+    fn get_token_key(lookup_key: &str) -> String {
+        format!("vault_token_{lookup_key}")
+    }
+
+    /// Encrypts the value with a newly generated data key. Returns the data key wrapped by the
+    /// vault key, along with the encrypted value.
+    pub fn encrypt_with_data_key(
+        state: &routes::AppState,
+        value: &String,
+    ) -> CustomResult<(Vec<u8>, Vec<u8>), errors::EncryptionError> {
+        let vault_key = Self::get_vault_key(state)?;
+        let data_key = key_manager::generate_data_key();
+        let encrypted_value = encryption::encrypt(value, &data_key)
+            .attach_printable("Failed to encrypt value with the data key")?;
+        let encrypted_data_key = encryption::encrypt(&hex::encode(data_key), vault_key)
+            .attach_printable("Failed to wrap the data key")?;
+
+        Ok((encrypted_data_key, encrypted_value))
+    }
+
+    /// Decrypts a value encrypted by [`Self::encrypt_with_data_key`]
+    pub fn decrypt_with_data_key(
+        state: &routes::AppState,
+        encrypted_data_key: Vec<u8>,
+        encrypted_value: Vec<u8>,
+    ) -> CustomResult<String, errors::EncryptionError> {
+        let vault_key = Self::get_vault_key(state)?;
+        let data_key = encryption::decrypt(encrypted_data_key, vault_key)
+            .attach_printable("Failed to unwrap the data key")?;
+        let data_key = hex::decode(data_key)
+            .into_report()
+            .change_context(errors::EncryptionError)
+            .attach_printable("Data key is not hex encoded")?;
+
+        encryption::decrypt(encrypted_value, &data_key)
+            .attach_printable("Failed to decrypt value with the data key")
+    }
+
+    async fn set_encrypted_value(
+        state: &routes::AppState,
+        key: &str,
+        value: &String,
+        ttl: i64,
+    ) -> CustomResult<(), errors::VaultError> {
+        let vault_key =
+            Self::get_vault_key(state).change_context(errors::VaultError::SaveCardFailed)?;
+        let encrypted_value = encryption::encrypt(value, vault_key)
+            .change_context(errors::VaultError::SaveCardFailed)
+            .attach_printable("Failed to encrypt value with the vault key")?;
+
+        state
+            .store
+            .get_redis_conn()
+            .set_key_with_expiry(key, encrypted_value.as_slice(), ttl)
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)
+            .attach_printable("Failed to save value in redis")
+    }
+
+    async fn get_encrypted_value(
+        state: &routes::AppState,
+        key: &str,
+    ) -> CustomResult<Option<String>, errors::VaultError> {
+        let encrypted_value = state
+            .store
+            .get_redis_conn()
+            .get_key::<Vec<u8>>(key)
+            .await
+            .change_context(errors::VaultError::FetchCardFailed)
+            .attach_printable("Failed to fetch value from redis")?;
+
+        if encrypted_value.is_empty() {
+            return Ok(None);
+        }
+
+        let vault_key =
+            Self::get_vault_key(state).change_context(errors::VaultError::FetchCardFailed)?;
+        encryption::decrypt(encrypted_value, vault_key)
+            .change_context(errors::VaultError::FetchCardFailed)
+            .attach_printable("Failed to decrypt value with the vault key")
+            .map(Some)
+    }
+
+    async fn delete_value(
+        state: &routes::AppState,
+        key: &str,
+    ) -> CustomResult<(), errors::VaultError> {
+        state
+            .store
+            .get_redis_conn()
+            .delete_key(key)
+            .await
+            .change_context(errors::VaultError::FetchCardFailed)
+            .attach_printable("Failed to delete value from redis")
+    }
+
+    #[instrument(skip_all)]
+    pub async fn store_token(
+        state: &routes::AppState,
+        lookup_key: &str,
+        value: &MockTokenizeDBValue,
+    ) -> CustomResult<(), errors::VaultError> {
+        let value = utils::Encode::<MockTokenizeDBValue>::encode_to_string_of_json(value)
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode payment method token value")?;
+
+        Self::set_encrypted_value(
+            state,
+            &Self::get_token_key(lookup_key),
+            &value,
+            state.conf.local_vault.token_ttl,
+        )
+        .await
+    }
+
+    #[instrument(skip_all)]
+    pub async fn get_token(
+        state: &routes::AppState,
+        lookup_key: &str,
+    ) -> CustomResult<MockTokenizeDBValue, errors::VaultError> {
+        Self::get_encrypted_value(state, &Self::get_token_key(lookup_key))
+            .await?
+            .ok_or(errors::VaultError::FetchCardFailed)
+            .into_report()
+            .attach_printable("Payment method token not found or expired")?
+            .parse_struct("MockTokenizeDBValue")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Unable to deserialize payment method token value")
+    }
+
+    #[instrument(skip_all)]
+    pub async fn delete_token(
+        state: &routes::AppState,
+        lookup_key: &str,
+    ) -> CustomResult<(), errors::VaultError> {
+        Self::delete_value(state, &Self::get_token_key(lookup_key)).await
+    }
+
+    fn get_card_data(card: &api::CardDetail) -> CustomResult<String, errors::VaultError> {
+        payment_methods::mk_card_value1(
+            card.card_number.peek().to_string(),
+            card.card_exp_year.peek().to_string(),
+            card.card_exp_month.peek().to_string(),
+            card.card_holder_name
+                .as_ref()
+                .map(|name| name.peek().to_string()),
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[instrument(skip_all)]
+    pub async fn add_card(
+        state: &routes::AppState,
+        card_id: &str,
+        merchant_id: &str,
+        card: &api::CardDetail,
+        card_cvc: Option<String>,
+        payment_method_id: Option<String>,
+        customer_id: Option<&str>,
+    ) -> CustomResult<payment_methods::AddCardResponse, errors::VaultError> {
+        let card_data = Self::get_card_data(card)?;
+
+        let card_fingerprint = payment_methods::get_card_fingerprint(
+            &state.conf.secrets.card_fingerprint_secret,
+            merchant_id,
+            card.card_number.peek(),
+        )?;
+        let card_global_fingerprint = payment_methods::get_card_global_fingerprint(
+            &state.conf.secrets.card_fingerprint_secret,
+            card.card_number.peek(),
+        )?;
+
+        let (encrypted_data_key, encrypted_card_data) =
+            Self::encrypt_with_data_key(state, &card_data)
+                .change_context(errors::VaultError::SaveCardFailed)
+                .attach_printable("Failed to encrypt card data")?;
+
+        let card_vault = storage::CardVaultNew {
+            card_id: card_id.to_string(),
+            external_id: uuid::Uuid::new_v4().to_string(),
+            merchant_id: merchant_id.to_string(),
+            customer_id: customer_id.map(str::to_string),
+            payment_method_id,
+            card_fingerprint,
+            card_global_fingerprint,
+            encrypted_data_key,
+            encrypted_card_data,
+        };
+
+        let response = state
+            .store
+            .insert_card_vault(card_vault)
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)?;
+
+        if let Some(card_cvc) = card_cvc {
+            Self::set_encrypted_value(
+                state,
+                &Self::get_card_cvc_key(card_id),
+                &card_cvc,
+                state.conf.local_vault.cvc_ttl,
+            )
+            .await?;
+        }
+
+        Ok(payment_methods::AddCardResponse {
+            card_id: response.card_id,
+            external_id: response.external_id,
+            card_fingerprint: response.card_fingerprint.into(),
+            card_global_fingerprint: response.card_global_fingerprint.into(),
+            merchant_id: Some(response.merchant_id),
+            card_number: Some(card.card_number.clone()),
+            card_exp_year: Some(card.card_exp_year.clone()),
+            card_exp_month: Some(card.card_exp_month.clone()),
+            name_on_card: card.card_holder_name.clone(),
+            nickname: None,
+            customer_id: response.customer_id,
+            duplicate: None,
+        })
+    }
+
+    /// Replaces the data of a saved card, such as when the card is saved again with a new expiry
+    /// date. The card data is encrypted with a new data key. A card still in the mock locker is
+    /// moved to the vault, keeping its card ID.
+    #[instrument(skip_all)]
+    pub async fn update_card(
+        state: &routes::AppState,
+        card_id: &str,
+        merchant_id: &str,
+        card: &api::CardDetail,
+        customer_id: Option<&str>,
+    ) -> CustomResult<(), errors::VaultError> {
+        let card_data = Self::get_card_data(card)?;
+        let (encrypted_data_key, encrypted_card_data) =
+            Self::encrypt_with_data_key(state, &card_data)
+                .change_context(errors::VaultError::SaveCardFailed)
+                .attach_printable("Failed to encrypt card data")?;
+
+        let card_vault_update = storage::CardVaultUpdate::CardDataUpdate {
+            encrypted_data_key,
+            encrypted_card_data,
+        };
+        match state
+            .store
+            .update_card_vault_by_card_id(card_id, card_vault_update)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(error) if error.current_context().is_db_not_found() => {
+                let locker_mock_up = state
+                    .store
+                    .find_locker_by_card_id(card_id)
+                    .await
+                    .change_context(errors::VaultError::FetchCardFailed)?;
+                Self::add_card(
+                    state,
+                    card_id,
+                    merchant_id,
+                    card,
+                    None,
+                    locker_mock_up.payment_method_id,
+                    customer_id,
+                )
+                .await?;
+                cards::mock_delete_card(&*state.store, card_id).await?;
+                Ok(())
+            }
+            Err(error) => Err(error).change_context(errors::VaultError::SaveCardFailed),
+        }
+    }
+
+    /// Fetches a saved card, along with its security code if it has not expired yet
+    #[instrument(skip_all)]
+    pub async fn get_card(
+        state: &routes::AppState,
+        card_id: &str,
+    ) -> CustomResult<(payment_methods::GetCardResponse, Option<String>), errors::VaultError> {
+        let card_vault = match state.store.find_card_vault_by_card_id(card_id).await {
+            Ok(card_vault) => card_vault,
+            // Cards saved before the local vault was introduced are still in the mock locker
+            Err(error) if error.current_context().is_db_not_found() => {
+                return cards::mock_get_card(&*state.store, card_id).await;
+            }
+            Err(error) => Err(error).change_context(errors::VaultError::FetchCardFailed)?,
+        };
+
+        let card_data: api::TokenizedCardValue1 = Self::decrypt_with_data_key(
+            state,
+            card_vault.encrypted_data_key,
+            card_vault.encrypted_card_data,
+        )
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to decrypt card data")?
+        .parse_struct("TokenizedCardValue1")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+        let card_cvc = Self::get_encrypted_value(state, &Self::get_card_cvc_key(card_id)).await?;
+
+        let card = payment_methods::AddCardResponse {
+            card_id: card_vault.payment_method_id.unwrap_or(card_vault.card_id),
+            external_id: card_vault.external_id,
+            card_fingerprint: card_vault.card_fingerprint.into(),
+            card_global_fingerprint: card_vault.card_global_fingerprint.into(),
+            merchant_id: Some(card_vault.merchant_id),
+            card_number: Some(card_data.card_number.into()),
+            card_exp_year: Some(card_data.exp_year.into()),
+            card_exp_month: Some(card_data.exp_month.into()),
+            name_on_card: card_data.name_on_card.map(Into::into),
+            nickname: card_data.nickname,
+            customer_id: card_vault.customer_id,
+            duplicate: None,
+        };
+
+        Ok((payment_methods::GetCardResponse { card }, card_cvc))
+    }
+
+    /// Deletes a saved card and its security code. The data key of the card is deleted along with
+    /// the card, so the card data cannot be recovered afterwards.
+    #[instrument(skip_all)]
+    pub async fn delete_card(
+        state: &routes::AppState,
+        card_id: &str,
+    ) -> CustomResult<payment_methods::DeleteCardResponse, errors::VaultError> {
+        let card_vault = match state.store.delete_card_vault_by_card_id(card_id).await {
+            Ok(card_vault) => card_vault,
+            Err(error) if error.current_context().is_db_not_found() => {
+                return cards::mock_delete_card(&*state.store, card_id).await;
+            }
+            Err(error) => Err(error).change_context(errors::VaultError::FetchCardFailed)?,
+        };
+
+        Self::delete_value(state, &Self::get_card_cvc_key(card_id)).await?;
+
+        Ok(payment_methods::DeleteCardResponse {
+            card_id: Some(card_vault.card_id),
+            external_id: Some(card_vault.external_id),
+            card_isin: None,
+            status: "SUCCESS".to_string(),
+        })
+    }
+
+    /// Moves a card saved in the mock locker, before the local vault was introduced, to the vault,
+    /// keeping its card ID. The card is deleted from the mock locker once moved, so that no card
+    /// data is left in plaintext in the database.
+    #[instrument(skip_all)]
+    pub async fn move_mock_locker_card(
+        state: &routes::AppState,
+        locker_mock_up: storage::LockerMockUp,
+    ) -> CustomResult<(), errors::VaultError> {
+        let card_data = payment_methods::mk_card_value1(
+            locker_mock_up.card_number,
+            locker_mock_up.card_exp_year,
+            locker_mock_up.card_exp_month,
+            locker_mock_up.name_on_card,
+            locker_mock_up.nickname,
+            None,
+            None,
+        )?;
+        let (encrypted_data_key, encrypted_card_data) =
+            Self::encrypt_with_data_key(state, &card_data)
+                .change_context(errors::VaultError::SaveCardFailed)
+                .attach_printable("Failed to encrypt card data")?;
+
+        let card_vault = storage::CardVaultNew {
+            card_id: locker_mock_up.card_id.clone(),
+            external_id: locker_mock_up.external_id,
+            merchant_id: locker_mock_up.merchant_id,
+            customer_id: locker_mock_up.customer_id,
+            payment_method_id: locker_mock_up.payment_method_id,
+            card_fingerprint: locker_mock_up.card_fingerprint,
+            card_global_fingerprint: locker_mock_up.card_global_fingerprint,
+            encrypted_data_key,
+            encrypted_card_data,
+        };
+        match state.store.insert_card_vault(card_vault).await {
+            Ok(_) => {}
+            // The card was moved before, but could not be deleted from the mock locker then
+            Err(error) if error.current_context().is_db_unique_violation() => {}
+            Err(error) => Err(error).change_context(errors::VaultError::SaveCardFailed)?,
+        }
+
+        // The security code is kept in the vault for its TTL, like the one of a newly saved card
+        if let Some(card_cvc) = locker_mock_up.card_cvc {
+            Self::set_encrypted_value(
+                state,
+                &Self::get_card_cvc_key(&locker_mock_up.card_id),
+                &card_cvc,
+                state.conf.local_vault.cvc_ttl,
+            )
+            .await?;
+        }
+
+        cards::mock_delete_card(&*state.store, &locker_mock_up.card_id).await?;
+        Ok(())
+    }
+}
+
+/// Schedules the task moving the cards still saved in the mock locker to the local vault. The task
+/// is scheduled only once, later calls find it already scheduled.
+#[instrument(skip_all)]
+pub async fn add_mock_locker_migration_task(state: &routes::AppState) -> RouterResult<()> {
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: format!(
+            "{MOCK_LOCKER_MIGRATION_WORKFLOW_RUNNER}_{MOCK_LOCKER_MIGRATION_WORKFLOW_TASK}"
+        ),
+        name: Some(String::from(MOCK_LOCKER_MIGRATION_WORKFLOW_TASK)),
+        tag: vec![String::from("LOCKER"), String::from("MIGRATION")],
+        runner: Some(String::from(MOCK_LOCKER_MIGRATION_WORKFLOW_RUNNER)),
+        retry_count: 0,
+        schedule_time: Some(current_time),
+        rule: String::new(),
+        tracking_data: serde_json::json!({}),
+        business_status: String::from("Pending"),
+        status: storage::enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while inserting the mock locker migration task"),
+    }
+}
+
 //------------------------------------------------TokenizeService------------------------------------------------
 pub async fn create_tokenize(
     state: &routes::AppState,
@@ -555,3 +978,206 @@ pub async fn delete_tokenized_data(
             .attach_printable(format!("Got 4xx from the basilisk locker: {err:?}")),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl};
+
+    async fn create_state(cvc_ttl: i64) -> routes::AppState {
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.local_vault.key_file = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../config/development_vault_key.hex"
+        )
+        .to_string();
+        conf.local_vault.cvc_ttl = cvc_ttl;
+        routes::AppState::with_storage(conf, StorageImpl::Mock).await
+    }
+
+    fn card_detail() -> api::CardDetail {
+        api::CardDetail {
+            card_number: masking::Secret::new("4111111111111111".to_string()),
+            card_exp_month: masking::Secret::new("10".to_string()),
+            card_exp_year: masking::Secret::new("2030".to_string()),
+            card_holder_name: Some(masking::Secret::new("John Doe".to_string())),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_encrypt_decrypt_with_data_key() {
+        let state = create_state(900).await;
+        let value = "4111111111111111".to_string();
+
+        let (encrypted_data_key, encrypted_value) =
+            LocalVault::encrypt_with_data_key(&state, &value).unwrap();
+        assert_ne!(encrypted_value, value.as_bytes());
+        let decrypted_value =
+            LocalVault::decrypt_with_data_key(&state, encrypted_data_key.clone(), encrypted_value)
+                .unwrap();
+        assert_eq!(decrypted_value, value);
+
+        // Every value is encrypted with a data key of its own
+        let (other_data_key, other_value) =
+            LocalVault::encrypt_with_data_key(&state, &value).unwrap();
+        assert_ne!(other_data_key, encrypted_data_key);
+        assert!(
+            LocalVault::decrypt_with_data_key(&state, encrypted_data_key, other_value).is_err()
+        );
+        assert!(LocalVault::decrypt_with_data_key(
+            &state,
+            other_data_key,
+            value.as_bytes().to_vec()
+        )
+        .is_err());
+    }
+
+    #[actix_rt::test]
+    async fn test_card_cvc_expires() {
+        let state = create_state(1).await;
+        let card_id = format!("card_{}", uuid::Uuid::new_v4());
+
+        LocalVault::add_card(
+            &state,
+            &card_id,
+            "merchant_1",
+            &card_detail(),
+            Some("123".to_string()),
+            None,
+            Some("cus_1"),
+        )
+        .await
+        .unwrap();
+
+        let (card, card_cvc) = LocalVault::get_card(&state, &card_id).await.unwrap();
+        assert_eq!(
+            card.card
+                .card_number
+                .as_ref()
+                .map(|number| number.peek().as_str()),
+            Some("4111111111111111")
+        );
+        assert_eq!(card_cvc.as_deref(), Some("123"));
+
+        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+        // The card outlives its security code
+        let (card, card_cvc) = LocalVault::get_card(&state, &card_id).await.unwrap();
+        assert_eq!(
+            card.card
+                .card_exp_year
+                .as_ref()
+                .map(|year| year.peek().as_str()),
+            Some("2030")
+        );
+        assert!(card_cvc.is_none());
+
+        LocalVault::delete_card(&state, &card_id).await.unwrap();
+        assert!(LocalVault::get_card(&state, &card_id).await.is_err());
+    }
+
+    #[actix_rt::test]
+    async fn test_legacy_card_fallback() {
+        let state = create_state(900).await;
+        let card_id = format!("card_{}", uuid::Uuid::new_v4());
+
+        state
+            .store
+            .insert_locker_mock_up(storage::LockerMockUpNew {
+                card_id: card_id.clone(),
+                external_id: uuid::Uuid::new_v4().to_string(),
+                card_fingerprint: "fingerprint".to_string(),
+                card_global_fingerprint: "global_fingerprint".to_string(),
+                merchant_id: "merchant_1".to_string(),
+                card_number: "4111111111111111".to_string(),
+                card_exp_year: "2030".to_string(),
+                card_exp_month: "10".to_string(),
+                card_cvc: Some("123".to_string()),
+                payment_method_id: None,
+                customer_id: Some("cus_1".to_string()),
+            })
+            .await
+            .unwrap();
+
+        let (card, card_cvc) = LocalVault::get_card(&state, &card_id).await.unwrap();
+        assert_eq!(card.card.card_id, card_id);
+        assert_eq!(
+            card.card
+                .card_number
+                .as_ref()
+                .map(|number| number.peek().as_str()),
+            Some("4111111111111111")
+        );
+        assert_eq!(card_cvc.as_deref(), Some("123"));
+
+        let response = LocalVault::delete_card(&state, &card_id).await.unwrap();
+        assert_eq!(response.card_id, Some(card_id.clone()));
+        assert!(matches!(
+            LocalVault::delete_card(&state, &card_id)
+                .await
+                .unwrap_err()
+                .current_context(),
+            errors::VaultError::CardNotFound
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn test_move_mock_locker_card() {
+        let state = create_state(900).await;
+        let card_id = format!("card_{}", uuid::Uuid::new_v4());
+        let external_id = uuid::Uuid::new_v4().to_string();
+
+        let locker_mock_up = state
+            .store
+            .insert_locker_mock_up(storage::LockerMockUpNew {
+                card_id: card_id.clone(),
+                external_id: external_id.clone(),
+                card_fingerprint: "fingerprint".to_string(),
+                card_global_fingerprint: "global_fingerprint".to_string(),
+                merchant_id: "merchant_1".to_string(),
+                card_number: "4111111111111111".to_string(),
+                card_exp_year: "2030".to_string(),
+                card_exp_month: "10".to_string(),
+                card_cvc: Some("123".to_string()),
+                payment_method_id: Some("pm_1".to_string()),
+                customer_id: Some("cus_1".to_string()),
+            })
+            .await
+            .unwrap();
+
+        LocalVault::move_mock_locker_card(&state, locker_mock_up)
+            .await
+            .unwrap();
+
+        // The plaintext card is gone from the mock locker
+        assert!(state
+            .store
+            .find_locker_by_card_id(&card_id)
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_not_found());
+
+        let card_vault = state
+            .store
+            .find_card_vault_by_card_id(&card_id)
+            .await
+            .unwrap();
+        assert_eq!(card_vault.external_id, external_id);
+        assert_eq!(card_vault.card_fingerprint, "fingerprint");
+
+        let (card, card_cvc) = LocalVault::get_card(&state, &card_id).await.unwrap();
+        assert_eq!(card.card.card_id, "pm_1");
+        assert_eq!(
+            card.card
+                .card_number
+                .as_ref()
+                .map(|number| number.peek().as_str()),
+            Some("4111111111111111")
+        );
+        assert_eq!(card.card.customer_id.as_deref(), Some("cus_1"));
+        assert_eq!(card_cvc.as_deref(), Some("123"));
+    }
+}
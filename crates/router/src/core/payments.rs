@@ -2,6 +2,8 @@ pub mod access_token;
 pub mod flows;
 pub mod helpers;
 pub mod operations;
+pub mod retry;
+pub mod routing;
 pub mod transformers;
 
 use std::{fmt::Debug, marker::PhantomData, time::Instant};
@@ -13,8 +15,8 @@ use router_env::{instrument, tracing};
 use time;
 
 pub use self::operations::{
-    PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate, PaymentMethodValidate,
-    PaymentResponse, PaymentSession, PaymentStatus, PaymentUpdate,
+    PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate, PaymentIncrementalAuthorization,
+    PaymentMethodValidate, PaymentResponse, PaymentSession, PaymentStatus, PaymentUpdate,
 };
 use self::{
     flows::{ConstructFlowSpecificData, Feature},
@@ -22,7 +24,7 @@ use self::{
 };
 use crate::{
     core::{
-        errors::{self, RouterResponse, RouterResult},
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::vault,
     },
     db::StorageInterface,
@@ -34,7 +36,7 @@ use crate::{
         self, api,
         storage::{self, enums as storage_enums},
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[instrument(skip_all)]
@@ -69,6 +71,15 @@ where
 
     tracing::Span::current().record("payment_id", &format!("{:?}", validate_result.payment_id));
 
+    // Mutations of a payment are serialized, so that concurrent requests on the same payment
+    // cannot both reach the connector
+    let payment_lock = match &validate_result.payment_id {
+        api::PaymentIdType::PaymentIntentId(payment_id) if operation.should_lock_payment() => Some(
+            services::locking::lock_payment(state, validate_result.merchant_id, payment_id).await?,
+        ),
+        _ => None,
+    };
+
     let (operation, mut payment_data, customer_details) = operation
         .to_get_tracker()?
         .get_trackers(
@@ -109,6 +120,8 @@ where
         )
         .await?;
 
+    let is_routed = matches!(connector_details, api::ConnectorCallType::Routing);
+
     let connector_details = route_connector(
         state,
         &merchant_account,
@@ -117,6 +130,25 @@ where
     )
     .await?;
 
+    // Authorizations are voided once they expire, so the capture of a payment cannot be scheduled
+    // after the expiry of its authorization
+    if payment_data.payment_attempt.capture_method == Some(storage_enums::CaptureMethod::Scheduled)
+        && payment_data.payment_attempt.status != storage_enums::AttemptStatus::Authorized
+        && should_call_connector(&operation, &payment_data)
+    {
+        let authorization_expires_at =
+            get_authorization_expires_at(state, &payment_data.payment_attempt).await?;
+        helpers::validate_capture_on_before_authorization_expiry(
+            payment_data.payment_attempt.capture_on,
+            authorization_expires_at,
+        )?;
+    }
+
+    // A request whose lock expired while it was being processed must not mutate the payment
+    if let Some(payment_lock) = &payment_lock {
+        services::locking::check_payment_lock(payment_lock).await?;
+    }
+
     let (operation, mut payment_data) = operation
         .to_update_tracker()?
         .update_trackers(
@@ -134,19 +166,36 @@ where
         .await?;
 
     if should_call_connector(&operation, &payment_data) {
+        let previous_attempt_status = payment_data.payment_attempt.status;
         payment_data = match connector_details {
             api::ConnectorCallType::Single(connector) => {
-                call_connector_service(
+                let (payment_data, connector_error) = call_connector_service(
                     state,
                     &merchant_account,
                     &validate_result.payment_id,
-                    connector,
+                    &connector,
                     &operation,
                     payment_data,
                     &customer,
                     call_connector_action,
                 )
-                .await?
+                .await?;
+
+                if retry::should_retry_payment::<F, FData>(state, &merchant_account, is_routed) {
+                    retry::do_retries(
+                        state,
+                        &merchant_account,
+                        &validate_result.payment_id,
+                        connector,
+                        &operation,
+                        payment_data,
+                        connector_error,
+                        &customer,
+                    )
+                    .await?
+                } else {
+                    payment_data
+                }
             }
             api::ConnectorCallType::Multiple(connectors) => {
                 call_multiple_connectors_service(
@@ -179,17 +228,37 @@ where
                     state,
                     &merchant_account,
                     &validate_result.payment_id,
-                    connector_data,
+                    &connector_data,
                     &operation,
                     payment_data,
                     &customer,
                     call_connector_action,
                 )
                 .await?
+                .0
             }
         };
+
+        // Authorizations which are not captured before they expire are voided. The payment is
+        // already authorized at the connector, so failing to schedule the void must not fail it.
+        if previous_attempt_status != storage_enums::AttemptStatus::Authorized
+            && payment_data.payment_attempt.status == storage_enums::AttemptStatus::Authorized
+        {
+            if let Err(error) = add_process_void_task(state, &payment_data.payment_attempt).await {
+                logger::error!(
+                    ?error,
+                    payment_id = %payment_data.payment_attempt.payment_id,
+                    "Failed to schedule the void of the authorization"
+                );
+            }
+        }
+
         vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token).await
     }
+
+    if let Some(payment_lock) = payment_lock {
+        services::locking::unlock_payment(payment_lock).await;
+    }
     Ok((payment_data, req, customer))
 }
 
@@ -324,12 +393,12 @@ pub async fn call_connector_service<F, Op, Req>(
     state: &AppState,
     merchant_account: &storage::MerchantAccount,
     payment_id: &api::PaymentIdType,
-    connector: api::ConnectorData,
+    connector: &api::ConnectorData,
     _operation: &Op,
     payment_data: PaymentData<F>,
     customer: &Option<storage::Customer>,
     call_connector_action: CallConnectorAction,
-) -> RouterResult<PaymentData<F>>
+) -> RouterResult<(PaymentData<F>, Option<types::ErrorResponse>)>
 where
     Op: Debug + Sync,
     F: Send + Clone,
@@ -353,7 +422,7 @@ where
         .await?;
 
     let add_access_token_result = router_data
-        .add_access_token(state, &connector, merchant_account)
+        .add_access_token(state, connector, merchant_account)
         .await?;
 
     access_token::update_router_data_with_access_token_result(
@@ -368,7 +437,7 @@ where
         router_data
             .decide_flows(
                 state,
-                &connector,
+                connector,
                 customer,
                 call_connector_action,
                 merchant_account,
@@ -380,6 +449,7 @@ where
 
     let response = router_data_res
         .async_and_then(|response| async {
+            let connector_error = response.response.as_ref().err().cloned();
             let operation = helpers::response_operation::<F, Req>();
             let payment_data = operation
                 .to_post_update_tracker()?
@@ -391,10 +461,19 @@ where
                     merchant_account.storage_scheme,
                 )
                 .await?;
-            Ok(payment_data)
+            Ok((payment_data, connector_error))
         })
         .await?;
 
+    if types::RouterData::<F, Req, types::PaymentsResponseData>::is_authorize_flow() {
+        routing::success_rate::record_authorize_outcome(
+            state,
+            merchant_account,
+            &response.0.payment_attempt,
+        )
+        .await;
+    }
+
     let etime_connector = Instant::now();
     let duration_connector = etime_connector.saturating_duration_since(stime_connector);
     tracing::info!(duration = format!("Duration taken: {}", duration_connector.as_millis()));
@@ -506,6 +585,8 @@ where
     pub force_sync: Option<bool>,
     pub payment_method_data: Option<api::PaymentMethod>,
     pub refunds: Vec<storage::Refund>,
+    pub captures: Vec<storage::Capture>,
+    pub incremental_authorizations: Vec<storage::IncrementalAuthorization>,
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<pii::Secret<String>>,
     pub email: Option<masking::Secret<String, pii::Email>>,
@@ -581,6 +662,7 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
                     | storage_enums::IntentStatus::Processing
                     | storage_enums::IntentStatus::Succeeded
                     | storage_enums::IntentStatus::RequiresCustomerAction
+                    | storage_enums::IntentStatus::RequiresCapture
             ) && payment_data.force_sync.unwrap_or(false)
         }
         "PaymentCancel" => matches!(
@@ -591,8 +673,13 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
             matches!(
                 payment_data.payment_intent.status,
                 storage_enums::IntentStatus::RequiresCapture
+                    | storage_enums::IntentStatus::PartiallyCaptured
             )
         }
+        "PaymentIncrementalAuthorization" => matches!(
+            payment_data.payment_intent.status,
+            storage_enums::IntentStatus::RequiresCapture
+        ),
         "PaymentSession" => true,
         _ => false,
     }
@@ -662,6 +749,138 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+/// Schedules the capture of an authorized payment, whose capture method is `scheduled`, at its
+/// `capture_on` time.
+pub async fn add_process_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let schedule_time = payment_attempt
+        .capture_on
+        .get_required_value("capture_on")?;
+    let tracking_data = api::PaymentsCaptureRequest {
+        payment_id: Some(payment_attempt.payment_id.clone()),
+        merchant_id: Some(payment_attempt.merchant_id.clone()),
+        ..Default::default()
+    };
+    let runner = "PAYMENTS_CAPTURE_WORKFLOW";
+    let task = "PAYMENTS_CAPTURE";
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: pt_utils::get_process_tracker_id(
+            runner,
+            task,
+            &payment_attempt.attempt_id,
+            &payment_attempt.merchant_id,
+        ),
+        name: Some(String::from(task)),
+        tag: vec![String::from("CAPTURE"), String::from("PAYMENT")],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(schedule_time),
+        rule: String::new(),
+        tracking_data: utils::Encode::<api::PaymentsCaptureRequest>::encode_to_value(
+            &tracking_data,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        business_status: String::from("Pending"),
+        status: storage_enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while scheduling the capture of payment {}",
+                payment_attempt.payment_id
+            )
+        })?;
+    Ok(())
+}
+
+/// Time at which an authorization made now for the payment attempt expires, and is voided.
+pub async fn get_authorization_expires_at(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<time::PrimitiveDateTime> {
+    let connector = payment_attempt
+        .connector
+        .clone()
+        .get_required_value("connector")?;
+    let merchant_connector_account = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &payment_attempt.merchant_id,
+            &connector,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
+        })?;
+    let expiry_days = helpers::get_authorization_expiry_days(
+        &state.conf.authorization_expiry,
+        &connector,
+        merchant_connector_account.authorization_expiry_days,
+    );
+
+    Ok(common_utils::date_time::now() + time::Duration::days(expiry_days))
+}
+
+/// Schedules the void of an authorized payment at the expiry of its authorization.
+pub async fn add_process_void_task(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let authorization_expires_at = get_authorization_expires_at(state, payment_attempt).await?;
+
+    let tracking_data = storage::AuthorizationExpiryTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+    };
+    let runner = "PAYMENTS_VOID_WORKFLOW";
+    let task = "PAYMENTS_VOID";
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: pt_utils::get_process_tracker_id(
+            runner,
+            task,
+            &payment_attempt.attempt_id,
+            &payment_attempt.merchant_id,
+        ),
+        name: Some(String::from(task)),
+        tag: vec![String::from("VOID"), String::from("PAYMENT")],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(authorization_expires_at),
+        rule: String::new(),
+        tracking_data: utils::Encode::<storage::AuthorizationExpiryTrackingData>::encode_to_value(
+            &tracking_data,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        business_status: String::from("Pending"),
+        status: storage_enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while scheduling the void of payment {}",
+                payment_attempt.payment_id
+            )
+        })?;
+    Ok(())
+}
+
 pub async fn route_connector<F>(
     state: &AppState,
     merchant_account: &storage::MerchantAccount,
@@ -688,7 +907,40 @@ where
 
             let connector_name = match routing_algorithm {
                 api::RoutingAlgorithm::Single(conn) => conn.to_string(),
+                api::RoutingAlgorithm::Priority(ref connectors) => connectors
+                    .first()
+                    .map(|connector| connector.to_string())
+                    .get_required_value("connector")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Priority routing has no connectors")?,
+                api::RoutingAlgorithm::RuleBased(ref algorithm) => {
+                    let routing_input = routing::RoutingInput::from(&*payment_data);
+                    routing::evaluate_rule_based_routing(algorithm, &routing_input).to_string()
+                }
+                api::RoutingAlgorithm::VolumeSplit(ref splits) => {
+                    routing::evaluate_volume_split_routing(
+                        splits,
+                        &payment_data.payment_attempt.payment_id,
+                    )?
+                    .to_string()
+                }
+                api::RoutingAlgorithm::SuccessRate(ref algorithm) => {
+                    let routing_input = routing::RoutingInput::from(&*payment_data);
+                    routing::success_rate::evaluate_success_rate_routing(
+                        state,
+                        &merchant_account.merchant_id,
+                        algorithm,
+                        &routing_input,
+                    )
+                    .await?
+                    .to_string()
+                }
             };
+            let routing_algorithm_version = merchant_account
+                .routing_algorithm
+                .as_ref()
+                .map(routing::get_routing_algorithm_version)
+                .transpose()?;
 
             let connector_data = api::ConnectorData::get_connector_by_name(
                 &state.conf.connectors,
@@ -699,6 +951,7 @@ where
             .attach_printable("Routing algorithm gave invalid connector")?;
 
             payment_data.payment_attempt.connector = Some(connector_name);
+            payment_data.payment_attempt.routing_algorithm_version = routing_algorithm_version;
 
             Ok(api::ConnectorCallType::Single(connector_data))
         }
@@ -706,3 +959,89 @@ where
         call_type @ api::ConnectorCallType::Multiple(_) => Ok(call_type),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl};
+
+    #[actix_rt::test]
+    async fn test_scheduled_capture_precedes_void() {
+        let conf = Settings::new().expect("invalid settings");
+        let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+        let db = &*state.store;
+        db.insert_merchant_connector_account(storage::MerchantConnectorAccountNew {
+            merchant_id: Some("merchant_1".to_string()),
+            connector_name: Some("stripe".to_string()),
+            merchant_connector_id: "mca_1".to_string(),
+            authorization_expiry_days: Some(7),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        let capture_on = common_utils::date_time::now() + time::Duration::days(3);
+        let payment_attempt = db
+            .insert_payment_attempt(
+                storage::PaymentAttemptNew {
+                    payment_id: "pay_1".to_string(),
+                    merchant_id: "merchant_1".to_string(),
+                    attempt_id: "pay_1_1".to_string(),
+                    status: storage_enums::AttemptStatus::Authorized,
+                    connector: Some("stripe".to_string()),
+                    capture_method: Some(storage_enums::CaptureMethod::Scheduled),
+                    capture_on: Some(capture_on),
+                    ..Default::default()
+                },
+                storage_enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        let authorization_expires_at = get_authorization_expires_at(&state, &payment_attempt)
+            .await
+            .unwrap();
+        assert!(helpers::validate_capture_on_before_authorization_expiry(
+            Some(capture_on),
+            authorization_expires_at
+        )
+        .is_ok());
+        // A capture scheduled after the expiry would only run once the authorization is voided
+        assert!(helpers::validate_capture_on_before_authorization_expiry(
+            Some(capture_on + time::Duration::days(5)),
+            authorization_expires_at
+        )
+        .is_err());
+
+        add_process_capture_task(db, &payment_attempt)
+            .await
+            .unwrap();
+        add_process_void_task(&state, &payment_attempt)
+            .await
+            .unwrap();
+
+        let capture_task = db
+            .find_process_by_id(&pt_utils::get_process_tracker_id(
+                "PAYMENTS_CAPTURE_WORKFLOW",
+                "PAYMENTS_CAPTURE",
+                &payment_attempt.attempt_id,
+                &payment_attempt.merchant_id,
+            ))
+            .await
+            .unwrap()
+            .expect("capture task is not scheduled");
+        let void_task = db
+            .find_process_by_id(&pt_utils::get_process_tracker_id(
+                "PAYMENTS_VOID_WORKFLOW",
+                "PAYMENTS_VOID",
+                &payment_attempt.attempt_id,
+                &payment_attempt.merchant_id,
+            ))
+            .await
+            .unwrap()
+            .expect("void task is not scheduled");
+        assert_eq!(capture_task.schedule_time, Some(capture_on));
+        assert!(capture_task.schedule_time < void_task.schedule_time);
+    }
+}
@@ -1,6 +1,7 @@
 pub mod authorize_flow;
 pub mod cancel_flow;
 pub mod capture_flow;
+pub mod incremental_authorization_flow;
 pub mod psync_flow;
 pub mod session_flow;
 pub mod verfiy_flow;
@@ -49,4 +50,12 @@ pub trait Feature<F, T> {
         F: Clone,
         Self: Sized,
         dyn api::Connector: services::ConnectorIntegration<F, T, types::PaymentsResponseData>;
+
+    /// Whether the flow authorizes the payment at the connector
+    fn is_authorize_flow() -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
 }
@@ -77,6 +77,10 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
     ) -> RouterResult<types::AddAccessTokenResult> {
         access_token::add_access_token(state, connector, merchant_account, self).await
     }
+
+    fn is_authorize_flow() -> bool {
+        true
+    }
 }
 
 impl types::PaymentsAuthorizeRouterData {
@@ -97,6 +101,7 @@ impl types::PaymentsAuthorizeRouterData {
                     types::PaymentsAuthorizeData,
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
+                mandate::reserve_mandate_usage(state, self).await?;
                 let resp = services::execute_connector_processing_step(
                     state,
                     connector_integration,
@@ -81,6 +81,7 @@ impl types::VerifyRouterData {
                     types::VerifyRequestData,
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
+                mandate::reserve_mandate_usage(state, self).await?;
                 let resp = services::execute_connector_processing_step(
                     state,
                     connector_integration,
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use common_utils::{ext_traits::AsyncExt, fp_utils};
 // TODO : Evaluate all the helper functions ()
 use error_stack::{report, IntoReport, ResultExt};
-use masking::ExposeOptionInterface;
+use masking::{ExposeOptionInterface, PeekInterface};
 use router_env::{instrument, tracing};
 use uuid::Uuid;
 
@@ -12,11 +12,11 @@ use super::{
     CustomerDetails, PaymentData,
 };
 use crate::{
-    configs::settings::Server,
+    configs::settings::{AuthorizationExpiry, Server},
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payment_methods::{cards, vault},
+        payment_methods::{cards, transformers as payment_methods, vault},
     },
     db::StorageInterface,
     routes::AppState,
@@ -164,6 +164,7 @@ pub async fn get_token_for_recurring_mandate(
         req.currency.get_required_value("currency")?,
         mandate.clone(),
     )?;
+    verify_mandate_validity(&mandate, common_utils::date_time::now())?;
 
     let payment_method = db
         .find_payment_method(payment_method_id.as_str())
@@ -193,6 +194,46 @@ pub async fn get_token_for_recurring_mandate(
     Ok((Some(token), Some(payment_method.payment_method)))
 }
 
+/// Checks that the mandate can be used for a payment at `current_time`, against its validity
+/// period and the number of payments it allows
+pub fn verify_mandate_validity(
+    mandate: &storage::Mandate,
+    current_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let failure_reason = if mandate
+        .start_date
+        .map(|start_date| current_time < start_date)
+        .unwrap_or(false)
+    {
+        Some("mandate is not valid yet")
+    } else if mandate
+        .end_date
+        .map(|end_date| current_time >= end_date)
+        .unwrap_or(false)
+    {
+        Some("mandate has expired")
+    } else if mandate
+        .max_usage_count
+        .map(|max_usage_count| mandate.usage_count >= max_usage_count)
+        .unwrap_or(false)
+    {
+        Some("mandate has reached its maximum usage count")
+    } else if mandate.mandate_type == storage_enums::MandateType::SingleUse
+        && mandate.usage_count > 0
+    {
+        Some("single use mandate has already been used")
+    } else {
+        None
+    };
+
+    match failure_reason {
+        Some(reason) => Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: reason.to_string(),
+        })),
+        None => Ok(()),
+    }
+}
+
 #[instrument(skip_all)]
 /// Check weather the merchant id in the request
 /// and merchant id in the merchant account are same.
@@ -300,7 +341,35 @@ fn validate_new_mandate_request(req: api::MandateValidationFields) -> RouterResu
         }))?
     }
 
-    Ok(())
+    let current_time = common_utils::date_time::now();
+    let valid_from = mandate_data
+        .start_date
+        .map_or(current_time, |start_date| start_date.max(current_time));
+    utils::when(
+        mandate_data
+            .end_date
+            .map(|end_date| end_date <= valid_from)
+            .unwrap_or(false),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "mandate_data.end_date".to_string(),
+                expected_format: "future time after the mandate start date".to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(
+        mandate_data
+            .max_usage_count
+            .map(|max_usage_count| max_usage_count <= 0)
+            .unwrap_or(false),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "mandate_data.max_usage_count".to_string(),
+                expected_format: "positive integer".to_string(),
+            }))
+        },
+    )
 }
 
 pub fn validate_customer_id_mandatory_cases(
@@ -771,6 +840,16 @@ pub async fn make_pm_data<'a, F: Clone, R>(
         _ => Ok(None),
     }?;
 
+    if let Some(api::PaymentMethod::Card(card)) = &payment_method {
+        let card_fingerprint = payment_methods::get_card_fingerprint(
+            &state.conf.secrets.card_fingerprint_secret,
+            &payment_data.payment_attempt.merchant_id,
+            card.card_number.peek(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        payment_data.payment_attempt.card_fingerprint = Some(card_fingerprint);
+    }
+
     Ok((operation, payment_method))
 }
 
@@ -791,16 +870,86 @@ pub(crate) fn validate_capture_method(
     )
 }
 
+/// Payments with the `scheduled` capture method are captured by the scheduler at `capture_on`,
+/// which has to be in the future.
+#[instrument(skip_all)]
+pub(crate) fn validate_capture_on(
+    capture_method: Option<api_enums::CaptureMethod>,
+    capture_on: Option<time::PrimitiveDateTime>,
+) -> RouterResult<()> {
+    if capture_method != Some(api_enums::CaptureMethod::Scheduled) {
+        return Ok(());
+    }
+
+    let capture_on = capture_on.get_required_value("capture_on")?;
+    utils::when(capture_on <= common_utils::date_time::now(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "capture_on must be in the future for the scheduled capture method"
+                .to_string()
+        }))
+    })
+}
+
+/// The scheduled capture of a payment has to be made before its authorization expires, since the
+/// authorization is voided once it expires.
+pub(crate) fn validate_capture_on_before_authorization_expiry(
+    capture_on: Option<time::PrimitiveDateTime>,
+    authorization_expires_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    utils::when(
+        capture_on.map_or(false, |capture_on| capture_on >= authorization_expires_at),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "capture_on must be before the authorization of the payment expires at \
+                     {authorization_expires_at}"
+                )
+            }))
+        },
+    )
+}
+
+/// Number of days after which an uncaptured authorization is voided. The expiry configured by the
+/// merchant on their connector account takes precedence over the one configured for the connector.
+pub(crate) fn get_authorization_expiry_days(
+    config: &AuthorizationExpiry,
+    connector: &str,
+    merchant_expiry_days: Option<i32>,
+) -> i64 {
+    merchant_expiry_days.map(i64::from).unwrap_or_else(|| {
+        config
+            .connectors
+            .get(connector)
+            .copied()
+            .unwrap_or(config.default_days)
+    })
+}
+
+/// Connectors only authorize payments whose capture is scheduled, the capture is made by the
+/// scheduler, so these payments are sent to the connectors as manually captured.
+pub(crate) fn get_connector_capture_method(
+    capture_method: Option<storage_enums::CaptureMethod>,
+) -> Option<storage_enums::CaptureMethod> {
+    capture_method.map(|capture_method| match capture_method {
+        storage_enums::CaptureMethod::Scheduled => storage_enums::CaptureMethod::Manual,
+        capture_method => capture_method,
+    })
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_status(status: storage_enums::IntentStatus) -> RouterResult<()> {
     utils::when(
-        status != storage_enums::IntentStatus::RequiresCapture,
+        !matches!(
+            status,
+            storage_enums::IntentStatus::RequiresCapture
+                | storage_enums::IntentStatus::PartiallyCaptured
+        ),
         || {
             Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
                 field_name: "payment.status".to_string(),
                 current_flow: "captured".to_string(),
                 current_value: status.to_string(),
-                states: "requires_capture".to_string()
+                states: "requires_capture, partially_captured".to_string()
             }))
         },
     )
@@ -821,6 +970,42 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+/// Amount captured, or being captured, by the captures of a payment.
+pub(crate) fn get_captured_amount(captures: &[storage::Capture]) -> i64 {
+    captures
+        .iter()
+        .filter(|capture| {
+            matches!(
+                capture.status,
+                storage_enums::CaptureStatus::Charged | storage_enums::CaptureStatus::Pending
+            )
+        })
+        .map(|capture| capture.amount)
+        .sum()
+}
+
+/// Decides whether a capture is the last capture of the payment. Payments with the
+/// `manual_multiple` capture method can be captured several times, until a final capture is made
+/// or the whole amount is captured, the other payments are captured once.
+#[instrument(skip_all)]
+pub(crate) fn get_final_capture(
+    capture_method: storage_enums::CaptureMethod,
+    final_capture: Option<bool>,
+    capturable_amount: i64,
+    amount_to_capture: i64,
+) -> RouterResult<bool> {
+    let is_multiple_capture = capture_method == storage_enums::CaptureMethod::ManualMultiple;
+    utils::when(!is_multiple_capture && final_capture == Some(false), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "final_capture can be false only for payments with the manual_multiple \
+                      capture method"
+                .to_string()
+        }))
+    })?;
+
+    Ok(final_capture.unwrap_or(!is_multiple_capture) || amount_to_capture == capturable_amount)
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
     req: &api::PaymentsRequest,
@@ -1095,6 +1280,9 @@ pub fn generate_mandate(
             let mut new_mandate = storage::MandateNew::default();
 
             new_mandate
+                .set_start_date(data.start_date)
+                .set_end_date(data.end_date)
+                .set_max_usage_count(data.max_usage_count)
                 .set_mandate_id(mandate_id)
                 .set_customer_id(cus.customer_id.clone())
                 .set_merchant_id(merchant_id)
@@ -1220,4 +1408,97 @@ mod tests {
         let pi_cs = Some("2".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), pi_cs.as_ref()).is_err())
     }
+
+    #[test]
+    fn test_get_final_capture() {
+        let manual = storage_enums::CaptureMethod::Manual;
+        let multiple = storage_enums::CaptureMethod::ManualMultiple;
+
+        assert!(matches!(get_final_capture(manual, None, 100, 40), Ok(true)));
+        assert!(get_final_capture(manual, Some(false), 100, 40).is_err());
+        assert!(matches!(
+            get_final_capture(multiple, None, 100, 40),
+            Ok(false)
+        ));
+        assert!(matches!(
+            get_final_capture(multiple, Some(true), 100, 40),
+            Ok(true)
+        ));
+        assert!(matches!(
+            get_final_capture(multiple, Some(false), 100, 100),
+            Ok(true)
+        ));
+    }
+
+    #[test]
+    fn test_get_authorization_expiry_days() {
+        let config = AuthorizationExpiry {
+            default_days: 7,
+            connectors: std::collections::HashMap::from([("adyen".to_string(), 28)]),
+        };
+
+        assert_eq!(get_authorization_expiry_days(&config, "stripe", None), 7);
+        assert_eq!(get_authorization_expiry_days(&config, "adyen", None), 28);
+        assert_eq!(get_authorization_expiry_days(&config, "adyen", Some(3)), 3);
+    }
+
+    #[test]
+    fn test_validate_capture_on() {
+        let scheduled = Some(api_enums::CaptureMethod::Scheduled);
+        let now = common_utils::date_time::now();
+
+        assert!(validate_capture_on(scheduled, Some(now + time::Duration::hours(1))).is_ok());
+        assert!(validate_capture_on(scheduled, Some(now - time::Duration::hours(1))).is_err());
+        assert!(validate_capture_on(scheduled, None).is_err());
+        assert!(validate_capture_on(Some(api_enums::CaptureMethod::Manual), None).is_ok());
+    }
+
+    fn get_mandate(
+        mandate_type: storage_enums::MandateType,
+        usage_count: i32,
+        max_usage_count: Option<i32>,
+    ) -> storage::Mandate {
+        let now = common_utils::date_time::now();
+        storage::Mandate {
+            id: 1,
+            mandate_id: "man_123".to_string(),
+            customer_id: "cus_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            payment_method_id: "pm_123".to_string(),
+            mandate_status: storage_enums::MandateStatus::Active,
+            mandate_type,
+            customer_accepted_at: Some(now),
+            customer_ip_address: None,
+            customer_user_agent: None,
+            network_transaction_id: None,
+            previous_attempt_id: None,
+            created_at: now,
+            mandate_amount: Some(1000),
+            mandate_currency: Some(storage_enums::Currency::USD),
+            amount_captured: None,
+            connector: "stripe".to_string(),
+            connector_mandate_id: None,
+            start_date: Some(now - time::Duration::days(1)),
+            end_date: Some(now + time::Duration::days(30)),
+            max_usage_count,
+            usage_count,
+        }
+    }
+
+    #[test]
+    fn test_verify_mandate_validity() {
+        let now = common_utils::date_time::now();
+        let multi_use = storage_enums::MandateType::MultiUse;
+        let single_use = storage_enums::MandateType::SingleUse;
+
+        assert!(verify_mandate_validity(&get_mandate(multi_use, 1, Some(2)), now).is_ok());
+        assert!(verify_mandate_validity(&get_mandate(multi_use, 2, Some(2)), now).is_err());
+        assert!(verify_mandate_validity(&get_mandate(multi_use, 5, None), now).is_ok());
+        assert!(verify_mandate_validity(&get_mandate(single_use, 0, None), now).is_ok());
+        assert!(verify_mandate_validity(&get_mandate(single_use, 1, None), now).is_err());
+
+        let mandate = get_mandate(multi_use, 0, None);
+        assert!(verify_mandate_validity(&mandate, now - time::Duration::days(2)).is_err());
+        assert!(verify_mandate_validity(&mandate, now + time::Duration::days(30)).is_err());
+    }
 }
@@ -2,6 +2,7 @@ pub mod payment_cancel;
 pub mod payment_capture;
 pub mod payment_confirm;
 pub mod payment_create;
+pub mod payment_incremental_authorization;
 pub mod payment_method_validate;
 pub mod payment_response;
 pub mod payment_session;
@@ -16,6 +17,7 @@ use router_env::{instrument, tracing};
 pub use self::{
     payment_cancel::PaymentCancel, payment_capture::PaymentCapture,
     payment_confirm::PaymentConfirm, payment_create::PaymentCreate,
+    payment_incremental_authorization::PaymentIncrementalAuthorization,
     payment_method_validate::PaymentMethodValidate, payment_response::PaymentResponse,
     payment_session::PaymentSession, payment_start::PaymentStart, payment_status::PaymentStatus,
     payment_update::PaymentUpdate,
@@ -62,6 +64,11 @@ pub trait Operation<F: Clone, T>: Send + std::fmt::Debug {
             format!("post connector update tracker not found for {self:?}")
         })
     }
+    /// Whether the payment is locked while the operation runs, serializing it with the other
+    /// mutations of the payment.
+    fn should_lock_payment(&self) -> bool {
+        true
+    }
 }
 
 pub struct ValidateResult<'a> {
@@ -266,6 +273,62 @@ where
     }
 }
 
+#[async_trait]
+impl<
+        F: Clone + Send,
+        Op: Send + Sync + Operation<F, api::PaymentsIncrementalAuthorizationRequest>,
+    > Domain<F, api::PaymentsIncrementalAuthorizationRequest> for Op
+where
+    for<'a> &'a Op: Operation<F, api::PaymentsIncrementalAuthorizationRequest>,
+{
+    #[instrument(skip_all)]
+    async fn get_or_create_customer_details<'a>(
+        &'a self,
+        db: &dyn StorageInterface,
+        payment_data: &mut PaymentData<F>,
+        _request: Option<CustomerDetails>,
+        merchant_id: &str,
+    ) -> CustomResult<
+        (
+            BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+            Option<storage::Customer>,
+        ),
+        errors::StorageError,
+    > {
+        Ok((
+            Box::new(self),
+            helpers::get_customer_from_details(
+                db,
+                payment_data.payment_intent.customer_id.clone(),
+                merchant_id,
+            )
+            .await?,
+        ))
+    }
+    #[instrument(skip_all)]
+    async fn make_pm_data<'a>(
+        &'a self,
+        _state: &'a AppState,
+        _payment_data: &mut PaymentData<F>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+        Option<api::PaymentMethod>,
+    )> {
+        Ok((Box::new(self), None))
+    }
+
+    async fn get_connector<'a>(
+        &'a self,
+        _merchant_account: &storage::MerchantAccount,
+        state: &AppState,
+        _request: &api::PaymentsIncrementalAuthorizationRequest,
+        previously_used_connector: Option<&String>,
+    ) -> CustomResult<api::ConnectorCallType, errors::ApiErrorResponse> {
+        helpers::get_connector_default(state, previously_used_connector).await
+    }
+}
+
 #[async_trait]
 impl<F: Clone + Send, Op: Send + Sync + Operation<F, api::PaymentsCancelRequest>>
     Domain<F, api::PaymentsCancelRequest> for Op
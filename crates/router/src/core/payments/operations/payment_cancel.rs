@@ -126,6 +126,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                     payment_method_data: None,
                     force_sync: None,
                     refunds: vec![],
+                    captures: vec![],
+                    incremental_authorizations: vec![],
                     connector_response,
                     sessions_token: vec![],
                     card_cvc: None,
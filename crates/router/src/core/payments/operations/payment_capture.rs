@@ -1,11 +1,12 @@
 use std::marker::PhantomData;
 
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payments::{self, helpers, operations},
@@ -17,7 +18,7 @@ use crate::{
         storage::{self, enums},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
@@ -59,8 +60,6 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
         helpers::validate_status(payment_intent.status)?;
 
-        helpers::validate_amount_to_capture(payment_intent.amount, request.amount_to_capture)?;
-
         payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id(
                 &payment_id,
@@ -72,18 +71,62 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        payment_attempt
-            .amount_to_capture
-            .update_value(request.amount_to_capture);
-
         let capture_method = payment_attempt
             .capture_method
             .get_required_value("capture_method")?;
 
         helpers::validate_capture_method(capture_method)?;
 
+        let mut captures = db
+            .find_captures_by_merchant_id_payment_id(merchant_id, &payment_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while getting the captures of the payment")?;
+
+        // Amounts which are captured, or being captured, by previous partial captures cannot be
+        // captured again
+        let capturable_amount = payment_intent.amount - helpers::get_captured_amount(&captures);
+        let amount_to_capture = request.amount_to_capture.unwrap_or(capturable_amount);
+
+        utils::when(amount_to_capture <= 0, || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "amount_to_capture".to_string(),
+                expected_format: "positive integer".to_string()
+            }))
+        })?;
+        helpers::validate_amount_to_capture(capturable_amount, Some(amount_to_capture))?;
+
+        let final_capture = helpers::get_final_capture(
+            capture_method,
+            request.final_capture,
+            capturable_amount,
+            amount_to_capture,
+        )?;
+
+        payment_attempt.amount_to_capture = Some(amount_to_capture);
+
         currency = payment_attempt.currency.get_required_value("currency")?;
 
+        let capture = db
+            .insert_capture(storage::CaptureNew {
+                capture_id: utils::generate_id(consts::ID_LENGTH, "cap"),
+                payment_id: payment_attempt.payment_id.clone(),
+                merchant_id: payment_attempt.merchant_id.clone(),
+                attempt_id: payment_attempt.attempt_id.clone(),
+                status: enums::CaptureStatus::Started,
+                amount: amount_to_capture,
+                currency: payment_attempt.currency,
+                connector: payment_attempt
+                    .connector
+                    .clone()
+                    .get_required_value("connector")?,
+                final_capture,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the capture")?;
+        captures.push(capture);
+
         amount = payment_attempt.amount.into();
 
         let connector_response = db
@@ -136,6 +179,8 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 confirm: None,
                 payment_method_data: None,
                 refunds: vec![],
+                captures,
+                incremental_authorizations: vec![],
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: None,
@@ -63,6 +63,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::PartiallyCaptured,
             ],
             "confirm",
         )?;
@@ -111,6 +112,18 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         )?;
 
         payment_attempt.payment_method = payment_method_type.or(payment_attempt.payment_method);
+        payment_attempt.capture_method = request
+            .capture_method
+            .map(ForeignInto::foreign_into)
+            .or(payment_attempt.capture_method);
+        payment_attempt.capture_on = request.capture_on.or(payment_attempt.capture_on);
+
+        helpers::validate_capture_on(
+            payment_attempt
+                .capture_method
+                .map(ForeignInto::foreign_into),
+            payment_attempt.capture_on,
+        )?;
         payment_attempt.browser_info = browser_info;
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.amount.into();
@@ -179,6 +192,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_method_data: request.payment_method_data.clone(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorizations: vec![],
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
             },
@@ -297,6 +312,13 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let connector = payment_data.payment_attempt.connector.clone();
         let payment_token = payment_data.token.clone();
+        let routing_algorithm_version = payment_data
+            .payment_attempt
+            .routing_algorithm_version
+            .clone();
+        let card_fingerprint = payment_data.payment_attempt.card_fingerprint.clone();
+        let capture_method = payment_data.payment_attempt.capture_method;
+        let capture_on = payment_data.payment_attempt.capture_on;
 
         payment_data.payment_attempt = db
             .update_payment_attempt(
@@ -310,6 +332,10 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     browser_info,
                     connector,
                     payment_token,
+                    routing_algorithm_version,
+                    card_fingerprint,
+                    capture_method,
+                    capture_on,
                 },
                 storage_scheme,
             )
@@ -188,6 +188,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 confirm: request.confirm,
                 payment_method_data: request.payment_method_data.clone(),
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorizations: vec![],
                 force_sync: None,
                 connector_response,
                 sessions_token: vec![],
@@ -295,6 +297,11 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let payment_token = payment_data.token.clone();
         let connector = payment_data.payment_attempt.connector.clone();
+        let routing_algorithm_version = payment_data
+            .payment_attempt
+            .routing_algorithm_version
+            .clone();
+        let card_fingerprint = payment_data.payment_attempt.card_fingerprint.clone();
 
         payment_data.payment_attempt = db
             .update_payment_attempt(
@@ -302,6 +309,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                 storage::PaymentAttemptUpdate::UpdateTrackers {
                     payment_token,
                     connector,
+                    routing_algorithm_version,
+                    card_fingerprint,
                 },
                 storage_scheme,
             )
@@ -371,6 +380,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
 
         helpers::validate_payment_method_fields_present(request)?;
 
+        helpers::validate_capture_on(request.capture_method, request.capture_on)?;
+
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
 
         let mandate_type = helpers::validate_mandate(request)?;
@@ -472,6 +483,7 @@ impl PaymentCreate {
             client_secret: Some(client_secret),
             setup_future_usage: request.setup_future_usage.map(ForeignInto::foreign_into),
             off_session: request.off_session,
+            request_incremental_authorization: request.request_incremental_authorization,
             return_url: request.return_url.clone(),
             shipping_address_id,
             billing_address_id,
@@ -0,0 +1,321 @@
+use std::marker::PhantomData;
+
+use async_trait::async_trait;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
+use crate::{
+    consts,
+    core::{
+        errors::{self, api_error_response::NotImplementedMessage, RouterResult, StorageErrorExt},
+        payments::{self, helpers, operations},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        api::{self, PaymentIdTypeExt},
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt},
+};
+
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(ops = "all", flow = "incrementalauthorization")]
+pub struct PaymentIncrementalAuthorization;
+
+#[async_trait]
+impl<F: Send + Clone>
+    GetTracker<F, payments::PaymentData<F>, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_id: &api::PaymentIdType,
+        request: &api::PaymentsIncrementalAuthorizationRequest,
+        _mandate_type: Option<api::MandateTxnType>,
+        merchant_account: &storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+        payments::PaymentData<F>,
+        Option<payments::CustomerDetails>,
+    )> {
+        let db = &*state.store;
+        let merchant_id = &merchant_account.merchant_id;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        validate_payment_can_be_incremented(
+            payment_intent.status,
+            payment_intent.request_incremental_authorization,
+        )?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id(
+                &payment_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        let connector = payment_attempt
+            .connector
+            .clone()
+            .get_required_value("connector")?;
+
+        // Connectors which cannot increase an authorization are rejected before anything is
+        // recorded on the payment
+        let connector_data = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &connector,
+            api::GetToken::Connector,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        utils::when(
+            !connector_data
+                .connector
+                .supports_incremental_authorization(),
+            || {
+                Err(report!(errors::ApiErrorResponse::NotImplemented {
+                    message: NotImplementedMessage::Reason(format!(
+                        "Incremental authorization for {connector}"
+                    )),
+                }))
+            },
+        )?;
+
+        let previously_authorized_amount = payment_attempt.amount;
+        validate_incremented_amount(request.amount, previously_authorized_amount)?;
+
+        let currency = payment_attempt.currency.get_required_value("currency")?;
+
+        let mut incremental_authorizations = db
+            .find_incremental_authorizations_by_merchant_id_payment_id(merchant_id, &payment_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed while getting the incremental authorizations of the payment",
+            )?;
+
+        let incremental_authorization = db
+            .insert_incremental_authorization(storage::IncrementalAuthorizationNew {
+                authorization_id: utils::generate_id(consts::ID_LENGTH, "auth"),
+                payment_id: payment_attempt.payment_id.clone(),
+                merchant_id: payment_attempt.merchant_id.clone(),
+                attempt_id: payment_attempt.attempt_id.clone(),
+                status: enums::AuthorizationStatus::Started,
+                amount: request.amount,
+                previously_authorized_amount,
+                currency: payment_attempt.currency,
+                connector,
+                reason: request.reason.clone(),
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the incremental authorization")?;
+        incremental_authorizations.push(incremental_authorization);
+
+        let amount = payment_attempt.amount.into();
+
+        let connector_response = db
+            .find_connector_response_by_payment_id_merchant_id_attempt_id(
+                &payment_attempt.payment_id,
+                &payment_attempt.merchant_id,
+                &payment_attempt.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        let shipping_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.shipping_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+        )
+        .await?;
+
+        let billing_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.billing_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+        )
+        .await?;
+
+        Ok((
+            Box::new(self),
+            payments::PaymentData {
+                flow: PhantomData,
+                payment_intent,
+                payment_attempt,
+                currency,
+                force_sync: None,
+                amount,
+                email: None,
+                mandate_id: None,
+                setup_mandate: None,
+                token: None,
+                address: payments::PaymentAddress {
+                    shipping: shipping_address.as_ref().map(|a| a.foreign_into()),
+                    billing: billing_address.as_ref().map(|a| a.foreign_into()),
+                },
+                confirm: None,
+                payment_method_data: None,
+                refunds: vec![],
+                captures: vec![],
+                incremental_authorizations,
+                connector_response,
+                sessions_token: vec![],
+                card_cvc: None,
+            },
+            None,
+        ))
+    }
+}
+
+/// Only authorizations which have not been captured yet, of payments which requested incremental
+/// authorizations, can be increased.
+fn validate_payment_can_be_incremented(
+    intent_status: enums::IntentStatus,
+    request_incremental_authorization: Option<bool>,
+) -> RouterResult<()> {
+    utils::when(
+        intent_status != enums::IntentStatus::RequiresCapture,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "You cannot increase the authorized amount of this payment because it has status {intent_status}"
+            ),
+        }))
+        },
+    )?;
+
+    utils::when(!request_incremental_authorization.unwrap_or(false), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Incremental authorizations were not requested for this payment".to_string()
+        }))
+    })
+}
+
+fn validate_incremented_amount(amount: i64, previously_authorized_amount: i64) -> RouterResult<()> {
+    utils::when(amount <= previously_authorized_amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "amount must be greater than the authorized amount of {previously_authorized_amount}"
+            )
+        }))
+    })
+}
+
+#[async_trait]
+impl<F: Clone>
+    UpdateTracker<F, payments::PaymentData<F>, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        _db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        payment_data: payments::PaymentData<F>,
+        _customer: Option<storage::Customer>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsIncrementalAuthorizationRequest>,
+        payments::PaymentData<F>,
+    )>
+    where
+        F: 'b + Send,
+    {
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone> ValidateRequest<F, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &api::PaymentsIncrementalAuthorizationRequest,
+        merchant_account: &'a storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsIncrementalAuthorizationRequest>,
+        operations::ValidateResult<'a>,
+    )> {
+        utils::when(request.amount <= 0, || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "amount".to_string(),
+                expected_format: "positive integer".to_string()
+            }))
+        })?;
+
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: &merchant_account.merchant_id,
+                payment_id: api::PaymentIdType::PaymentIntentId(request.payment_id.to_owned()),
+                mandate_type: None,
+                storage_scheme: merchant_account.storage_scheme,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_validate_payment_can_be_incremented() {
+        assert!(validate_payment_can_be_incremented(
+            enums::IntentStatus::RequiresCapture,
+            Some(true)
+        )
+        .is_ok());
+
+        let error = validate_payment_can_be_incremented(enums::IntentStatus::RequiresCapture, None)
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+
+        for status in [
+            enums::IntentStatus::Succeeded,
+            enums::IntentStatus::Processing,
+            enums::IntentStatus::Cancelled,
+        ] {
+            assert!(validate_payment_can_be_incremented(status, Some(true)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_incremented_amount() {
+        assert!(validate_incremented_amount(1500, 1000).is_ok());
+        assert!(validate_incremented_amount(1000, 1000).is_err());
+        assert!(validate_incremented_amount(500, 1000).is_err());
+    }
+}
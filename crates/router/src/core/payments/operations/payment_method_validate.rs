@@ -149,6 +149,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 address: types::PaymentAddress::default(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorizations: vec![],
                 sessions_token: vec![],
                 card_cvc: None,
             },
@@ -1,14 +1,15 @@
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_derive;
 
 use super::{Operation, PostUpdateTracker};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::PaymentData,
+        payments::{self, helpers, PaymentData},
     },
     db::StorageInterface,
+    logger,
     services::RedirectForm,
     types::{
         self, api,
@@ -21,7 +22,7 @@ use crate::{
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
 #[operation(
     ops = "post_tracker",
-    flow = "syncdata,authorizedata,canceldata,capturedata,verifydata,sessiondata"
+    flow = "syncdata,authorizedata,canceldata,capturedata,incrementalauthorizationdata,verifydata,sessiondata"
 )]
 pub struct PaymentResponse;
 
@@ -106,11 +107,40 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
     where
         F: 'b + Send,
     {
-        payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
+        capture_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
             .await
     }
 }
 
+#[async_trait]
+impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAuthorizationData>
+    for PaymentResponse
+{
+    async fn update_tracker<'b>(
+        &'b self,
+        db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        payment_data: PaymentData<F>,
+        response: types::RouterData<
+            F,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<PaymentData<F>>
+    where
+        F: 'b + Send,
+    {
+        incremental_authorization_response_update_tracker(
+            db,
+            payment_data,
+            response,
+            storage_scheme,
+        )
+        .await
+    }
+}
+
 #[async_trait]
 impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> for PaymentResponse {
     async fn update_tracker<'b>(
@@ -154,13 +184,179 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::VerifyRequestData> fo
     }
 }
 
+/// Records the outcome of the capture made on the payment, before updating the payment. The
+/// amount captured by the payment is the sum of its successful captures, and the payment remains
+/// capturable after a successful capture which is not final.
+async fn capture_response_update_tracker<F: Clone>(
+    db: &dyn StorageInterface,
+    payment_id: &api::PaymentIdType,
+    mut payment_data: PaymentData<F>,
+    mut router_data: types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    let capture = payment_data
+        .captures
+        .pop()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Capture not found in payment data")?;
+
+    let capture_update = match &router_data.response {
+        Err(error) => storage::CaptureUpdate::ErrorUpdate {
+            status: enums::CaptureStatus::Failed,
+            error_message: Some(error.message.clone()),
+            error_code: Some(error.code.clone()),
+        },
+        Ok(_) => match router_data.status {
+            enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged => {
+                router_data.amount_captured =
+                    Some(helpers::get_captured_amount(&payment_data.captures) + capture.amount);
+                router_data.status = if capture.final_capture {
+                    enums::AttemptStatus::Charged
+                } else {
+                    enums::AttemptStatus::PartialCharged
+                };
+                storage::CaptureUpdate::StatusUpdate {
+                    status: enums::CaptureStatus::Charged,
+                }
+            }
+            enums::AttemptStatus::CaptureInitiated | enums::AttemptStatus::Pending => {
+                storage::CaptureUpdate::StatusUpdate {
+                    status: enums::CaptureStatus::Pending,
+                }
+            }
+            _ => storage::CaptureUpdate::StatusUpdate {
+                status: enums::CaptureStatus::Failed,
+            },
+        },
+    };
+
+    let capture = db
+        .update_capture(capture, capture_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the capture")?;
+    payment_data.captures.push(capture);
+
+    payment_response_update_tracker(db, payment_id, payment_data, router_data, storage_scheme).await
+}
+
+/// Records the outcome of the incremental authorization made on the payment. The amount of the
+/// payment is raised to the newly authorized amount once the connector has authorized it, while a
+/// declined increment leaves the payment, and its existing authorization, untouched.
+async fn incremental_authorization_response_update_tracker<F: Clone>(
+    db: &dyn StorageInterface,
+    mut payment_data: PaymentData<F>,
+    router_data: types::RouterData<
+        F,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    >,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    let authorization = payment_data
+        .incremental_authorizations
+        .pop()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Incremental authorization not found in payment data")?;
+
+    let authorization_update =
+        get_incremental_authorization_update(&router_data.response, router_data.status);
+    let authorization = db
+        .update_incremental_authorization(authorization, authorization_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the incremental authorization")?;
+
+    if authorization.status == enums::AuthorizationStatus::Success {
+        payment_data.payment_attempt = db
+            .update_payment_attempt(
+                payment_data.payment_attempt,
+                storage::PaymentAttemptUpdate::AmountUpdate {
+                    amount: authorization.amount,
+                },
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        payment_data.payment_intent = db
+            .update_payment_intent(
+                payment_data.payment_intent,
+                storage::PaymentIntentUpdate::AmountUpdate {
+                    amount: authorization.amount,
+                },
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+        payment_data.amount = authorization.amount.into();
+    }
+    payment_data.incremental_authorizations.push(authorization);
+
+    let connector = router_data.connector;
+    router_data.response.map_err(|error_response| {
+        errors::ApiErrorResponse::ExternalConnectorError {
+            message: error_response.message,
+            code: error_response.code,
+            status_code: error_response.status_code,
+            connector,
+        }
+    })?;
+
+    Ok(payment_data)
+}
+
+/// The update of an incremental authorization from the response of the connector.
+fn get_incremental_authorization_update(
+    response: &Result<types::PaymentsResponseData, types::ErrorResponse>,
+    status: enums::AttemptStatus,
+) -> storage::IncrementalAuthorizationUpdate {
+    match response {
+        Err(error) => storage::IncrementalAuthorizationUpdate::ErrorUpdate {
+            status: enums::AuthorizationStatus::Failure,
+            error_message: Some(error.message.clone()),
+            error_code: Some(error.code.clone()),
+        },
+        Ok(_) => match status {
+            enums::AttemptStatus::Authorized => {
+                storage::IncrementalAuthorizationUpdate::StatusUpdate {
+                    status: enums::AuthorizationStatus::Success,
+                }
+            }
+            enums::AttemptStatus::Authorizing | enums::AttemptStatus::Pending => {
+                storage::IncrementalAuthorizationUpdate::StatusUpdate {
+                    status: enums::AuthorizationStatus::Processing,
+                }
+            }
+            _ => storage::IncrementalAuthorizationUpdate::StatusUpdate {
+                status: enums::AuthorizationStatus::Failure,
+            },
+        },
+    }
+}
+
 async fn payment_response_update_tracker<F: Clone, T>(
     db: &dyn StorageInterface,
     _payment_id: &api::PaymentIdType,
     mut payment_data: PaymentData<F>,
-    router_data: types::RouterData<F, T, types::PaymentsResponseData>,
+    mut router_data: types::RouterData<F, T, types::PaymentsResponseData>,
     storage_scheme: enums::MerchantStorageScheme,
 ) -> RouterResult<PaymentData<F>> {
+    // Connectors report payments which are partially captured, and can still be captured, as
+    // authorized
+    if payment_data.payment_attempt.status == enums::AttemptStatus::PartialCharged
+        && router_data.status == enums::AttemptStatus::Authorized
+    {
+        router_data.status = enums::AttemptStatus::PartialCharged;
+    }
+
+    let previous_attempt_status = payment_data.payment_attempt.status;
     let connector = router_data.connector.clone();
     let (payment_attempt_update, connector_response_update) = match router_data.response.clone() {
         Err(err) => (
@@ -276,6 +472,24 @@ async fn payment_response_update_tracker<F: Clone, T>(
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
 
+    // Payments whose capture is scheduled are captured by the scheduler once they are authorized.
+    // The payment is already authorized at the connector, so failing to schedule the capture must
+    // not fail it.
+    if previous_attempt_status != enums::AttemptStatus::Authorized
+        && payment_data.payment_attempt.status == enums::AttemptStatus::Authorized
+        && payment_data.payment_attempt.capture_method == Some(enums::CaptureMethod::Scheduled)
+    {
+        if let Err(error) =
+            payments::add_process_capture_task(db, &payment_data.payment_attempt).await
+        {
+            logger::error!(
+                ?error,
+                payment_id = %payment_data.payment_attempt.payment_id,
+                "Failed to schedule the capture of the authorization"
+            );
+        }
+    }
+
     router_data.response.map_err(|error_response| {
         errors::ApiErrorResponse::ExternalConnectorError {
             message: error_response.message,
@@ -287,3 +501,67 @@ async fn payment_response_update_tracker<F: Clone, T>(
 
     Ok(payment_data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transaction_response() -> Result<types::PaymentsResponseData, types::ErrorResponse> {
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            resource_id: types::ResponseId::ConnectorTransactionId("pi_123".to_string()),
+            redirect: false,
+            redirection_data: None,
+            mandate_reference: None,
+            connector_metadata: None,
+        })
+    }
+
+    fn authorization_status(
+        update: storage::IncrementalAuthorizationUpdate,
+    ) -> enums::AuthorizationStatus {
+        match update {
+            storage::IncrementalAuthorizationUpdate::StatusUpdate { status }
+            | storage::IncrementalAuthorizationUpdate::ErrorUpdate { status, .. } => status,
+        }
+    }
+
+    #[test]
+    fn test_get_incremental_authorization_update() {
+        let status_of = |status| {
+            authorization_status(get_incremental_authorization_update(
+                &transaction_response(),
+                status,
+            ))
+        };
+        assert_eq!(
+            status_of(enums::AttemptStatus::Authorized),
+            enums::AuthorizationStatus::Success
+        );
+        assert_eq!(
+            status_of(enums::AttemptStatus::Pending),
+            enums::AuthorizationStatus::Processing
+        );
+        assert_eq!(
+            status_of(enums::AttemptStatus::AuthorizationFailed),
+            enums::AuthorizationStatus::Failure
+        );
+
+        let update = get_incremental_authorization_update(
+            &Err(types::ErrorResponse {
+                code: "card_declined".to_string(),
+                message: "Your card was declined".to_string(),
+                reason: None,
+                status_code: 402,
+            }),
+            enums::AttemptStatus::Authorized,
+        );
+        assert!(matches!(
+            update,
+            storage::IncrementalAuthorizationUpdate::ErrorUpdate {
+                status: enums::AuthorizationStatus::Failure,
+                error_code: Some(ref code),
+                ..
+            } if code == "card_declined"
+        ));
+    }
+}
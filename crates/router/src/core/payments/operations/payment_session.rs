@@ -25,7 +25,7 @@ use crate::{
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
-#[operation(ops = "all", flow = "session")]
+#[operation(ops = "all", flow = "session", lock = "false")]
 pub struct PaymentSession;
 
 #[async_trait]
@@ -65,6 +65,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::PartiallyCaptured,
             ],
             "create a session token for",
         )?;
@@ -154,6 +155,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 payment_method_data: None,
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorizations: vec![],
                 sessions_token: vec![],
                 connector_response,
                 card_cvc: None,
@@ -24,7 +24,7 @@ use crate::{
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
-#[operation(ops = "all", flow = "start")]
+#[operation(ops = "all", flow = "start", lock = "false")]
 pub struct PaymentStart;
 
 #[async_trait]
@@ -63,6 +63,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::PartiallyCaptured,
             ],
             "update",
         )?;
@@ -141,6 +142,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
                 payment_method_data: None,
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorizations: vec![],
                 sessions_token: vec![],
                 card_cvc: None,
             },
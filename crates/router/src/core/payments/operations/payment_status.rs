@@ -22,7 +22,7 @@ use crate::{
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
-#[operation(ops = "all", flow = "sync")]
+#[operation(ops = "all", flow = "sync", lock = "false")]
 pub struct PaymentStatus;
 
 impl<F: Send + Clone> Operation<F, api::PaymentsRequest> for PaymentStatus {
@@ -35,6 +35,9 @@ impl<F: Send + Clone> Operation<F, api::PaymentsRequest> for PaymentStatus {
     {
         Ok(self)
     }
+    fn should_lock_payment(&self) -> bool {
+        false
+    }
 }
 impl<F: Send + Clone> Operation<F, api::PaymentsRequest> for &PaymentStatus {
     fn to_domain(&self) -> RouterResult<&dyn Domain<F, api::PaymentsRequest>> {
@@ -46,6 +49,9 @@ impl<F: Send + Clone> Operation<F, api::PaymentsRequest> for &PaymentStatus {
     {
         Ok(*self)
     }
+    fn should_lock_payment(&self) -> bool {
+        false
+    }
 }
 
 #[async_trait]
@@ -243,6 +249,28 @@ async fn get_tracker_for_sync<
             )
         })?;
 
+    let captures = db
+        .find_captures_by_merchant_id_payment_id(merchant_id, &payment_id_str)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while getting capture list for, payment_id: {}, merchant_id: {}",
+                &payment_id_str, merchant_id
+            )
+        })?;
+
+    let incremental_authorizations = db
+        .find_incremental_authorizations_by_merchant_id_payment_id(merchant_id, &payment_id_str)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while getting incremental authorization list for, payment_id: {}, merchant_id: {}",
+                &payment_id_str, merchant_id
+            )
+        })?;
+
     Ok((
         Box::new(operation),
         PaymentData {
@@ -266,6 +294,8 @@ async fn get_tracker_for_sync<
             ),
             payment_attempt,
             refunds,
+            captures,
+            incremental_authorizations,
             sessions_token: vec![],
             card_cvc: None,
         },
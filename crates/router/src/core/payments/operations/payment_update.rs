@@ -63,6 +63,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::PartiallyCaptured,
                 storage_enums::IntentStatus::RequiresCapture,
             ],
             "update",
@@ -99,6 +100,18 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         };
 
         payment_attempt.payment_method = payment_method_type.or(payment_attempt.payment_method);
+        payment_attempt.capture_method = request
+            .capture_method
+            .map(ForeignInto::foreign_into)
+            .or(payment_attempt.capture_method);
+        payment_attempt.capture_on = request.capture_on.or(payment_attempt.capture_on);
+
+        helpers::validate_capture_on(
+            payment_attempt
+                .capture_method
+                .map(ForeignInto::foreign_into),
+            payment_attempt.capture_on,
+        )?;
 
         let amount = request
             .amount
@@ -215,6 +228,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_method_data: request.payment_method_data.clone(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorizations: vec![],
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
@@ -318,6 +333,9 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             }
         };
 
+        let capture_method = payment_data.payment_attempt.capture_method;
+        let capture_on = payment_data.payment_attempt.capture_on;
+
         payment_data.payment_attempt = db
             .update_payment_attempt(
                 payment_data.payment_attempt,
@@ -328,6 +346,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     authentication_type: None,
                     payment_method,
                     payment_token: payment_data.token.clone(),
+                    capture_method,
+                    capture_on,
                 },
                 storage_scheme,
             )
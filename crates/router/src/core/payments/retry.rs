@@ -0,0 +1,235 @@
+use std::fmt::Debug;
+
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use uuid::Uuid;
+
+use super::{
+    call_connector_service,
+    flows::{ConstructFlowSpecificData, Feature},
+    operations::Operation,
+    routing, CallConnectorAction, PaymentData, PaymentResponse,
+};
+use crate::{
+    core::errors::{self, RouterResult},
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+    },
+    utils::OptionExt,
+};
+
+/// Whether a payment failed by the connector can be retried with another connector of the
+/// merchant. Only authorizations of payments routed by the merchant's routing algorithm are
+/// retried, as the connector of the other payments is chosen by the merchant.
+pub fn should_retry_payment<F, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    is_routed: bool,
+) -> bool
+where
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req>,
+{
+    let is_authorize_flow =
+        types::RouterData::<F, Req, types::PaymentsResponseData>::is_authorize_flow();
+    state.conf.payment_retry.enabled
+        && is_routed
+        && is_authorize_flow
+        // Payment attempts stored in the KV store can only be looked up by the payment id
+        && matches!(
+            merchant_account.storage_scheme,
+            storage_enums::MerchantStorageScheme::PostgresOnly
+        )
+}
+
+/// Retries a payment that failed with a retryable error with the next connectors of the
+/// merchant's priority list, creating a new payment attempt for each retry. Retries stop when a
+/// connector returns an error that cannot be retried, such as a hard decline, or when the maximum
+/// number of attempts has been made. Errors raised before a response is received from the
+/// connector, such as network errors, are never retried, as the payment may have been processed.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn do_retries<F, Op, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &api::PaymentIdType,
+    mut connector: api::ConnectorData,
+    operation: &Op,
+    mut payment_data: PaymentData<F>,
+    mut connector_error: Option<types::ErrorResponse>,
+    customer: &Option<storage::Customer>,
+) -> RouterResult<PaymentData<F>>
+where
+    Op: Debug + Sync,
+    F: Send + Clone,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, Req, types::PaymentsResponseData>,
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, Req, types::PaymentsResponseData>,
+
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, Req>,
+{
+    let routing_algorithm: api::RoutingAlgorithm = merchant_account
+        .routing_algorithm
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode merchant routing rules")?;
+    let priority_list = routing::get_connector_priority_list(
+        &routing_algorithm,
+        &routing::RoutingInput::from(&payment_data),
+        &payment_data.payment_attempt.payment_id,
+    )?;
+
+    let mut attempted_connectors = vec![connector.connector_name.to_string()];
+
+    while let Some(error) = connector_error.take() {
+        if attempted_connectors.len() >= state.conf.payment_retry.max_attempts {
+            logger::info!("Maximum number of payment attempts reached");
+            break;
+        }
+
+        if !connector.connector.is_retryable_error(&error) {
+            logger::info!(error_code = %error.code, "Connector error is not retryable");
+            break;
+        }
+
+        let next_connector = match priority_list
+            .iter()
+            .map(ToString::to_string)
+            .find(|connector_name| !attempted_connectors.contains(connector_name))
+        {
+            Some(connector_name) => connector_name,
+            None => {
+                logger::info!("No connector left in the priority list to retry the payment");
+                break;
+            }
+        };
+
+        logger::info!(
+            error_code = %error.code,
+            from_connector = %connector.connector_name,
+            to_connector = %next_connector,
+            "Retrying payment with the next connector"
+        );
+
+        connector = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &next_connector,
+            api::GetToken::Connector,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Routing algorithm gave invalid connector")?;
+
+        payment_data = create_retry_attempt(
+            state,
+            merchant_account,
+            payment_data,
+            next_connector.clone(),
+        )
+        .await?;
+        attempted_connectors.push(next_connector);
+
+        (payment_data, connector_error) = call_connector_service(
+            state,
+            merchant_account,
+            payment_id,
+            &connector,
+            operation,
+            payment_data,
+            customer,
+            CallConnectorAction::Trigger,
+        )
+        .await?;
+    }
+
+    Ok(payment_data)
+}
+
+#[instrument(skip_all)]
+async fn create_retry_attempt<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mut payment_data: PaymentData<F>,
+    connector: String,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Clone,
+{
+    let db = &*state.store;
+    let failed_attempt = &payment_data.payment_attempt;
+
+    let status = match failed_attempt.authentication_type {
+        Some(storage_enums::AuthenticationType::NoThreeDs) => storage_enums::AttemptStatus::Pending,
+        _ => storage_enums::AttemptStatus::AuthenticationPending,
+    };
+    let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
+
+    let payment_attempt = db
+        .insert_payment_attempt(
+            storage::PaymentAttemptNew {
+                payment_id: failed_attempt.payment_id.clone(),
+                merchant_id: failed_attempt.merchant_id.clone(),
+                attempt_id: Uuid::new_v4().to_string(),
+                status,
+                amount: failed_attempt.amount,
+                currency: failed_attempt.currency,
+                save_to_locker: failed_attempt.save_to_locker,
+                connector: Some(connector),
+                offer_amount: failed_attempt.offer_amount,
+                surcharge_amount: failed_attempt.surcharge_amount,
+                tax_amount: failed_attempt.tax_amount,
+                payment_method_id: failed_attempt.payment_method_id.clone(),
+                payment_method: failed_attempt.payment_method,
+                payment_flow: failed_attempt.payment_flow,
+                capture_method: failed_attempt.capture_method,
+                capture_on: failed_attempt.capture_on,
+                confirm: failed_attempt.confirm,
+                authentication_type: failed_attempt.authentication_type,
+                created_at,
+                modified_at,
+                last_synced,
+                amount_to_capture: failed_attempt.amount_to_capture,
+                mandate_id: failed_attempt.mandate_id.clone(),
+                browser_info: failed_attempt.browser_info.clone(),
+                payment_token: failed_attempt.payment_token.clone(),
+                routing_algorithm_version: failed_attempt.routing_algorithm_version.clone(),
+                card_fingerprint: failed_attempt.card_fingerprint.clone(),
+                ..storage::PaymentAttemptNew::default()
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the payment attempt for the retry")?;
+
+    let connector_response = db
+        .insert_connector_response(
+            storage::ConnectorResponseNew {
+                payment_id: payment_attempt.payment_id.clone(),
+                merchant_id: payment_attempt.merchant_id.clone(),
+                attempt_id: payment_attempt.attempt_id.clone(),
+                created_at: payment_attempt.created_at,
+                modified_at: payment_attempt.modified_at,
+                connector_name: payment_attempt.connector.clone(),
+                connector_transaction_id: None,
+                authentication_data: None,
+                encoded_data: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the connector response for the retry")?;
+
+    payment_data.payment_attempt = payment_attempt;
+    payment_data.connector_response = connector_response;
+    Ok(payment_data)
+}
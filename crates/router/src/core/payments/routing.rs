@@ -0,0 +1,799 @@
+pub mod success_rate;
+
+use common_utils::crypto::{self, GenerateDigest};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use super::PaymentData;
+use crate::{
+    core::errors::{self, RouterResult},
+    logger,
+    types::{
+        api::{self, enums as api_enums},
+        transformers::ForeignInto,
+    },
+};
+
+/// The attributes of a payment on which routing rules are evaluated.
+#[derive(Debug)]
+pub struct RoutingInput<'a> {
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    pub payment_method: Option<api_enums::PaymentMethodType>,
+    pub card_number: Option<&'a str>,
+    pub metadata: Option<&'a serde_json::Value>,
+}
+
+impl<'a, F> From<&'a PaymentData<F>> for RoutingInput<'a>
+where
+    F: Clone,
+{
+    fn from(payment_data: &'a PaymentData<F>) -> Self {
+        let card_number = match payment_data.payment_method_data {
+            Some(api::PaymentMethod::Card(ref card)) => Some(card.card_number.peek().as_str()),
+            _ => None,
+        };
+
+        Self {
+            amount: payment_data.payment_attempt.amount,
+            currency: payment_data.currency.foreign_into(),
+            payment_method: payment_data
+                .payment_attempt
+                .payment_method
+                .map(ForeignInto::foreign_into),
+            card_number,
+            metadata: payment_data.payment_intent.metadata.as_ref(),
+        }
+    }
+}
+
+/// Returns the connector of the first rule matching the payment, or the default connector of
+/// the algorithm when no rule matches.
+#[instrument(skip_all)]
+pub fn evaluate_rule_based_routing(
+    algorithm: &api::RuleBasedRoutingAlgorithm,
+    input: &RoutingInput<'_>,
+) -> api_enums::RoutableConnectors {
+    let card_issuing_country = get_card_issuing_country(algorithm, input.card_number);
+    algorithm
+        .rules
+        .iter()
+        .enumerate()
+        .find(|(_, rule)| rule_matches(&rule.conditions, input, card_issuing_country))
+        .map(|(index, rule)| {
+            logger::debug!(
+                routing_rule_index = index,
+                routing_rule_name = ?rule.name,
+                connector = %rule.connector,
+                "Routing rule matched"
+            );
+            rule.connector
+        })
+        .unwrap_or(algorithm.default_connector)
+}
+
+/// Resolves the issuing country of the card from the BIN table of the algorithm, the longest
+/// matching BIN prefix taking precedence.
+fn get_card_issuing_country<'a>(
+    algorithm: &'a api::RuleBasedRoutingAlgorithm,
+    card_number: Option<&str>,
+) -> Option<&'a str> {
+    let card_number = card_number?;
+    algorithm
+        .bin_countries
+        .as_ref()?
+        .iter()
+        .flat_map(|(country, bins)| {
+            bins.iter()
+                .filter(|bin| card_number.starts_with(bin.as_str()))
+                .map(move |bin| (bin.len(), country.as_str()))
+        })
+        .max_by_key(|(bin_length, _)| *bin_length)
+        .map(|(_, country)| country)
+}
+
+fn rule_matches(
+    conditions: &api::RoutingConditions,
+    input: &RoutingInput<'_>,
+    card_issuing_country: Option<&str>,
+) -> bool {
+    let amount_matches = conditions.amount.as_ref().map_or(true, |range| {
+        range.min.map_or(true, |min| input.amount >= min)
+            && range.max.map_or(true, |max| input.amount <= max)
+    });
+
+    let currency_matches = conditions
+        .currencies
+        .as_ref()
+        .map_or(true, |currencies| currencies.contains(&input.currency));
+
+    let payment_method_matches =
+        conditions
+            .payment_methods
+            .as_ref()
+            .map_or(true, |payment_methods| {
+                input.payment_method.map_or(false, |payment_method| {
+                    payment_methods.contains(&payment_method)
+                })
+            });
+
+    let card_network_matches = conditions
+        .card_networks
+        .as_ref()
+        .map_or(true, |card_networks| {
+            input
+                .card_number
+                .and_then(get_card_network)
+                .map_or(false, |card_network| card_networks.contains(&card_network))
+        });
+
+    let card_bin_matches = conditions.card_bins.as_ref().map_or(true, |card_bins| {
+        input.card_number.map_or(false, |card_number| {
+            card_bins
+                .iter()
+                .any(|bin| card_number.starts_with(bin.as_str()))
+        })
+    });
+
+    let card_issuing_country_matches =
+        conditions
+            .card_issuing_countries
+            .as_ref()
+            .map_or(true, |countries| {
+                card_issuing_country.map_or(false, |card_issuing_country| {
+                    countries
+                        .iter()
+                        .any(|country| country.eq_ignore_ascii_case(card_issuing_country))
+                })
+            });
+
+    let metadata_matches = conditions.metadata.as_ref().map_or(true, |expected| {
+        input.metadata.map_or(false, |metadata| {
+            expected.iter().all(|(key, value)| {
+                metadata
+                    .get(key)
+                    .and_then(serde_json::Value::as_str)
+                    .map_or(false, |actual| actual == value)
+            })
+        })
+    });
+
+    amount_matches
+        && currency_matches
+        && payment_method_matches
+        && card_network_matches
+        && card_bin_matches
+        && card_issuing_country_matches
+        && metadata_matches
+}
+
+/// Picks a connector from the volume split. The bucket of a payment is derived from its payment
+/// id, so that the same payment is always routed to the same connector by a given split.
+#[instrument(skip_all)]
+pub fn evaluate_volume_split_routing(
+    splits: &[api::ConnectorVolumeSplit],
+    payment_id: &str,
+) -> RouterResult<api_enums::RoutableConnectors> {
+    let total_weight: u64 = splits.iter().map(|split| u64::from(split.weight)).sum();
+    if total_weight == 0 {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Volume split routing has no connector with a non-zero weight");
+    }
+
+    let digest = crypto::Sha512
+        .generate_digest(payment_id.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the volume split bucket")?;
+    let hash = digest
+        .get(..8)
+        .and_then(|bytes| <[u8; 8]>::try_from(bytes).ok())
+        .map(u64::from_be_bytes)
+        .unwrap_or_default();
+    let mut bucket = hash % total_weight;
+
+    for split in splits {
+        let weight = u64::from(split.weight);
+        if bucket < weight {
+            logger::debug!(connector = %split.connector, "Volume split bucket selected");
+            return Ok(split.connector);
+        }
+        bucket -= weight;
+    }
+
+    Err(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Volume split bucket lies outside the total weight")
+}
+
+/// Orders the connectors of the routing algorithm by preference for the payment, starting with
+/// the connector the payment is routed to. A payment that fails with a retryable error is
+/// retried with the connectors that follow.
+pub fn get_connector_priority_list(
+    algorithm: &api::RoutingAlgorithm,
+    input: &RoutingInput<'_>,
+    payment_id: &str,
+) -> RouterResult<Vec<api_enums::RoutableConnectors>> {
+    let connectors: Vec<_> = match algorithm {
+        api::RoutingAlgorithm::Single(connector) => vec![*connector],
+        api::RoutingAlgorithm::Priority(connectors) => connectors.clone(),
+        api::RoutingAlgorithm::SuccessRate(success_rate) => success_rate.connectors.clone(),
+        api::RoutingAlgorithm::RuleBased(rule_based) => {
+            let card_issuing_country = get_card_issuing_country(rule_based, input.card_number);
+            rule_based
+                .rules
+                .iter()
+                .filter(|rule| rule_matches(&rule.conditions, input, card_issuing_country))
+                .map(|rule| rule.connector)
+                .chain(std::iter::once(rule_based.default_connector))
+                .collect()
+        }
+        api::RoutingAlgorithm::VolumeSplit(splits) => {
+            let selected = evaluate_volume_split_routing(splits, payment_id)?;
+            let mut fallbacks: Vec<_> = splits
+                .iter()
+                .filter(|split| split.weight > 0 && split.connector != selected)
+                .collect();
+            fallbacks.sort_by(|a, b| b.weight.cmp(&a.weight));
+            std::iter::once(selected)
+                .chain(fallbacks.into_iter().map(|split| split.connector))
+                .collect()
+        }
+    };
+
+    Ok(connectors
+        .into_iter()
+        .fold(Vec::new(), |mut priority_list, connector| {
+            if !priority_list.contains(&connector) {
+                priority_list.push(connector);
+            }
+            priority_list
+        }))
+}
+
+/// Identifies the routing algorithm configured for the merchant, so that payment attempts routed
+/// by different configurations can be told apart when analysing them.
+pub fn get_routing_algorithm_version(
+    routing_algorithm: &serde_json::Value,
+) -> RouterResult<String> {
+    let digest = crypto::Sha512
+        .generate_digest(routing_algorithm.to_string().as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the routing algorithm version")?;
+    Ok(hex::encode(digest.get(..8).unwrap_or_default()))
+}
+
+/// Identifies the card network from the leading digits (BIN) of the card number.
+pub fn get_card_network(card_number: &str) -> Option<api_enums::CardNetwork> {
+    let prefix = |length: usize| -> Option<u32> {
+        card_number
+            .get(..length)
+            .and_then(|digits| digits.parse().ok())
+    };
+    let in_range = |length: usize, start: u32, end: u32| {
+        prefix(length).map_or(false, |digits| (start..=end).contains(&digits))
+    };
+
+    if in_range(2, 34, 34) || in_range(2, 37, 37) {
+        Some(api_enums::CardNetwork::AmericanExpress)
+    } else if in_range(4, 3528, 3589) {
+        Some(api_enums::CardNetwork::Jcb)
+    } else if in_range(3, 300, 305) || in_range(2, 36, 36) || in_range(2, 38, 39) {
+        Some(api_enums::CardNetwork::DinersClub)
+    } else if in_range(1, 4, 4) {
+        Some(api_enums::CardNetwork::Visa)
+    } else if in_range(2, 51, 55) || in_range(4, 2221, 2720) {
+        Some(api_enums::CardNetwork::Mastercard)
+    } else if in_range(4, 6011, 6011) {
+        Some(api_enums::CardNetwork::Discover)
+    } else if in_range(2, 60, 60) || in_range(3, 652, 652) || in_range(2, 81, 82) {
+        Some(api_enums::CardNetwork::RuPay)
+    } else if in_range(3, 644, 649) || in_range(2, 65, 65) {
+        Some(api_enums::CardNetwork::Discover)
+    } else if in_range(2, 62, 62) {
+        Some(api_enums::CardNetwork::UnionPay)
+    } else if in_range(2, 50, 50) || in_range(2, 56, 58) || in_range(1, 6, 6) {
+        Some(api_enums::CardNetwork::Maestro)
+    } else {
+        None
+    }
+}
+
+/// Validates the rules of a routing algorithm configured by a merchant.
+pub fn validate_routing_algorithm(algorithm: &api::RoutingAlgorithm) -> RouterResult<()> {
+    match algorithm {
+        api::RoutingAlgorithm::Single(_) => Ok(()),
+        api::RoutingAlgorithm::Priority(connectors) => {
+            if connectors.is_empty() {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Priority routing needs at least one connector".to_string(),
+                }));
+            }
+            let mut unique_connectors = std::collections::HashSet::new();
+            if connectors
+                .iter()
+                .any(|connector| !unique_connectors.insert(connector.to_string()))
+            {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Each connector can appear only once in a priority list".to_string(),
+                }));
+            }
+            Ok(())
+        }
+        api::RoutingAlgorithm::VolumeSplit(splits) => {
+            let mut connectors = std::collections::HashSet::new();
+            if splits
+                .iter()
+                .any(|split| !connectors.insert(split.connector.to_string()))
+            {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Each connector can appear only once in a volume split".to_string(),
+                }));
+            }
+            if splits.iter().all(|split| split.weight == 0) {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Volume split needs at least one connector with a non-zero weight"
+                        .to_string(),
+                }));
+            }
+            Ok(())
+        }
+        api::RoutingAlgorithm::SuccessRate(success_rate) => {
+            if success_rate.connectors.is_empty() {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Success rate routing needs at least one connector".to_string(),
+                }));
+            }
+            let mut unique_connectors = std::collections::HashSet::new();
+            if success_rate
+                .connectors
+                .iter()
+                .any(|connector| !unique_connectors.insert(connector.to_string()))
+            {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Each connector can appear only once in success rate routing"
+                        .to_string(),
+                }));
+            }
+            if success_rate
+                .exploration_percentage
+                .map_or(false, |percentage| percentage > 100)
+            {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Exploration percentage cannot be greater than 100".to_string(),
+                }));
+            }
+            Ok(())
+        }
+        api::RoutingAlgorithm::RuleBased(rule_based) => {
+            if let Some(ref bin_countries) = rule_based.bin_countries {
+                validate_bin_countries(bin_countries).map_err(|reason| {
+                    report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!("Invalid bin_countries: {reason}"),
+                    })
+                })?;
+            }
+            rule_based
+                .rules
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, rule)| {
+                    validate_routing_conditions(&rule.conditions)
+                        .and_then(|()| {
+                            if rule.conditions.card_issuing_countries.is_some()
+                                && rule_based.bin_countries.is_none()
+                            {
+                                Err(
+                                    "card_issuing_countries needs bin_countries to be configured"
+                                        .to_string(),
+                                )
+                            } else {
+                                Ok(())
+                            }
+                        })
+                        .map_err(|reason| {
+                            report!(errors::ApiErrorResponse::InvalidRequestData {
+                                message: format!(
+                                    "Invalid routing rule {}: {reason}",
+                                    rule.name.clone().unwrap_or_else(|| index.to_string())
+                                ),
+                            })
+                        })
+                })
+        }
+    }
+}
+
+fn is_valid_card_bin(bin: &str) -> bool {
+    !bin.is_empty() && bin.len() <= 8 && bin.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_valid_country_code(country: &str) -> bool {
+    country.len() == 2 && country.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn validate_bin_countries(
+    bin_countries: &std::collections::HashMap<String, Vec<String>>,
+) -> Result<(), String> {
+    let mut unique_bins = std::collections::HashSet::new();
+    for (country, bins) in bin_countries {
+        if !is_valid_country_code(country) {
+            return Err(format!(
+                "country {country} must be an ISO 3166-1 alpha-2 country code"
+            ));
+        }
+        if bins.is_empty() {
+            return Err(format!("bins of country {country} cannot be empty"));
+        }
+        if let Some(bin) = bins.iter().find(|bin| !is_valid_card_bin(bin)) {
+            return Err(format!("card bin {bin} must consist of 1 to 8 digits"));
+        }
+        if let Some(bin) = bins.iter().find(|bin| !unique_bins.insert(bin.as_str())) {
+            return Err(format!("card bin {bin} can be mapped to only one country"));
+        }
+    }
+    Ok(())
+}
+
+fn validate_routing_conditions(conditions: &api::RoutingConditions) -> Result<(), String> {
+    if let Some(ref range) = conditions.amount {
+        if range.min.map_or(false, |min| min < 0) || range.max.map_or(false, |max| max < 0) {
+            return Err("amount bounds cannot be negative".to_string());
+        }
+        if let (Some(min), Some(max)) = (range.min, range.max) {
+            if min > max {
+                return Err("minimum amount cannot be greater than maximum amount".to_string());
+            }
+        }
+    }
+
+    let empty_list = [
+        (
+            "currencies",
+            conditions.currencies.as_ref().map(Vec::is_empty),
+        ),
+        (
+            "payment_methods",
+            conditions.payment_methods.as_ref().map(Vec::is_empty),
+        ),
+        (
+            "card_networks",
+            conditions.card_networks.as_ref().map(Vec::is_empty),
+        ),
+        (
+            "card_bins",
+            conditions.card_bins.as_ref().map(Vec::is_empty),
+        ),
+        (
+            "card_issuing_countries",
+            conditions
+                .card_issuing_countries
+                .as_ref()
+                .map(Vec::is_empty),
+        ),
+        (
+            "metadata",
+            conditions
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.is_empty()),
+        ),
+    ]
+    .into_iter()
+    .find(|(_, is_empty)| is_empty.unwrap_or(false));
+    if let Some((field_name, _)) = empty_list {
+        return Err(format!("{field_name} cannot be empty"));
+    }
+
+    if let Some(ref card_bins) = conditions.card_bins {
+        if let Some(bin) = card_bins.iter().find(|bin| !is_valid_card_bin(bin)) {
+            return Err(format!("card bin {bin} must consist of 1 to 8 digits"));
+        }
+    }
+
+    if let Some(ref countries) = conditions.card_issuing_countries {
+        if let Some(country) = countries
+            .iter()
+            .find(|country| !is_valid_country_code(country))
+        {
+            return Err(format!(
+                "country {country} must be an ISO 3166-1 alpha-2 country code"
+            ));
+        }
+    }
+
+    if let Some(ref metadata) = conditions.metadata {
+        if metadata.keys().any(|key| key.is_empty()) {
+            return Err("metadata keys cannot be empty".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_get_card_network() {
+        assert_eq!(
+            get_card_network("4242424242424242"),
+            Some(api_enums::CardNetwork::Visa)
+        );
+        assert_eq!(
+            get_card_network("5555555555554444"),
+            Some(api_enums::CardNetwork::Mastercard)
+        );
+        assert_eq!(
+            get_card_network("378282246310005"),
+            Some(api_enums::CardNetwork::AmericanExpress)
+        );
+        assert_eq!(get_card_network("1234"), None);
+    }
+
+    #[test]
+    fn test_evaluate_rule_based_routing() {
+        let algorithm = api::RuleBasedRoutingAlgorithm {
+            rules: vec![api::RoutingRule {
+                name: Some("high_value_usd".to_string()),
+                conditions: api::RoutingConditions {
+                    amount: Some(api::AmountRange {
+                        min: Some(10000),
+                        max: None,
+                    }),
+                    currencies: Some(vec![api_enums::Currency::USD]),
+                    ..Default::default()
+                },
+                connector: api_enums::RoutableConnectors::Adyen,
+            }],
+            default_connector: api_enums::RoutableConnectors::Stripe,
+            bin_countries: None,
+        };
+        let mut input = RoutingInput {
+            amount: 20000,
+            currency: api_enums::Currency::USD,
+            payment_method: Some(api_enums::PaymentMethodType::Card),
+            card_number: Some("4242424242424242"),
+            metadata: None,
+        };
+        assert_eq!(
+            evaluate_rule_based_routing(&algorithm, &input),
+            api_enums::RoutableConnectors::Adyen
+        );
+
+        input.amount = 500;
+        assert_eq!(
+            evaluate_rule_based_routing(&algorithm, &input),
+            api_enums::RoutableConnectors::Stripe
+        );
+    }
+
+    #[test]
+    fn test_evaluate_rule_based_routing_on_card_issuing_country() {
+        let algorithm = api::RuleBasedRoutingAlgorithm {
+            rules: vec![api::RoutingRule {
+                name: Some("indian_cards".to_string()),
+                conditions: api::RoutingConditions {
+                    card_issuing_countries: Some(vec!["IN".to_string()]),
+                    ..Default::default()
+                },
+                connector: api_enums::RoutableConnectors::Checkout,
+            }],
+            default_connector: api_enums::RoutableConnectors::Stripe,
+            bin_countries: Some(std::collections::HashMap::from([
+                ("US".to_string(), vec!["4".to_string()]),
+                ("IN".to_string(), vec!["4012".to_string()]),
+            ])),
+        };
+        let mut input = RoutingInput {
+            amount: 1000,
+            currency: api_enums::Currency::INR,
+            payment_method: Some(api_enums::PaymentMethodType::Card),
+            card_number: Some("4012888888881881"),
+            metadata: None,
+        };
+        assert_eq!(
+            evaluate_rule_based_routing(&algorithm, &input),
+            api_enums::RoutableConnectors::Checkout
+        );
+
+        input.card_number = Some("4242424242424242");
+        assert_eq!(
+            evaluate_rule_based_routing(&algorithm, &input),
+            api_enums::RoutableConnectors::Stripe
+        );
+
+        input.card_number = None;
+        assert_eq!(
+            evaluate_rule_based_routing(&algorithm, &input),
+            api_enums::RoutableConnectors::Stripe
+        );
+    }
+
+    #[test]
+    fn test_validate_card_issuing_country_rules() {
+        let rule = api::RoutingRule {
+            name: None,
+            conditions: api::RoutingConditions {
+                card_issuing_countries: Some(vec!["IN".to_string()]),
+                ..Default::default()
+            },
+            connector: api_enums::RoutableConnectors::Checkout,
+        };
+        let mut algorithm = api::RuleBasedRoutingAlgorithm {
+            rules: vec![rule],
+            default_connector: api_enums::RoutableConnectors::Stripe,
+            bin_countries: None,
+        };
+        assert!(
+            validate_routing_algorithm(&api::RoutingAlgorithm::RuleBased(algorithm.clone()))
+                .is_err()
+        );
+
+        algorithm.bin_countries = Some(std::collections::HashMap::from([(
+            "IN".to_string(),
+            vec!["4012".to_string()],
+        )]));
+        assert!(
+            validate_routing_algorithm(&api::RoutingAlgorithm::RuleBased(algorithm.clone()))
+                .is_ok()
+        );
+
+        algorithm.bin_countries = Some(std::collections::HashMap::from([
+            ("IN".to_string(), vec!["4012".to_string()]),
+            ("US".to_string(), vec!["4012".to_string()]),
+        ]));
+        assert!(
+            validate_routing_algorithm(&api::RoutingAlgorithm::RuleBased(algorithm.clone()))
+                .is_err()
+        );
+
+        algorithm.bin_countries = Some(std::collections::HashMap::from([(
+            "IND".to_string(),
+            vec!["4012".to_string()],
+        )]));
+        assert!(validate_routing_algorithm(&api::RoutingAlgorithm::RuleBased(algorithm)).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_volume_split_routing() {
+        let splits = vec![
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                weight: 0,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                weight: 100,
+            },
+        ];
+        let connector = evaluate_volume_split_routing(&splits, "pay_123");
+        assert!(matches!(
+            connector,
+            Ok(api_enums::RoutableConnectors::Adyen)
+        ));
+
+        let splits = vec![
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                weight: 50,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                weight: 50,
+            },
+        ];
+        let first = evaluate_volume_split_routing(&splits, "pay_123").ok();
+        let second = evaluate_volume_split_routing(&splits, "pay_123").ok();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_volume_split_routing_distribution() {
+        let splits = vec![
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                weight: 70,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                weight: 30,
+            },
+        ];
+        let stripe_payments = (0..10_000)
+            .filter(|index| {
+                evaluate_volume_split_routing(&splits, &format!("pay_{index}")).unwrap()
+                    == api_enums::RoutableConnectors::Stripe
+            })
+            .count();
+        assert!(
+            (6_700..=7_300).contains(&stripe_payments),
+            "{stripe_payments} of 10000 payments routed to the connector with a 70% split"
+        );
+    }
+
+    #[test]
+    fn test_volume_split_routing_with_empty_and_full_splits() {
+        let splits = vec![
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                weight: 0,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                weight: 100,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Checkout,
+                weight: 0,
+            },
+        ];
+        for index in 0..1_000 {
+            assert_eq!(
+                evaluate_volume_split_routing(&splits, &format!("pay_{index}")).unwrap(),
+                api_enums::RoutableConnectors::Adyen
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_connector_priority_list() {
+        let algorithm = api::RoutingAlgorithm::RuleBased(api::RuleBasedRoutingAlgorithm {
+            rules: vec![
+                api::RoutingRule {
+                    name: None,
+                    conditions: api::RoutingConditions {
+                        currencies: Some(vec![api_enums::Currency::EUR]),
+                        ..Default::default()
+                    },
+                    connector: api_enums::RoutableConnectors::Checkout,
+                },
+                api::RoutingRule {
+                    name: None,
+                    conditions: api::RoutingConditions::default(),
+                    connector: api_enums::RoutableConnectors::Adyen,
+                },
+                api::RoutingRule {
+                    name: None,
+                    conditions: api::RoutingConditions::default(),
+                    connector: api_enums::RoutableConnectors::Stripe,
+                },
+            ],
+            default_connector: api_enums::RoutableConnectors::Adyen,
+            bin_countries: None,
+        });
+        let input = RoutingInput {
+            amount: 1000,
+            currency: api_enums::Currency::USD,
+            payment_method: None,
+            card_number: None,
+            metadata: None,
+        };
+        assert!(matches!(
+            get_connector_priority_list(&algorithm, &input, "pay_123").as_deref(),
+            Ok([
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Stripe
+            ])
+        ));
+
+        let algorithm = api::RoutingAlgorithm::VolumeSplit(vec![
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                weight: 10,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Checkout,
+                weight: 0,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                weight: 90,
+            },
+        ]);
+        let priority_list = get_connector_priority_list(&algorithm, &input, "pay_123");
+        assert!(matches!(priority_list.as_deref(), Ok([_, _])));
+    }
+}
@@ -0,0 +1,170 @@
+use error_stack::report;
+use rand::{seq::SliceRandom, Rng};
+use router_env::{instrument, tracing};
+
+use super::RoutingInput;
+use crate::{
+    core::errors::{self, RouterResult},
+    logger,
+    routes::AppState,
+    types::{
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils::OptionExt,
+};
+
+/// Percentage of payments routed to a random connector when the algorithm does not specify one
+pub const DEFAULT_EXPLORATION_PERCENTAGE: u8 = 5;
+
+fn get_window_key(
+    merchant_id: &str,
+    connector: &str,
+    payment_method: Option<api_enums::PaymentMethodType>,
+    currency: api_enums::Currency,
+) -> String {
+    let payment_method = payment_method
+        .map(|payment_method| payment_method.to_string())
+        .unwrap_or_else(|| "none".to_string());
+    format!("success_rate_{merchant_id}_{connector}_{payment_method}_{currency}")
+}
+
+/// Estimates the probability of the next payment being successful from the outcomes of the
+/// recent payments, a connector without any recent payment is scored at one half.
+pub fn get_success_rate_score(outcomes: &[bool]) -> f64 {
+    let to_f64 = |count: usize| f64::from(u32::try_from(count).unwrap_or(u32::MAX));
+    let successes = outcomes.iter().filter(|is_success| **is_success).count();
+    (to_f64(successes) + 1.0) / (to_f64(outcomes.len()) + 2.0)
+}
+
+/// Picks the connector with the highest success rate for the payment method and currency of the
+/// payment. A fraction of the payments is routed to a random connector instead, so that a
+/// connector which has recovered from a degradation gets traffic again.
+#[instrument(skip_all)]
+pub async fn evaluate_success_rate_routing(
+    state: &AppState,
+    merchant_id: &str,
+    algorithm: &api::SuccessRateRoutingAlgorithm,
+    input: &RoutingInput<'_>,
+) -> RouterResult<api_enums::RoutableConnectors> {
+    let exploration_percentage = algorithm
+        .exploration_percentage
+        .unwrap_or(DEFAULT_EXPLORATION_PERCENTAGE);
+    let explored_connector = {
+        let mut rng = rand::thread_rng();
+        if rng.gen_range(0..100) < exploration_percentage {
+            algorithm.connectors.choose(&mut rng).copied()
+        } else {
+            None
+        }
+    };
+    if let Some(connector) = explored_connector {
+        logger::debug!(connector = %connector, "Success rate routing explored connector");
+        return Ok(connector);
+    }
+
+    let mut best_connector: Option<(api_enums::RoutableConnectors, f64)> = None;
+    for connector in &algorithm.connectors {
+        let window_key = get_window_key(
+            merchant_id,
+            &connector.to_string(),
+            input.payment_method,
+            input.currency,
+        );
+        let outcomes = state
+            .store
+            .get_connector_outcomes(&window_key, state.conf.success_rate_routing.window_size)
+            .await
+            .map_err(|error| logger::error!(connector_outcomes_error=?error))
+            .unwrap_or_default();
+
+        let score = get_success_rate_score(&outcomes);
+        logger::debug!(
+            connector = %connector,
+            success_rate_score = score,
+            samples = outcomes.len(),
+            "Success rate routing scored connector"
+        );
+        if best_connector.map_or(true, |(_, best_score)| score > best_score) {
+            best_connector = Some((*connector, score));
+        }
+    }
+
+    best_connector
+        .map(|(connector, _)| connector)
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Success rate routing has no connectors")
+        })
+}
+
+/// Records the outcome of an authorization in the success rate window of its connector, when
+/// the merchant routes payments by success rate. Failures to record the outcome are logged and
+/// do not affect the payment.
+#[instrument(skip_all)]
+pub async fn record_authorize_outcome(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    let routing_algorithm: Option<api::RoutingAlgorithm> = merchant_account
+        .routing_algorithm
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .ok();
+    if !matches!(
+        routing_algorithm,
+        Some(api::RoutingAlgorithm::SuccessRate(_))
+    ) {
+        return;
+    }
+
+    let is_success = match payment_attempt.status {
+        storage_enums::AttemptStatus::Charged | storage_enums::AttemptStatus::Authorized => true,
+        storage_enums::AttemptStatus::Failure
+        | storage_enums::AttemptStatus::AuthorizationFailed => false,
+        // The outcome of payments awaiting customer action or the connector is not known yet
+        _ => return,
+    };
+    let (connector, currency) = match (&payment_attempt.connector, payment_attempt.currency) {
+        (Some(connector), Some(currency)) => (connector, currency),
+        _ => return,
+    };
+
+    let window_key = get_window_key(
+        &payment_attempt.merchant_id,
+        connector,
+        payment_attempt
+            .payment_method
+            .map(ForeignInto::foreign_into),
+        currency.foreign_into(),
+    );
+    let config = &state.conf.success_rate_routing;
+    if let Err(error) = state
+        .store
+        .record_connector_outcome(
+            &window_key,
+            is_success,
+            config.window_size,
+            config.window_ttl,
+        )
+        .await
+    {
+        logger::error!(connector_outcome_error=?error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_success_rate_score() {
+        assert!((get_success_rate_score(&[]) - 0.5).abs() < f64::EPSILON);
+
+        let degraded = get_success_rate_score(&[false, false, true, false]);
+        let healthy = get_success_rate_score(&[true, true, true, false]);
+        assert!(healthy > degraded);
+    }
+}
@@ -138,6 +138,8 @@ where
             payment_data.payment_attempt,
             payment_data.payment_intent,
             payment_data.refunds,
+            payment_data.captures,
+            payment_data.incremental_authorizations,
             payment_data.payment_method_data,
             customer,
             auth_flow,
@@ -227,6 +229,8 @@ pub fn payments_to_payments_response<R, Op>(
     payment_attempt: storage::PaymentAttempt,
     payment_intent: storage::PaymentIntent,
     refunds: Vec<storage::Refund>,
+    captures: Vec<storage::Capture>,
+    incremental_authorizations: Vec<storage::IncrementalAuthorization>,
     payment_method_data: Option<api::PaymentMethod>,
     customer: Option<storage::Customer>,
     auth_flow: services::AuthFlow,
@@ -250,6 +254,26 @@ where
     } else {
         Some(refunds.into_iter().map(ForeignInto::foreign_into).collect())
     };
+    let captures_response = if captures.is_empty() {
+        None
+    } else {
+        Some(
+            captures
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        )
+    };
+    let incremental_authorizations_response = if incremental_authorizations.is_empty() {
+        None
+    } else {
+        Some(
+            incremental_authorizations
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        )
+    };
 
     Ok(match payment_request {
         Some(request) => {
@@ -305,6 +329,8 @@ where
                         .set_mandate_id(mandate_id)
                         .set_description(payment_intent.description)
                         .set_refunds(refunds_response) // refunds.iter().map(refund_to_refund_response),
+                        .set_captures(captures_response)
+                        .set_incremental_authorizations(incremental_authorizations_response)
                         .set_payment_method(
                             payment_attempt
                                 .payment_method
@@ -316,6 +342,7 @@ where
                             auth_flow == services::AuthFlow::Merchant,
                         )
                         .set_payment_token(payment_attempt.payment_token)
+                        .set_card_fingerprint(payment_attempt.card_fingerprint)
                         .set_error_message(payment_attempt.error_message)
                         .set_error_code(payment_attempt.error_code)
                         .set_shipping(address.shipping)
@@ -358,6 +385,8 @@ where
             customer_id: payment_intent.customer_id,
             description: payment_intent.description,
             refunds: refunds_response,
+            captures: captures_response,
+            incremental_authorizations: incremental_authorizations_response,
             payment_method: payment_attempt
                 .payment_method
                 .map(ForeignInto::foreign_into),
@@ -381,6 +410,7 @@ where
             billing: address.billing,
             cancellation_reason: payment_attempt.cancellation_reason,
             payment_token: payment_attempt.payment_token,
+            card_fingerprint: payment_attempt.card_fingerprint,
             ..Default::default()
         }),
     })
@@ -425,12 +455,18 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
             setup_mandate_details: payment_data.setup_mandate.clone(),
             confirm: payment_data.payment_attempt.confirm,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
-            capture_method: payment_data.payment_attempt.capture_method,
+            capture_method: helpers::get_connector_capture_method(
+                payment_data.payment_attempt.capture_method,
+            ),
             amount: payment_data.amount.into(),
             currency: payment_data.currency,
             browser_info,
             email: payment_data.email,
             order_details,
+            request_incremental_authorization: payment_data
+                .payment_intent
+                .request_incremental_authorization
+                .unwrap_or(false),
         })
     }
 }
@@ -447,7 +483,9 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsSyncData {
                 None => types::ResponseId::NoResponseId,
             },
             encoded_data: payment_data.connector_response.encoded_data,
-            capture_method: payment_data.payment_attempt.capture_method,
+            capture_method: helpers::get_connector_capture_method(
+                payment_data.payment_attempt.capture_method,
+            ),
         })
     }
 }
@@ -464,6 +502,32 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCaptureData {
                 .connector_transaction_id
                 .ok_or(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?,
             amount: payment_data.amount.into(),
+            final_capture: payment_data
+                .captures
+                .last()
+                .map_or(true, |capture| capture.final_capture),
+        })
+    }
+}
+
+impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsIncrementalAuthorizationData {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(payment_data: PaymentData<F>) -> Result<Self, Self::Error> {
+        let authorization = payment_data
+            .incremental_authorizations
+            .last()
+            .get_required_value("incremental_authorization")?;
+
+        Ok(Self {
+            total_amount: authorization.amount,
+            additional_amount: authorization.amount - authorization.previously_authorized_amount,
+            currency: payment_data.currency,
+            connector_transaction_id: payment_data
+                .payment_attempt
+                .connector_transaction_id
+                .get_required_value("connector_transaction_id")?,
+            reason: authorization.reason.clone(),
         })
     }
 }
@@ -0,0 +1,660 @@
+use std::fmt::Debug;
+
+use common_utils::pii;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{
+            self, api_error_response::NotImplementedMessage, ConnectorErrorExt, RouterResponse,
+            RouterResult, StorageErrorExt,
+        },
+        payments::{self, access_token},
+        utils as core_utils,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, payouts},
+        storage::{self, enums},
+        transformers::{Foreign, ForeignInto},
+    },
+    utils::{self, OptionExt, ValueExt},
+};
+
+// ********************************************** PAYOUT CREATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payouts::PayoutCreateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than zero"))
+    })?;
+
+    let payout_id = core_utils::get_or_generate_id("payout_id", &req.payout_id, "payout")?;
+    let connector_name = get_payout_connector(&merchant_account, &payout_id, req.connector)?;
+
+    let payout_new = storage::PayoutsNew {
+        payout_id: payout_id.clone(),
+        merchant_id: merchant_id.to_string(),
+        customer_id: req.customer_id.clone(),
+        connector: connector_name,
+        connector_payout_id: None,
+        payout_type: req.payout_type.foreign_into(),
+        status: enums::PayoutStatus::Pending,
+        amount: req.amount,
+        currency: req.currency.foreign_into(),
+        description: req.description.clone(),
+        metadata: req.metadata.clone(),
+        created_at: None,
+        modified_at: None,
+    };
+
+    let payout = db.insert_payout(payout_new).await.map_err(|error| {
+        error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout {
+            payout_id: payout_id.clone(),
+        })
+    })?;
+
+    // The payout is recorded before it is sent to the connector, so it has to be marked as failed
+    // when it could not be created at the connector instead of being left pending
+    let payout = match create_payout_at_connector(
+        state,
+        &merchant_account,
+        &payout,
+        req.payout_method_data,
+        req.email,
+    )
+    .await
+    {
+        Ok(payout) => payout,
+        Err(error) => {
+            let error_response = error.current_context();
+            let payout_update = storage::PayoutsUpdate::ErrorUpdate {
+                status: enums::PayoutStatus::Failed,
+                error_message: Some(error_response.error_message()),
+                error_code: Some(error_response.error_code()),
+            };
+            if let Err(update_error) = db.update_payout(payout, payout_update).await {
+                logger::error!(?update_error, "Failed to mark the payout as failed");
+            }
+            return Err(error);
+        }
+    };
+
+    // A payout whose fulfillment fails stays awaiting fulfillment, so that it can be fulfilled
+    // or cancelled later on
+    let payout = if req.auto_fulfill.unwrap_or(false)
+        && payout.status == enums::PayoutStatus::RequiresFulfillment
+    {
+        let router_data = core_utils::construct_payout_router_data::<api::PoFulfill>(
+            state,
+            &merchant_account,
+            &payout,
+            None,
+            None,
+        )
+        .await?;
+        trigger_payout_to_gateway(
+            state,
+            &merchant_account,
+            &payout,
+            router_data,
+            enums::PayoutStatus::Failed,
+        )
+        .await?
+    } else {
+        payout
+    };
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+async fn create_payout_at_connector(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &storage::Payouts,
+    payout_method_data: payouts::PayoutMethodData,
+    email: Option<masking::Secret<String, pii::Email>>,
+) -> RouterResult<storage::Payouts> {
+    let router_data = core_utils::construct_payout_router_data::<api::PoCreate>(
+        state,
+        merchant_account,
+        payout,
+        Some(payout_method_data),
+        email,
+    )
+    .await?;
+
+    trigger_payout_to_gateway(
+        state,
+        merchant_account,
+        payout,
+        router_data,
+        enums::PayoutStatus::Failed,
+    )
+    .await
+}
+
+/// Picks the connector explicitly requested for the payout, falling back to the connector
+/// configured in the merchant's routing algorithm.
+fn get_payout_connector(
+    merchant_account: &storage::MerchantAccount,
+    payout_id: &str,
+    connector: Option<api::enums::Connector>,
+) -> RouterResult<String> {
+    match connector {
+        Some(connector) => Ok(connector.to_string()),
+        None => {
+            let routing_algorithm: api::RoutingAlgorithm = merchant_account
+                .routing_algorithm
+                .clone()
+                .parse_value("RoutingAlgorithm")
+                .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "connector",
+                })
+                .attach_printable("Could not decode merchant routing rules")?;
+
+            match routing_algorithm {
+                api::RoutingAlgorithm::Single(conn) => Ok(conn.to_string()),
+                api::RoutingAlgorithm::Priority(connectors) => connectors
+                    .first()
+                    .map(|connector| connector.to_string())
+                    .get_required_value("connector"),
+                // Success rates are measured on payment authorizations, payouts use the first
+                api::RoutingAlgorithm::SuccessRate(algorithm) => algorithm
+                    .connectors
+                    .first()
+                    .map(|connector| connector.to_string())
+                    .get_required_value("connector"),
+                // Routing rules are evaluated on payment attributes, payouts use the fallback
+                api::RoutingAlgorithm::RuleBased(algorithm) => {
+                    Ok(algorithm.default_connector.to_string())
+                }
+                api::RoutingAlgorithm::VolumeSplit(splits) => {
+                    payments::routing::evaluate_volume_split_routing(&splits, payout_id)
+                        .map(|connector| connector.to_string())
+                }
+            }
+        }
+    }
+}
+
+/// Sends the payout request to the connector and records its outcome. When the connector rejects
+/// the request, the payout is moved to `status_on_failure`, which lets flows such as cancelling
+/// or reversing a payout keep the payout in its current state.
+#[instrument(skip_all)]
+pub async fn trigger_payout_to_gateway<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &storage::Payouts,
+    mut router_data: types::PayoutsRouterData<F>,
+    status_on_failure: enums::PayoutStatus,
+) -> RouterResult<storage::Payouts>
+where
+    F: Clone + Debug + 'static,
+    dyn api::Connector:
+        services::ConnectorIntegration<F, types::PayoutsData, types::PayoutsResponseData>,
+{
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &payout.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    let add_access_token_result =
+        access_token::add_access_token(state, &connector, merchant_account, &router_data).await?;
+
+    logger::debug!(payout_router_data=?router_data);
+
+    access_token::update_router_data_with_access_token_result(
+        &add_access_token_result,
+        &mut router_data,
+        &payments::CallConnectorAction::Trigger,
+    );
+
+    let router_data_res = if !(add_access_token_result.connector_supports_access_token
+        && router_data.access_token.is_none())
+    {
+        let connector_integration: services::BoxedConnectorIntegration<
+            '_,
+            F,
+            types::PayoutsData,
+            types::PayoutsResponseData,
+        > = connector.connector.get_connector_integration();
+        services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            &router_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await
+        .map_err(|error| error.to_payout_failed_response())?
+    } else {
+        router_data
+    };
+
+    let payout_update = match router_data_res.response {
+        Err(err) => storage::PayoutsUpdate::ErrorUpdate {
+            status: status_on_failure,
+            error_message: Some(err.message),
+            error_code: Some(err.code),
+        },
+        Ok(response) => storage::PayoutsUpdate::StatusUpdate {
+            connector_payout_id: Some(response.connector_payout_id),
+            status: response.status,
+        },
+    };
+
+    state
+        .store
+        .update_payout(payout.to_owned(), payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while updating payout: payout_id: {}",
+                payout.payout_id
+            )
+        })
+}
+
+// ********************************************** PAYOUT RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+async fn find_payout(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout_id: &str,
+) -> RouterResult<storage::Payouts> {
+    state
+        .store
+        .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, payout_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))
+}
+
+// ********************************************** PAYOUT UPDATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_update_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+    req: payouts::PayoutUpdateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+
+    let response = state
+        .store
+        .update_payout(
+            payout,
+            storage::PayoutsUpdate::MetadataUpdate {
+                description: req.description,
+                metadata: req.metadata,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Unable to update payout with payout_id: {payout_id}"))?;
+
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+// ********************************************** PAYOUT FULFILL **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_fulfill_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+    validate_payout_status(
+        &payout,
+        enums::PayoutStatus::RequiresFulfillment,
+        "fulfilled",
+    )?;
+
+    let router_data = core_utils::construct_payout_router_data::<api::PoFulfill>(
+        state,
+        &merchant_account,
+        &payout,
+        None,
+        None,
+    )
+    .await?;
+
+    let response = trigger_payout_to_gateway(
+        state,
+        &merchant_account,
+        &payout,
+        router_data,
+        enums::PayoutStatus::Failed,
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+// ********************************************** PAYOUT CANCEL **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_cancel_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+    validate_payout_status(
+        &payout,
+        enums::PayoutStatus::RequiresFulfillment,
+        "cancelled",
+    )?;
+
+    let router_data = core_utils::construct_payout_router_data::<api::PoCancel>(
+        state,
+        &merchant_account,
+        &payout,
+        None,
+        None,
+    )
+    .await?;
+
+    let response = trigger_payout_to_gateway(
+        state,
+        &merchant_account,
+        &payout,
+        router_data,
+        enums::PayoutStatus::RequiresFulfillment,
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+// ********************************************** PAYOUT REVERSE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_reverse_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+    validate_payout_status(&payout, enums::PayoutStatus::Success, "reversed")?;
+
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &payout.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    utils::when(!connector.connector.supports_payout_reverse(), || {
+        Err(report!(errors::ApiErrorResponse::NotImplemented {
+            message: NotImplementedMessage::Reason(format!(
+                "Reversal of payouts made through {}",
+                payout.connector
+            )),
+        }))
+    })?;
+
+    let router_data = core_utils::construct_payout_router_data::<api::PoReverse>(
+        state,
+        &merchant_account,
+        &payout,
+        None,
+        None,
+    )
+    .await?;
+
+    let response = trigger_payout_to_gateway(
+        state,
+        &merchant_account,
+        &payout,
+        router_data,
+        enums::PayoutStatus::Success,
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+/// Payouts awaiting fulfillment can be fulfilled or cancelled, while only payouts which have been
+/// paid out can be reversed.
+fn validate_payout_status(
+    payout: &storage::Payouts,
+    expected_status: enums::PayoutStatus,
+    current_flow: &str,
+) -> RouterResult<()> {
+    utils::when(payout.status != expected_status, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "This payout could not be {current_flow} because it has a status of {}. The expected state is {expected_status}",
+                payout.status,
+            ),
+        }))
+    })
+}
+
+/// Whether the status of a payout can be moved to the status reported by a connector webhook.
+/// Payouts which failed, were cancelled or were reversed are final, while successful payouts can
+/// still be reversed by the recipient's bank.
+pub fn is_valid_payout_status_transition(
+    current_status: enums::PayoutStatus,
+    new_status: enums::PayoutStatus,
+) -> bool {
+    match current_status {
+        enums::PayoutStatus::Pending | enums::PayoutStatus::RequiresFulfillment => !matches!(
+            new_status,
+            enums::PayoutStatus::Pending | enums::PayoutStatus::RequiresFulfillment
+        ),
+        enums::PayoutStatus::Success => new_status == enums::PayoutStatus::Reversed,
+        enums::PayoutStatus::Failed
+        | enums::PayoutStatus::Cancelled
+        | enums::PayoutStatus::Reversed => false,
+    }
+}
+
+// ********************************************** PAYOUT LIST **********************************************
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn payouts_list_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payouts::PayoutListRequest,
+) -> RouterResponse<payouts::PayoutListResponse> {
+    let limit = req.limit.unwrap_or(10);
+    let payouts = state
+        .store
+        .filter_payouts_by_merchant_id(
+            &merchant_account.merchant_id,
+            req.customer_id.as_deref(),
+            Some(limit),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let data: Vec<payouts::PayoutResponse> =
+        payouts.into_iter().map(ForeignInto::foreign_into).collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+// ********************************************** PAYOUT ACCOUNTS **********************************************
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn payouts_accounts_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+) -> RouterResponse<payouts::PayoutAccountsResponse> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_list(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let data: Vec<payouts::PayoutAccount> = merchant_connector_accounts
+        .into_iter()
+        .filter(|merchant_connector_account| {
+            api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &merchant_connector_account.connector_name,
+                api::GetToken::Connector,
+            )
+            .map(|connector| connector.connector.supports_payouts())
+            .unwrap_or(false)
+        })
+        .map(|merchant_connector_account| payouts::PayoutAccount {
+            connector: merchant_connector_account.connector_name,
+            merchant_connector_id: merchant_connector_account.merchant_connector_id,
+            test_mode: merchant_connector_account.test_mode,
+            disabled: merchant_connector_account.disabled,
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutAccountsResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+impl From<Foreign<storage::Payouts>> for Foreign<payouts::PayoutResponse> {
+    fn from(payout: Foreign<storage::Payouts>) -> Self {
+        let payout = payout.0;
+        payouts::PayoutResponse {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            customer_id: payout.customer_id,
+            connector: payout.connector,
+            amount: payout.amount,
+            currency: payout.currency.foreign_into(),
+            payout_type: payout.payout_type.foreign_into(),
+            status: payout.status.foreign_into(),
+            description: payout.description,
+            metadata: payout.metadata,
+            error_message: payout.error_message,
+            error_code: payout.error_code,
+            created_at: Some(payout.created_at),
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn payout(status: enums::PayoutStatus) -> storage::Payouts {
+        let now = common_utils::date_time::now();
+        storage::Payouts {
+            id: 1,
+            payout_id: "payout_mbabizu24mvu3mela5njyhpit4".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            customer_id: None,
+            connector: "adyen".to_string(),
+            connector_payout_id: Some("8816789012345678".to_string()),
+            payout_type: enums::PayoutType::Bank,
+            status,
+            amount: 6540,
+            currency: enums::Currency::EUR,
+            description: None,
+            metadata: None,
+            error_message: None,
+            error_code: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_validate_payout_status() {
+        let requires_fulfillment = payout(enums::PayoutStatus::RequiresFulfillment);
+        assert!(validate_payout_status(
+            &requires_fulfillment,
+            enums::PayoutStatus::RequiresFulfillment,
+            "fulfilled"
+        )
+        .is_ok());
+        assert!(validate_payout_status(
+            &requires_fulfillment,
+            enums::PayoutStatus::Success,
+            "reversed"
+        )
+        .is_err());
+
+        let pending = payout(enums::PayoutStatus::Pending);
+        assert!(validate_payout_status(
+            &pending,
+            enums::PayoutStatus::RequiresFulfillment,
+            "cancelled"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_payout_status_transitions() {
+        use enums::PayoutStatus::*;
+
+        assert!(is_valid_payout_status_transition(Pending, Success));
+        assert!(is_valid_payout_status_transition(Pending, Failed));
+        assert!(is_valid_payout_status_transition(
+            RequiresFulfillment,
+            Cancelled
+        ));
+        assert!(is_valid_payout_status_transition(Success, Reversed));
+
+        // Webhooks which are received again or out of order do not move final payouts
+        assert!(!is_valid_payout_status_transition(Success, Success));
+        assert!(!is_valid_payout_status_transition(Success, Failed));
+        assert!(!is_valid_payout_status_transition(Failed, Success));
+        assert!(!is_valid_payout_status_transition(Cancelled, Success));
+        assert!(!is_valid_payout_status_transition(Reversed, Success));
+    }
+
+    #[test]
+    fn test_payout_response_from_payout() {
+        let mut failed_payout = payout(enums::PayoutStatus::Failed);
+        failed_payout.error_code = Some("HE_00".to_string());
+
+        let response: payouts::PayoutResponse = failed_payout.clone().foreign_into();
+        assert_eq!(response.payout_id, failed_payout.payout_id);
+        assert_eq!(response.status, api::enums::PayoutStatus::Failed);
+        assert_eq!(response.payout_type, api::enums::PayoutType::Bank);
+        assert_eq!(response.error_code.as_deref(), Some("HE_00"));
+        assert_eq!(response.created_at, Some(failed_payout.created_at));
+    }
+}
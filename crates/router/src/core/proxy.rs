@@ -0,0 +1,591 @@
+use std::collections::{HashMap, HashSet};
+
+use error_stack::{report, IntoReport, ResultExt};
+use masking::PeekInterface;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::{cards, vault},
+    },
+    headers,
+    routes::AppState,
+    services,
+    types::{
+        api::proxy,
+        storage::{self, enums},
+    },
+    utils::{self, OptionExt},
+};
+
+/// Placeholder referencing a card field of a payment method, e.g. `{{pm_abc.card_number}}`
+static PLACEHOLDER_REGEX: Lazy<Option<Regex>> =
+    Lazy::new(
+        || match Regex::new(r"\{\{\s*([A-Za-z0-9_]+)\.([a-z_]+)\s*\}\}") {
+            Ok(regex) => Some(regex),
+            Err(error) => {
+                logger::error!(?error);
+                None
+            }
+        },
+    );
+
+/// Headers describing the connection to the destination or the framing of the body. These are set
+/// by the HTTP client for the forwarded request, and are not taken from the merchant's headers.
+const CONNECTION_HEADERS: [&str; 11] = [
+    "connection",
+    "content-length",
+    "expect",
+    "host",
+    "keep-alive",
+    "proxy-authorization",
+    "proxy-connection",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+/// Card field of a payment method which can be referenced by a placeholder
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+enum CardField {
+    CardNumber,
+    CardExpMonth,
+    CardExpYear,
+    CardHolderName,
+    CardCvc,
+}
+
+/// Card data of a payment method, fetched from the vault
+struct VaultedCard {
+    card_number: String,
+    card_exp_month: String,
+    card_exp_year: String,
+    card_holder_name: Option<String>,
+    card_cvc: Option<String>,
+}
+
+impl VaultedCard {
+    fn get_field(&self, field: CardField) -> Option<&str> {
+        match field {
+            CardField::CardNumber => Some(&self.card_number),
+            CardField::CardExpMonth => Some(&self.card_exp_month),
+            CardField::CardExpYear => Some(&self.card_exp_year),
+            CardField::CardHolderName => self.card_holder_name.as_deref(),
+            CardField::CardCvc => self.card_cvc.as_deref(),
+        }
+    }
+}
+
+/// Card data substituted in the forwarded request, to be masked in the response
+#[derive(Default)]
+struct SubstitutedValues {
+    /// Card numbers are masked wherever they appear in the response
+    card_numbers: HashSet<String>,
+    /// The other card fields are short enough to appear in unrelated data, so they are only
+    /// masked when they make up an entire string of the response
+    other_values: HashSet<String>,
+}
+
+impl SubstitutedValues {
+    fn insert(&mut self, field: CardField, value: &str) {
+        if value.is_empty() {
+            return;
+        }
+
+        match field {
+            CardField::CardNumber => self.card_numbers.insert(value.to_owned()),
+            _ => self.other_values.insert(value.to_owned()),
+        };
+    }
+}
+
+// ********************************************** PROXY **********************************************
+
+#[instrument(skip_all)]
+pub async fn proxy_request(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: proxy::ProxyRequest,
+) -> RouterResponse<proxy::ProxyResponse> {
+    let destination_url = validate_destination_url(&req.destination_url, &state.conf.vault_proxy)?;
+    let placeholder_regex = PLACEHOLDER_REGEX
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Invalid placeholder regex")?;
+
+    let mut payment_method_ids = HashSet::new();
+    if let Some(body) = &req.body {
+        collect_payment_method_ids(placeholder_regex, body, &mut payment_method_ids);
+    }
+
+    let mut vaulted_cards = HashMap::new();
+    for payment_method_id in payment_method_ids {
+        let vaulted_card = get_vaulted_card(state, &merchant_account, &payment_method_id).await?;
+        vaulted_cards.insert(payment_method_id, vaulted_card);
+    }
+
+    let mut substituted_values = SubstitutedValues::default();
+    let body = req
+        .body
+        .map(|body| {
+            substitute_placeholders(
+                placeholder_regex,
+                body,
+                &vaulted_cards,
+                &mut substituted_values,
+            )
+        })
+        .transpose()?;
+
+    let request_headers = get_forwarded_headers(req.headers.unwrap_or_default());
+    let has_content_type = request_headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case(headers::CONTENT_TYPE));
+
+    let mut request = services::RequestBuilder::new()
+        .method(match req.method {
+            proxy::ProxyMethod::Get => services::Method::Get,
+            proxy::ProxyMethod::Post => services::Method::Post,
+            proxy::ProxyMethod::Put => services::Method::Put,
+            proxy::ProxyMethod::Delete => services::Method::Delete,
+        })
+        .url(destination_url.as_str())
+        .headers(request_headers);
+    if body.is_some() && !has_content_type {
+        request = request.header(headers::CONTENT_TYPE, "application/json");
+    }
+    let request = request.body(body.as_ref().map(ToString::to_string)).build();
+
+    let response = services::call_connector_api(state, request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to forward the request to the destination")?;
+    // Error responses of the destination are returned to the merchant as they are
+    let response = match response {
+        Ok(response) | Err(response) => response,
+    };
+
+    Ok(services::ApplicationResponse::Json(proxy::ProxyResponse {
+        status_code: response.status_code,
+        response: mask_response(&response.response, &substituted_values),
+    }))
+}
+
+/// Drops the connection headers from the merchant's headers, along with the headers named in
+/// their `Connection` header
+fn get_forwarded_headers(headers: HashMap<String, String>) -> Vec<(String, String)> {
+    let connection_options = headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("connection"))
+        .flat_map(|(_, value)| value.split(','))
+        .map(|option| option.trim().to_ascii_lowercase())
+        .collect::<HashSet<_>>();
+
+    headers
+        .into_iter()
+        .filter(|(name, _)| {
+            let name = name.to_ascii_lowercase();
+            !CONNECTION_HEADERS.contains(&name.as_str()) && !connection_options.contains(&name)
+        })
+        .collect()
+}
+
+fn validate_destination_url(
+    destination_url: &str,
+    vault_proxy: &settings::VaultProxy,
+) -> RouterResult<url::Url> {
+    let destination_url = url::Url::parse(destination_url)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "destination_url".to_string(),
+            expected_format: "URL".to_string(),
+        })?;
+
+    let is_allowed = vault_proxy
+        .allowed_destinations
+        .iter()
+        .filter_map(|allowed_destination| url::Url::parse(allowed_destination).ok())
+        .any(|allowed_destination| is_within_destination(&destination_url, &allowed_destination));
+    utils::when(!is_allowed, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "destination_url is not an allowed destination".to_string(),
+        }))
+    })?;
+
+    Ok(destination_url)
+}
+
+/// Whether the URL has the origin of the allowed destination and is under its path
+fn is_within_destination(url: &url::Url, allowed_destination: &url::Url) -> bool {
+    let allowed_path = allowed_destination.path();
+
+    url.origin() == allowed_destination.origin()
+        && url.username().is_empty()
+        && url.password().is_none()
+        && url.path().strip_prefix(allowed_path).map_or(false, |rest| {
+            allowed_path.ends_with('/') || rest.is_empty() || rest.starts_with('/')
+        })
+}
+
+fn collect_payment_method_ids(
+    placeholder_regex: &Regex,
+    value: &serde_json::Value,
+    payment_method_ids: &mut HashSet<String>,
+) {
+    match value {
+        serde_json::Value::String(string) => {
+            payment_method_ids.extend(
+                placeholder_regex
+                    .captures_iter(string)
+                    .filter_map(|captures| captures.get(1))
+                    .map(|payment_method_id| payment_method_id.as_str().to_owned()),
+            );
+        }
+        serde_json::Value::Array(values) => values.iter().for_each(|value| {
+            collect_payment_method_ids(placeholder_regex, value, payment_method_ids)
+        }),
+        serde_json::Value::Object(map) => map.values().for_each(|value| {
+            collect_payment_method_ids(placeholder_regex, value, payment_method_ids)
+        }),
+        _ => {}
+    }
+}
+
+async fn get_vaulted_card(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_method_id: &str,
+) -> RouterResult<VaultedCard> {
+    let payment_method = state
+        .store
+        .find_payment_method(payment_method_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+    utils::when(
+        payment_method.merchant_id != merchant_account.merchant_id,
+        || Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)),
+    )?;
+    utils::when(
+        payment_method.payment_method != enums::PaymentMethodType::Card,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Payment method {payment_method_id} is not a card"),
+            }))
+        },
+    )?;
+
+    let (get_card_response, card_cvc) = if state.conf.locker.mock_locker {
+        vault::LocalVault::get_card(state, &payment_method.payment_method_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching card from local vault")?
+    } else {
+        let locker_id = merchant_account
+            .locker_id
+            .as_deref()
+            .get_required_value("locker_id")?;
+        let get_card_response =
+            cards::get_card_from_legacy_locker(state, locker_id, &payment_method.payment_method_id)
+                .await?;
+        // The external locker does not keep card security codes
+        (get_card_response, None)
+    };
+
+    let card = get_card_response.card;
+    let missing_card_data = || {
+        report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Card data missing in the vault response")
+    };
+    Ok(VaultedCard {
+        card_number: card
+            .card_number
+            .ok_or_else(missing_card_data)?
+            .peek()
+            .to_owned(),
+        card_exp_month: card
+            .card_exp_month
+            .ok_or_else(missing_card_data)?
+            .peek()
+            .to_owned(),
+        card_exp_year: card
+            .card_exp_year
+            .ok_or_else(missing_card_data)?
+            .peek()
+            .to_owned(),
+        card_holder_name: card.name_on_card.map(|name| name.peek().to_owned()),
+        card_cvc,
+    })
+}
+
+fn substitute_placeholders(
+    placeholder_regex: &Regex,
+    value: serde_json::Value,
+    vaulted_cards: &HashMap<String, VaultedCard>,
+    substituted_values: &mut SubstitutedValues,
+) -> RouterResult<serde_json::Value> {
+    Ok(match value {
+        serde_json::Value::String(string) => serde_json::Value::String(substitute_string(
+            placeholder_regex,
+            &string,
+            vaulted_cards,
+            substituted_values,
+        )?),
+        serde_json::Value::Array(values) => serde_json::Value::Array(
+            values
+                .into_iter()
+                .map(|value| {
+                    substitute_placeholders(
+                        placeholder_regex,
+                        value,
+                        vaulted_cards,
+                        substituted_values,
+                    )
+                })
+                .collect::<RouterResult<_>>()?,
+        ),
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    substitute_placeholders(
+                        placeholder_regex,
+                        value,
+                        vaulted_cards,
+                        substituted_values,
+                    )
+                    .map(|value| (key, value))
+                })
+                .collect::<RouterResult<_>>()?,
+        ),
+        value => value,
+    })
+}
+
+fn substitute_string(
+    placeholder_regex: &Regex,
+    string: &str,
+    vaulted_cards: &HashMap<String, VaultedCard>,
+    substituted_values: &mut SubstitutedValues,
+) -> RouterResult<String> {
+    let mut substituted = String::with_capacity(string.len());
+    let mut last_end = 0;
+
+    for captures in placeholder_regex.captures_iter(string) {
+        let (placeholder, payment_method_id, field) =
+            match (captures.get(0), captures.get(1), captures.get(2)) {
+                (Some(placeholder), Some(payment_method_id), Some(field)) => {
+                    (placeholder, payment_method_id.as_str(), field.as_str())
+                }
+                _ => continue,
+            };
+
+        let field: CardField = field.parse().into_report().change_context(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Unsupported card field {field} in placeholder"),
+            },
+        )?;
+        let value = vaulted_cards
+            .get(payment_method_id)
+            .and_then(|vaulted_card| vaulted_card.get_field(field))
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Card field {field} of payment method {payment_method_id} is not available"
+                    ),
+                })
+            })?;
+
+        substituted.push_str(&string[last_end..placeholder.start()]);
+        substituted.push_str(value);
+        substituted_values.insert(field, value);
+        last_end = placeholder.end();
+    }
+    substituted.push_str(&string[last_end..]);
+
+    Ok(substituted)
+}
+
+/// Masks the card data substituted in the request from the response of the destination. A
+/// response which is not JSON is returned as a string, with card numbers masked.
+fn mask_response(response: &[u8], substituted_values: &SubstitutedValues) -> serde_json::Value {
+    match serde_json::from_slice(response) {
+        Ok(value) => mask_value(value, substituted_values),
+        Err(_) => serde_json::Value::String(mask_card_numbers(
+            &String::from_utf8_lossy(response),
+            substituted_values,
+        )),
+    }
+}
+
+fn mask_value(
+    value: serde_json::Value,
+    substituted_values: &SubstitutedValues,
+) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(string) if substituted_values.other_values.contains(&string) => {
+            serde_json::Value::String(mask(&string))
+        }
+        serde_json::Value::String(string) => {
+            serde_json::Value::String(mask_card_numbers(&string, substituted_values))
+        }
+        serde_json::Value::Number(number)
+            if substituted_values
+                .card_numbers
+                .contains(&number.to_string()) =>
+        {
+            serde_json::Value::String(mask(&number.to_string()))
+        }
+        serde_json::Value::Array(values) => serde_json::Value::Array(
+            values
+                .into_iter()
+                .map(|value| mask_value(value, substituted_values))
+                .collect(),
+        ),
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, mask_value(value, substituted_values)))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+fn mask_card_numbers(string: &str, substituted_values: &SubstitutedValues) -> String {
+    substituted_values
+        .card_numbers
+        .iter()
+        .fold(string.to_owned(), |string, card_number| {
+            string.replace(card_number.as_str(), &mask(card_number))
+        })
+}
+
+fn mask(value: &str) -> String {
+    "*".repeat(value.chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_forwarded_headers() {
+        let headers = HashMap::from([
+            ("Authorization".to_string(), "Bearer sk_vendor".to_string()),
+            ("Host".to_string(), "internal.vendor.com".to_string()),
+            ("content-length".to_string(), "0".to_string()),
+            ("Transfer-Encoding".to_string(), "chunked".to_string()),
+            ("Connection".to_string(), "close, X-Internal".to_string()),
+            ("x-internal".to_string(), "1".to_string()),
+            ("X-Request-Id".to_string(), "req_1".to_string()),
+        ]);
+
+        let mut forwarded_headers = get_forwarded_headers(headers);
+        forwarded_headers.sort();
+        assert_eq!(
+            forwarded_headers,
+            vec![
+                ("Authorization".to_string(), "Bearer sk_vendor".to_string()),
+                ("X-Request-Id".to_string(), "req_1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_within_destination() {
+        let allowed_destination = url::Url::parse("https://api.vendor.com/v1/").unwrap();
+        let is_within =
+            |url: &str| is_within_destination(&url::Url::parse(url).unwrap(), &allowed_destination);
+
+        assert!(is_within("https://api.vendor.com/v1/screen"));
+        assert!(!is_within("https://api.vendor.com/v2/screen"));
+        assert!(!is_within("https://api.vendor.com/v1/../admin"));
+        assert!(!is_within("http://api.vendor.com/v1/screen"));
+        assert!(!is_within("https://api.vendor.com:8443/v1/screen"));
+        assert!(!is_within("https://api.vendor.com.evil.com/v1/screen"));
+        assert!(!is_within("https://user@api.vendor.com/v1/screen"));
+
+        let allowed_destination = url::Url::parse("https://api.vendor.com/v1").unwrap();
+        let url = url::Url::parse("https://api.vendor.com/v10").unwrap();
+        assert!(!is_within_destination(&url, &allowed_destination));
+    }
+
+    #[test]
+    fn test_substitute_placeholders_and_mask_response() {
+        let placeholder_regex = PLACEHOLDER_REGEX.as_ref().unwrap();
+        let vaulted_cards = HashMap::from([(
+            "pm_abc".to_string(),
+            VaultedCard {
+                card_number: "4111111111111111".to_string(),
+                card_exp_month: "03".to_string(),
+                card_exp_year: "2030".to_string(),
+                card_holder_name: Some("John Doe".to_string()),
+                card_cvc: None,
+            },
+        )]);
+        let body = serde_json::json!({
+            "card": {
+                "number": "{{pm_abc.card_number}}",
+                "expiry": "{{ pm_abc.card_exp_month }}/{{pm_abc.card_exp_year}}",
+            },
+            "amount": 6540,
+        });
+
+        let mut payment_method_ids = HashSet::new();
+        collect_payment_method_ids(placeholder_regex, &body, &mut payment_method_ids);
+        assert_eq!(payment_method_ids, HashSet::from(["pm_abc".to_string()]));
+
+        let mut substituted_values = SubstitutedValues::default();
+        let substituted = substitute_placeholders(
+            placeholder_regex,
+            body,
+            &vaulted_cards,
+            &mut substituted_values,
+        )
+        .unwrap();
+        assert_eq!(
+            substituted,
+            serde_json::json!({
+                "card": { "number": "4111111111111111", "expiry": "03/2030" },
+                "amount": 6540,
+            })
+        );
+
+        let cvc_body = serde_json::json!({ "cvc": "{{pm_abc.card_cvc}}" });
+        assert!(substitute_placeholders(
+            placeholder_regex,
+            cvc_body,
+            &vaulted_cards,
+            &mut SubstitutedValues::default(),
+        )
+        .is_err());
+
+        let response = serde_json::json!({
+            "echo": "card 4111111111111111",
+            "year": "2030",
+            "card_number": 4111111111111111_u64,
+            "score": 3,
+        });
+        assert_eq!(
+            mask_response(response.to_string().as_bytes(), &substituted_values),
+            serde_json::json!({
+                "echo": "card ****************",
+                "year": "****",
+                "card_number": "****************",
+                "score": 3,
+            })
+        );
+        assert_eq!(
+            mask_response(b"number=4111111111111111", &substituted_values),
+            serde_json::json!("number=****************")
+        );
+    }
+}
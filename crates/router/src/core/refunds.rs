@@ -36,6 +36,10 @@ pub async fn refund_create_core(
 
     merchant_id = &merchant_account.merchant_id;
 
+    // Refunds of a payment are serialized with its other mutations, so that concurrent refunds
+    // cannot exceed the amount of the payment
+    let payment_lock = services::locking::lock_payment(state, merchant_id, &req.payment_id).await?;
+
     payment_attempt = db
         .find_payment_attempt_last_successful_attempt_by_payment_id_merchant_id(
             &req.payment_id,
@@ -45,17 +49,6 @@ pub async fn refund_create_core(
         .await
         .change_context(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
 
-    // Amount is not passed in request refer from payment attempt.
-    amount = req.amount.unwrap_or(payment_attempt.amount); // [#298]: Need to that capture amount
-                                                           //[#299]: Can we change the flow based on some workflow idea
-    utils::when(amount <= 0, || {
-        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
-            field_name: "amount".to_string(),
-            expected_format: "positive integer".to_string()
-        })
-        .attach_printable("amount less than zero"))
-    })?;
-
     payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
             &req.payment_id,
@@ -65,15 +58,36 @@ pub async fn refund_create_core(
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    // Amount is not passed in request refer from payment attempt, or from the captured amount
+    // of a partially captured payment.
+    amount = req.amount.unwrap_or_else(|| {
+        validator::get_refundable_amount(
+            payment_attempt.amount,
+            payment_intent.status,
+            payment_intent.amount_captured,
+        )
+    }); //[#299]: Can we change the flow based on some workflow idea
+    utils::when(amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than zero"))
+    })?;
+
     utils::when(
-        payment_intent.status != enums::IntentStatus::Succeeded,
+        !matches!(
+            payment_intent.status,
+            enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+        ),
         || {
             Err(report!(errors::ApiErrorResponse::PaymentNotSucceeded)
                 .attach_printable("unable to refund for a unsuccessful payment intent"))
         },
     )?;
 
-    validate_and_create_refund(
+    services::locking::check_payment_lock(&payment_lock).await?;
+    let refund = validate_and_create_refund(
         state,
         &merchant_account,
         &payment_attempt,
@@ -81,8 +95,10 @@ pub async fn refund_create_core(
         amount,
         req,
     )
-    .await
-    .map(services::ApplicationResponse::Json)
+    .await;
+
+    services::locking::unlock_payment(payment_lock).await;
+    refund.map(services::ApplicationResponse::Json)
 }
 
 #[instrument(skip_all)]
@@ -469,7 +485,12 @@ pub async fn validate_and_create_refund(
                 ),
             })?;
 
-            validator::validate_refund_amount(payment_attempt.amount, &all_refunds, refund_amount)
+            let refundable_amount = validator::get_refundable_amount(
+                payment_attempt.amount,
+                payment_intent.status,
+                payment_intent.amount_captured,
+            );
+            validator::validate_refund_amount(refundable_amount, &all_refunds, refund_amount)
                 .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
             validator::validate_maximum_refund_against_payment_attempt(
@@ -560,6 +581,23 @@ pub async fn refund_list(
     ))
 }
 
+/// Whether the status of a refund can be moved to the status reported by a connector webhook.
+/// Refunds which succeeded or failed are final, so that a webhook delivered out of order does not
+/// overwrite the outcome of the refund.
+pub fn is_valid_refund_status_transition(
+    current_status: enums::RefundStatus,
+    new_status: enums::RefundStatus,
+) -> bool {
+    match current_status {
+        enums::RefundStatus::Pending | enums::RefundStatus::ManualReview => {
+            !matches!(new_status, enums::RefundStatus::Pending)
+        }
+        enums::RefundStatus::Success
+        | enums::RefundStatus::Failure
+        | enums::RefundStatus::TransactionFailure => false,
+    }
+}
+
 impl From<Foreign<storage::Refund>> for Foreign<api::RefundResponse> {
     fn from(refund: Foreign<storage::Refund>) -> Self {
         let refund = refund.0;
@@ -950,3 +988,45 @@ pub async fn retry_refund_sync_task(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refund_status_transitions() {
+        use enums::RefundStatus::*;
+
+        assert!(is_valid_refund_status_transition(Pending, Success));
+        assert!(is_valid_refund_status_transition(Pending, Failure));
+        assert!(is_valid_refund_status_transition(ManualReview, Success));
+
+        // A failure delivered after the success of the refund is ignored
+        assert!(!is_valid_refund_status_transition(Success, Failure));
+        assert!(!is_valid_refund_status_transition(Success, Success));
+        assert!(!is_valid_refund_status_transition(Failure, Success));
+        assert!(!is_valid_refund_status_transition(
+            TransactionFailure,
+            Success
+        ));
+        assert!(!is_valid_refund_status_transition(Pending, Pending));
+    }
+
+    #[test]
+    fn test_refundable_amount_of_partially_captured_payment() {
+        let refundable_amount = validator::get_refundable_amount(
+            10000,
+            enums::IntentStatus::PartiallyCaptured,
+            Some(4000),
+        );
+        assert_eq!(refundable_amount, 4000);
+        assert!(validator::validate_refund_amount(refundable_amount, &[], 4000).is_ok());
+        // The part of the authorization which was not captured cannot be refunded
+        assert!(validator::validate_refund_amount(refundable_amount, &[], 4001).is_err());
+
+        assert_eq!(
+            validator::get_refundable_amount(10000, enums::IntentStatus::Succeeded, Some(10000)),
+            10000
+        );
+    }
+}
@@ -65,6 +65,19 @@ pub fn validate_refund_amount(
     )
 }
 
+/// The amount of a payment which can be refunded. Only the captured amount of a partially captured
+/// payment can be refunded, the rest of its authorization never having been charged.
+pub fn get_refundable_amount(
+    payment_attempt_amount: i64,
+    intent_status: enums::IntentStatus,
+    amount_captured: Option<i64>,
+) -> i64 {
+    match intent_status {
+        enums::IntentStatus::PartiallyCaptured => amount_captured.unwrap_or_default(),
+        _ => payment_attempt_amount,
+    }
+}
+
 #[instrument(skip_all)]
 pub fn validate_payment_order_age(
     created_at: &PrimitiveDateTime,
@@ -0,0 +1,435 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::utils as pt_utils,
+    services,
+    types::{
+        api::{self, subscriptions},
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils::{self, generate_id, Encode},
+};
+
+const SUBSCRIPTION_WORKFLOW_RUNNER: &str = "SUBSCRIPTION_WORKFLOW";
+const SUBSCRIPTION_WORKFLOW_TASK: &str = "SUBSCRIPTION_PAYMENT";
+
+// ********************************************** SUBSCRIPTION CREATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn create_subscription(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: subscriptions::SubscriptionCreateRequest,
+) -> RouterResponse<subscriptions::SubscriptionResponse> {
+    let db = &*state.store;
+    let current_time = common_utils::date_time::now();
+    validate_subscription_create_request(&req, current_time)?;
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
+    validate_subscription_mandate(&mandate, &req)?;
+
+    let status = match req.trial_end {
+        Some(_) => storage_enums::SubscriptionStatus::Trialing,
+        None => storage_enums::SubscriptionStatus::Active,
+    };
+
+    let subscription_new = storage::SubscriptionNew {
+        subscription_id: generate_id(consts::ID_LENGTH, "sub"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: req.customer_id,
+        mandate_id: req.mandate_id,
+        status,
+        amount: req.amount,
+        currency: req.currency.foreign_into(),
+        billing_interval: req.interval.foreign_into(),
+        interval_count: req.interval_count.unwrap_or(1),
+        trial_end: req.trial_end,
+        end_date: req.end_date,
+        // The customer is first charged at the end of the trial, or right away without a trial
+        current_period_start: current_time,
+        current_period_end: req.trial_end.unwrap_or(current_time),
+        description: req.description,
+        metadata: req.metadata,
+    };
+
+    let subscription = db
+        .insert_subscription(subscription_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting the subscription")?;
+
+    add_subscription_task(db, &subscription).await?;
+
+    trigger_subscription_webhook(
+        state,
+        &merchant_account,
+        storage_enums::EventType::SubscriptionCreated,
+        subscription.clone(),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription.foreign_into(),
+    ))
+}
+
+fn validate_subscription_create_request(
+    req: &subscriptions::SubscriptionCreateRequest,
+    current_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string(),
+        }))
+    })?;
+
+    utils::when(
+        req.interval_count
+            .map(|interval_count| interval_count <= 0)
+            .unwrap_or(false),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "interval_count".to_string(),
+                expected_format: "positive integer".to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(
+        req.trial_end
+            .map(|trial_end| trial_end <= current_time)
+            .unwrap_or(false),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "trial_end".to_string(),
+                expected_format: "time in the future".to_string(),
+            }))
+        },
+    )?;
+
+    let first_billing_time = req.trial_end.unwrap_or(current_time);
+    utils::when(
+        req.end_date
+            .map(|end_date| end_date <= first_billing_time)
+            .unwrap_or(false),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "end_date".to_string(),
+                expected_format: "time after the trial end".to_string(),
+            }))
+        },
+    )
+}
+
+fn validate_subscription_mandate(
+    mandate: &storage::Mandate,
+    req: &subscriptions::SubscriptionCreateRequest,
+) -> RouterResult<()> {
+    let failure_reason = if mandate.customer_id != req.customer_id {
+        Some("mandate does not belong to the customer")
+    } else if mandate.mandate_status != storage_enums::MandateStatus::Active {
+        Some("mandate is not active")
+    } else if mandate.mandate_type != storage_enums::MandateType::MultiUse {
+        Some("subscriptions require a multi use mandate")
+    } else if mandate
+        .mandate_currency
+        .map(|currency| currency != req.currency.foreign_into())
+        .unwrap_or(false)
+    {
+        Some("cross currency mandates not supported")
+    } else if mandate
+        .mandate_amount
+        .map(|mandate_amount| req.amount > mandate_amount)
+        .unwrap_or(false)
+    {
+        Some("subscription amount is greater than mandate amount")
+    } else {
+        None
+    };
+
+    match failure_reason {
+        Some(reason) => Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: reason.to_string(),
+        })),
+        None => Ok(()),
+    }
+}
+
+// ********************************************** SUBSCRIPTION RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    subscription_id: String,
+) -> RouterResponse<subscriptions::SubscriptionResponse> {
+    let subscription =
+        find_subscription(&*state.store, &merchant_account, &subscription_id).await?;
+    Ok(services::ApplicationResponse::Json(
+        subscription.foreign_into(),
+    ))
+}
+
+async fn find_subscription(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    subscription_id: &str,
+) -> RouterResult<storage::Subscription> {
+    db.find_subscription_by_merchant_id_subscription_id(
+        &merchant_account.merchant_id,
+        subscription_id,
+    )
+    .await
+    .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound))
+}
+
+// ********************************************** SUBSCRIPTION CANCEL **********************************************
+
+#[instrument(skip_all)]
+pub async fn cancel_subscription(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    subscription_id: String,
+) -> RouterResponse<subscriptions::SubscriptionResponse> {
+    let db = &*state.store;
+    let subscription = find_subscription(db, &merchant_account, &subscription_id).await?;
+
+    utils::when(
+        matches!(
+            subscription.status,
+            storage_enums::SubscriptionStatus::Cancelled | storage_enums::SubscriptionStatus::Ended
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "This subscription cannot be cancelled because it is {}",
+                    subscription.status
+                ),
+            }))
+        },
+    )?;
+
+    // The billing task of the subscription finishes on its next run, as cancelled subscriptions
+    // are no longer billed
+    let subscription = db
+        .update_subscription(
+            subscription,
+            storage::SubscriptionUpdate::StatusUpdate {
+                status: storage_enums::SubscriptionStatus::Cancelled,
+                cancelled_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed while updating the status of subscription {subscription_id}")
+        })?;
+
+    trigger_subscription_webhook(
+        state,
+        &merchant_account,
+        storage_enums::EventType::SubscriptionCancelled,
+        subscription.clone(),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription.foreign_into(),
+    ))
+}
+
+// ********************************************** SUBSCRIPTION WORKFLOW **********************************************
+
+/// Schedules the billing task of the subscription at the end of its current period. The same
+/// task is rescheduled at the end of every period, and retried when the payment of a period
+/// fails.
+#[instrument(skip_all)]
+pub async fn add_subscription_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+) -> RouterResult<()> {
+    let tracking_data = storage::SubscriptionTrackingData {
+        merchant_id: subscription.merchant_id.clone(),
+        subscription_id: subscription.subscription_id.clone(),
+        pending_payment_id: None,
+    };
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: pt_utils::get_process_tracker_id(
+            SUBSCRIPTION_WORKFLOW_RUNNER,
+            SUBSCRIPTION_WORKFLOW_TASK,
+            &subscription.subscription_id,
+            &subscription.merchant_id,
+        ),
+        name: Some(String::from(SUBSCRIPTION_WORKFLOW_TASK)),
+        tag: vec![String::from("SUBSCRIPTION"), String::from("PAYMENT")],
+        runner: Some(String::from(SUBSCRIPTION_WORKFLOW_RUNNER)),
+        retry_count: 0,
+        schedule_time: Some(subscription.current_period_end),
+        rule: String::new(),
+        tracking_data: Encode::<storage::SubscriptionTrackingData>::encode_to_value(&tracking_data)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        business_status: String::from("Pending"),
+        status: storage_enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: subscription_id: {}",
+                subscription.subscription_id
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Computes the end of the period following the one ending at `period_end`. Monthly and yearly
+/// periods end on the day of the month of `billing_anchor`, or on the last day of the month for
+/// shorter months, so that billing does not drift after a short month.
+pub fn get_next_billing_time(
+    billing_anchor: PrimitiveDateTime,
+    period_end: PrimitiveDateTime,
+    interval: storage_enums::SubscriptionInterval,
+    interval_count: i32,
+) -> Option<PrimitiveDateTime> {
+    match interval {
+        storage_enums::SubscriptionInterval::Day => {
+            period_end.checked_add(time::Duration::days(i64::from(interval_count)))
+        }
+        storage_enums::SubscriptionInterval::Week => {
+            period_end.checked_add(time::Duration::weeks(i64::from(interval_count)))
+        }
+        storage_enums::SubscriptionInterval::Month => {
+            add_months(billing_anchor.day(), period_end, interval_count)
+        }
+        storage_enums::SubscriptionInterval::Year => add_months(
+            billing_anchor.day(),
+            period_end,
+            interval_count.checked_mul(12)?,
+        ),
+    }
+}
+
+fn add_months(
+    anchor_day: u8,
+    date_time: PrimitiveDateTime,
+    months: i32,
+) -> Option<PrimitiveDateTime> {
+    let month_index = date_time
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(date_time.month())) - 1)?
+        .checked_add(months)?;
+    let year = month_index.div_euclid(12);
+    let month = time::Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = anchor_day.min(time::util::days_in_year_month(year, month));
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+
+    Some(PrimitiveDateTime::new(date, date_time.time()))
+}
+
+/// Creates the event for a change of the subscription and sends its webhook from the API flows,
+/// which run on an actix arbiter. Failures are logged, as the change of the subscription has
+/// already been made.
+async fn trigger_subscription_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event_type: storage_enums::EventType,
+    subscription: storage::Subscription,
+) {
+    let subscription_id = subscription.subscription_id.clone();
+    let subscription_response: subscriptions::SubscriptionResponse = subscription.foreign_into();
+
+    if let Err(error) = webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        event_type,
+        storage_enums::EventClass::Subscriptions,
+        None,
+        subscription_id.clone(),
+        storage_enums::EventObjectType::SubscriptionDetails,
+        api::OutgoingWebhookContent::SubscriptionDetails(Box::new(subscription_response)),
+    )
+    .await
+    {
+        logger::error!(?error, %subscription_id, "Failed to send the webhook of the subscription");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_get_next_billing_time() {
+        let anchor = datetime!(2023-01-31 10:00);
+
+        assert_eq!(
+            get_next_billing_time(anchor, anchor, storage_enums::SubscriptionInterval::Day, 3),
+            Some(datetime!(2023-02-03 10:00))
+        );
+        assert_eq!(
+            get_next_billing_time(anchor, anchor, storage_enums::SubscriptionInterval::Week, 2),
+            Some(datetime!(2023-02-14 10:00))
+        );
+        assert_eq!(
+            get_next_billing_time(
+                anchor,
+                anchor,
+                storage_enums::SubscriptionInterval::Month,
+                1
+            ),
+            Some(datetime!(2023-02-28 10:00))
+        );
+        // Billing returns to the day of the anchor after a short month
+        assert_eq!(
+            get_next_billing_time(
+                anchor,
+                datetime!(2023-02-28 10:00),
+                storage_enums::SubscriptionInterval::Month,
+                1
+            ),
+            Some(datetime!(2023-03-31 10:00))
+        );
+        assert_eq!(
+            get_next_billing_time(
+                anchor,
+                datetime!(2023-11-30 10:00),
+                storage_enums::SubscriptionInterval::Month,
+                3
+            ),
+            Some(datetime!(2024-02-29 10:00))
+        );
+        assert_eq!(
+            get_next_billing_time(
+                datetime!(2024-02-29 10:00),
+                datetime!(2024-02-29 10:00),
+                storage_enums::SubscriptionInterval::Year,
+                1
+            ),
+            Some(datetime!(2025-02-28 10:00))
+        );
+    }
+}
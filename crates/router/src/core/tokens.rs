@@ -0,0 +1,298 @@
+use common_utils::date_time;
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::vault,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{api::tokens, storage, transformers::ForeignInto},
+    utils::{self, generate_id, StringExt},
+};
+
+const DATA_TYPE_MAX_LENGTH: usize = 64;
+
+// ********************************************** TOKEN CREATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn create_token(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: tokens::TokenCreateRequest,
+) -> RouterResponse<tokens::TokenResponse> {
+    let db = &*state.store;
+    let ttl = validate_token_create_request(&req, &state.conf.local_vault)?;
+    let current_time = date_time::now();
+
+    // Expired tokens are never detokenized again, purge those of the merchant before adding a
+    // new one so that sensitive data is not kept around longer than requested
+    if let Err(error) = db
+        .delete_expired_data_tokens_by_merchant_id(&merchant_account.merchant_id, current_time)
+        .await
+    {
+        logger::error!(?error, "Failed to delete expired data tokens");
+    }
+
+    let data = utils::Encode::<serde_json::Value>::encode_to_string_of_json(req.data.peek())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the data to tokenize")?;
+    let (encrypted_data_key, encrypted_data) =
+        vault::LocalVault::encrypt_with_data_key(state, &data)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the data to tokenize")?;
+
+    let data_token_new = storage::DataTokenNew {
+        token_id: generate_id(consts::ID_LENGTH, "tok"),
+        merchant_id: merchant_account.merchant_id,
+        data_type: req.data_type,
+        single_use: req.usage == tokens::TokenUsage::SingleUse,
+        encrypted_data_key,
+        encrypted_data,
+        expires_at: current_time.saturating_add(time::Duration::seconds(ttl)),
+    };
+
+    let data_token = db
+        .insert_data_token(data_token_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert data token")?;
+
+    Ok(services::ApplicationResponse::Json(
+        data_token.foreign_into(),
+    ))
+}
+
+/// Validates the token create request, returning the TTL of the token
+fn validate_token_create_request(
+    req: &tokens::TokenCreateRequest,
+    local_vault: &settings::LocalVault,
+) -> RouterResult<i64> {
+    utils::when(
+        req.data_type
+            .as_ref()
+            .map(|data_type| data_type.len() > DATA_TYPE_MAX_LENGTH)
+            .unwrap_or(false),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "data_type".to_string(),
+                expected_format: format!("string of at most {DATA_TYPE_MAX_LENGTH} characters"),
+            }))
+        },
+    )?;
+
+    let ttl = req.ttl.unwrap_or(local_vault.data_token_ttl);
+    utils::when(ttl <= 0 || ttl > local_vault.data_token_max_ttl, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "ttl".to_string(),
+            expected_format: format!(
+                "integer between 1 and {} seconds",
+                local_vault.data_token_max_ttl
+            ),
+        }))
+    })?;
+
+    Ok(ttl)
+}
+
+// ********************************************** TOKEN RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn retrieve_token(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    token_id: String,
+) -> RouterResponse<tokens::TokenResponse> {
+    let data_token = find_data_token(&*state.store, &merchant_account, &token_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        data_token.foreign_into(),
+    ))
+}
+
+async fn find_data_token(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    token_id: &str,
+) -> RouterResult<storage::DataToken> {
+    let data_token = db
+        .find_data_token_by_merchant_id_token_id(&merchant_account.merchant_id, token_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::TokenNotFound))?;
+
+    utils::when(data_token.expires_at <= date_time::now(), || {
+        Err(report!(errors::ApiErrorResponse::TokenNotFound))
+            .attach_printable("Data token has expired")
+    })?;
+
+    Ok(data_token)
+}
+
+// ********************************************** TOKEN DETOKENIZE **********************************************
+
+#[instrument(skip_all)]
+pub async fn detokenize(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    token_id: String,
+) -> RouterResponse<tokens::DetokenizeResponse> {
+    let db = &*state.store;
+    let data_token = find_data_token(db, &merchant_account, &token_id).await?;
+
+    // A single use token is claimed by deleting it, so that concurrent requests cannot both
+    // detokenize it
+    let data_token = if data_token.single_use {
+        db.delete_data_token_by_merchant_id_token_id(&merchant_account.merchant_id, &token_id)
+            .await
+            .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::TokenNotFound))?
+    } else {
+        data_token
+    };
+
+    let data: serde_json::Value = vault::LocalVault::decrypt_with_data_key(
+        state,
+        data_token.encrypted_data_key,
+        data_token.encrypted_data,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the tokenized data")?
+    .parse_struct("TokenizedData")
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(services::ApplicationResponse::Json(
+        tokens::DetokenizeResponse {
+            token_id: data_token.token_id,
+            data_type: data_token.data_type,
+            data: Secret::new(data),
+        },
+    ))
+}
+
+// ********************************************** TOKEN DELETE **********************************************
+
+#[instrument(skip_all)]
+pub async fn delete_token(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    token_id: String,
+) -> RouterResponse<tokens::TokenDeleteResponse> {
+    let data_token = state
+        .store
+        .delete_data_token_by_merchant_id_token_id(&merchant_account.merchant_id, &token_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::TokenNotFound))?;
+
+    Ok(services::ApplicationResponse::Json(
+        tokens::TokenDeleteResponse {
+            token_id: data_token.token_id,
+            deleted: true,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, types::storage::enums};
+
+    fn token_create_request(ttl: Option<i64>) -> tokens::TokenCreateRequest {
+        tokens::TokenCreateRequest {
+            data: Secret::new(serde_json::json!({ "account_number": "000123456789" })),
+            data_type: Some("bank_account_number".to_string()),
+            ttl,
+            usage: tokens::TokenUsage::SingleUse,
+        }
+    }
+
+    #[test]
+    fn test_validate_token_create_request() {
+        let local_vault = settings::LocalVault::default();
+
+        assert_eq!(
+            validate_token_create_request(&token_create_request(None), &local_vault).unwrap(),
+            local_vault.data_token_ttl
+        );
+        assert_eq!(
+            validate_token_create_request(&token_create_request(Some(60)), &local_vault).unwrap(),
+            60
+        );
+        assert!(
+            validate_token_create_request(&token_create_request(Some(0)), &local_vault).is_err()
+        );
+        assert!(validate_token_create_request(
+            &token_create_request(Some(local_vault.data_token_max_ttl + 1)),
+            &local_vault
+        )
+        .is_err());
+    }
+
+    fn merchant_account() -> storage::MerchantAccount {
+        storage::MerchantAccount {
+            id: 1,
+            merchant_id: "merchant_1".to_string(),
+            api_key: None,
+            return_url: None,
+            enable_payment_response_hash: false,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: false,
+            merchant_name: None,
+            merchant_details: None,
+            webhook_details: None,
+            sub_merchants_enabled: None,
+            parent_merchant_id: None,
+            publishable_key: None,
+            storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
+            locker_id: None,
+            metadata: None,
+            routing_algorithm: None,
+            webhook_signing_secret: None,
+            previous_webhook_signing_secret: None,
+            previous_webhook_signing_secret_expires_at: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_single_use_token_is_detokenized_once() {
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.local_vault.key_file = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../config/development_vault_key.hex"
+        )
+        .to_string();
+        let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+
+        let token_id = match create_token(&state, merchant_account(), token_create_request(None))
+            .await
+            .unwrap()
+        {
+            services::ApplicationResponse::Json(response) => response.token_id,
+            _ => panic!("Unexpected response for token create"),
+        };
+
+        match detokenize(&state, merchant_account(), token_id.clone())
+            .await
+            .unwrap()
+        {
+            services::ApplicationResponse::Json(response) => assert_eq!(
+                response.data.peek(),
+                &serde_json::json!({ "account_number": "000123456789" })
+            ),
+            _ => panic!("Unexpected response for detokenize"),
+        }
+
+        assert!(matches!(
+            detokenize(&state, merchant_account(), token_id)
+                .await
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::TokenNotFound
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use common_utils::pii::Email;
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 
@@ -9,7 +10,7 @@ use crate::{
     core::errors::{self, RouterResult},
     routes::AppState,
     types::{
-        self,
+        self, api,
         storage::{self, enums},
     },
     utils::{generate_id, OptionExt, ValueExt},
@@ -87,6 +88,338 @@ pub async fn construct_refund_router_data<'a, F>(
     Ok(router_data)
 }
 
+#[instrument(skip_all)]
+pub async fn construct_payout_router_data<'a, F>(
+    state: &'a AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &'a storage::Payouts,
+    payout_method_data: Option<api::PayoutMethodData>,
+    email: Option<masking::Secret<String, Email>>,
+) -> RouterResult<types::PayoutsRouterData<F>> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            &payout.connector,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method = match payout.payout_type {
+        enums::PayoutType::Card => enums::PaymentMethodType::Card,
+        enums::PayoutType::Bank => enums::PaymentMethodType::BankTransfer,
+    };
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name,
+        // Payouts are not linked to a payment, the payout ID is used to identify the transaction
+        payment_id: payout.payout_id.clone(),
+        attempt_id: None,
+        status: enums::AttemptStatus::Pending,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: payout.description.clone(),
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::NoThreeDs,
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: None,
+        request: types::PayoutsData {
+            payout_id: payout.payout_id.clone(),
+            amount: payout.amount,
+            currency: payout.currency,
+            payout_type: payout.payout_type,
+            payout_method_data,
+            connector_payout_id: payout.connector_payout_id.clone(),
+            customer_id: payout.customer_id.clone(),
+            email,
+        },
+        // Connectors which do not implement the payout flows leave the response untouched
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        access_token: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_accept_dispute_router_data<'a>(
+    state: &'a AppState,
+    payment_intent: &'a storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    merchant_account: &storage::MerchantAccount,
+    dispute: &storage::Dispute,
+) -> RouterResult<types::AcceptDisputeRouterData> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            &dispute.connector,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method_type = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: dispute.connector.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: Some(payment_attempt.attempt_id.clone()),
+        status: payment_attempt.status,
+        payment_method: payment_method_type,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: payment_intent.return_url.clone(),
+        router_return_url: None,
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: payment_intent.amount_captured,
+        request: types::AcceptDisputeRequestData {
+            dispute_id: dispute.dispute_id.clone(),
+            connector_dispute_id: dispute.connector_dispute_id.clone(),
+        },
+        // Connectors which do not implement the accept flow leave the response untouched
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        access_token: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_submit_evidence_router_data<'a>(
+    state: &'a AppState,
+    payment_intent: &'a storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    merchant_account: &storage::MerchantAccount,
+    connector_id: &str,
+    submit_evidence_request_data: types::SubmitEvidenceRequestData,
+) -> RouterResult<types::SubmitEvidenceRouterData> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            connector_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method_type = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: connector_id.to_string(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: Some(payment_attempt.attempt_id.clone()),
+        status: payment_attempt.status,
+        payment_method: payment_method_type,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: payment_intent.return_url.clone(),
+        router_return_url: None,
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: payment_intent.amount_captured,
+        request: submit_evidence_request_data,
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        access_token: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_defend_dispute_router_data<'a>(
+    state: &'a AppState,
+    payment_intent: &'a storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    merchant_account: &storage::MerchantAccount,
+    dispute: &storage::Dispute,
+    evidence_response: types::SubmitEvidenceResponse,
+) -> RouterResult<types::DefendDisputeRouterData> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            &dispute.connector,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method_type = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: dispute.connector.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: Some(payment_attempt.attempt_id.clone()),
+        status: payment_attempt.status,
+        payment_method: payment_method_type,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: payment_intent.return_url.clone(),
+        router_return_url: None,
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: payment_intent.amount_captured,
+        request: types::DefendDisputeRequestData {
+            dispute_id: dispute.dispute_id.clone(),
+            connector_dispute_id: dispute.connector_dispute_id.clone(),
+        },
+        // Connectors which submit the evidence in a single step leave the response untouched
+        response: Ok(types::DefendDisputeResponse {
+            dispute_status: evidence_response.dispute_status,
+            connector_status: evidence_response.connector_status,
+        }),
+        access_token: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_upload_file_router_data<'a>(
+    state: &'a AppState,
+    payment_intent: &'a storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    merchant_account: &storage::MerchantAccount,
+    connector_id: &str,
+    upload_file_request_data: types::UploadFileRequestData,
+) -> RouterResult<types::UploadFileRouterData> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            connector_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method_type = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: connector_id.to_string(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: Some(payment_attempt.attempt_id.clone()),
+        status: payment_attempt.status,
+        payment_method: payment_method_type,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: payment_intent.return_url.clone(),
+        router_return_url: None,
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: payment_intent.amount_captured,
+        request: upload_file_request_data,
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        access_token: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_mandate_revoke_router_data(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: &storage::Mandate,
+    payment_method: enums::PaymentMethodType,
+    connector_mandate_id: String,
+) -> RouterResult<types::MandateRevokeRouterData> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            &mandate.connector,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: mandate.connector.clone(),
+        // Mandates are revoked outside of a payment, the mandate ID is used to identify the request
+        payment_id: mandate.mandate_id.clone(),
+        attempt_id: None,
+        status: enums::AttemptStatus::Pending,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: Some(mandate.payment_method_id.clone()),
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::NoThreeDs,
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: None,
+        request: types::MandateRevokeRequestData {
+            mandate_id: mandate.mandate_id.clone(),
+            connector_mandate_id,
+        },
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        access_token: None,
+    };
+
+    Ok(router_data)
+}
+
 pub fn get_or_generate_id(
     key: &str,
     provided_id: &Option<String>,
@@ -1,19 +1,21 @@
 pub mod transformers;
 pub mod utils;
 
+use common_utils::crypto::{self, SignMessage};
 use error_stack::{IntoReport, ResultExt};
-use masking::ExposeInterface;
+use masking::{ExposeInterface, PeekInterface};
 use router_env::{instrument, tracing};
 
 use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResponse},
-        payments,
+        payments, payouts, refunds,
     },
     db::StorageInterface,
     logger,
     routes::AppState,
+    scheduler::{utils as pt_utils, workflows::outgoing_webhook_retry},
     services,
     types::{
         api,
@@ -24,6 +26,11 @@ use crate::{
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_MS: u64 = 5000;
+const OUTGOING_WEBHOOK_RESPONSE_BODY_MAX_LENGTH: usize = 1024;
+const OUTGOING_WEBHOOK_SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+const OUTGOING_WEBHOOK_TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+const OUTGOING_WEBHOOK_RETRY_RUNNER: &str = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
 
 #[instrument(skip_all)]
 async fn payments_incoming_webhook_flow(
@@ -92,7 +99,279 @@ async fn payments_incoming_webhook_flow(
 
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
-async fn create_event_and_trigger_outgoing_webhook(
+async fn refunds_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    connector: &(dyn api::Connector + Sync),
+    request_body: &[u8],
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let db = &*state.store;
+    let connector_refund_id = connector
+        .get_webhook_connector_refund_id(request_body)
+        .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?;
+
+    // The object reference id of refund webhooks is the connector transaction id of the payment
+    let refund = db
+        .find_refund_by_merchant_id_connector_transaction_id(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?
+        .into_iter()
+        .find(|refund| refund.connector_refund_id.as_deref() == Some(connector_refund_id.as_str()))
+        .ok_or(errors::WebhooksFlowError::RefundsCoreFailed)
+        .into_report()
+        .attach_printable_lazy(|| {
+            format!("No refund found for the connector refund id {connector_refund_id}")
+        })?;
+
+    let updated_refund = if source_verified {
+        // The refund status of a verified webhook is applied without a sync, since not every
+        // connector supports syncing the status of a refund
+        let refund_status: enums::RefundStatus = event_type
+            .foreign_try_into()
+            .into_report()
+            .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?;
+        if !refunds::is_valid_refund_status_transition(refund.refund_status, refund_status) {
+            logger::info!(
+                current_status = ?refund.refund_status,
+                webhook_status = ?refund_status,
+                "Ignoring refund webhook which does not advance the refund"
+            );
+            return Ok(());
+        }
+        db.update_refund(
+            refund,
+            storage::RefundUpdate::StatusUpdate {
+                connector_refund_id: Some(connector_refund_id),
+                sent_to_gateway: true,
+                refund_status,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?
+    } else {
+        refunds::refund_retrieve_core(&state, merchant_account.clone(), refund.refund_id)
+            .await
+            .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?
+    };
+
+    // Refunds which are still pending after the sync are left to the refund sync task
+    let event_type: enums::EventType = match updated_refund.refund_status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => {
+            logger::info!(refund_status = ?updated_refund.refund_status, "Refund is not final yet");
+            return Ok(());
+        }
+    };
+
+    create_event_and_trigger_outgoing_webhook(
+        state,
+        merchant_account,
+        event_type,
+        enums::EventClass::Refunds,
+        Some(updated_refund.payment_id.clone()),
+        updated_refund.refund_id.clone(),
+        enums::EventObjectType::RefundDetails,
+        api::OutgoingWebhookContent::RefundDetails(updated_refund.foreign_into()),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+async fn get_or_update_dispute_object(
+    state: &AppState,
+    option_dispute: Option<storage::Dispute>,
+    dispute_details: api::disputes::DisputePayload,
+    merchant_id: &str,
+    payment_attempt: &storage::PaymentAttempt,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+    connector_name: &str,
+) -> CustomResult<storage::Dispute, errors::WebhooksFlowError> {
+    let db = &*state.store;
+    let dispute_status: enums::DisputeStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+
+    match option_dispute {
+        None => {
+            let dispute_new = storage::DisputeNew {
+                dispute_id: generate_id(consts::ID_LENGTH, "dp"),
+                amount: dispute_details.amount,
+                currency: payment_attempt
+                    .currency
+                    .get_required_value("currency")
+                    .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?,
+                dispute_stage: dispute_details.dispute_stage.foreign_into(),
+                dispute_status,
+                payment_id: payment_attempt.payment_id.to_owned(),
+                attempt_id: payment_attempt.attempt_id.to_owned(),
+                merchant_id: merchant_id.to_owned(),
+                connector: connector_name.to_owned(),
+                connector_status: dispute_details.connector_status,
+                connector_dispute_id: dispute_details.connector_dispute_id,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+                dispute_created_at: dispute_details.created_at,
+                updated_at: dispute_details.updated_at,
+            };
+            db.insert_dispute(dispute_new)
+                .await
+                .change_context(errors::WebhooksFlowError::DisputeCoreFailed)
+        }
+        Some(dispute) => {
+            logger::info!("Dispute already exists, updating the dispute details");
+            let dispute_update = storage::DisputeUpdate::Update {
+                dispute_stage: dispute_details.dispute_stage.foreign_into(),
+                dispute_status,
+                connector_status: dispute_details.connector_status,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+                updated_at: dispute_details.updated_at,
+            };
+            db.update_dispute(dispute, dispute_update)
+                .await
+                .change_context(errors::WebhooksFlowError::DisputeCoreFailed)
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn disputes_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    connector: &(dyn api::Connector + Sync),
+    request_body: &[u8],
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+    connector_name: &str,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    // Disputes are only created from webhooks whose source could be verified, since unlike
+    // payments there is no connector sync call to confirm the state of the dispute
+    if !source_verified {
+        logger::error!("Webhook source verification failed for dispute webhook flow");
+        Err(errors::WebhooksFlowError::DisputeCoreFailed).into_report()?;
+    }
+
+    let db = &*state.store;
+    let dispute_details = connector
+        .get_dispute_details(request_body)
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+
+    let option_dispute = db
+        .find_by_merchant_id_payment_id_connector_dispute_id(
+            &merchant_account.merchant_id,
+            &payment_attempt.payment_id,
+            &dispute_details.connector_dispute_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+
+    let dispute_object = get_or_update_dispute_object(
+        &state,
+        option_dispute,
+        dispute_details,
+        &merchant_account.merchant_id,
+        &payment_attempt,
+        event_type,
+        connector_name,
+    )
+    .await?;
+
+    let disputes_response: api::disputes::DisputeResponse = dispute_object.clone().foreign_into();
+    let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
+
+    create_event_and_trigger_outgoing_webhook(
+        state,
+        merchant_account,
+        event_type,
+        enums::EventClass::Disputes,
+        Some(dispute_object.payment_id),
+        dispute_object.dispute_id,
+        enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(disputes_response)),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+async fn payouts_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    // The final state of a payout is only known from the connector webhook, since there is no
+    // connector sync call for payouts, webhooks whose source could not be verified are rejected
+    if !source_verified {
+        logger::error!("Webhook source verification failed for payout webhook flow");
+        Err(errors::WebhooksFlowError::PayoutsCoreFailed).into_report()?;
+    }
+
+    let db = &*state.store;
+    let payout = db
+        .find_payout_by_merchant_id_connector_payout_id(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)?;
+
+    let payout_status: enums::PayoutStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)?;
+
+    if !payouts::is_valid_payout_status_transition(payout.status, payout_status) {
+        logger::info!(
+            current_status = ?payout.status,
+            webhook_status = ?payout_status,
+            "Ignoring payout webhook which does not advance the payout"
+        );
+        return Ok(());
+    }
+
+    db.update_payout(
+        payout,
+        storage::PayoutsUpdate::StatusUpdate {
+            connector_payout_id: None,
+            status: payout_status,
+        },
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     state: AppState,
     merchant_account: storage::MerchantAccount,
     event_type: enums::EventType,
@@ -102,21 +381,16 @@ async fn create_event_and_trigger_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let new_event = storage::EventNew {
-        event_id: generate_id(consts::ID_LENGTH, "evt"),
+    let event = insert_outgoing_webhook_event(
+        &*state.store,
+        &merchant_account.merchant_id,
         event_type,
         event_class,
-        is_webhook_notified: false,
         intent_reference_id,
         primary_object_id,
         primary_object_type,
-    };
-
-    let event = state
-        .store
-        .insert_event(new_event)
-        .await
-        .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)?;
+    )
+    .await?;
 
     if state.conf.webhooks.outgoing_enabled {
         let arbiter = actix::Arbiter::try_current()
@@ -125,7 +399,7 @@ async fn create_event_and_trigger_outgoing_webhook(
 
         let outgoing_webhook = api::OutgoingWebhook {
             merchant_id: merchant_account.merchant_id.clone(),
-            event_id: event.event_id,
+            event_id: event.event_id.clone(),
             event_type: event.event_type.foreign_into(),
             content,
             timestamp: event.created_at,
@@ -133,7 +407,8 @@ async fn create_event_and_trigger_outgoing_webhook(
 
         arbiter.spawn(async move {
             let result =
-                trigger_webhook_to_merchant(merchant_account, outgoing_webhook, state.store).await;
+                trigger_webhook_to_merchant(&state, &merchant_account, event, outgoing_webhook)
+                    .await;
 
             if let Err(e) = result {
                 logger::error!(?e);
@@ -144,13 +419,146 @@ async fn create_event_and_trigger_outgoing_webhook(
     Ok(())
 }
 
+/// Creates the event for a payment whose status was changed by the scheduler, and delivers its
+/// webhook to the merchant in the current task, since the scheduler does not run on an actix
+/// arbiter. Payments whose status has no corresponding event are skipped.
+#[instrument(skip_all)]
+pub async fn create_event_and_send_payment_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payments_response: api::PaymentsResponse,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let event_type: enums::EventType = match payments_response.status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => return Ok(()),
+    };
+
+    let payment_id = payments_response
+        .payment_id
+        .clone()
+        .get_required_value("payment_id")
+        .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
+
+    create_event_and_send_outgoing_webhook(
+        state,
+        merchant_account,
+        event_type,
+        enums::EventClass::Payments,
+        None,
+        payment_id,
+        enums::EventObjectType::PaymentDetails,
+        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+    )
+    .await
+}
+
+/// Creates the event and delivers its webhook to the merchant in the current task, for flows
+/// which do not run on an actix arbiter, such as the workflows of the scheduler.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn create_event_and_send_outgoing_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    intent_reference_id: Option<String>,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let event = insert_outgoing_webhook_event(
+        &*state.store,
+        &merchant_account.merchant_id,
+        event_type,
+        event_class,
+        intent_reference_id,
+        primary_object_id,
+        primary_object_type,
+    )
+    .await?;
+
+    if state.conf.webhooks.outgoing_enabled {
+        let outgoing_webhook = api::OutgoingWebhook {
+            merchant_id: merchant_account.merchant_id.clone(),
+            event_id: event.event_id.clone(),
+            event_type: event.event_type.foreign_into(),
+            content,
+            timestamp: event.created_at,
+        };
+
+        trigger_webhook_to_merchant(state, merchant_account, event, outgoing_webhook).await?;
+    }
+
+    Ok(())
+}
+
+async fn insert_outgoing_webhook_event(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    intent_reference_id: Option<String>,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+) -> CustomResult<storage::Event, errors::WebhooksFlowError> {
+    let new_event = storage::EventNew {
+        event_id: generate_id(consts::ID_LENGTH, "evt"),
+        event_type,
+        event_class,
+        is_webhook_notified: false,
+        intent_reference_id,
+        primary_object_id,
+        primary_object_type,
+        merchant_id: Some(merchant_id.to_string()),
+    };
+
+    db.insert_event(new_event)
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)
+}
+
 async fn trigger_webhook_to_merchant(
-    merchant_account: storage::MerchantAccount,
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event: storage::Event,
     webhook: api::OutgoingWebhook,
-    _db: Box<dyn StorageInterface>,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let webhook = Encode::<api::OutgoingWebhook>::encode_to_value(&webhook)
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)?;
+
+    match send_webhook_to_merchant(&*state.store, merchant_account, &event.event_id, &webhook).await
+    {
+        Ok(()) => mark_event_as_notified(&*state.store, event).await,
+        Err(error) => {
+            if matches!(
+                error.current_context(),
+                errors::WebhooksFlowError::CallToMerchantFailed
+                    | errors::WebhooksFlowError::NotReceivedByMerchant
+            ) {
+                add_outgoing_webhook_retry_task(
+                    state,
+                    &merchant_account.merchant_id,
+                    &event.event_id,
+                    webhook,
+                )
+                .await?;
+            }
+            Err(error)
+        }
+    }
+}
+
+/// Delivers a webhook to the webhook URL configured by the merchant, recording the request and
+/// the response of the merchant as a delivery attempt of the event.
+pub async fn send_webhook_to_merchant(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    event_id: &str,
+    webhook: &serde_json::Value,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_details_json = merchant_account
         .webhook_details
+        .clone()
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
@@ -165,31 +573,193 @@ async fn trigger_webhook_to_merchant(
         .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
         .map(ExposeInterface::expose)?;
 
-    let response = reqwest::Client::new()
+    let payload = serde_json::to_string(webhook)
+        .into_report()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)?;
+
+    let mut request = reqwest::Client::new()
         .post(&webhook_url)
-        .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .json(&webhook)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let signature = get_outgoing_webhook_signature_header(merchant_account, timestamp, &payload)?;
+    request = request
+        .header(OUTGOING_WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+        .header(OUTGOING_WEBHOOK_SIGNATURE_HEADER, signature);
+
+    let started_at = std::time::Instant::now();
+    let response = request
+        .body(payload.clone())
         .timeout(core::time::Duration::from_millis(
             OUTGOING_WEBHOOK_TIMEOUT_MS,
         ))
         .send()
         .await;
+    let latency_ms = i64::try_from(started_at.elapsed().as_millis()).ok();
+
+    let mut delivery_attempt = storage::EventDeliveryAttemptNew {
+        attempt_id: generate_id(consts::ID_LENGTH, "attempt"),
+        event_id: event_id.to_string(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        request_body: payload,
+        response_status_code: None,
+        response_body: None,
+        latency_ms,
+        is_success: false,
+        error_message: None,
+    };
 
-    match response {
+    let result = match response {
         Err(e) => {
-            // [#217]: Schedule webhook for retry.
+            delivery_attempt.error_message = Some(e.to_string());
             Err(e)
                 .into_report()
-                .change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
+                .change_context(errors::WebhooksFlowError::CallToMerchantFailed)
         }
         Ok(res) => {
-            if !res.status().is_success() {
-                // [#217]: Schedule webhook for retry.
-                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
+            let status = res.status();
+            delivery_attempt.response_status_code = Some(i32::from(status.as_u16()));
+            delivery_attempt.response_body = res.text().await.ok().map(|body| {
+                body.chars()
+                    .take(OUTGOING_WEBHOOK_RESPONSE_BODY_MAX_LENGTH)
+                    .collect()
+            });
+            delivery_attempt.is_success = status.is_success();
+
+            if status.is_success() {
+                Ok(())
+            } else {
+                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()
             }
         }
+    };
+
+    if let Err(error) = db.insert_event_delivery_attempt(delivery_attempt).await {
+        logger::error!(?error, "Failed to record the webhook delivery attempt");
     }
 
+    result
+}
+
+/// Computes the signature of an outgoing webhook, the HMAC-SHA512 of `{timestamp}.{payload}`
+/// keyed with the webhook signing secret of the merchant.
+/// Signs the webhook with the signing secret of the merchant. After the secret is rotated, the
+/// webhook is also signed with the previous secret until it expires, the signatures being
+/// separated by a comma.
+fn get_outgoing_webhook_signature_header(
+    merchant_account: &storage::MerchantAccount,
+    timestamp: i64,
+    payload: &str,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let secret = merchant_account
+        .webhook_signing_secret
+        .as_ref()
+        .ok_or(errors::WebhooksFlowError::MerchantWebhookSigningSecretNotFound)
+        .into_report()?;
+    let mut signatures = vec![get_outgoing_webhook_signature(
+        secret.peek(),
+        timestamp,
+        payload,
+    )?];
+
+    let now = common_utils::date_time::now();
+    if let (Some(previous_secret), Some(expires_at)) = (
+        merchant_account.previous_webhook_signing_secret.as_ref(),
+        merchant_account.previous_webhook_signing_secret_expires_at,
+    ) {
+        if now < expires_at {
+            signatures.push(get_outgoing_webhook_signature(
+                previous_secret.peek(),
+                timestamp,
+                payload,
+            )?);
+        }
+    }
+
+    Ok(signatures.join(","))
+}
+
+fn get_outgoing_webhook_signature(
+    secret: &str,
+    timestamp: i64,
+    payload: &str,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let message = format!("{timestamp}.{payload}");
+    let signature = crypto::HmacSha512
+        .sign_message(secret.as_bytes(), message.as_bytes())
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)?;
+
+    Ok(hex::encode(signature))
+}
+
+pub async fn mark_event_as_notified(
+    db: &dyn StorageInterface,
+    event: storage::Event,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    db.update_event(
+        event,
+        storage::EventUpdate::UpdateWebhookNotified {
+            is_webhook_notified: Some(true),
+        },
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::WebhookEventUpdateFailed)?;
+
+    Ok(())
+}
+
+async fn add_outgoing_webhook_retry_task(
+    state: &AppState,
+    merchant_id: &str,
+    event_id: &str,
+    webhook: serde_json::Value,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let schedule_time =
+        match outgoing_webhook_retry::get_webhook_retry_schedule_time(&state.conf.webhooks, 0) {
+            Some(schedule_time) => schedule_time,
+            None => return Ok(()),
+        };
+
+    let tracking_data = storage::OutgoingWebhookTrackingData {
+        merchant_id: merchant_id.to_string(),
+        event_id: event_id.to_string(),
+        webhook,
+    };
+    let tracking_data =
+        Encode::<storage::OutgoingWebhookTrackingData>::encode_to_value(&tracking_data)
+            .change_context(errors::WebhooksFlowError::RetrySchedulingFailed)?;
+
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: pt_utils::get_process_tracker_id(
+            OUTGOING_WEBHOOK_RETRY_RUNNER,
+            OUTGOING_WEBHOOK_RETRY_TASK,
+            event_id,
+            merchant_id,
+        ),
+        name: Some(String::from(OUTGOING_WEBHOOK_RETRY_TASK)),
+        tag: vec![String::from("WEBHOOK")],
+        runner: Some(String::from(OUTGOING_WEBHOOK_RETRY_RUNNER)),
+        retry_count: 0,
+        schedule_time: Some(schedule_time),
+        rule: String::new(),
+        tracking_data,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::WebhooksFlowError::RetrySchedulingFailed)
+        .attach_printable_lazy(|| {
+            format!("Failed to schedule the retry of the webhook for event {event_id}")
+        })?;
+
     Ok(())
 }
 
@@ -266,7 +836,7 @@ pub async fn webhooks_core(
                 )?,
         };
 
-        let flow_type: api::WebhookFlow = event_type.into();
+        let flow_type: api::WebhookFlow = event_type.to_owned().into();
         match flow_type {
             api::WebhookFlow::Payment => payments_incoming_webhook_flow(
                 state.clone(),
@@ -277,6 +847,41 @@ pub async fn webhooks_core(
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Incoming webhook flow for payments failed")?,
+            api::WebhookFlow::Refund => refunds_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                *connector,
+                &decoded_body,
+                event_type,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Incoming webhook flow for refunds failed")?,
+            api::WebhookFlow::Dispute => disputes_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                *connector,
+                &decoded_body,
+                event_type,
+                connector_name,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Incoming webhook flow for disputes failed")?,
+            api::WebhookFlow::Payout => payouts_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                event_type,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Incoming webhook flow for payouts failed")?,
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .into_report()
                 .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
@@ -290,3 +895,97 @@ pub async fn webhooks_core(
 
     Ok(response)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::crypto::VerifySignature;
+
+    use super::*;
+
+    #[test]
+    fn test_outgoing_webhook_signature() {
+        let secret = "whsec_test";
+        let payload = r#"{"event_id":"evt_123"}"#;
+        let signature = get_outgoing_webhook_signature(secret, 1677142800, payload).unwrap();
+
+        let is_verified = crypto::HmacSha512
+            .verify_signature(
+                secret.as_bytes(),
+                &hex::decode(&signature).unwrap(),
+                format!("1677142800.{payload}").as_bytes(),
+            )
+            .unwrap();
+        assert!(is_verified);
+
+        let replayed_signature =
+            get_outgoing_webhook_signature(secret, 1677146400, payload).unwrap();
+        assert_ne!(signature, replayed_signature);
+    }
+
+    fn merchant_account(
+        previous_webhook_signing_secret_expires_at: Option<time::PrimitiveDateTime>,
+    ) -> storage::MerchantAccount {
+        storage::MerchantAccount {
+            id: 1,
+            merchant_id: "merchant_1".to_string(),
+            api_key: None,
+            return_url: None,
+            enable_payment_response_hash: false,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: false,
+            merchant_name: None,
+            merchant_details: None,
+            webhook_details: None,
+            sub_merchants_enabled: None,
+            parent_merchant_id: None,
+            publishable_key: None,
+            storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
+            locker_id: None,
+            metadata: None,
+            routing_algorithm: None,
+            webhook_signing_secret: Some("whsec_new".to_string().into()),
+            previous_webhook_signing_secret: Some("whsec_old".to_string().into()),
+            previous_webhook_signing_secret_expires_at,
+        }
+    }
+
+    #[test]
+    fn test_outgoing_webhook_signature_header_after_rotation() {
+        let payload = r#"{"event_id":"evt_123"}"#;
+        let new_signature =
+            get_outgoing_webhook_signature("whsec_new", 1677142800, payload).unwrap();
+        let old_signature =
+            get_outgoing_webhook_signature("whsec_old", 1677142800, payload).unwrap();
+
+        let expires_at = common_utils::date_time::now().saturating_add(time::Duration::hours(1));
+        let signature_header = get_outgoing_webhook_signature_header(
+            &merchant_account(Some(expires_at)),
+            1677142800,
+            payload,
+        )
+        .unwrap();
+        assert_eq!(signature_header, format!("{new_signature},{old_signature}"));
+
+        let expired_at = common_utils::date_time::now().saturating_sub(time::Duration::hours(1));
+        let signature_header = get_outgoing_webhook_signature_header(
+            &merchant_account(Some(expired_at)),
+            1677142800,
+            payload,
+        )
+        .unwrap();
+        assert_eq!(signature_header, new_signature);
+    }
+
+    #[test]
+    fn test_outgoing_webhook_without_signing_secret_is_not_signed() {
+        let mut merchant_account = merchant_account(None);
+        merchant_account.webhook_signing_secret = None;
+
+        let result = get_outgoing_webhook_signature_header(&merchant_account, 1677142800, "{}");
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::WebhooksFlowError::MerchantWebhookSigningSecretNotFound
+        ));
+    }
+}
@@ -4,7 +4,15 @@ use crate::{
 };
 
 fn default_webhook_config() -> api::MerchantWebhookConfig {
-    std::collections::HashSet::from([api::IncomingWebhookEvent::PaymentIntentSuccess])
+    std::collections::HashSet::from([
+        api::IncomingWebhookEvent::PaymentIntentSuccess,
+        api::IncomingWebhookEvent::RefundSuccess,
+        api::IncomingWebhookEvent::RefundFailure,
+        api::IncomingWebhookEvent::PayoutSuccess,
+        api::IncomingWebhookEvent::PayoutFailure,
+        api::IncomingWebhookEvent::PayoutCancelled,
+        api::IncomingWebhookEvent::PayoutReversed,
+    ])
 }
 
 pub async fn lookup_webhook_event(
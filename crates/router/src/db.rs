@@ -1,22 +1,35 @@
 pub mod address;
 pub mod api_keys;
 pub mod cache;
+pub mod capture;
+pub mod card_vault;
 pub mod configs;
+pub mod connector_health;
 pub mod connector_response;
+pub mod customer_erasure;
 pub mod customers;
+pub mod data_token;
+pub mod dispute;
 pub mod ephemeral_key;
+pub mod event_delivery_attempt;
 pub mod events;
+pub mod file;
+pub mod idempotent_response;
+pub mod incremental_authorization;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod queue;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod subscription;
 
 use std::sync::Arc;
 
@@ -38,26 +51,41 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + capture::CaptureInterface
+    + card_vault::CardVaultInterface
     + configs::ConfigInterface
+    + connector_health::ConnectorHealthInterface
     + connector_response::ConnectorResponseInterface
+    + customer_erasure::CustomerErasureInterface
     + customers::CustomerInterface
+    + data_token::DataTokenInterface
+    + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
+    + event_delivery_attempt::EventDeliveryAttemptInterface
     + events::EventInterface
+    + file::FileMetadataInterface
+    + idempotent_response::IdempotentResponseInterface
+    + incremental_authorization::IncrementalAuthorizationInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
     + merchant_connector_account::ConnectorAccessToken
     + merchant_connector_account::MerchantConnectorAccountInterface
+    + merchant_key_store::MerchantKeyStoreInterface
     + payment_attempt::PaymentAttemptInterface
     + payment_intent::PaymentIntentInterface
     + payment_method::PaymentMethodInterface
+    + payouts::PayoutsInterface
     + process_tracker::ProcessTrackerInterface
     + queue::QueueInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
+    + subscription::SubscriptionInterface
     + 'static
 {
     async fn close(&mut self) {}
+
+    fn get_redis_conn(&self) -> Arc<redis_interface::RedisConnectionPool>;
 }
 
 #[async_trait::async_trait]
@@ -69,6 +97,10 @@ impl StorageInterface for Store {
             .close_connections()
             .await;
     }
+
+    fn get_redis_conn(&self) -> Arc<redis_interface::RedisConnectionPool> {
+        self.redis_conn.clone()
+    }
 }
 
 #[derive(Clone)]
@@ -81,6 +113,13 @@ pub struct MockDb {
     refunds: Arc<Mutex<Vec<storage::Refund>>>,
     processes: Arc<Mutex<Vec<storage::ProcessTracker>>>,
     connector_response: Arc<Mutex<Vec<storage::ConnectorResponse>>>,
+    events: Arc<Mutex<Vec<storage::Event>>>,
+    addresses: Arc<Mutex<Vec<storage::Address>>>,
+    payment_methods: Arc<Mutex<Vec<storage::PaymentMethod>>>,
+    mandates: Arc<Mutex<Vec<storage::Mandate>>>,
+    card_vaults: Arc<Mutex<Vec<storage::CardVault>>>,
+    lockers: Arc<Mutex<Vec<storage::LockerMockUp>>>,
+    data_tokens: Arc<Mutex<Vec<storage::DataToken>>>,
     redis: Arc<redis_interface::RedisConnectionPool>,
 }
 
@@ -95,6 +134,13 @@ impl MockDb {
             refunds: Default::default(),
             processes: Default::default(),
             connector_response: Default::default(),
+            events: Default::default(),
+            addresses: Default::default(),
+            payment_methods: Default::default(),
+            mandates: Default::default(),
+            card_vaults: Default::default(),
+            lockers: Default::default(),
+            data_tokens: Default::default(),
             redis: Arc::new(crate::connection::redis_connection(redis).await),
         }
     }
@@ -109,6 +155,10 @@ impl StorageInterface for MockDb {
             .close_connections()
             .await;
     }
+
+    fn get_redis_conn(&self) -> Arc<redis_interface::RedisConnectionPool> {
+        self.redis.clone()
+    }
 }
 
 pub async fn get_and_deserialize_key<T>(
@@ -31,6 +31,12 @@ pub trait AddressInterface {
         merchant_id: &str,
         address: storage::AddressUpdate,
     ) -> CustomResult<Vec<storage::Address>, errors::StorageError>;
+
+    async fn find_address_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::Address>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -87,6 +93,18 @@ impl AddressInterface for Store {
         .map_err(Into::into)
         .into_report()
     }
+
+    async fn find_address_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Address::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -110,19 +128,68 @@ impl AddressInterface for MockDb {
 
     async fn insert_address(
         &self,
-        _address: storage::AddressNew,
+        address: storage::AddressNew,
     ) -> CustomResult<storage::Address, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut addresses = self.addresses.lock().await;
+        let now = common_utils::date_time::now();
+        let address = storage::Address {
+            #[allow(clippy::as_conversions)]
+            id: addresses.len() as i32,
+            address_id: address.address_id,
+            city: address.city,
+            country: address.country,
+            line1: address.line1,
+            line2: address.line2,
+            line3: address.line3,
+            state: address.state,
+            zip: address.zip,
+            first_name: address.first_name,
+            last_name: address.last_name,
+            phone_number: address.phone_number,
+            country_code: address.country_code,
+            created_at: now,
+            modified_at: now,
+            customer_id: address.customer_id,
+            merchant_id: address.merchant_id,
+        };
+        addresses.push(address.clone());
+        Ok(address)
     }
 
     async fn update_address_by_merchant_id_customer_id(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
-        _address: storage::AddressUpdate,
+        customer_id: &str,
+        merchant_id: &str,
+        address_update: storage::AddressUpdate,
     ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut addresses = self.addresses.lock().await;
+        let address_update: storage::AddressUpdateInternal = address_update.into();
+        let updated_addresses: Vec<_> = addresses
+            .iter_mut()
+            .filter(|address| {
+                address.customer_id == customer_id && address.merchant_id == merchant_id
+            })
+            .map(|address| {
+                *address = address_update.clone().apply_changeset(address.clone());
+                address.clone()
+            })
+            .collect();
+        Ok(updated_addresses)
+    }
+
+    async fn find_address_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
+        let addresses = self.addresses.lock().await;
+
+        Ok(addresses
+            .iter()
+            .filter(|address| {
+                address.customer_id == customer_id && address.merchant_id == merchant_id
+            })
+            .cloned()
+            .collect())
     }
 }
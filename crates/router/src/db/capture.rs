@@ -0,0 +1,96 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CaptureInterface {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn find_captures_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for Store {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        capture
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, capture)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_captures_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Capture::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for MockDb {
+    async fn insert_capture(
+        &self,
+        _capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_capture(
+        &self,
+        _this: storage::Capture,
+        _capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_captures_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
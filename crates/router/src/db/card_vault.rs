@@ -0,0 +1,157 @@
+use error_stack::{report, IntoReport};
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CardVaultInterface {
+    async fn insert_card_vault(
+        &self,
+        new: storage::CardVaultNew,
+    ) -> CustomResult<storage::CardVault, errors::StorageError>;
+
+    async fn find_card_vault_by_card_id(
+        &self,
+        card_id: &str,
+    ) -> CustomResult<storage::CardVault, errors::StorageError>;
+
+    async fn update_card_vault_by_card_id(
+        &self,
+        card_id: &str,
+        card_vault: storage::CardVaultUpdate,
+    ) -> CustomResult<storage::CardVault, errors::StorageError>;
+
+    async fn delete_card_vault_by_card_id(
+        &self,
+        card_id: &str,
+    ) -> CustomResult<storage::CardVault, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CardVaultInterface for Store {
+    async fn insert_card_vault(
+        &self,
+        new: storage::CardVaultNew,
+    ) -> CustomResult<storage::CardVault, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        new.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_card_vault_by_card_id(
+        &self,
+        card_id: &str,
+    ) -> CustomResult<storage::CardVault, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::CardVault::find_by_card_id(&conn, card_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_card_vault_by_card_id(
+        &self,
+        card_id: &str,
+        card_vault: storage::CardVaultUpdate,
+    ) -> CustomResult<storage::CardVault, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::CardVault::update_by_card_id(&conn, card_id, card_vault)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_card_vault_by_card_id(
+        &self,
+        card_id: &str,
+    ) -> CustomResult<storage::CardVault, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::CardVault::delete_by_card_id(&conn, card_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CardVaultInterface for MockDb {
+    async fn insert_card_vault(
+        &self,
+        new: storage::CardVaultNew,
+    ) -> CustomResult<storage::CardVault, errors::StorageError> {
+        let mut card_vaults = self.card_vaults.lock().await;
+        let card_vault = storage::CardVault {
+            #[allow(clippy::as_conversions)]
+            id: card_vaults.len() as i32,
+            card_id: new.card_id,
+            external_id: new.external_id,
+            merchant_id: new.merchant_id,
+            customer_id: new.customer_id,
+            payment_method_id: new.payment_method_id,
+            card_fingerprint: new.card_fingerprint,
+            card_global_fingerprint: new.card_global_fingerprint,
+            encrypted_data_key: new.encrypted_data_key,
+            encrypted_card_data: new.encrypted_card_data,
+            created_at: common_utils::date_time::now(),
+        };
+        card_vaults.push(card_vault.clone());
+        Ok(card_vault)
+    }
+
+    async fn find_card_vault_by_card_id(
+        &self,
+        card_id: &str,
+    ) -> CustomResult<storage::CardVault, errors::StorageError> {
+        let card_vaults = self.card_vaults.lock().await;
+
+        card_vaults
+            .iter()
+            .find(|card_vault| card_vault.card_id == card_id)
+            .cloned()
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })
+    }
+
+    async fn update_card_vault_by_card_id(
+        &self,
+        card_id: &str,
+        card_vault: storage::CardVaultUpdate,
+    ) -> CustomResult<storage::CardVault, errors::StorageError> {
+        let mut card_vaults = self.card_vaults.lock().await;
+
+        let existing = card_vaults
+            .iter_mut()
+            .find(|existing| existing.card_id == card_id)
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        *existing = card_vault.apply_changeset(existing.clone());
+        Ok(existing.clone())
+    }
+
+    async fn delete_card_vault_by_card_id(
+        &self,
+        card_id: &str,
+    ) -> CustomResult<storage::CardVault, errors::StorageError> {
+        let mut card_vaults = self.card_vaults.lock().await;
+
+        let position = card_vaults
+            .iter()
+            .position(|card_vault| card_vault.card_id == card_id)
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        Ok(card_vaults.remove(position))
+    }
+}
@@ -0,0 +1,89 @@
+use error_stack::ResultExt;
+
+use super::{MockDb, Store};
+use crate::core::errors::{self, CustomResult};
+
+const SUCCESS: &str = "1";
+const FAILURE: &str = "0";
+
+#[async_trait::async_trait]
+pub trait ConnectorHealthInterface {
+    /// Records the outcome of a payment in a sliding window holding the latest `window_size`
+    /// outcomes, which expires when no outcome is recorded for `window_ttl` seconds.
+    async fn record_connector_outcome(
+        &self,
+        window_key: &str,
+        is_success: bool,
+        window_size: usize,
+        window_ttl: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Returns the outcomes of the sliding window, latest first.
+    async fn get_connector_outcomes(
+        &self,
+        window_key: &str,
+        window_size: usize,
+    ) -> CustomResult<Vec<bool>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorHealthInterface for Store {
+    async fn record_connector_outcome(
+        &self,
+        window_key: &str,
+        is_success: bool,
+        window_size: usize,
+        window_ttl: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        let outcome = if is_success { SUCCESS } else { FAILURE };
+
+        self.redis_conn
+            .push_to_capped_list_with_expiry(window_key, outcome, window_size, window_ttl)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to record connector outcome")
+    }
+
+    async fn get_connector_outcomes(
+        &self,
+        window_key: &str,
+        window_size: usize,
+    ) -> CustomResult<Vec<bool>, errors::StorageError> {
+        let last_index = i64::try_from(window_size)
+            .unwrap_or(i64::MAX)
+            .saturating_sub(1);
+
+        let outcomes = self
+            .redis_conn
+            .get_list_elements(window_key, 0, last_index)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to get connector outcomes")?;
+
+        Ok(outcomes
+            .iter()
+            .map(|outcome| outcome.as_str() == SUCCESS)
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorHealthInterface for MockDb {
+    async fn record_connector_outcome(
+        &self,
+        _window_key: &str,
+        _is_success: bool,
+        _window_size: usize,
+        _window_ttl: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn get_connector_outcomes(
+        &self,
+        _window_key: &str,
+        _window_size: usize,
+    ) -> CustomResult<Vec<bool>, errors::StorageError> {
+        Ok(Vec::new())
+    }
+}
@@ -0,0 +1,96 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CustomerErasureInterface {
+    async fn insert_customer_erasure(
+        &self,
+        customer_erasure: storage::CustomerErasureNew,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError>;
+
+    async fn find_customer_erasure_by_merchant_id_erasure_id(
+        &self,
+        merchant_id: &str,
+        erasure_id: &str,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError>;
+
+    async fn update_customer_erasure(
+        &self,
+        this: storage::CustomerErasure,
+        customer_erasure: storage::CustomerErasureUpdate,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerErasureInterface for Store {
+    async fn insert_customer_erasure(
+        &self,
+        customer_erasure: storage::CustomerErasureNew,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        customer_erasure
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_customer_erasure_by_merchant_id_erasure_id(
+        &self,
+        merchant_id: &str,
+        erasure_id: &str,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::CustomerErasure::find_by_merchant_id_erasure_id(&conn, merchant_id, erasure_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_customer_erasure(
+        &self,
+        this: storage::CustomerErasure,
+        customer_erasure: storage::CustomerErasureUpdate,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, customer_erasure)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerErasureInterface for MockDb {
+    async fn insert_customer_erasure(
+        &self,
+        _customer_erasure: storage::CustomerErasureNew,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_customer_erasure_by_merchant_id_erasure_id(
+        &self,
+        _merchant_id: &str,
+        _erasure_id: &str,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_customer_erasure(
+        &self,
+        _this: storage::CustomerErasure,
+        _customer_erasure: storage::CustomerErasureUpdate,
+    ) -> CustomResult<storage::CustomerErasure, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
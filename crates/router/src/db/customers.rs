@@ -1,4 +1,5 @@
-use error_stack::IntoReport;
+use error_stack::{report, IntoReport};
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
@@ -149,21 +150,48 @@ impl CustomerInterface for MockDb {
 
     async fn update_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: String,
-        _merchant_id: String,
-        _customer: storage::CustomerUpdate,
+        customer_id: String,
+        merchant_id: String,
+        customer_update: storage::CustomerUpdate,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut customers = self.customers.lock().await;
+        let customer = customers
+            .iter_mut()
+            .find(|customer| {
+                customer.customer_id == customer_id && customer.merchant_id == merchant_id
+            })
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        *customer = customer_update.apply_changeset(customer.clone());
+        Ok(customer.clone())
     }
 
     async fn find_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
+        customer_id: &str,
+        merchant_id: &str,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let customer = self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .find(|customer| {
+                customer.customer_id == customer_id && customer.merchant_id == merchant_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        match customer.name {
+            Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
+            _ => Ok(customer),
+        }
     }
 
     #[allow(clippy::panic)]
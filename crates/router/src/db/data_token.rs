@@ -0,0 +1,161 @@
+use error_stack::{report, IntoReport};
+use storage_models::errors::DatabaseError;
+use time::PrimitiveDateTime;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait DataTokenInterface {
+    async fn insert_data_token(
+        &self,
+        new: storage::DataTokenNew,
+    ) -> CustomResult<storage::DataToken, errors::StorageError>;
+
+    async fn find_data_token_by_merchant_id_token_id(
+        &self,
+        merchant_id: &str,
+        token_id: &str,
+    ) -> CustomResult<storage::DataToken, errors::StorageError>;
+
+    async fn delete_data_token_by_merchant_id_token_id(
+        &self,
+        merchant_id: &str,
+        token_id: &str,
+    ) -> CustomResult<storage::DataToken, errors::StorageError>;
+
+    async fn delete_expired_data_tokens_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        now: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataTokenInterface for Store {
+    async fn insert_data_token(
+        &self,
+        new: storage::DataTokenNew,
+    ) -> CustomResult<storage::DataToken, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        new.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_data_token_by_merchant_id_token_id(
+        &self,
+        merchant_id: &str,
+        token_id: &str,
+    ) -> CustomResult<storage::DataToken, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::DataToken::find_by_merchant_id_token_id(&conn, merchant_id, token_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_data_token_by_merchant_id_token_id(
+        &self,
+        merchant_id: &str,
+        token_id: &str,
+    ) -> CustomResult<storage::DataToken, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::DataToken::delete_by_merchant_id_token_id(&conn, merchant_id, token_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_expired_data_tokens_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        now: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::DataToken::delete_expired_by_merchant_id(&conn, merchant_id, now)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl DataTokenInterface for MockDb {
+    async fn insert_data_token(
+        &self,
+        new: storage::DataTokenNew,
+    ) -> CustomResult<storage::DataToken, errors::StorageError> {
+        let mut data_tokens = self.data_tokens.lock().await;
+        let data_token = storage::DataToken {
+            #[allow(clippy::as_conversions)]
+            id: data_tokens.len() as i32,
+            token_id: new.token_id,
+            merchant_id: new.merchant_id,
+            data_type: new.data_type,
+            single_use: new.single_use,
+            encrypted_data_key: new.encrypted_data_key,
+            encrypted_data: new.encrypted_data,
+            created_at: common_utils::date_time::now(),
+            expires_at: new.expires_at,
+        };
+        data_tokens.push(data_token.clone());
+        Ok(data_token)
+    }
+
+    async fn find_data_token_by_merchant_id_token_id(
+        &self,
+        merchant_id: &str,
+        token_id: &str,
+    ) -> CustomResult<storage::DataToken, errors::StorageError> {
+        let data_tokens = self.data_tokens.lock().await;
+
+        data_tokens
+            .iter()
+            .find(|data_token| {
+                data_token.merchant_id == merchant_id && data_token.token_id == token_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })
+    }
+
+    async fn delete_data_token_by_merchant_id_token_id(
+        &self,
+        merchant_id: &str,
+        token_id: &str,
+    ) -> CustomResult<storage::DataToken, errors::StorageError> {
+        let mut data_tokens = self.data_tokens.lock().await;
+
+        let position = data_tokens
+            .iter()
+            .position(|data_token| {
+                data_token.merchant_id == merchant_id && data_token.token_id == token_id
+            })
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        Ok(data_tokens.remove(position))
+    }
+
+    async fn delete_expired_data_tokens_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        now: PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut data_tokens = self.data_tokens.lock().await;
+
+        let count = data_tokens.len();
+        data_tokens.retain(|data_token| {
+            data_token.merchant_id != merchant_id || data_token.expires_at > now
+        });
+        Ok(data_tokens.len() < count)
+    }
+}
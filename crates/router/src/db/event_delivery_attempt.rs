@@ -0,0 +1,67 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait EventDeliveryAttemptInterface {
+    async fn insert_event_delivery_attempt(
+        &self,
+        attempt: storage::EventDeliveryAttemptNew,
+    ) -> CustomResult<storage::EventDeliveryAttempt, errors::StorageError>;
+
+    /// Returns the delivery attempts of the event, latest first.
+    async fn find_event_delivery_attempts_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::EventDeliveryAttempt>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl EventDeliveryAttemptInterface for Store {
+    async fn insert_event_delivery_attempt(
+        &self,
+        attempt: storage::EventDeliveryAttemptNew,
+    ) -> CustomResult<storage::EventDeliveryAttempt, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        attempt
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_event_delivery_attempts_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::EventDeliveryAttempt>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::EventDeliveryAttempt::filter_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl EventDeliveryAttemptInterface for MockDb {
+    async fn insert_event_delivery_attempt(
+        &self,
+        _attempt: storage::EventDeliveryAttemptNew,
+    ) -> CustomResult<storage::EventDeliveryAttempt, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_event_delivery_attempts_by_event_id(
+        &self,
+        _event_id: &str,
+    ) -> CustomResult<Vec<storage::EventDeliveryAttempt>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -13,6 +13,30 @@ pub trait EventInterface {
         &self,
         event: storage::EventNew,
     ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    /// Returns the events of the merchant, latest first.
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError>;
+
+    async fn update_event(
+        &self,
+        this: storage::Event,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -24,13 +48,120 @@ impl EventInterface for Store {
         let conn = pg_connection(&self.master_pool).await;
         event.insert(&conn).await.map_err(Into::into).into_report()
     }
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Event::find_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Event::find_by_merchant_id_event_id(&conn, merchant_id, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Event::filter_by_merchant_id(&conn, merchant_id, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_event(
+        &self,
+        this: storage::Event,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, event)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
 impl EventInterface for MockDb {
     async fn insert_event(
         &self,
-        _event: storage::EventNew,
+        event: storage::EventNew,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let mut events = self.events.lock().await;
+        let event = storage::Event {
+            #[allow(clippy::as_conversions)]
+            id: events.len() as i32,
+            event_id: event.event_id,
+            event_type: event.event_type,
+            event_class: event.event_class,
+            is_webhook_notified: event.is_webhook_notified,
+            intent_reference_id: event.intent_reference_id,
+            primary_object_id: event.primary_object_id,
+            primary_object_type: event.primary_object_type,
+            created_at: common_utils::date_time::now(),
+            merchant_id: event.merchant_id,
+        };
+        events.push(event.clone());
+        Ok(event)
+    }
+
+    async fn find_event_by_event_id(
+        &self,
+        _event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        _merchant_id: &str,
+        _event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let events = self.events.lock().await;
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        // Events are stored in the order of their creation
+        Ok(events
+            .iter()
+            .rev()
+            .filter(|event| event.merchant_id.as_deref() == Some(merchant_id))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_event(
+        &self,
+        _this: storage::Event,
+        _event: storage::EventUpdate,
     ) -> CustomResult<storage::Event, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
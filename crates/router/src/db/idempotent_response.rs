@@ -0,0 +1,145 @@
+use error_stack::{IntoReport, ResultExt};
+use redis_interface::SetnxReply;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+    utils::Encode,
+};
+
+fn get_idempotency_redis_key(merchant_id: &str, idempotency_key: &str) -> String {
+    format!("idempotency_{merchant_id}_{idempotency_key}")
+}
+
+#[async_trait::async_trait]
+pub trait IdempotentResponseInterface {
+    /// Reserves the idempotency key for a request, returning the record of the key when it was
+    /// already used by an earlier request of the merchant.
+    async fn reserve_idempotency_key(
+        &self,
+        merchant_id: &str,
+        idempotency_key: &str,
+        request_fingerprint: &str,
+    ) -> CustomResult<Option<storage::IdempotencyRecord>, errors::StorageError>;
+
+    /// Stores the response of the request holding the idempotency key, which is replayed to the
+    /// requests made with the key for `ttl` seconds.
+    async fn insert_idempotent_response(
+        &self,
+        response: storage::IdempotentResponseNew,
+        ttl: i64,
+    ) -> CustomResult<storage::IdempotentResponse, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IdempotentResponseInterface for Store {
+    async fn reserve_idempotency_key(
+        &self,
+        merchant_id: &str,
+        idempotency_key: &str,
+        request_fingerprint: &str,
+    ) -> CustomResult<Option<storage::IdempotencyRecord>, errors::StorageError> {
+        let redis_key = get_idempotency_redis_key(merchant_id, idempotency_key);
+        let in_progress_record = storage::IdempotencyRecord {
+            request_fingerprint: request_fingerprint.to_owned(),
+            response_status_code: None,
+            response_body: None,
+        };
+        let serialized_record =
+            Encode::<storage::IdempotencyRecord>::encode_to_vec(&in_progress_record)
+                .change_context(errors::StorageError::SerializationFailed)?;
+
+        let reply = self
+            .redis_conn
+            .set_key_if_not_exist(&redis_key, serialized_record.as_slice())
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to reserve the idempotency key")?;
+
+        match reply {
+            SetnxReply::KeySet => {
+                // Responses which are no longer cached in Redis are looked up in Postgres
+                let conn = pg_connection(&self.master_pool).await;
+                let stored_response: CustomResult<_, errors::StorageError> =
+                    storage::IdempotentResponse::find_by_merchant_id_idempotency_key(
+                        &conn,
+                        merchant_id,
+                        idempotency_key,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report();
+
+                match stored_response {
+                    Ok(stored_response) => {
+                        self.redis_conn
+                            .delete_key(&redis_key)
+                            .await
+                            .change_context(errors::StorageError::KVError)
+                            .attach_printable("Failed to release the idempotency key")?;
+                        Ok(Some(stored_response.into()))
+                    }
+                    Err(error) if error.current_context().is_db_not_found() => Ok(None),
+                    Err(error) => Err(error),
+                }
+            }
+            SetnxReply::KeyNotSet => self
+                .redis_conn
+                .get_and_deserialize_key(&redis_key, "IdempotencyRecord")
+                .await
+                .map(Some)
+                .change_context(errors::StorageError::KVError)
+                .attach_printable("Failed to get the idempotency record"),
+        }
+    }
+
+    async fn insert_idempotent_response(
+        &self,
+        response: storage::IdempotentResponseNew,
+        ttl: i64,
+    ) -> CustomResult<storage::IdempotentResponse, errors::StorageError> {
+        let redis_key = get_idempotency_redis_key(&response.merchant_id, &response.idempotency_key);
+        let serialized_record = Encode::<storage::IdempotencyRecord>::encode_to_vec(
+            &storage::IdempotencyRecord::from(response.clone()),
+        )
+        .change_context(errors::StorageError::SerializationFailed)?;
+
+        let conn = pg_connection(&self.master_pool).await;
+        let stored_response = response
+            .insert(&conn)
+            .await
+            .map_err(errors::StorageError::from)
+            .into_report()?;
+
+        self.redis_conn
+            .set_key_with_expiry(&redis_key, serialized_record.as_slice(), ttl)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to cache the idempotent response")?;
+
+        Ok(stored_response)
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotentResponseInterface for MockDb {
+    async fn reserve_idempotency_key(
+        &self,
+        _merchant_id: &str,
+        _idempotency_key: &str,
+        _request_fingerprint: &str,
+    ) -> CustomResult<Option<storage::IdempotencyRecord>, errors::StorageError> {
+        Ok(None)
+    }
+
+    async fn insert_idempotent_response(
+        &self,
+        _response: storage::IdempotentResponseNew,
+        _ttl: i64,
+    ) -> CustomResult<storage::IdempotentResponse, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,100 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IncrementalAuthorizationInterface {
+    async fn insert_incremental_authorization(
+        &self,
+        authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError>;
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError>;
+
+    async fn find_incremental_authorizations_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncrementalAuthorizationInterface for Store {
+    async fn insert_incremental_authorization(
+        &self,
+        authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        authorization
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, authorization)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_incremental_authorizations_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::IncrementalAuthorization::find_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl IncrementalAuthorizationInterface for MockDb {
+    async fn insert_incremental_authorization(
+        &self,
+        _authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_incremental_authorization(
+        &self,
+        _this: storage::IncrementalAuthorization,
+        _authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_incremental_authorizations_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &str,
+        _payment_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
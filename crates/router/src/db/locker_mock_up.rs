@@ -1,4 +1,5 @@
-use error_stack::IntoReport;
+use error_stack::{report, IntoReport};
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
@@ -19,6 +20,12 @@ pub trait LockerMockUpInterface {
         new: storage::LockerMockUpNew,
     ) -> CustomResult<storage::LockerMockUp, errors::StorageError>;
 
+    async fn find_locker_mock_ups_from_id(
+        &self,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::LockerMockUp>, errors::StorageError>;
+
     async fn delete_locker_mock_up(
         &self,
         card_id: &str,
@@ -46,6 +53,18 @@ impl LockerMockUpInterface for Store {
         new.insert(&conn).await.map_err(Into::into).into_report()
     }
 
+    async fn find_locker_mock_ups_from_id(
+        &self,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::LockerMockUp>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::LockerMockUp::find_from_id(&conn, id, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
     async fn delete_locker_mock_up(
         &self,
         card_id: &str,
@@ -62,25 +81,79 @@ impl LockerMockUpInterface for Store {
 impl LockerMockUpInterface for MockDb {
     async fn find_locker_by_card_id(
         &self,
-        _card_id: &str,
+        card_id: &str,
     ) -> CustomResult<storage::LockerMockUp, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let lockers = self.lockers.lock().await;
+
+        lockers
+            .iter()
+            .find(|locker| locker.card_id == card_id)
+            .cloned()
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })
     }
 
     async fn insert_locker_mock_up(
         &self,
-        _new: storage::LockerMockUpNew,
+        new: storage::LockerMockUpNew,
     ) -> CustomResult<storage::LockerMockUp, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut lockers = self.lockers.lock().await;
+        let locker = storage::LockerMockUp {
+            #[allow(clippy::as_conversions)]
+            id: lockers.len() as i32,
+            card_id: new.card_id,
+            external_id: new.external_id,
+            card_fingerprint: new.card_fingerprint,
+            card_global_fingerprint: new.card_global_fingerprint,
+            merchant_id: new.merchant_id,
+            card_number: new.card_number,
+            card_exp_year: new.card_exp_year,
+            card_exp_month: new.card_exp_month,
+            name_on_card: None,
+            nickname: None,
+            customer_id: new.customer_id,
+            duplicate: None,
+            card_cvc: new.card_cvc,
+            payment_method_id: new.payment_method_id,
+        };
+        lockers.push(locker.clone());
+        Ok(locker)
+    }
+
+    async fn find_locker_mock_ups_from_id(
+        &self,
+        id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::LockerMockUp>, errors::StorageError> {
+        let lockers = self.lockers.lock().await;
+
+        let mut lockers: Vec<_> = lockers
+            .iter()
+            .filter(|locker| locker.id >= id)
+            .cloned()
+            .collect();
+        lockers.sort_by_key(|locker| locker.id);
+        lockers.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(lockers)
     }
 
     async fn delete_locker_mock_up(
         &self,
-        _card_id: &str,
+        card_id: &str,
     ) -> CustomResult<storage::LockerMockUp, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut lockers = self.lockers.lock().await;
+
+        let position = lockers
+            .iter()
+            .position(|locker| locker.card_id == card_id)
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        Ok(lockers.remove(position))
     }
 }
@@ -1,4 +1,5 @@
-use error_stack::IntoReport;
+use error_stack::{report, IntoReport};
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
@@ -28,6 +29,20 @@ pub trait MandateInterface {
         mandate: storage::MandateUpdate,
     ) -> CustomResult<storage::Mandate, errors::StorageError>;
 
+    async fn reserve_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+
+    async fn release_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+
     async fn insert_mandate(
         &self,
         mandate: storage::MandateNew,
@@ -73,6 +88,42 @@ impl MandateInterface for Store {
             .into_report()
     }
 
+    async fn reserve_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Mandate::reserve_usage_by_merchant_id_mandate_id(
+            &conn,
+            merchant_id,
+            mandate_id,
+            amount,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn release_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Mandate::release_usage_by_merchant_id_mandate_id(
+            &conn,
+            merchant_id,
+            mandate_id,
+            amount,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn insert_mandate(
         &self,
         mandate: storage::MandateNew,
@@ -90,20 +141,36 @@ impl MandateInterface for Store {
 impl MandateInterface for MockDb {
     async fn find_mandate_by_merchant_id_mandate_id(
         &self,
-        _merchant_id: &str,
-        _mandate_id: &str,
+        merchant_id: &str,
+        mandate_id: &str,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mandates = self.mandates.lock().await;
+
+        mandates
+            .iter()
+            .find(|mandate| mandate.merchant_id == merchant_id && mandate.mandate_id == mandate_id)
+            .cloned()
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })
     }
 
     async fn find_mandate_by_merchant_id_customer_id(
         &self,
-        _merchant_id: &str,
-        _customer_id: &str,
+        merchant_id: &str,
+        customer_id: &str,
     ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mandates = self.mandates.lock().await;
+
+        Ok(mandates
+            .iter()
+            .filter(|mandate| {
+                mandate.merchant_id == merchant_id && mandate.customer_id == customer_id
+            })
+            .cloned()
+            .collect())
     }
 
     async fn update_mandate_by_merchant_id_mandate_id(
@@ -116,11 +183,94 @@ impl MandateInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn reserve_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        let mut mandates = self.mandates.lock().await;
+        let mandate = mandates
+            .iter_mut()
+            .find(|mandate| {
+                mandate.merchant_id == merchant_id
+                    && mandate.mandate_id == mandate_id
+                    && mandate.max_usage_count.map_or(true, |max_usage_count| {
+                        mandate.usage_count < max_usage_count
+                    })
+                    && mandate.mandate_amount.map_or(true, |mandate_amount| {
+                        mandate.amount_captured.unwrap_or(0) + amount <= mandate_amount
+                    })
+                    && (mandate.mandate_type == storage::enums::MandateType::MultiUse
+                        || mandate.usage_count == 0)
+            })
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        mandate.usage_count += 1;
+        mandate.amount_captured = Some(mandate.amount_captured.unwrap_or(0) + amount);
+        Ok(mandate.clone())
+    }
+
+    async fn release_mandate_usage_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        let mut mandates = self.mandates.lock().await;
+        let mandate = mandates
+            .iter_mut()
+            .find(|mandate| {
+                mandate.merchant_id == merchant_id
+                    && mandate.mandate_id == mandate_id
+                    && mandate.usage_count > 0
+            })
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        mandate.usage_count -= 1;
+        mandate.amount_captured = Some((mandate.amount_captured.unwrap_or(0) - amount).max(0));
+        Ok(mandate.clone())
+    }
+
     async fn insert_mandate(
         &self,
-        _mandate: storage::MandateNew,
+        mandate_new: storage::MandateNew,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut mandates = self.mandates.lock().await;
+        let mandate = storage::Mandate {
+            #[allow(clippy::as_conversions)]
+            id: mandates.len() as i32,
+            mandate_id: mandate_new.mandate_id,
+            customer_id: mandate_new.customer_id,
+            merchant_id: mandate_new.merchant_id,
+            payment_method_id: mandate_new.payment_method_id,
+            mandate_status: mandate_new.mandate_status,
+            mandate_type: mandate_new.mandate_type,
+            customer_accepted_at: mandate_new.customer_accepted_at,
+            customer_ip_address: mandate_new.customer_ip_address,
+            customer_user_agent: mandate_new.customer_user_agent,
+            network_transaction_id: mandate_new.network_transaction_id,
+            previous_attempt_id: mandate_new.previous_attempt_id,
+            created_at: mandate_new
+                .created_at
+                .unwrap_or_else(common_utils::date_time::now),
+            mandate_amount: mandate_new.mandate_amount,
+            mandate_currency: mandate_new.mandate_currency,
+            amount_captured: mandate_new.amount_captured,
+            connector: mandate_new.connector,
+            connector_mandate_id: mandate_new.connector_mandate_id,
+            start_date: mandate_new.start_date,
+            end_date: mandate_new.end_date,
+            max_usage_count: mandate_new.max_usage_count,
+            usage_count: mandate_new.usage_count.unwrap_or(0),
+        };
+        mandates.push(mandate.clone());
+        Ok(mandate)
     }
 }
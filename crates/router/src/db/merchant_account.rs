@@ -215,6 +215,9 @@ impl MerchantAccountInterface for MockDb {
             storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
             locker_id: merchant_account.locker_id,
             metadata: merchant_account.metadata,
+            webhook_signing_secret: merchant_account.webhook_signing_secret,
+            previous_webhook_signing_secret: None,
+            previous_webhook_signing_secret_expires_at: None,
         };
         accounts.push(account.clone());
         Ok(account)
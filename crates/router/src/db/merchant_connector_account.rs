@@ -1,12 +1,12 @@
 use common_utils::ext_traits::{ByteSliceExt, Encode};
 use error_stack::{IntoReport, ResultExt};
-use masking::ExposeInterface;
+use masking::{ExposeInterface, PeekInterface, Secret};
 
-use super::{MockDb, Store};
+use super::{merchant_key_store::MerchantKeyStoreInterface, MockDb, Store};
 use crate::{
     connection::pg_connection,
     core::errors::{self, CustomResult},
-    services::logger,
+    services::{key_manager, logger},
     types::{self, storage},
 };
 
@@ -131,6 +131,105 @@ pub trait MerchantConnectorAccountInterface {
     ) -> CustomResult<bool, errors::StorageError>;
 }
 
+// The connector account details are stored encrypted with the data key of the merchant whose
+// version is recorded in `key_version`, and are decrypted when read. Accounts without a key version
+// were stored before encryption was introduced, and are read as is until they are re-encrypted.
+impl Store {
+    /// Returns the version and the unwrapped latest data key of the merchant, creating the first
+    /// data key of the merchant when it has none yet
+    async fn get_latest_merchant_data_key(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<(i32, Vec<u8>), errors::StorageError> {
+        let key_store = match self
+            .find_latest_merchant_key_store_by_merchant_id(merchant_id)
+            .await
+        {
+            Ok(key_store) => key_store,
+            Err(error) if error.current_context().is_db_not_found() => {
+                match self.create_merchant_key_store(merchant_id).await {
+                    Ok(key_store) => key_store,
+                    // The first data key was created concurrently by another request
+                    Err(error) if error.current_context().is_db_unique_violation() => {
+                        self.find_latest_merchant_key_store_by_merchant_id(merchant_id)
+                            .await?
+                    }
+                    Err(error) => Err(error)?,
+                }
+            }
+            Err(error) => Err(error)?,
+        };
+
+        let key_version = key_store.key_version;
+        let data_key = self.unwrap_merchant_data_key(key_store).await?;
+        Ok((key_version, data_key))
+    }
+
+    /// Returns the unwrapped data key of the merchant with the given version, only reading the key
+    /// store when the key is not cached yet
+    async fn get_merchant_data_key(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+    ) -> CustomResult<Vec<u8>, errors::StorageError> {
+        if let Some(data_key) = self
+            .master_key_manager
+            .get_cached_data_key(merchant_id, key_version)
+        {
+            return Ok(data_key);
+        }
+
+        let key_store = self
+            .find_merchant_key_store_by_merchant_id_key_version(merchant_id, key_version)
+            .await?;
+        self.unwrap_merchant_data_key(key_store).await
+    }
+
+    async fn unwrap_merchant_data_key(
+        &self,
+        key_store: storage::MerchantKeyStore,
+    ) -> CustomResult<Vec<u8>, errors::StorageError> {
+        self.master_key_manager
+            .unwrap_merchant_data_key(key_store)
+            .await
+            .change_context(errors::StorageError::DecryptionFailed)
+            .attach_printable("Failed to unwrap the data key of the merchant")
+    }
+
+    /// Encrypts the connector account details with the latest data key of the merchant, returning
+    /// them along with the version of the key
+    async fn encrypt_connector_account_details(
+        &self,
+        merchant_id: &str,
+        connector_account_details: &Secret<serde_json::Value>,
+    ) -> CustomResult<(Secret<serde_json::Value>, i32), errors::StorageError> {
+        let (key_version, data_key) = self.get_latest_merchant_data_key(merchant_id).await?;
+        let encrypted_details =
+            key_manager::encrypt_value(connector_account_details.peek(), &data_key)
+                .change_context(errors::StorageError::EncryptionFailed)
+                .attach_printable("Failed to encrypt the connector account details")?;
+        Ok((Secret::new(encrypted_details), key_version))
+    }
+
+    async fn decrypt_merchant_connector_account(
+        &self,
+        mut merchant_connector_account: storage::MerchantConnectorAccount,
+    ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+        if let Some(key_version) = merchant_connector_account.key_version {
+            let data_key = self
+                .get_merchant_data_key(&merchant_connector_account.merchant_id, key_version)
+                .await?;
+            merchant_connector_account.connector_account_details = key_manager::decrypt_value(
+                merchant_connector_account.connector_account_details,
+                &data_key,
+            )
+            .change_context(errors::StorageError::DecryptionFailed)
+            .attach_printable("Failed to decrypt the connector account details")?;
+        }
+        Ok(merchant_connector_account)
+    }
+}
+
 #[async_trait::async_trait]
 impl MerchantConnectorAccountInterface for Store {
     async fn find_merchant_connector_account_by_merchant_id_connector(
@@ -139,14 +238,17 @@ impl MerchantConnectorAccountInterface for Store {
         connector: &str,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await;
-        storage::MerchantConnectorAccount::find_by_merchant_id_connector(
-            &conn,
-            merchant_id,
-            connector,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        let merchant_connector_account =
+            storage::MerchantConnectorAccount::find_by_merchant_id_connector(
+                &conn,
+                merchant_id,
+                connector,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+        self.decrypt_merchant_connector_account(merchant_connector_account)
+            .await
     }
 
     async fn find_by_merchant_connector_account_merchant_id_merchant_connector_id(
@@ -165,23 +267,38 @@ impl MerchantConnectorAccountInterface for Store {
             .map_err(Into::into)
             .into_report()
         };
+        // The cache holds the encrypted account, which is decrypted once read
         #[cfg(not(feature = "accounts_cache"))]
-        {
-            find_call().await
-        }
+        let merchant_connector_account = find_call().await?;
 
         #[cfg(feature = "accounts_cache")]
-        {
-            super::cache::get_or_populate_cache(self, merchant_connector_id, find_call).await
-        }
+        let merchant_connector_account =
+            super::cache::get_or_populate_cache(self, merchant_connector_id, find_call).await?;
+
+        self.decrypt_merchant_connector_account(merchant_connector_account)
+            .await
     }
 
     async fn insert_merchant_connector_account(
         &self,
-        t: storage::MerchantConnectorAccountNew,
+        mut t: storage::MerchantConnectorAccountNew,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+        if let Some(connector_account_details) = &t.connector_account_details {
+            let merchant_id = t
+                .merchant_id
+                .as_deref()
+                .ok_or_else(|| errors::StorageError::ValueNotFound("merchant_id".to_string()))?;
+            let (encrypted_details, key_version) = self
+                .encrypt_connector_account_details(merchant_id, connector_account_details)
+                .await?;
+            t.connector_account_details = Some(encrypted_details);
+            t.key_version = Some(key_version);
+        }
+
         let conn = pg_connection(&self.master_pool).await;
-        t.insert(&conn).await.map_err(Into::into).into_report()
+        let merchant_connector_account = t.insert(&conn).await.map_err(Into::into).into_report()?;
+        self.decrypt_merchant_connector_account(merchant_connector_account)
+            .await
     }
 
     async fn find_merchant_connector_account_by_merchant_id_list(
@@ -189,10 +306,17 @@ impl MerchantConnectorAccountInterface for Store {
         merchant_id: &str,
     ) -> CustomResult<Vec<storage::MerchantConnectorAccount>, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await;
-        storage::MerchantConnectorAccount::find_by_merchant_id(&conn, merchant_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        let merchant_connector_accounts =
+            storage::MerchantConnectorAccount::find_by_merchant_id(&conn, merchant_id)
+                .await
+                .map_err(Into::into)
+                .into_report()?;
+        futures::future::try_join_all(
+            merchant_connector_accounts
+                .into_iter()
+                .map(|account| self.decrypt_merchant_connector_account(account)),
+        )
+        .await
     }
 
     async fn update_merchant_connector_account(
@@ -200,6 +324,43 @@ impl MerchantConnectorAccountInterface for Store {
         this: storage::MerchantConnectorAccount,
         merchant_connector_account: storage::MerchantConnectorAccountUpdate,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
+        let merchant_connector_account = match merchant_connector_account {
+            storage::MerchantConnectorAccountUpdate::Update {
+                merchant_id,
+                connector_type,
+                connector_name,
+                connector_account_details: Some(connector_account_details),
+                test_mode,
+                disabled,
+                merchant_connector_id,
+                payment_methods_enabled,
+                metadata,
+                authorization_expiry_days,
+                key_version: _,
+            } => {
+                let (encrypted_details, key_version) = self
+                    .encrypt_connector_account_details(
+                        &this.merchant_id,
+                        &connector_account_details,
+                    )
+                    .await?;
+                storage::MerchantConnectorAccountUpdate::Update {
+                    merchant_id,
+                    connector_type,
+                    connector_name,
+                    connector_account_details: Some(encrypted_details),
+                    test_mode,
+                    disabled,
+                    merchant_connector_id,
+                    payment_methods_enabled,
+                    metadata,
+                    authorization_expiry_days,
+                    key_version: Some(key_version),
+                }
+            }
+            update => update,
+        };
+
         let _merchant_connector_id = this.merchant_connector_id.clone();
         let update_call = || async {
             let conn = pg_connection(&self.master_pool).await;
@@ -210,14 +371,14 @@ impl MerchantConnectorAccountInterface for Store {
         };
 
         #[cfg(feature = "accounts_cache")]
-        {
-            super::cache::redact_cache(self, &_merchant_connector_id, update_call).await
-        }
+        let updated_merchant_connector_account =
+            super::cache::redact_cache(self, &_merchant_connector_id, update_call).await?;
 
         #[cfg(not(feature = "accounts_cache"))]
-        {
-            update_call().await
-        }
+        let updated_merchant_connector_account = update_call().await?;
+
+        self.decrypt_merchant_connector_account(updated_merchant_connector_account)
+            .await
     }
 
     async fn delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
@@ -283,6 +444,8 @@ impl MerchantConnectorAccountInterface for MockDb {
             merchant_connector_id: t.merchant_connector_id,
             payment_methods_enabled: t.payment_methods_enabled,
             metadata: t.metadata,
+            authorization_expiry_days: t.authorization_expiry_days,
+            key_version: t.key_version,
             connector_type: t
                 .connector_type
                 .unwrap_or(crate::types::storage::enums::ConnectorType::FinOperations),
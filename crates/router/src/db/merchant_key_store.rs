@@ -0,0 +1,116 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    services::key_manager,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait MerchantKeyStoreInterface {
+    /// Generates a new data key for the merchant, and stores it wrapped by the master key as the
+    /// latest version of the data keys of the merchant
+    async fn create_merchant_key_store(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError>;
+
+    async fn find_merchant_key_store_by_merchant_id_key_version(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError>;
+
+    async fn find_latest_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyStoreInterface for Store {
+    async fn create_merchant_key_store(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let latest_key_version = match self
+            .find_latest_merchant_key_store_by_merchant_id(merchant_id)
+            .await
+        {
+            Ok(key_store) => key_store.key_version,
+            Err(error) if error.current_context().is_db_not_found() => 0,
+            Err(error) => Err(error)?,
+        };
+
+        let encrypted_key = self
+            .master_key_manager
+            .wrap_data_key(&key_manager::generate_data_key())
+            .await
+            .change_context(errors::StorageError::EncryptionFailed)
+            .attach_printable("Failed to wrap the data key of the merchant")?;
+
+        let conn = pg_connection(&self.master_pool).await;
+        storage::MerchantKeyStoreNew {
+            merchant_id: merchant_id.to_string(),
+            key_version: latest_key_version + 1,
+            encrypted_key,
+        }
+        .insert(&conn)
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_merchant_key_store_by_merchant_id_key_version(
+        &self,
+        merchant_id: &str,
+        key_version: i32,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::MerchantKeyStore::find_by_merchant_id_key_version(&conn, merchant_id, key_version)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_latest_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::MerchantKeyStore::find_latest_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyStoreInterface for MockDb {
+    async fn create_merchant_key_store(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_merchant_key_store_by_merchant_id_key_version(
+        &self,
+        _merchant_id: &str,
+        _key_version: i32,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_latest_merchant_key_store_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
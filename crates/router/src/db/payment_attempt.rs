@@ -244,6 +244,8 @@ impl PaymentAttemptInterface for MockDb {
             payment_token: None,
             error_code: payment_attempt.error_code,
             connector_metadata: None,
+            routing_algorithm_version: payment_attempt.routing_algorithm_version,
+            card_fingerprint: payment_attempt.card_fingerprint,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -383,6 +385,10 @@ mod storage {
                         payment_token: payment_attempt.payment_token.clone(),
                         error_code: payment_attempt.error_code.clone(),
                         connector_metadata: payment_attempt.connector_metadata.clone(),
+                        routing_algorithm_version: payment_attempt
+                            .routing_algorithm_version
+                            .clone(),
+                        card_fingerprint: payment_attempt.card_fingerprint.clone(),
                     };
 
                     let field = format!("pa_{}", created_attempt.attempt_id);
@@ -607,7 +613,7 @@ mod storage {
             )
             .await
             .and_then(|attempt| match attempt.status {
-                enums::AttemptStatus::Charged => Ok(attempt),
+                enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged => Ok(attempt),
                 _ => Err(errors::StorageError::ValueNotFound(format!(
                     "Successful payment attempt does not exist for {payment_id}_{merchant_id}"
                 )))
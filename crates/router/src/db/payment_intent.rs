@@ -28,6 +28,13 @@ pub trait PaymentIntentInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentIntent, errors::StorageError>;
 
+    async fn find_payment_intent_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
+
     #[cfg(feature = "olap")]
     async fn filter_payment_intent_by_constraints(
         &self,
@@ -92,6 +99,7 @@ mod storage {
                         setup_future_usage: new.setup_future_usage,
                         off_session: new.off_session,
                         client_secret: new.client_secret.clone(),
+                        request_incremental_authorization: new.request_incremental_authorization,
                     };
 
                     match self
@@ -118,6 +126,18 @@ mod storage {
                                 },
                             )
                             .await?;
+                            if let Some(customer_id) = created_intent.customer_id.as_deref() {
+                                self.redis_conn
+                                    .add_member_to_set(
+                                        &get_customer_payment_ids_key(
+                                            &created_intent.merchant_id,
+                                            customer_id,
+                                        ),
+                                        &created_intent.payment_id,
+                                    )
+                                    .await
+                                    .change_context(errors::StorageError::KVError)?;
+                            }
                             Ok(created_intent)
                         }
                         Err(error) => Err(error.change_context(errors::StorageError::KVError)),
@@ -158,6 +178,23 @@ mod storage {
                         .map(|_| updated_intent)
                         .change_context(errors::StorageError::KVError)?;
 
+                    if let Some(customer_id) = updated_intent
+                        .customer_id
+                        .as_deref()
+                        .filter(|customer_id| this.customer_id.as_deref() != Some(customer_id))
+                    {
+                        self.redis_conn
+                            .add_member_to_set(
+                                &get_customer_payment_ids_key(
+                                    &updated_intent.merchant_id,
+                                    customer_id,
+                                ),
+                                &updated_intent.payment_id,
+                            )
+                            .await
+                            .change_context(errors::StorageError::KVError)?;
+                    }
+
                     let redis_entry = kv::TypedSql {
                         op: kv::DBOperation::Update {
                             updatable: kv::Updateable::PaymentIntentUpdate(
@@ -229,6 +266,70 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => Err(errors::StorageError::KVError.into()),
             }
         }
+
+        async fn find_payment_intent_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            let drained_intents =
+                PaymentIntent::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()?;
+
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => Ok(drained_intents),
+
+                enums::MerchantStorageScheme::RedisKv => {
+                    // Payments which have not been drained to the database yet are only known to
+                    // redis, and the ones in redis are more recent than the ones in the database
+                    let mut payment_ids = self
+                        .redis_conn
+                        .get_set_members(&get_customer_payment_ids_key(merchant_id, customer_id))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+                    payment_ids.extend(
+                        drained_intents
+                            .into_iter()
+                            .map(|payment_intent| payment_intent.payment_id),
+                    );
+                    payment_ids.sort();
+                    payment_ids.dedup();
+
+                    let mut payment_intents = Vec::with_capacity(payment_ids.len());
+                    for payment_id in payment_ids {
+                        match self
+                            .find_payment_intent_by_payment_id_merchant_id(
+                                &payment_id,
+                                merchant_id,
+                                storage_scheme,
+                            )
+                            .await
+                        {
+                            Ok(payment_intent)
+                                if payment_intent.customer_id.as_deref() == Some(customer_id) =>
+                            {
+                                payment_intents.push(payment_intent)
+                            }
+                            Ok(_) => {}
+                            Err(error) if error.current_context().is_db_not_found() => {}
+                            Err(error) => return Err(error),
+                        }
+                    }
+                    Ok(payment_intents)
+                }
+            }
+        }
+    }
+
+    /// Set of the payments of the customer which were stored in redis. The set expires
+    /// `default_hash_ttl` after its latest member was added, by when the members have been
+    /// drained to the database.
+    fn get_customer_payment_ids_key(merchant_id: &str, customer_id: &str) -> String {
+        format!("{merchant_id}_{customer_id}_payment_ids")
     }
 }
 
@@ -296,6 +397,19 @@ mod storage {
                 .map_err(Into::into)
                 .into_report()
         }
+
+        async fn find_payment_intent_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            PaymentIntent::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
 }
 
@@ -344,6 +458,7 @@ impl PaymentIntentInterface for MockDb {
             setup_future_usage: new.setup_future_usage,
             off_session: new.off_session,
             client_secret: new.client_secret,
+            request_incremental_authorization: new.request_incremental_authorization,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
@@ -384,4 +499,22 @@ impl PaymentIntentInterface for MockDb {
             .cloned()
             .unwrap())
     }
+
+    async fn find_payment_intent_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+
+        Ok(payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && payment_intent.customer_id.as_deref() == Some(customer_id)
+            })
+            .cloned()
+            .collect())
+    }
 }
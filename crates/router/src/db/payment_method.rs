@@ -1,4 +1,5 @@
-use error_stack::IntoReport;
+use error_stack::{report, IntoReport};
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
@@ -20,6 +21,13 @@ pub trait PaymentMethodInterface {
         merchant_id: &str,
     ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
 
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
     async fn insert_payment_method(
         &self,
         m: storage::PaymentMethodNew,
@@ -65,6 +73,24 @@ impl PaymentMethodInterface for Store {
             .into_report()
     }
 
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentMethod::find_by_customer_id_merchant_id_card_fingerprint(
+            &conn,
+            customer_id,
+            merchant_id,
+            card_fingerprint,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
@@ -86,35 +112,124 @@ impl PaymentMethodInterface for Store {
 impl PaymentMethodInterface for MockDb {
     async fn find_payment_method(
         &self,
-        _payment_method_id: &str,
+        payment_method_id: &str,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let payment_methods = self.payment_methods.lock().await;
+
+        payment_methods
+            .iter()
+            .find(|payment_method| payment_method.payment_method_id == payment_method_id)
+            .cloned()
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })
     }
 
     async fn insert_payment_method(
         &self,
-        _m: storage::PaymentMethodNew,
+        payment_method_new: storage::PaymentMethodNew,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut payment_methods = self.payment_methods.lock().await;
+        if payment_method_new.card_fingerprint.is_some()
+            && payment_methods.iter().any(|payment_method| {
+                payment_method.merchant_id == payment_method_new.merchant_id
+                    && payment_method.customer_id == payment_method_new.customer_id
+                    && payment_method.card_fingerprint == payment_method_new.card_fingerprint
+            })
+        {
+            Err(report!(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into()
+            )))?;
+        }
+        let payment_method = storage::PaymentMethod {
+            #[allow(clippy::as_conversions)]
+            id: payment_methods.len() as i32,
+            customer_id: payment_method_new.customer_id,
+            merchant_id: payment_method_new.merchant_id,
+            payment_method_id: payment_method_new.payment_method_id,
+            accepted_currency: payment_method_new.accepted_currency,
+            scheme: payment_method_new.scheme,
+            token: payment_method_new.token,
+            cardholder_name: payment_method_new.cardholder_name,
+            issuer_name: payment_method_new.issuer_name,
+            issuer_country: payment_method_new.issuer_country,
+            payer_country: payment_method_new.payer_country,
+            is_stored: payment_method_new.is_stored,
+            swift_code: payment_method_new.swift_code,
+            direct_debit_token: payment_method_new.direct_debit_token,
+            created_at: payment_method_new.created_at,
+            last_modified: payment_method_new.last_modified,
+            payment_method: payment_method_new.payment_method,
+            payment_method_type: payment_method_new.payment_method_type,
+            payment_method_issuer: payment_method_new.payment_method_issuer,
+            payment_method_issuer_code: payment_method_new.payment_method_issuer_code,
+            metadata: payment_method_new.metadata,
+            card_fingerprint: payment_method_new.card_fingerprint,
+        };
+        payment_methods.push(payment_method.clone());
+        Ok(payment_method)
     }
 
     async fn find_payment_method_by_customer_id_merchant_id_list(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
+        customer_id: &str,
+        merchant_id: &str,
     ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let payment_methods = self.payment_methods.lock().await;
+
+        Ok(payment_methods
+            .iter()
+            .filter(|payment_method| {
+                payment_method.customer_id == customer_id
+                    && payment_method.merchant_id == merchant_id
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+
+        payment_methods
+            .iter()
+            .find(|payment_method| {
+                payment_method.customer_id == customer_id
+                    && payment_method.merchant_id == merchant_id
+                    && payment_method.card_fingerprint.as_deref() == Some(card_fingerprint)
+            })
+            .cloned()
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })
     }
 
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
-        _merchant_id: &str,
-        _payment_method_id: &str,
+        merchant_id: &str,
+        payment_method_id: &str,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut payment_methods = self.payment_methods.lock().await;
+
+        let position = payment_methods
+            .iter()
+            .position(|payment_method| {
+                payment_method.merchant_id == merchant_id
+                    && payment_method.payment_method_id == payment_method_id
+            })
+            .ok_or_else(|| {
+                report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        Ok(payment_methods.remove(position))
     }
 }
@@ -0,0 +1,100 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SubscriptionInterface {
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn update_subscription(
+        &self,
+        this: storage::Subscription,
+        subscription: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for Store {
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        subscription
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Subscription::find_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_subscription(
+        &self,
+        this: storage::Subscription,
+        subscription: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, subscription)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for MockDb {
+    async fn insert_subscription(
+        &self,
+        _subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &str,
+        _subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription(
+        &self,
+        _this: storage::Subscription,
+        _subscription: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
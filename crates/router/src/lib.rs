@@ -82,6 +82,7 @@ pub fn mk_app(
             .service(routes::Configs::server(state.clone()))
             .service(routes::Refunds::server(state.clone()))
             .service(routes::Payouts::server(state.clone()))
+            .service(routes::Disputes::server(state.clone()))
             .service(routes::MerchantConnectorAccount::server(state.clone()))
             .service(routes::Mandates::server(state.clone()));
     }
@@ -91,14 +92,19 @@ pub fn mk_app(
         server_app = server_app
             .service(routes::PaymentMethods::server(state.clone()))
             .service(routes::EphemeralKey::server(state.clone()))
-            .service(routes::Webhooks::server(state.clone()));
+            .service(routes::Webhooks::server(state.clone()))
+            .service(routes::Files::server(state.clone()))
+            .service(routes::Subscriptions::server(state.clone()))
+            .service(routes::Tokens::server(state.clone()))
+            .service(routes::Proxy::server(state.clone()));
     }
 
     #[cfg(feature = "olap")]
     {
         server_app = server_app
             .service(routes::MerchantAccount::server(state.clone()))
-            .service(routes::ApiKeys::server(state.clone()));
+            .service(routes::ApiKeys::server(state.clone()))
+            .service(routes::Events::server(state.clone()));
     }
 
     #[cfg(feature = "stripe")]
@@ -119,6 +125,13 @@ pub async fn start_server(conf: settings::Settings) -> ApplicationResult<(Server
     logger::debug!(startup_config=?conf);
     let server = conf.server.clone();
     let state = routes::AppState::new(conf).await;
+    if state.conf.locker.mock_locker {
+        if let Err(error) =
+            core::payment_methods::vault::add_mock_locker_migration_task(&state).await
+        {
+            logger::error!(?error, "Failed to schedule the mock locker migration");
+        }
+    }
     // Cloning to close connections before shutdown
     let app_state = state.clone();
     let request_body_limit = server.request_body_limit;
@@ -150,6 +163,7 @@ pub fn get_application_builder(
 
     actix_web::App::new()
         .app_data(json_cfg)
+        .wrap(middleware::IdempotentRequestBody)
         .wrap(middleware::RequestId)
         .wrap(router_env::tracing_actix_web::TracingLogger::default())
         .wrap(ErrorHandlers::new().handler(
@@ -59,3 +59,72 @@ where
         })
     }
 }
+
+/// Middleware to capture the raw body of requests carrying an idempotency key, so that the
+/// request can be fingerprinted after the body is consumed by the extractors of the route.
+pub(crate) struct IdempotentRequestBody;
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for IdempotentRequestBody
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = IdempotentRequestBodyMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(IdempotentRequestBodyMiddleware {
+            service: std::rc::Rc::new(service),
+        }))
+    }
+}
+
+pub(crate) struct IdempotentRequestBodyMiddleware<S> {
+    service: std::rc::Rc<S>,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for IdempotentRequestBodyMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let service = std::rc::Rc::clone(&self.service);
+
+        Box::pin(async move {
+            let mut req = req;
+            let is_idempotent_request = matches!(
+                crate::services::api::idempotency::get_idempotency_key(req.request()),
+                Ok(Some(_))
+            );
+            if is_idempotent_request {
+                let body = req.extract::<bytes::Bytes>().await?;
+                actix_web::HttpMessage::extensions_mut(&req)
+                    .insert(crate::services::api::idempotency::RequestBody(body.clone()));
+                req.set_payload(actix_web::dev::Payload::from(body));
+            }
+
+            service.call(req).await
+        })
+    }
+}
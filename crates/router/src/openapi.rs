@@ -54,7 +54,14 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Merchant Connector Account", description = "Create and manage merchant connector accounts"),
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
+        (name = "Payouts", description = "Create and manage payouts to customers and sellers"),
+        (name = "Disputes", description = "Manage disputes raised against payments"),
+        (name = "Events", description = "Inspect and redeliver the webhooks sent to merchants"),
+        (name = "Files", description = "Upload and manage files used as dispute evidence"),
         (name = "Mandates", description = "Manage mandates"),
+        (name = "Subscriptions", description = "Create and manage recurring billing of customers through mandates"),
+        (name = "Tokens", description = "Tokenize sensitive data and detokenize it with privileged API Keys"),
+        (name = "Proxy", description = "Forward requests carrying card data from the vault to allowed destinations"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
         (name = "API Key", description = "Create and manage API Keys"),
@@ -65,10 +72,30 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::refunds::refunds_update,
         crate::routes::refunds::refunds_list,
         crate::routes::refunds::refunds_create,
+        crate::routes::payouts::payouts_create,
+        crate::routes::payouts::payouts_retrieve,
+        crate::routes::payouts::payouts_update,
+        crate::routes::payouts::payouts_fulfill,
+        crate::routes::payouts::payouts_cancel,
+        crate::routes::payouts::payouts_reverse,
+        crate::routes::payouts::payouts_list,
+        crate::routes::payouts::payouts_accounts,
+        crate::routes::disputes::retrieve_dispute,
+        crate::routes::disputes::retrieve_disputes_list,
+        crate::routes::disputes::accept_dispute,
+        crate::routes::disputes::submit_dispute_evidence,
+        crate::routes::events::list_events,
+        crate::routes::events::list_event_delivery_attempts,
+        crate::routes::events::retry_event_delivery,
+        crate::routes::files::files_create,
+        crate::routes::files::files_retrieve,
+        crate::routes::files::files_delete,
         crate::routes::admin::merchant_account_create,
         crate::routes::admin::retrieve_merchant_account,
         crate::routes::admin::update_merchant_account,
         crate::routes::admin::delete_merchant_account,
+        crate::routes::admin::merchant_account_rotate_webhook_secret,
+        crate::routes::admin::merchant_account_rotate_connector_credentials_key,
         crate::routes::admin::payment_connector_create,
         crate::routes::admin::payment_connector_retrieve,
         crate::routes::admin::payment_connector_list,
@@ -76,12 +103,21 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::admin::payment_connector_delete,
         crate::routes::mandates::get_mandate,
         crate::routes::mandates::revoke_mandate,
+        crate::routes::subscriptions::create_subscription,
+        crate::routes::subscriptions::retrieve_subscription,
+        crate::routes::subscriptions::cancel_subscription,
+        crate::routes::tokens::create_token,
+        crate::routes::tokens::retrieve_token,
+        crate::routes::tokens::detokenize,
+        crate::routes::tokens::delete_token,
+        crate::routes::proxy::proxy_request,
         crate::routes::payments::payments_create,
        // crate::routes::payments::payments_start,
         crate::routes::payments::payments_retrieve,
         crate::routes::payments::payments_update,
         crate::routes::payments::payments_confirm,
         crate::routes::payments::payments_capture,
+        crate::routes::payments::payments_incremental_authorization,
         crate::routes::payments::payments_connector_session,
        // crate::routes::payments::payments_redirect_response,
         crate::routes::payments::payments_cancel,
@@ -96,6 +132,9 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::customers::customers_retrieve,
         crate::routes::customers::customers_update,
         crate::routes::customers::customers_delete,
+        crate::routes::customers::customers_export,
+        crate::routes::customers::customer_erasure_create,
+        crate::routes::customers::customer_erasure_retrieve,
         crate::routes::api_keys::api_key_create,
         crate::routes::api_keys::api_key_retrieve,
         crate::routes::api_keys::api_key_update,
@@ -131,6 +170,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
+        api_models::enums::CaptureStatus,
+        api_models::enums::AuthorizationStatus,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
         api_models::enums::WalletIssuer,
@@ -169,6 +210,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentIdType,
         api_models::payments::PaymentsCaptureRequest,
+        api_models::payments::CaptureResponse,
+        api_models::payments::PaymentsIncrementalAuthorizationRequest,
+        api_models::payments::IncrementalAuthorizationResponse,
         api_models::payments::PaymentsSessionRequest,
         api_models::payments::PaymentsSessionResponse,
         api_models::payments::SessionToken,
@@ -183,14 +227,60 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentListResponse,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
+        api_models::enums::PayoutStatus,
+        api_models::enums::PayoutType,
+        api_models::payouts::PayoutCreateRequest,
+        api_models::payouts::PayoutMethodData,
+        api_models::payouts::CardPayout,
+        api_models::payouts::BankPayout,
+        api_models::payouts::PayoutUpdateRequest,
+        api_models::payouts::PayoutResponse,
+        api_models::payouts::PayoutListRequest,
+        api_models::payouts::PayoutListResponse,
+        api_models::payouts::PayoutAccount,
+        api_models::payouts::PayoutAccountsResponse,
+        api_models::enums::DisputeStage,
+        api_models::enums::DisputeStatus,
+        api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputeListConstraints,
+        api_models::disputes::DisputeListResponse,
+        api_models::disputes::SubmitEvidenceRequest,
+        api_models::events::EventListConstraints,
+        api_models::events::EventResponse,
+        api_models::events::EventListResponse,
+        api_models::events::EventDeliveryAttemptResponse,
+        api_models::events::EventDeliveryAttemptListResponse,
+        api_models::enums::FilePurpose,
+        api_models::files::CreateFileResponse,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
+        api_models::enums::SubscriptionStatus,
+        api_models::enums::SubscriptionInterval,
+        api_models::subscriptions::SubscriptionCreateRequest,
+        api_models::subscriptions::SubscriptionResponse,
+        api_models::tokens::TokenUsage,
+        api_models::tokens::TokenCreateRequest,
+        api_models::tokens::TokenResponse,
+        api_models::tokens::DetokenizeResponse,
+        api_models::tokens::TokenDeleteResponse,
+        api_models::proxy::ProxyMethod,
+        api_models::proxy::ProxyRequest,
+        api_models::proxy::ProxyResponse,
+        api_models::enums::CustomerErasureStatus,
+        api_models::customers::CustomerExportResponse,
+        api_models::customers::CustomerPaymentMethodExport,
+        api_models::customers::CustomerMandateExport,
+        api_models::customers::CustomerPaymentExport,
+        api_models::customers::CustomerErasureResponse,
         crate::types::api::admin::MerchantAccountResponse,
         crate::types::api::admin::MerchantConnectorId,
         crate::types::api::admin::MerchantDetails,
         crate::types::api::admin::WebhookDetails,
+        crate::types::api::admin::WebhookSigningSecretResponse,
+        crate::types::api::admin::ConnectorCredentialsKeyRotationResponse,
         crate::types::api::api_keys::ApiKeyExpiration,
+        crate::types::api::api_keys::ApiKeyScope,
         crate::types::api::api_keys::CreateApiKeyRequest,
         crate::types::api::api_keys::CreateApiKeyResponse,
         crate::types::api::api_keys::RetrieveApiKeyResponse,
@@ -3,19 +3,26 @@ pub mod api_keys;
 pub mod app;
 pub mod configs;
 pub mod customers;
+pub mod disputes;
 pub mod ephemeral_key;
+pub mod events;
+pub mod files;
 pub mod health;
 pub mod mandates;
 pub mod metrics;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
+pub mod proxy;
 pub mod refunds;
+pub mod subscriptions;
+pub mod tokens;
 pub mod webhooks;
 
 pub use self::app::{
-    ApiKeys, AppState, Configs, Customers, EphemeralKey, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Refunds, Webhooks,
+    ApiKeys, AppState, Configs, Customers, Disputes, EphemeralKey, Events, Files, Health, Mandates,
+    MerchantAccount, MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Proxy, Refunds,
+    Subscriptions, Tokens, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
@@ -363,6 +363,70 @@ pub async fn merchant_account_toggle_kv(
     .await
 }
 
+// Merchant Account - Rotate Webhook Signing Secret
+
+///
+/// Generate a new secret to sign the webhooks sent to the merchant.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/webhook_secret/rotate",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Webhook Signing Secret Rotated", body = WebhookSigningSecretResponse),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Rotate the Webhook Signing Secret of a Merchant Account"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountWebhookSecretRotate))]
+pub async fn merchant_account_rotate_webhook_secret(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        merchant_id,
+        |state, _, merchant_id| rotate_webhook_signing_secret(&*state.store, merchant_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+// Merchant Account - Rotate Connector Credentials Key
+
+///
+/// Generate a new key to encrypt the connector account details of the merchant, and re-encrypt the existing connector accounts with it in the background.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connector_credentials/rotate",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Connector Credentials Key Rotated", body = ConnectorCredentialsKeyRotationResponse),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Rotate the Connector Credentials Key of a Merchant Account"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountConnectorCredentialsKeyRotate))]
+pub async fn merchant_account_rotate_connector_credentials_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let merchant_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        merchant_id,
+        |state, _, merchant_id| rotate_connector_credentials_key(&*state.store, merchant_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
 // Merchant Account - KV Status
 
 ///
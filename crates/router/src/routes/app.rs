@@ -2,15 +2,20 @@ use actix_web::{web, Scope};
 
 use super::health::*;
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*};
+use super::{admin::*, api_keys::*, events::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
-use super::{configs::*, customers::*, mandates::*, payments::*, payouts::*, refunds::*};
+use super::{
+    configs::*, customers::*, disputes::*, mandates::*, payments::*, payouts::*, refunds::*,
+};
 #[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
+use super::{
+    ephemeral_key::*, files::*, payment_methods::*, proxy::*, subscriptions::*, tokens::*,
+    webhooks::*,
+};
 use crate::{
     configs::settings::Settings,
     db::{MockDb, StorageImpl, StorageInterface},
-    services::Store,
+    services::{key_manager, Store},
 };
 
 #[derive(Clone)]
@@ -18,6 +23,7 @@ pub struct AppState {
     pub flow_name: String,
     pub store: Box<dyn StorageInterface>,
     pub conf: Settings,
+    pub local_vault_key_manager: key_manager::LocalVaultKeyManager,
 }
 
 pub trait AppStateInfo {
@@ -51,6 +57,7 @@ impl AppState {
         Self {
             flow_name: String::from("default"),
             store,
+            local_vault_key_manager: key_manager::LocalVaultKeyManager::new(&conf.local_vault),
             conf,
         }
     }
@@ -104,6 +111,10 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/incremental_authorization")
+                        .route(web::post().to(payments_incremental_authorization)),
+                )
                 .service(
                     web::resource("/start/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
@@ -145,6 +156,17 @@ impl Customers {
                 .service(
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/export").route(web::get().to(customers_export)),
+                )
+                .service(
+                    web::resource("/{customer_id}/erasure")
+                        .route(web::post().to(customer_erasure_create)),
+                )
+                .service(
+                    web::resource("/{customer_id}/erasure/{erasure_id}")
+                        .route(web::get().to(customer_erasure_retrieve)),
                 );
         }
         route
@@ -185,22 +207,113 @@ impl Payouts {
 
         #[cfg(feature = "olap")]
         {
-            route =
-                route.service(web::resource("/accounts").route(web::get().to(payouts_accounts)));
+            route = route
+                .service(web::resource("/list").route(web::get().to(payouts_list)))
+                .service(web::resource("/accounts").route(web::get().to(payouts_accounts)));
         }
         #[cfg(feature = "oltp")]
         {
             route = route
                 .service(web::resource("/create").route(web::post().to(payouts_create)))
-                .service(web::resource("/retrieve").route(web::get().to(payouts_retrieve)))
-                .service(web::resource("/update").route(web::post().to(payouts_update)))
-                .service(web::resource("/reverse").route(web::post().to(payouts_reverse)))
-                .service(web::resource("/cancel").route(web::post().to(payouts_cancel)));
+                .service(
+                    web::resource("/{payout_id}")
+                        .route(web::get().to(payouts_retrieve))
+                        .route(web::post().to(payouts_update)),
+                )
+                .service(
+                    web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)),
+                )
+                .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
+                .service(
+                    web::resource("/{payout_id}/reverse").route(web::post().to(payouts_reverse)),
+                );
+        }
+        route
+    }
+}
+
+pub struct Disputes;
+
+#[cfg(any(feature = "olap", feature = "oltp"))]
+impl Disputes {
+    pub fn server(state: AppState) -> Scope {
+        let mut route = web::scope("/disputes").app_data(web::Data::new(state));
+
+        #[cfg(feature = "olap")]
+        {
+            route =
+                route.service(web::resource("/list").route(web::get().to(retrieve_disputes_list)));
         }
+        route = route
+            .service(web::resource("/evidence").route(web::post().to(submit_dispute_evidence)))
+            .service(web::resource("/{dispute_id}").route(web::get().to(retrieve_dispute)))
+            .service(web::resource("/{dispute_id}/accept").route(web::post().to(accept_dispute)));
         route
     }
 }
 
+pub struct Files;
+
+#[cfg(feature = "oltp")]
+impl Files {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/files")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(files_create)))
+            .service(
+                web::resource("/{file_id}")
+                    .route(web::get().to(files_retrieve))
+                    .route(web::delete().to(files_delete)),
+            )
+    }
+}
+
+pub struct Subscriptions;
+
+#[cfg(feature = "oltp")]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(create_subscription)))
+            .service(
+                web::resource("/{subscription_id}").route(web::get().to(retrieve_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(cancel_subscription)),
+            )
+    }
+}
+
+pub struct Tokens;
+
+#[cfg(feature = "oltp")]
+impl Tokens {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/tokens")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(create_token)))
+            .service(
+                web::resource("/{token_id}")
+                    .route(web::get().to(retrieve_token))
+                    .route(web::delete().to(delete_token)),
+            )
+            .service(web::resource("/{token_id}/detokenize").route(web::post().to(detokenize)))
+    }
+}
+
+pub struct Proxy;
+
+#[cfg(feature = "oltp")]
+impl Proxy {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/proxy")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(proxy_request)))
+    }
+}
+
 pub struct PaymentMethods;
 
 #[cfg(feature = "oltp")]
@@ -231,6 +344,14 @@ impl MerchantAccount {
                     .route(web::post().to(merchant_account_toggle_kv))
                     .route(web::get().to(merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/webhook_secret/rotate")
+                    .route(web::post().to(merchant_account_rotate_webhook_secret)),
+            )
+            .service(
+                web::resource("/{id}/connector_credentials/rotate")
+                    .route(web::post().to(merchant_account_rotate_connector_credentials_key)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -352,3 +473,19 @@ impl ApiKeys {
             )
     }
 }
+
+pub struct Events;
+
+#[cfg(feature = "olap")]
+impl Events {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(list_events)))
+            .service(
+                web::resource("/{event_id}/attempts")
+                    .route(web::get().to(list_event_delivery_attempts)),
+            )
+            .service(web::resource("/{event_id}/retry").route(web::post().to(retry_event_delivery)))
+    }
+}
@@ -151,6 +151,116 @@ pub async fn customers_delete(
     .await
 }
 
+// Export Customer
+
+///
+/// Export every record held about a customer, including its addresses, payment methods, mandates and payments.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/export",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Customer data exported", body = CustomerExportResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Export a Customer"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersExport))]
+pub async fn customers_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        export_customer,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Create Customer Erasure
+
+///
+/// Schedule the erasure of the personal data of a customer. The customer, its addresses and the description and metadata of its payments are redacted, and its payment methods are deleted along with their locker entries.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/erasure",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Customer erasure scheduled", body = CustomerErasureResponse),
+        (status = 400, description = "Customer has an active mandate"),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Erase a Customer"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerErasureCreate))]
+pub async fn customer_erasure_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        create_customer_erasure,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Retrieve Customer Erasure
+
+///
+/// Retrieve the status of the erasure of the personal data of a customer.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/erasure/{erasure_id}",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("erasure_id" = String, Path, description = "The unique identifier for the Customer Erasure")
+    ),
+    responses(
+        (status = 200, description = "Customer erasure retrieved", body = CustomerErasureResponse),
+        (status = 404, description = "Customer erasure was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Retrieve a Customer Erasure"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerErasureRetrieve))]
+pub async fn customer_erasure_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (customer_id, erasure_id) = path.into_inner();
+    let payload = customers::CustomerErasureId {
+        customer_id,
+        erasure_id,
+    };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            retrieve_customer_erasure(&*state.store, merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::CustomersGetMandates))]
 pub async fn get_customer_mandates(
     state: web::Data<AppState>,
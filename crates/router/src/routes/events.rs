@@ -0,0 +1,109 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::events,
+    services::{api, authentication as auth},
+    types::api::events as event_types,
+};
+
+// Events - List
+
+///
+/// To list the events created for a merchant
+#[utoipa::path(
+    get,
+    path = "/events",
+    params(
+        ("merchant_id" = String, Query, description = "The identifier for the merchant"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of Event Objects to include in the response"),
+    ),
+    responses(
+        (status = 200, description = "The event list was retrieved successfully", body = EventListResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Events",
+    operation_id = "List Events"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::EventsList))]
+pub async fn list_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<event_types::EventListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        |state, _, constraints| events::list_events(state, constraints),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+// Events - Delivery Attempts
+
+///
+/// To list the attempts to deliver the webhook of an event to the merchant
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}/attempts",
+    params(
+        ("event_id" = String, Path, description = "The identifier for the event")
+    ),
+    responses(
+        (status = 200, description = "The delivery attempts were retrieved successfully", body = EventDeliveryAttemptListResponse),
+        (status = 404, description = "Event does not exist in our records")
+    ),
+    tag = "Events",
+    operation_id = "List Event Delivery Attempts"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::EventDeliveryAttemptsList))]
+pub async fn list_event_delivery_attempts(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        |state, _, event_id| events::list_event_delivery_attempts(state, event_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+// Events - Retry
+
+///
+/// To redeliver the webhook of an event to the merchant
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/retry",
+    params(
+        ("event_id" = String, Path, description = "The identifier for the event")
+    ),
+    responses(
+        (status = 200, description = "The webhook was redelivered, the attempt holds the response of the merchant", body = EventDeliveryAttemptResponse),
+        (status = 404, description = "Event does not exist in our records")
+    ),
+    tag = "Events",
+    operation_id = "Retry Event Delivery"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::EventDeliveryRetry))]
+pub async fn retry_event_delivery(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        |state, _, event_id| events::retry_event_delivery(state, event_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
@@ -0,0 +1,115 @@
+use actix_multipart::Multipart;
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::files::{self, helpers},
+    services::{api, authentication as auth},
+};
+
+// Files - Create
+
+///
+/// To upload a file that can be used as dispute evidence
+#[utoipa::path(
+    post,
+    path = "/files",
+    request_body(content_type = "multipart/form-data", description = "The file to be uploaded along with its purpose"),
+    responses(
+        (status = 200, description = "The file was uploaded successfully", body = CreateFileResponse),
+        (status = 400, description = "The file or its purpose is missing or invalid")
+    ),
+    tag = "Files",
+    operation_id = "Create a File"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CreateFile))]
+// #[post("")]
+pub async fn files_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: Multipart,
+) -> HttpResponse {
+    let max_file_size = state.conf.file_upload.max_file_size;
+    let create_file_request = match helpers::get_create_file_request(payload, max_file_size).await {
+        Ok(valid_request) => valid_request,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        create_file_request,
+        files::files_create_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Files - Retrieve
+
+///
+/// To retrieve the contents of an uploaded file
+#[utoipa::path(
+    get,
+    path = "/files/{file_id}",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "The file was retrieved successfully"),
+        (status = 400, description = "The file is not available"),
+        (status = 404, description = "File does not exist in our records")
+    ),
+    tag = "Files",
+    operation_id = "Retrieve a File"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RetrieveFile))]
+// #[get("/{file_id}")]
+pub async fn files_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        files::files_retrieve_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Files - Delete
+
+///
+/// To delete an uploaded file
+#[utoipa::path(
+    delete,
+    path = "/files/{file_id}",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "The file was deleted successfully"),
+        (status = 404, description = "File does not exist in our records")
+    ),
+    tag = "Files",
+    operation_id = "Delete a File"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeleteFile))]
+// #[delete("/{file_id}")]
+pub async fn files_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        files::files_delete_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
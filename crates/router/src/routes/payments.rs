@@ -4,9 +4,9 @@ use router_env::{instrument, tracing, Flow};
 
 use crate::{
     self as app,
-    core::{errors::http_not_implemented, payments},
+    core::payments,
     services::{api, authentication as auth},
-    types::api::{self as api_types, enums as api_enums, payments as payment_types},
+    types::api::{self as api_types, payments as payment_types},
 };
 
 // Payments - Create
@@ -33,10 +33,6 @@ pub async fn payments_create(
 ) -> impl Responder {
     let payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
     api::server_wrap(
         state.get_ref(),
         &req,
@@ -189,10 +185,6 @@ pub async fn payments_update(
 ) -> impl Responder {
     let mut payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
     let payment_id = path.into_inner();
 
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
@@ -248,10 +240,6 @@ pub async fn payments_confirm(
 ) -> impl Responder {
     let mut payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
     let payment_id = path.into_inner();
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
@@ -330,6 +318,64 @@ pub async fn payments_capture(
     .await
 }
 
+// Payments - Incremental Authorization
+
+///
+/// To increase the amount authorized on an uncaptured payment
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/incremental_authorization",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body=PaymentsIncrementalAuthorizationRequest,
+    responses(
+        (status = 200, description = "Authorized amount of the payment increased", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields"),
+        (status = 501, description = "Incremental authorization not supported by the connector")
+    ),
+    tag = "Payments",
+    operation_id = "Increase the Authorized Amount of a Payment"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsIncrementalAuthorization))]
+// #[post("/{payment_id}/incremental_authorization")]
+pub async fn payments_incremental_authorization(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsIncrementalAuthorizationRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let incremental_authorization_payload =
+        payment_types::PaymentsIncrementalAuthorizationRequest {
+            payment_id: path.into_inner(),
+            ..json_payload.into_inner()
+        };
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        incremental_authorization_payload,
+        |state, merchant_account, payload| {
+            payments::payments_core::<
+                api_types::IncrementalAuthorization,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state,
+                merchant_account,
+                payments::PaymentIncrementalAuthorization,
+                payload,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 // Payments - Session token
 
 ///
@@ -1,45 +1,279 @@
-use actix_web::{
-    body::{BoxBody, MessageBody},
-    HttpResponse, Responder,
-};
+use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
+use super::app::AppState;
+use crate::{
+    core::payouts::*,
+    services::{api, authentication as auth},
+    types::api::payouts,
+};
+
+// Payouts - Create
+
+///
+/// To create a payout to a customer's card or bank account
+#[utoipa::path(
+    post,
+    path = "/payouts/create",
+    request_body=PayoutCreateRequest,
+    responses(
+        (status = 200, description = "Payout created", body = PayoutResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout"
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCreate))]
 // #[post("/create")]
-pub async fn payouts_create() -> impl Responder {
-    http_response("create")
+pub async fn payouts_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payouts::PayoutCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        payouts_create_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+// Payouts - Retrieve
+
+///
+/// To retrieve the properties of a Payout
+#[utoipa::path(
+    get,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout"
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieve))]
-// #[get("/retrieve")]
-pub async fn payouts_retrieve() -> impl Responder {
-    http_response("retrieve")
+// #[get("/{payout_id}")]
+pub async fn payouts_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        payouts_retrieve_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+// Payouts - Update
+
+///
+/// To update the description or metadata of a Payout
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutUpdateRequest,
+    responses(
+        (status = 200, description = "Payout updated", body = PayoutResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Update a Payout"
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
-// #[post("/update")]
-pub async fn payouts_update() -> impl Responder {
-    http_response("update")
+// #[post("/{payout_id}")]
+pub async fn payouts_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payouts::PayoutUpdateRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            payouts_update_core(state, merchant_account, payout_id.clone(), req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsReverse))]
-// #[post("/reverse")]
-pub async fn payouts_reverse() -> impl Responder {
-    http_response("reverse")
+// Payouts - Fulfill
+
+///
+/// To fulfill a Payout which is awaiting fulfillment, releasing the funds to the recipient
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/fulfill",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout fulfilled", body = PayoutResponse),
+        (status = 400, description = "Payout is not awaiting fulfillment")
+    ),
+    tag = "Payouts",
+    operation_id = "Fulfill a Payout"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsFulfill))]
+// #[post("/{payout_id}/fulfill")]
+pub async fn payouts_fulfill(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        payouts_fulfill_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+// Payouts - Cancel
+
+///
+/// To cancel a Payout which is awaiting fulfillment
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/cancel",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout cancelled", body = PayoutResponse),
+        (status = 400, description = "Payout is not awaiting fulfillment")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout"
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCancel))]
-// #[post("/cancel")]
-pub async fn payouts_cancel() -> impl Responder {
-    http_response("cancel")
+// #[post("/{payout_id}/cancel")]
+pub async fn payouts_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        payouts_cancel_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+// Payouts - Reverse
+
+///
+/// To reverse a Payout which has been paid out, returning the funds to the merchant
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/reverse",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout reversed", body = PayoutResponse),
+        (status = 400, description = "Payout has not been paid out"),
+        (status = 501, description = "Reversal is not supported by the connector of the payout")
+    ),
+    tag = "Payouts",
+    operation_id = "Reverse a Payout"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsReverse))]
+// #[post("/{payout_id}/reverse")]
+pub async fn payouts_reverse(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        payouts_reverse_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Payouts - Accounts
+
+///
+/// To list the connector accounts of the merchant through which payouts can be made
+#[utoipa::path(
+    get,
+    path = "/payouts/accounts",
+    responses(
+        (status = 200, description = "List of payout accounts", body = PayoutAccountsResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "List all Payout Accounts"
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
+#[cfg(feature = "olap")]
 // #[get("/accounts")]
-pub async fn payouts_accounts() -> impl Responder {
-    http_response("accounts")
+pub async fn payouts_accounts(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |state, merchant_account, _| payouts_accounts_core(state, merchant_account),
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
-fn http_response<T: MessageBody + 'static>(response: T) -> HttpResponse<BoxBody> {
-    HttpResponse::Ok().body(response)
+// Payouts - List
+
+///
+/// To list the payouts made by the merchant, optionally filtered by customer
+#[utoipa::path(
+    get,
+    path = "/payouts/list",
+    params(
+        ("customer_id" = String, Query, description = "The identifier for the customer"),
+        ("limit" = i64, Query, description = "Limit on the number of objects to return")
+    ),
+    responses(
+        (status = 200, description = "List of payouts", body = PayoutListResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "List all Payouts"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
+#[cfg(feature = "olap")]
+// #[get("/list")]
+pub async fn payouts_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<payouts::PayoutListRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        payouts_list_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
@@ -0,0 +1,43 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::proxy,
+    services::{api, authentication as auth},
+    types::api::proxy as proxy_types,
+};
+
+// Proxy - Request
+
+///
+/// To forward a request to an allowed destination, with placeholders in its body substituted with
+/// card data from the vault. The card data substituted is masked in the response returned.
+#[utoipa::path(
+    post,
+    path = "/proxy",
+    request_body = ProxyRequest,
+    responses(
+        (status = 200, description = "The request was forwarded to the destination", body = ProxyResponse),
+        (status = 400, description = "The destination is not allowed or a placeholder is invalid"),
+        (status = 404, description = "Payment method does not exist in our records")
+    ),
+    tag = "Proxy",
+    operation_id = "Forward a Request"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::VaultProxy))]
+// #[post("")]
+pub async fn proxy_request(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<proxy_types::ProxyRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        proxy::proxy_request,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
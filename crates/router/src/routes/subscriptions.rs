@@ -0,0 +1,110 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::subscriptions,
+    services::{api, authentication as auth},
+    types::api::subscriptions as subscription_types,
+};
+
+// Subscriptions - Create
+
+///
+/// To create a subscription, charging the customer through a mandate at every billing cycle
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "The subscription was created successfully", body = SubscriptionResponse),
+        (status = 400, description = "Invalid data or the mandate cannot be used for the subscription")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsCreate))]
+// #[post("")]
+pub async fn create_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        subscriptions::create_subscription,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Subscriptions - Retrieve
+
+///
+/// To retrieve a subscription
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for subscription")
+    ),
+    responses(
+        (status = 200, description = "The subscription was retrieved successfully", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsRetrieve))]
+// #[get("/{subscription_id}")]
+pub async fn retrieve_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        subscriptions::retrieve_subscription,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Subscriptions - Cancel
+
+///
+/// To cancel a subscription, the customer is no longer charged
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for subscription")
+    ),
+    responses(
+        (status = 200, description = "The subscription was cancelled successfully", body = SubscriptionResponse),
+        (status = 400, description = "The subscription is already cancelled or ended"),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsCancel))]
+// #[post("/{subscription_id}/cancel")]
+pub async fn cancel_subscription(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        subscriptions::cancel_subscription,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
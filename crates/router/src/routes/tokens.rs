@@ -0,0 +1,145 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::tokens,
+    services::{api, authentication as auth},
+    types::api::{api_keys::ApiKeyScope, tokens as token_types},
+};
+
+// Tokens - Create
+
+///
+/// To tokenize sensitive data, such as a bank account number or a national identifier
+#[utoipa::path(
+    post,
+    path = "/tokens",
+    request_body = TokenCreateRequest,
+    responses(
+        (status = 200, description = "The token was created successfully", body = TokenResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Tokens",
+    operation_id = "Create a Token"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::TokensCreate))]
+// #[post("")]
+pub async fn create_token(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<token_types::TokenCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        tokens::create_token,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Tokens - Retrieve
+
+///
+/// To retrieve the details of a token, without the data behind it
+#[utoipa::path(
+    get,
+    path = "/tokens/{token_id}",
+    params(
+        ("token_id" = String, Path, description = "The identifier for the token")
+    ),
+    responses(
+        (status = 200, description = "The token was retrieved successfully", body = TokenResponse),
+        (status = 404, description = "Token does not exist in our records or has expired")
+    ),
+    tag = "Tokens",
+    operation_id = "Retrieve a Token"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::TokensRetrieve))]
+// #[get("/{token_id}")]
+pub async fn retrieve_token(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        tokens::retrieve_token,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Tokens - Detokenize
+
+///
+/// To retrieve the data behind a token. Requires an API Key granted the `detokenize` scope. A
+/// single use token is deleted once detokenized.
+#[utoipa::path(
+    post,
+    path = "/tokens/{token_id}/detokenize",
+    params(
+        ("token_id" = String, Path, description = "The identifier for the token")
+    ),
+    responses(
+        (status = 200, description = "The token was detokenized successfully", body = DetokenizeResponse),
+        (status = 401, description = "The API Key has not been granted the detokenize scope"),
+        (status = 404, description = "Token does not exist in our records or has expired")
+    ),
+    tag = "Tokens",
+    operation_id = "Detokenize a Token"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::TokensDetokenize))]
+// #[post("/{token_id}/detokenize")]
+pub async fn detokenize(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        tokens::detokenize,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::Detokenize),
+    )
+    .await
+}
+
+// Tokens - Delete
+
+///
+/// To delete a token along with the data behind it
+#[utoipa::path(
+    delete,
+    path = "/tokens/{token_id}",
+    params(
+        ("token_id" = String, Path, description = "The identifier for the token")
+    ),
+    responses(
+        (status = 200, description = "The token was deleted successfully", body = TokenDeleteResponse),
+        (status = 404, description = "Token does not exist in our records")
+    ),
+    tag = "Tokens",
+    operation_id = "Delete a Token"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::TokensDelete))]
+// #[delete("/{token_id}")]
+pub async fn delete_token(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        tokens::delete_token,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
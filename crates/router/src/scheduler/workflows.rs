@@ -4,8 +4,16 @@ use serde::{Deserialize, Serialize};
 use strum::EnumString;
 
 use crate::{core::errors, routes::AppState, scheduler::consumer, types::storage};
+pub mod connector_credentials_reencryption;
+pub mod customer_erasure;
+pub mod mandate_expiry;
+pub mod mock_locker_migration;
+pub mod outgoing_webhook_retry;
+pub mod payment_capture;
 pub mod payment_sync;
+pub mod payment_void;
 pub mod refund_router;
+pub mod subscription;
 
 macro_rules! runners {
     ($($body:tt),*) => {
@@ -44,7 +52,15 @@ macro_rules! as_item {
 
 runners! {
     PaymentsSyncWorkflow,
-    RefundWorkflowRouter
+    PaymentsCaptureWorkflow,
+    PaymentsVoidWorkflow,
+    RefundWorkflowRouter,
+    OutgoingWebhookRetryWorkflow,
+    SubscriptionWorkflow,
+    MandateExpiryWorkflow,
+    CustomerErasureWorkflow,
+    ConnectorCredentialsReencryptionWorkflow,
+    MockLockerMigrationWorkflow
 }
 
 #[async_trait]
@@ -0,0 +1,76 @@
+use masking::Secret;
+
+use super::{ConnectorCredentialsReencryptionWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::errors,
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for ConnectorCredentialsReencryptionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::ConnectorCredentialsReencryptionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ConnectorCredentialsReencryptionTrackingData")?;
+
+        let merchant_connector_accounts = db
+            .find_merchant_connector_account_by_merchant_id_list(&tracking_data.merchant_id)
+            .await?;
+
+        for merchant_connector_account in merchant_connector_accounts {
+            // Accounts already encrypted with this key, or with a key rotated since, are skipped
+            if merchant_connector_account
+                .key_version
+                .map(|key_version| key_version >= tracking_data.key_version)
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
+            // The storage layer encrypts the details with the latest data key of the merchant
+            let connector_account_details =
+                Secret::new(merchant_connector_account.connector_account_details.clone());
+            db.update_merchant_connector_account(
+                merchant_connector_account,
+                storage::MerchantConnectorAccountUpdate::Update {
+                    merchant_id: None,
+                    connector_type: None,
+                    connector_name: None,
+                    connector_account_details: Some(connector_account_details),
+                    test_mode: None,
+                    disabled: None,
+                    merchant_connector_id: None,
+                    payment_methods_enabled: None,
+                    metadata: None,
+                    authorization_expiry_days: None,
+                    key_version: None,
+                },
+            )
+            .await?;
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
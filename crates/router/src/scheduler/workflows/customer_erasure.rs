@@ -0,0 +1,101 @@
+use router_env::logger;
+
+use super::{CustomerErasureWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{customers, errors},
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, enums, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+/// Number of times a failed erasure is retried before it is marked as failed
+const CUSTOMER_ERASURE_MAX_RETRIES: i32 = 3;
+/// Delay in seconds before a failed erasure is retried
+const CUSTOMER_ERASURE_RETRY_DELAY: i64 = 300;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for CustomerErasureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::CustomerErasureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerErasureTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        let customer_erasure = db
+            .find_customer_erasure_by_merchant_id_erasure_id(
+                &tracking_data.merchant_id,
+                &tracking_data.erasure_id,
+            )
+            .await?;
+
+        if customer_erasure.status != enums::CustomerErasureStatus::Pending {
+            let id = process.id.clone();
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await;
+        }
+
+        match customers::erase_customer_data(
+            state,
+            &merchant_account,
+            &customer_erasure.customer_id,
+        )
+        .await
+        {
+            Ok(completed_update) => {
+                db.update_customer_erasure(customer_erasure, completed_update)
+                    .await?;
+
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await
+            }
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    erasure_id = %customer_erasure.erasure_id,
+                    retry_count = process.retry_count,
+                    "Erasure of the customer data failed"
+                );
+
+                if process.retry_count >= CUSTOMER_ERASURE_MAX_RETRIES {
+                    db.update_customer_erasure(
+                        customer_erasure,
+                        storage::CustomerErasureUpdate::FailedUpdate {
+                            failure_reason: error.current_context().error_message(),
+                        },
+                    )
+                    .await?;
+                    process
+                        .finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                        .await
+                } else {
+                    let retry_time = common_utils::date_time::now()
+                        + time::Duration::seconds(CUSTOMER_ERASURE_RETRY_DELAY);
+                    process.retry(db, retry_time).await
+                }
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
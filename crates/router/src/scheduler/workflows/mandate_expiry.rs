@@ -0,0 +1,62 @@
+use super::{MandateExpiryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::errors,
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, enums, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for MandateExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::MandateExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MandateExpiryTrackingData")?;
+
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(
+                &tracking_data.merchant_id,
+                &tracking_data.mandate_id,
+            )
+            .await?;
+
+        // Mandates which were revoked in the meantime are left untouched
+        if matches!(
+            mandate.mandate_status,
+            enums::MandateStatus::Active
+                | enums::MandateStatus::Inactive
+                | enums::MandateStatus::Pending
+        ) {
+            db.update_mandate_by_merchant_id_mandate_id(
+                &tracking_data.merchant_id,
+                &tracking_data.mandate_id,
+                storage::MandateUpdate::StatusUpdate {
+                    mandate_status: enums::MandateStatus::Expired,
+                },
+            )
+            .await?;
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,52 @@
+use super::{MockLockerMigrationWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{errors, payment_methods::vault},
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+};
+
+/// Number of cards fetched from the mock locker at a time
+const MOCK_LOCKER_MIGRATION_BATCH_SIZE: i64 = 100;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for MockLockerMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+
+        let mut from_id = 0;
+        loop {
+            let locker_mock_ups = db
+                .find_locker_mock_ups_from_id(from_id, MOCK_LOCKER_MIGRATION_BATCH_SIZE)
+                .await?;
+            let last_id = match locker_mock_ups.last() {
+                Some(locker_mock_up) => locker_mock_up.id,
+                None => break,
+            };
+
+            for locker_mock_up in locker_mock_ups {
+                vault::LocalVault::move_mock_locker_card(state, locker_mock_up).await?;
+            }
+            from_id = last_id + 1;
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
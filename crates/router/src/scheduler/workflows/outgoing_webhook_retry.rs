@@ -0,0 +1,141 @@
+use router_env::logger;
+
+use super::{OutgoingWebhookRetryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    configs::settings::WebhooksSettings,
+    core::webhooks,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::{consumer, utils},
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+
+        let event = db.find_event_by_event_id(&tracking_data.event_id).await?;
+        if event.is_webhook_notified {
+            let id = process.id.clone();
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await;
+        }
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        match webhooks::send_webhook_to_merchant(
+            db,
+            &merchant_account,
+            &tracking_data.event_id,
+            &tracking_data.webhook,
+        )
+        .await
+        {
+            Ok(()) => {
+                webhooks::mark_event_as_notified(db, event).await?;
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await
+            }
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    event_id = %tracking_data.event_id,
+                    retry_count = process.retry_count,
+                    "Outgoing webhook retry failed"
+                );
+                retry_outgoing_webhook_task(db, &state.conf.webhooks, process).await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+/// Returns the delay before the next delivery of a webhook which has already been retried
+/// `retry_count` times, or `None` when the retries are exhausted.
+fn get_webhook_retry_delay(config: &WebhooksSettings, retry_count: i32) -> Option<i32> {
+    if retry_count >= config.outgoing_max_retries {
+        return None;
+    }
+
+    let delay = u32::try_from(retry_count)
+        .ok()
+        .and_then(|exponent| 2_i32.checked_pow(exponent))
+        .and_then(|multiplier| config.outgoing_retry_initial_delay.checked_mul(multiplier))
+        .unwrap_or(i32::MAX);
+    Some(delay)
+}
+
+pub fn get_webhook_retry_schedule_time(
+    config: &WebhooksSettings,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    utils::get_time_from_delta(get_webhook_retry_delay(config, retry_count))
+}
+
+pub async fn retry_outgoing_webhook_task(
+    db: &dyn StorageInterface,
+    config: &WebhooksSettings,
+    pt: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    match get_webhook_retry_schedule_time(config, pt.retry_count + 1) {
+        Some(s_time) => pt.retry(db, s_time).await,
+        None => {
+            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_webhook_retry_delay() {
+        let config = WebhooksSettings {
+            outgoing_enabled: true,
+            outgoing_retry_initial_delay: 60,
+            outgoing_max_retries: 3,
+        };
+
+        let delays: Vec<_> = (0..4)
+            .map(|retry_count| get_webhook_retry_delay(&config, retry_count))
+            .collect();
+        assert_eq!(delays, vec![Some(60), Some(120), Some(240), None]);
+    }
+
+    #[test]
+    fn test_get_webhook_retry_delay_saturates() {
+        let config = WebhooksSettings {
+            outgoing_enabled: true,
+            outgoing_retry_initial_delay: 60,
+            outgoing_max_retries: 100,
+        };
+
+        assert_eq!(get_webhook_retry_delay(&config, 40), Some(i32::MAX));
+    }
+}
@@ -0,0 +1,235 @@
+use error_stack::IntoReport;
+use router_env::logger;
+
+use super::{payment_sync, PaymentsCaptureWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments::{self as payment_flows, operations},
+        webhooks,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::consumer,
+    services,
+    types::{
+        api::{self, enums as api_enums},
+        storage::{self, ProcessTrackerExt},
+    },
+    utils::{OptionExt, ValueExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for PaymentsCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsCaptureRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsCaptureRequest")?;
+
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+        let payment_id = tracking_data
+            .payment_id
+            .clone()
+            .get_required_value("payment_id")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&merchant_id)
+            .await?;
+
+        // A previous attempt may have failed after the connector captured the payment, the payment
+        // is synced with the connector so that it is not captured twice
+        if process.retry_count > 0 {
+            let payments_response =
+                retrieve_payment(state, &merchant_account, &payment_id, true).await?;
+            if payments_response.status != api_enums::IntentStatus::RequiresCapture {
+                logger::info!(
+                    %payment_id,
+                    status = ?payments_response.status,
+                    "Payment is no longer capturable, skipping the scheduled capture"
+                );
+                return finish_capture_task(
+                    state,
+                    &merchant_account,
+                    &payment_id,
+                    payments_response,
+                    process,
+                )
+                .await;
+            }
+        }
+
+        let capture_response =
+            payment_flows::payments_core::<api::Capture, api::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account.clone(),
+                operations::PaymentCapture,
+                tracking_data,
+                services::AuthFlow::Merchant,
+                payment_flows::CallConnectorAction::Trigger,
+            )
+            .await;
+
+        let payments_response = match capture_response {
+            Ok(services::ApplicationResponse::Json(payments_response)) => payments_response,
+            Ok(_) => Err(errors::ProcessTrackerError::UnexpectedFlow)?,
+            Err(error) if is_transient_capture_error(error.current_context()) => {
+                logger::warn!(
+                    ?error,
+                    %payment_id,
+                    retry_count = process.retry_count,
+                    "Scheduled capture of the payment failed"
+                );
+                return retry_capture_task(db, &merchant_account, &payment_id, process).await;
+            }
+            Err(error) => {
+                // The capture was rejected, the merchant is notified of the resulting status of
+                // the payment
+                logger::error!(?error, %payment_id, "Scheduled capture of the payment failed");
+                retrieve_payment(state, &merchant_account, &payment_id, false).await?
+            }
+        };
+
+        finish_capture_task(
+            state,
+            &merchant_account,
+            &payment_id,
+            payments_response,
+            process,
+        )
+        .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+/// Failures which happened before the connector could capture the payment, such as connector
+/// timeouts, server errors or a concurrent operation on the payment, are retried.
+fn is_transient_capture_error(error: &errors::ApiErrorResponse) -> bool {
+    matches!(
+        error,
+        errors::ApiErrorResponse::InternalServerError | errors::ApiErrorResponse::ResourceBusy
+    )
+}
+
+async fn finish_capture_task(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+    payments_response: api::PaymentsResponse,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    if let Err(error) =
+        webhooks::create_event_and_send_payment_webhook(state, merchant_account, payments_response)
+            .await
+    {
+        logger::error!(?error, %payment_id, "Failed to send the webhook of the capture");
+    }
+
+    let id = process.id.clone();
+    process
+        .finish_with_status(&*state.store, format!("COMPLETED_BY_PT_{id}"))
+        .await
+}
+
+/// Retrieves the payment, syncing its status with the connector when `force_sync` is set.
+async fn retrieve_payment(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+    force_sync: bool,
+) -> RouterResult<api::PaymentsResponse> {
+    let call_connector_action = if force_sync {
+        payment_flows::CallConnectorAction::Trigger
+    } else {
+        payment_flows::CallConnectorAction::Avoid
+    };
+    let payments_response =
+        payment_flows::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+            state,
+            merchant_account.clone(),
+            operations::PaymentStatus,
+            api::PaymentsRetrieveRequest {
+                resource_id: api::PaymentIdType::PaymentIntentId(payment_id.to_string()),
+                merchant_id: Some(merchant_account.merchant_id.clone()),
+                force_sync,
+                connector: None,
+                param: None,
+            },
+            services::AuthFlow::Merchant,
+            call_connector_action,
+        )
+        .await?;
+
+    match payments_response {
+        services::ApplicationResponse::Json(payments_response) => Ok(payments_response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError).into_report(),
+    }
+}
+
+async fn retry_capture_task(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+    pt: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    let connector = payment_attempt
+        .connector
+        .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
+
+    let schedule_time = payment_sync::get_sync_process_schedule_time(
+        db,
+        &connector,
+        &merchant_account.merchant_id,
+        pt.retry_count,
+    )
+    .await?;
+
+    match schedule_time {
+        Some(s_time) => pt.retry(db, s_time).await,
+        None => {
+            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_transient_capture_error() {
+        assert!(is_transient_capture_error(
+            &errors::ApiErrorResponse::InternalServerError
+        ));
+        assert!(is_transient_capture_error(
+            &errors::ApiErrorResponse::ResourceBusy
+        ));
+        assert!(!is_transient_capture_error(
+            &errors::ApiErrorResponse::PaymentNotFound
+        ));
+    }
+}
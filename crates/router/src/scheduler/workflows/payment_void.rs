@@ -0,0 +1,134 @@
+use router_env::logger;
+
+use super::{payment_sync, PaymentsVoidWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{
+        errors,
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::consumer,
+    services,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::ValueExt,
+};
+
+/// Cancellation reason recorded on the payments which are voided because their authorization
+/// expired
+const AUTHORIZATION_EXPIRED_CANCELLATION_REASON: &str = "authorization_expired";
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for PaymentsVoidWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::AuthorizationExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AuthorizationExpiryTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // Payments which were captured or cancelled in the meantime are left untouched
+        if payment_intent.status != enums::IntentStatus::RequiresCapture {
+            let id = process.id.clone();
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await;
+        }
+
+        let void_response =
+            payment_flows::payments_core::<api::Void, api::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account.clone(),
+                operations::PaymentCancel,
+                api::PaymentsCancelRequest {
+                    payment_id: tracking_data.payment_id.clone(),
+                    cancellation_reason: Some(
+                        AUTHORIZATION_EXPIRED_CANCELLATION_REASON.to_string(),
+                    ),
+                },
+                services::AuthFlow::Merchant,
+                payment_flows::CallConnectorAction::Trigger,
+            )
+            .await;
+
+        match void_response {
+            Ok(_) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await
+            }
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    payment_id = %tracking_data.payment_id,
+                    retry_count = process.retry_count,
+                    "Void of the expired authorization failed"
+                );
+                retry_void_task(db, &merchant_account, &tracking_data.payment_id, process).await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+async fn retry_void_task(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+    pt: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    let connector = payment_attempt
+        .connector
+        .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
+
+    let schedule_time = payment_sync::get_sync_process_schedule_time(
+        db,
+        &connector,
+        &merchant_account.merchant_id,
+        pt.retry_count,
+    )
+    .await?;
+
+    match schedule_time {
+        Some(s_time) => pt.retry(db, s_time).await,
+        None => {
+            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
@@ -0,0 +1,498 @@
+use router_env::logger;
+
+use super::{ProcessTrackerWorkflow, SubscriptionWorkflow};
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments::{self as payment_flows, operations},
+        subscriptions, webhooks,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::consumer,
+    services,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+        transformers::ForeignInto,
+    },
+    utils::{Encode, ValueExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for SubscriptionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: storage::SubscriptionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SubscriptionTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        let subscription = db
+            .find_subscription_by_merchant_id_subscription_id(
+                &tracking_data.merchant_id,
+                &tracking_data.subscription_id,
+            )
+            .await?;
+
+        // Cancelled subscriptions are no longer billed
+        if matches!(
+            subscription.status,
+            enums::SubscriptionStatus::Cancelled | enums::SubscriptionStatus::Ended
+        ) {
+            let id = process.id.clone();
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await;
+        }
+
+        if subscription
+            .end_date
+            .map(|end_date| subscription.current_period_end >= end_date)
+            .unwrap_or(false)
+        {
+            let subscription = db
+                .update_subscription(
+                    subscription,
+                    storage::SubscriptionUpdate::StatusUpdate {
+                        status: enums::SubscriptionStatus::Ended,
+                        cancelled_at: None,
+                    },
+                )
+                .await?;
+            send_subscription_webhook(
+                state,
+                &merchant_account,
+                enums::EventType::SubscriptionEnded,
+                subscription,
+            )
+            .await;
+
+            let id = process.id.clone();
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await;
+        }
+
+        // A task which runs again after the subscription moved to its next cycle, having failed to
+        // be rescheduled, waits for the next cycle to be due instead of charging it early
+        if subscription.current_period_end > common_utils::date_time::now() {
+            let retry_count = process.retry_count;
+            return reschedule_task(
+                db,
+                process,
+                &tracking_data,
+                subscription.current_period_end,
+                retry_count,
+            )
+            .await;
+        }
+
+        let payment_id = get_cycle_payment_id(
+            &subscription.subscription_id,
+            subscription.current_period_end,
+            subscription.failed_payment_attempts,
+        );
+        let payment_result =
+            get_or_create_cycle_payment(state, &merchant_account, &subscription, &payment_id).await;
+
+        match payment_result {
+            Ok(payment) if payment.status == api::enums::IntentStatus::Succeeded => {
+                let next_period_end = subscriptions::get_next_billing_time(
+                    subscription.trial_end.unwrap_or(subscription.created_at),
+                    subscription.current_period_end,
+                    subscription.billing_interval,
+                    subscription.interval_count,
+                )
+                .ok_or(errors::ProcessTrackerError::UnexpectedFlow)?;
+
+                let subscription = db
+                    .update_subscription(
+                        subscription.clone(),
+                        storage::SubscriptionUpdate::CycleUpdate {
+                            status: enums::SubscriptionStatus::Active,
+                            current_period_start: subscription.current_period_end,
+                            current_period_end: next_period_end,
+                            latest_payment_id: payment.payment_id,
+                        },
+                    )
+                    .await?;
+                send_subscription_webhook(
+                    state,
+                    &merchant_account,
+                    enums::EventType::SubscriptionRenewed,
+                    subscription,
+                )
+                .await;
+
+                tracking_data.pending_payment_id = None;
+                reschedule_task(db, process, &tracking_data, next_period_end, 0).await
+            }
+            Ok(payment) if is_pending_payment_status(payment.status) => {
+                wait_for_cycle_payment(
+                    state,
+                    subscription,
+                    tracking_data,
+                    payment.payment_id,
+                    process,
+                )
+                .await
+            }
+            Err(error) if cycle_payment_exists(db, &merchant_account, &payment_id).await => {
+                // The outcome of the payment is still unknown, it is synced again later
+                logger::warn!(
+                    ?error,
+                    subscription_id = %subscription.subscription_id,
+                    "Failed to process the payment of the subscription cycle"
+                );
+                wait_for_cycle_payment(
+                    state,
+                    subscription,
+                    tracking_data,
+                    Some(payment_id),
+                    process,
+                )
+                .await
+            }
+            payment_result => {
+                tracking_data.pending_payment_id = None;
+                let latest_payment_id = match payment_result {
+                    Ok(payment) => payment.payment_id,
+                    Err(error) => {
+                        logger::warn!(
+                            ?error,
+                            subscription_id = %subscription.subscription_id,
+                            "Payment of the subscription cycle failed"
+                        );
+                        None
+                    }
+                };
+                handle_failed_cycle_payment(
+                    state,
+                    &merchant_account,
+                    subscription,
+                    tracking_data,
+                    latest_payment_id,
+                    process,
+                )
+                .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+/// Id of the payment of a subscription cycle. The id is derived from the cycle and the number of
+/// failed payments of the cycle, so that a task which runs again finds the payment it has already
+/// made instead of charging the customer twice.
+fn get_cycle_payment_id(
+    subscription_id: &str,
+    current_period_end: time::PrimitiveDateTime,
+    failed_payment_attempts: i32,
+) -> String {
+    format!(
+        "{subscription_id}_{}_{failed_payment_attempts}",
+        current_period_end.assume_utc().unix_timestamp()
+    )
+}
+
+/// Whether the payment of the cycle has been created. Payments which cannot be looked up are
+/// assumed to exist, so that they are synced again instead of being charged again.
+async fn cycle_payment_exists(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+) -> bool {
+    match db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(_) => true,
+        Err(error) => !error.current_context().is_db_not_found(),
+    }
+}
+
+/// Syncs the payment of the cycle when it has already been created, and creates it otherwise
+async fn get_or_create_cycle_payment(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    subscription: &storage::Subscription,
+    payment_id: &str,
+) -> RouterResult<api::PaymentsResponse> {
+    if cycle_payment_exists(&*state.store, merchant_account, payment_id).await {
+        sync_cycle_payment(state, merchant_account, payment_id).await
+    } else {
+        create_cycle_payment(state, merchant_account, subscription, payment_id).await
+    }
+}
+
+/// Creates and confirms an off session payment of the subscription amount through its mandate
+async fn create_cycle_payment(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    subscription: &storage::Subscription,
+    payment_id: &str,
+) -> RouterResult<api::PaymentsResponse> {
+    let payment_request = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id.to_string())),
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        amount: Some(subscription.amount.into()),
+        currency: Some(subscription.currency.foreign_into()),
+        customer_id: Some(subscription.customer_id.clone()),
+        mandate_id: Some(subscription.mandate_id.clone()),
+        confirm: Some(true),
+        off_session: Some(true),
+        description: subscription.description.clone(),
+        ..Default::default()
+    };
+
+    let payments_response =
+        payment_flows::payments_core::<api::Authorize, api::PaymentsResponse, _, _, _>(
+            state,
+            merchant_account.clone(),
+            operations::PaymentCreate,
+            payment_request,
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Trigger,
+        )
+        .await?;
+
+    match payments_response {
+        services::ApplicationResponse::Json(payments_response) => Ok(payments_response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)?,
+    }
+}
+
+/// Syncs the status of the payment of the cycle with the connector
+async fn sync_cycle_payment(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+) -> RouterResult<api::PaymentsResponse> {
+    let payments_response =
+        payment_flows::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+            state,
+            merchant_account.clone(),
+            operations::PaymentStatus,
+            api::PaymentsRetrieveRequest {
+                resource_id: api::PaymentIdType::PaymentIntentId(payment_id.to_string()),
+                merchant_id: Some(merchant_account.merchant_id.clone()),
+                force_sync: true,
+                connector: None,
+                param: None,
+            },
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Trigger,
+        )
+        .await?;
+
+    match payments_response {
+        services::ApplicationResponse::Json(payments_response) => Ok(payments_response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)?,
+    }
+}
+
+/// Payments in these statuses have neither succeeded nor failed yet
+fn is_pending_payment_status(status: api::enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        api::enums::IntentStatus::Processing | api::enums::IntentStatus::RequiresCustomerAction
+    )
+}
+
+/// Checks the pending payment of the cycle again after the dunning interval, without counting it
+/// as a failed payment of the subscription.
+async fn wait_for_cycle_payment(
+    state: &AppState,
+    subscription: storage::Subscription,
+    mut tracking_data: storage::SubscriptionTrackingData,
+    payment_id: Option<String>,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+
+    if payment_id.is_some() && subscription.latest_payment_id != payment_id {
+        db.update_subscription(
+            subscription,
+            storage::SubscriptionUpdate::PaymentPendingUpdate {
+                latest_payment_id: payment_id.clone(),
+            },
+        )
+        .await?;
+    }
+
+    tracking_data.pending_payment_id = payment_id;
+    let retry_count = process.retry_count + 1;
+    let retry_time = common_utils::date_time::now()
+        + time::Duration::seconds(state.conf.subscriptions.dunning_retry_interval);
+    reschedule_task(db, process, &tracking_data, retry_time, retry_count).await
+}
+
+/// Retries the payment of the cycle after the dunning interval, and cancels the subscription
+/// once the payment has failed more times than the configured number of retries.
+async fn handle_failed_cycle_payment(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    subscription: storage::Subscription,
+    tracking_data: storage::SubscriptionTrackingData,
+    latest_payment_id: Option<String>,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let dunning = &state.conf.subscriptions;
+    let failed_payment_attempts = subscription.failed_payment_attempts + 1;
+    let retries_exceeded = failed_payment_attempts > dunning.dunning_max_retries;
+
+    let (status, cancelled_at) = if retries_exceeded {
+        (
+            enums::SubscriptionStatus::Cancelled,
+            Some(common_utils::date_time::now()),
+        )
+    } else {
+        (enums::SubscriptionStatus::PastDue, None)
+    };
+
+    let subscription = db
+        .update_subscription(
+            subscription,
+            storage::SubscriptionUpdate::PaymentFailedUpdate {
+                status,
+                failed_payment_attempts,
+                latest_payment_id,
+                cancelled_at,
+            },
+        )
+        .await?;
+    send_subscription_webhook(
+        state,
+        merchant_account,
+        enums::EventType::SubscriptionPaymentFailed,
+        subscription.clone(),
+    )
+    .await;
+
+    if retries_exceeded {
+        send_subscription_webhook(
+            state,
+            merchant_account,
+            enums::EventType::SubscriptionCancelled,
+            subscription,
+        )
+        .await;
+        process
+            .finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+            .await
+    } else {
+        let retry_count = process.retry_count + 1;
+        let retry_time = common_utils::date_time::now()
+            + time::Duration::seconds(dunning.dunning_retry_interval);
+        reschedule_task(db, process, &tracking_data, retry_time, retry_count).await
+    }
+}
+
+/// Reschedules the task of the subscription, along with the payment of the cycle which is pending
+async fn reschedule_task(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    tracking_data: &storage::SubscriptionTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+    retry_count: i32,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data =
+        Encode::<storage::SubscriptionTrackingData>::encode_to_value(tracking_data)?;
+    db.update_process_tracker(
+        process,
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(retry_count),
+            schedule_time: Some(schedule_time),
+            tracking_data: Some(tracking_data),
+            business_status: Some(String::from("Pending")),
+            status: Some(enums::ProcessTrackerStatus::Pending),
+            updated_at: Some(common_utils::date_time::now()),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Sends the webhook of the subscription in the current task, as the scheduler does not run on an
+/// actix arbiter. Failures are logged, as the change of the subscription has already been made.
+async fn send_subscription_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event_type: enums::EventType,
+    subscription: storage::Subscription,
+) {
+    let subscription_id = subscription.subscription_id.clone();
+    let subscription_response: api::SubscriptionResponse = subscription.foreign_into();
+
+    if let Err(error) = webhooks::create_event_and_send_outgoing_webhook(
+        state,
+        merchant_account,
+        event_type,
+        enums::EventClass::Subscriptions,
+        None,
+        subscription_id.clone(),
+        enums::EventObjectType::SubscriptionDetails,
+        api::OutgoingWebhookContent::SubscriptionDetails(Box::new(subscription_response)),
+    )
+    .await
+    {
+        logger::error!(?error, %subscription_id, "Failed to send the webhook of the subscription");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_pending_payment_status() {
+        assert!(is_pending_payment_status(
+            api::enums::IntentStatus::Processing
+        ));
+        assert!(is_pending_payment_status(
+            api::enums::IntentStatus::RequiresCustomerAction
+        ));
+        assert!(!is_pending_payment_status(
+            api::enums::IntentStatus::Succeeded
+        ));
+        assert!(!is_pending_payment_status(api::enums::IntentStatus::Failed));
+    }
+
+    #[test]
+    fn test_get_cycle_payment_id() {
+        let period_end = time::macros::datetime!(2023-04-01 00:00);
+        let payment_id = get_cycle_payment_id("sub_1", period_end, 0);
+
+        // A task which runs again for the same cycle finds the payment it has already made
+        assert_eq!(payment_id, "sub_1_1680307200_0");
+        assert_eq!(payment_id, get_cycle_payment_id("sub_1", period_end, 0));
+        assert_ne!(payment_id, get_cycle_payment_id("sub_1", period_end, 1));
+        assert_ne!(
+            payment_id,
+            get_cycle_payment_id("sub_1", period_end + time::Duration::days(30), 0)
+        );
+    }
+}
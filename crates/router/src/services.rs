@@ -1,6 +1,9 @@
 pub mod api;
 pub mod authentication;
 pub mod encryption;
+pub mod file_storage;
+pub mod key_manager;
+pub mod locking;
 pub mod logger;
 
 use std::sync::Arc;
@@ -14,6 +17,7 @@ pub struct Store {
     #[cfg(feature = "olap")]
     pub replica_pool: PgPool,
     pub redis_conn: Arc<redis_interface::RedisConnectionPool>,
+    pub master_key_manager: key_manager::MasterKeyManager,
     #[cfg(feature = "kv_store")]
     pub(crate) config: StoreConfig,
 }
@@ -32,6 +36,7 @@ impl Store {
             #[cfg(feature = "olap")]
             replica_pool: diesel_make_pg_pool(&config.replica_database, test_transaction).await,
             redis_conn: Arc::new(crate::connection::redis_connection(config).await),
+            master_key_manager: key_manager::MasterKeyManager::new(&config.connector_credentials),
             #[cfg(feature = "kv_store")]
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
@@ -1,4 +1,5 @@
 mod client;
+pub(crate) mod idempotency;
 pub(crate) mod request;
 
 use std::{
@@ -9,16 +10,17 @@ use std::{
     time::{Duration, Instant},
 };
 
-use actix_web::{body, HttpRequest, HttpResponse, Responder};
+use actix_web::{body, http::StatusCode, HttpRequest, HttpResponse, Responder, ResponseError};
 use error_stack::{report, IntoReport, Report, ResultExt};
 use masking::ExposeOptionInterface;
 use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
-use self::request::{ContentType, HeaderExt, RequestBuilderExt};
-pub use self::request::{Method, Request, RequestBuilder};
+pub use self::request::{ContentType, Method, Request, RequestBuilder};
+use self::request::{HeaderExt, RequestBuilderExt};
 use crate::{
     configs::settings::Connectors,
+    consts,
     core::{
         errors::{self, CustomResult, RouterResponse, RouterResult},
         payments,
@@ -83,6 +85,13 @@ pub trait ConnectorIntegration<T, Req, Resp>: ConnectorIntegrationAny<T, Req, Re
         Ok(None)
     }
 
+    fn get_request_form_data(
+        &self,
+        _req: &types::RouterData<T, Req, Resp>,
+    ) -> CustomResult<Option<reqwest::multipart::Form>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     fn build_request(
         &self,
         _req: &types::RouterData<T, Req, Resp>,
@@ -241,6 +250,10 @@ async fn send_request(
                     logger::debug!(?url_encoded_payload);
                     client.body(url_encoded_payload)
                 }
+                Some(ContentType::FormData) => match request.form_data {
+                    Some(form_data) => client.multipart(form_data),
+                    None => client,
+                },
                 // If payload needs processing the body cannot have default
                 None => client.body(request.payload.expose_option().unwrap_or_default()),
             }
@@ -339,6 +352,7 @@ pub enum ApplicationResponse<R> {
     StatusOk,
     TextPlain(String),
     JsonForRedirection(api::RedirectionResponse),
+    FileData((Vec<u8>, mime::Mime)),
     Form(RedirectForm),
 }
 
@@ -404,33 +418,125 @@ where
     func(state, auth_out, payload).await
 }
 
-#[instrument(
-    skip(request, payload, state, func, api_auth),
-    fields(request_method, request_url_path)
-)]
-pub async fn server_wrap<'a, 'b, A, T, U, Q, F, Fut>(
+/// Processes a request which carries an idempotency key. The response of the first request made
+/// with the key is stored, and replayed to the later requests made with the same key. Once the
+/// handler has run the key is never released, as the handler may have had side effects even when
+/// it failed.
+#[instrument(skip_all)]
+async fn idempotent_server_wrap<'a, 'b, A, T, U, Q, F, Fut>(
     state: &'b A,
     request: &'a HttpRequest,
     payload: T,
     func: F,
     api_auth: &dyn auth::AuthenticateAndFetch<U, A>,
+    idempotency_key: String,
 ) -> HttpResponse
 where
     F: Fn(&'b A, U, T) -> Fut,
     Fut: Future<Output = RouterResult<ApplicationResponse<Q>>>,
     Q: Serialize + Debug + 'a,
     T: Debug,
+    U: auth::AuthInfo,
     A: AppStateInfo,
 {
-    let request_method = request.method().as_str();
-    let url_path = request.path();
-    tracing::Span::current().record("request_method", request_method);
-    tracing::Span::current().record("request_url_path", url_path);
+    let auth_out = match api_auth
+        .authenticate_and_fetch(request.headers(), state)
+        .await
+    {
+        Ok(auth_out) => auth_out,
+        Err(error) => return log_and_return_error_response(error),
+    };
 
-    let start_instant = Instant::now();
-    logger::info!(tag = ?Tag::BeginRequest);
+    // Idempotency keys are scoped to a merchant, requests which are not made on behalf of a
+    // merchant are not deduplicated
+    let merchant_id = match auth_out.get_merchant_id() {
+        Some(merchant_id) => merchant_id.to_owned(),
+        None => return build_http_response(request, func(state, auth_out, payload).await),
+    };
+    let request_fingerprint = match idempotency::get_request_fingerprint(request) {
+        Ok(request_fingerprint) => request_fingerprint,
+        Err(error) => return log_and_return_error_response(error),
+    };
+
+    let store = state.store();
+    match store
+        .reserve_idempotency_key(&merchant_id, &idempotency_key, &request_fingerprint)
+        .await
+    {
+        Ok(None) => {}
+        Ok(Some(record)) => {
+            logger::info!(%idempotency_key, "Replaying the response of the idempotency key");
+            return idempotency::replay_idempotent_response(record, &request_fingerprint)
+                .unwrap_or_else(log_and_return_error_response);
+        }
+        Err(error) => {
+            return log_and_return_error_response(
+                error.change_context(errors::ApiErrorResponse::InternalServerError),
+            )
+        }
+    }
+
+    let result = func(state, auth_out, payload).await;
+
+    // Responses which cannot be replayed as they were sent, such as redirections and forms, are
+    // not stored. Their key is left reserved, and requests made with it are rejected until the
+    // reservation expires.
+    if let Some((status_code, response_body)) = get_idempotent_response_to_store(&result) {
+        if let Err(error) = store
+            .insert_idempotent_response(
+                storage::IdempotentResponseNew {
+                    merchant_id,
+                    idempotency_key,
+                    request_fingerprint,
+                    response_status_code: i32::from(status_code.as_u16()),
+                    response_body,
+                },
+                consts::IDEMPOTENT_RESPONSE_TTL,
+            )
+            .await
+        {
+            logger::error!(idempotent_response_error=?error);
+        }
+    }
+
+    build_http_response(request, result)
+}
+
+/// The status code and body of a response which can be replayed to later requests made with the
+/// same idempotency key. Errors are stored along with the successes, server errors included, since
+/// the request may have had side effects before it failed.
+fn get_idempotent_response_to_store<Q>(
+    result: &RouterResult<ApplicationResponse<Q>>,
+) -> Option<(StatusCode, String)>
+where
+    Q: Serialize,
+{
+    match result {
+        Ok(ApplicationResponse::Json(response)) => serde_json::to_string(response)
+            .ok()
+            .map(|response_body| (StatusCode::OK, response_body)),
+        Ok(ApplicationResponse::StatusOk) => Some((StatusCode::OK, String::new())),
+        Ok(
+            ApplicationResponse::TextPlain(_)
+            | ApplicationResponse::JsonForRedirection(_)
+            | ApplicationResponse::FileData(_)
+            | ApplicationResponse::Form(_),
+        ) => None,
+        Err(error) => Some((
+            error.current_context().status_code(),
+            error.current_context().to_string(),
+        )),
+    }
+}
 
-    let res = match server_wrap_util(state, request, payload, func, api_auth).await {
+fn build_http_response<Q>(
+    request: &HttpRequest,
+    result: RouterResult<ApplicationResponse<Q>>,
+) -> HttpResponse
+where
+    Q: Serialize + Debug,
+{
+    match result {
         Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
             Ok(res) => http_response_json(res),
             Err(_) => http_response_err(
@@ -443,6 +549,9 @@ where
         },
         Ok(ApplicationResponse::StatusOk) => http_response_ok(),
         Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
+        Ok(ApplicationResponse::FileData((file_data, content_type))) => {
+            http_response_file_data(file_data, content_type)
+        }
         Ok(ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => http_redirect_response(res, response),
@@ -460,6 +569,48 @@ where
             .map_into_boxed_body(),
 
         Err(error) => log_and_return_error_response(error),
+    }
+}
+
+/// Authenticates and processes a request. POST requests carrying an `Idempotency-Key` header are
+/// processed at most once per merchant and key, the response of the first request being replayed
+/// to the requests made later with the same key.
+#[instrument(
+    skip(request, payload, state, func, api_auth),
+    fields(request_method, request_url_path)
+)]
+pub async fn server_wrap<'a, 'b, A, T, U, Q, F, Fut>(
+    state: &'b A,
+    request: &'a HttpRequest,
+    payload: T,
+    func: F,
+    api_auth: &dyn auth::AuthenticateAndFetch<U, A>,
+) -> HttpResponse
+where
+    F: Fn(&'b A, U, T) -> Fut,
+    Fut: Future<Output = RouterResult<ApplicationResponse<Q>>>,
+    Q: Serialize + Debug + 'a,
+    T: Debug,
+    U: auth::AuthInfo,
+    A: AppStateInfo,
+{
+    let request_method = request.method().as_str();
+    let url_path = request.path();
+    tracing::Span::current().record("request_method", request_method);
+    tracing::Span::current().record("request_url_path", url_path);
+
+    let start_instant = Instant::now();
+    logger::info!(tag = ?Tag::BeginRequest);
+
+    let res = match idempotency::get_idempotency_key(request) {
+        Ok(Some(idempotency_key)) => {
+            idempotent_server_wrap(state, request, payload, func, api_auth, idempotency_key).await
+        }
+        Ok(None) => build_http_response(
+            request,
+            server_wrap_util(state, request, payload, func, api_auth).await,
+        ),
+        Err(error) => log_and_return_error_response(error),
     };
 
     let response_code = res.status().as_u16();
@@ -507,6 +658,13 @@ pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpRe
         .body(res)
 }
 
+pub fn http_response_file_data<T: body::MessageBody + 'static>(
+    res: T,
+    content_type: mime::Mime,
+) -> HttpResponse {
+    HttpResponse::Ok().content_type(content_type).body(res)
+}
+
 pub fn http_response_ok() -> HttpResponse {
     HttpResponse::Ok().finish()
 }
@@ -620,8 +778,39 @@ pub fn build_redirection_form(form: &RedirectForm) -> maud::Markup {
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[test]
+    fn test_idempotent_response_to_store() {
+        let response_to_store = |result: RouterResult<ApplicationResponse<serde_json::Value>>| {
+            get_idempotent_response_to_store(&result)
+        };
+
+        assert_eq!(
+            response_to_store(Ok(ApplicationResponse::Json(
+                serde_json::json!({ "status": "succeeded" })
+            ))),
+            Some((StatusCode::OK, r#"{"status":"succeeded"}"#.to_string()))
+        );
+        // Server errors are stored as well, the request may have had side effects before failing
+        let (status_code, _) =
+            response_to_store(Err(report!(errors::ApiErrorResponse::InternalServerError)))
+                .expect("server error is not stored");
+        assert_eq!(status_code, StatusCode::INTERNAL_SERVER_ERROR);
+        // Forms cannot be replayed, their key stays reserved until it expires
+        assert_eq!(
+            response_to_store(Ok(ApplicationResponse::Form(RedirectForm::new(
+                "https://example.com".to_string(),
+                Method::Post,
+                HashMap::new(),
+            )))),
+            None
+        );
+    }
 }
@@ -0,0 +1,197 @@
+use actix_web::{
+    http::{Method, StatusCode},
+    HttpMessage, HttpRequest, HttpResponse,
+};
+use common_utils::crypto::{self, GenerateDigest};
+use error_stack::{report, IntoReport, ResultExt};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    types::storage,
+};
+
+const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+const IDEMPOTENT_REPLAYED_HEADER: &str = "Idempotent-Replayed";
+const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+/// The raw body of a request carrying an idempotency key, captured before the extractors of the
+/// route consume the body.
+#[derive(Clone, Debug)]
+pub struct RequestBody(pub bytes::Bytes);
+
+/// Returns the idempotency key of a POST request. Requests of the other methods do not mutate
+/// resources, and are not deduplicated.
+pub fn get_idempotency_key(request: &HttpRequest) -> RouterResult<Option<String>> {
+    if request.method() != Method::POST {
+        return Ok(None);
+    }
+
+    request
+        .headers()
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .map(|header_value| {
+            let idempotency_key = header_value.to_str().into_report().change_context(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("{IDEMPOTENCY_KEY_HEADER} header must be an ASCII string"),
+                },
+            )?;
+
+            if idempotency_key.is_empty() || idempotency_key.len() > IDEMPOTENCY_KEY_MAX_LENGTH {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "{IDEMPOTENCY_KEY_HEADER} header must be between 1 and \
+                         {IDEMPOTENCY_KEY_MAX_LENGTH} characters long"
+                    ),
+                }));
+            }
+
+            Ok(idempotency_key.to_owned())
+        })
+        .transpose()
+}
+
+/// Fingerprint of the request, used to detect an idempotency key being reused with a different
+/// request. The raw body is fingerprinted, as the masked fields of the deserialized payload would
+/// hide differences in sensitive data.
+pub fn get_request_fingerprint(request: &HttpRequest) -> RouterResult<String> {
+    let extensions = HttpMessage::extensions(request);
+    let body = extensions
+        .get::<RequestBody>()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("The body of the idempotent request was not captured")?;
+
+    let mut message = format!("{} {} ", request.method(), request.path()).into_bytes();
+    message.extend_from_slice(&body.0);
+
+    crypto::Sha512
+        .generate_digest(&message)
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the request fingerprint")
+}
+
+/// Replays the response stored for an idempotency key, provided that the key was used with the
+/// same request and its response is known.
+pub fn replay_idempotent_response(
+    record: storage::IdempotencyRecord,
+    request_fingerprint: &str,
+) -> RouterResult<HttpResponse> {
+    if record.request_fingerprint != request_fingerprint {
+        return Err(report!(errors::ApiErrorResponse::IdempotencyKeyMismatch));
+    }
+
+    let (status_code, response_body) = record
+        .response_status_code
+        .zip(record.response_body)
+        .ok_or(errors::ApiErrorResponse::IdempotencyKeyInUse)
+        .into_report()?;
+    let status_code = u16::try_from(status_code)
+        .ok()
+        .and_then(|status_code| StatusCode::from_u16(status_code).ok())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Invalid status code stored for the idempotency key")?;
+
+    Ok(HttpResponse::build(status_code)
+        .content_type("application/json")
+        .append_header(("Via", "Juspay_router"))
+        .append_header((IDEMPOTENT_REPLAYED_HEADER, "true"))
+        .body(response_body))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::{test::TestRequest, web, App};
+
+    use super::*;
+    use crate::middleware;
+
+    #[test]
+    fn test_get_request_fingerprint() {
+        let request = TestRequest::post().uri("/payments").to_http_request();
+        assert!(get_request_fingerprint(&request).is_err());
+
+        let fingerprint_of = |body: &'static str| {
+            let request = TestRequest::post().uri("/payments").to_http_request();
+            HttpMessage::extensions_mut(&request)
+                .insert(RequestBody(bytes::Bytes::from_static(body.as_bytes())));
+            get_request_fingerprint(&request).unwrap()
+        };
+        let fingerprint = fingerprint_of(r#"{"card_number":"4242424242424242"}"#);
+        assert_eq!(
+            fingerprint,
+            fingerprint_of(r#"{"card_number":"4242424242424242"}"#)
+        );
+        assert_ne!(
+            fingerprint,
+            fingerprint_of(r#"{"card_number":"4000000000000002"}"#)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_request_body_is_captured_for_idempotent_requests() {
+        async fn fingerprint(request: HttpRequest, body: bytes::Bytes) -> HttpResponse {
+            let fingerprint = get_request_fingerprint(&request).unwrap_or_default();
+            HttpResponse::Ok().body(format!("{fingerprint}:{}", body.len()))
+        }
+        let app = actix_web::test::init_service(
+            App::new()
+                .wrap(middleware::IdempotentRequestBody)
+                .route("/payments", web::post().to(fingerprint)),
+        )
+        .await;
+
+        let request = TestRequest::post()
+            .uri("/payments")
+            .insert_header((IDEMPOTENCY_KEY_HEADER, "key_1"))
+            .set_payload("body")
+            .to_request();
+        let response = actix_web::test::call_and_read_body(&app, request).await;
+        let (fingerprint, body_length) = std::str::from_utf8(&response)
+            .unwrap()
+            .split_once(':')
+            .unwrap();
+        assert!(!fingerprint.is_empty());
+        assert_eq!(body_length, "4");
+
+        let request = TestRequest::post()
+            .uri("/payments")
+            .set_payload("body")
+            .to_request();
+        let response = actix_web::test::call_and_read_body(&app, request).await;
+        assert_eq!(&response[..], b":4");
+    }
+
+    #[test]
+    fn test_replay_idempotent_response() {
+        let in_progress_record = storage::IdempotencyRecord {
+            request_fingerprint: "fingerprint".to_string(),
+            response_status_code: None,
+            response_body: None,
+        };
+        let error =
+            replay_idempotent_response(in_progress_record.clone(), "fingerprint").unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyInUse
+        ));
+
+        let error =
+            replay_idempotent_response(in_progress_record, "other_fingerprint").unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyMismatch
+        ));
+
+        let completed_record = storage::IdempotencyRecord {
+            request_fingerprint: "fingerprint".to_string(),
+            response_status_code: Some(400),
+            response_body: Some(r#"{"error":{}}"#.to_string()),
+        };
+        let response = replay_idempotent_response(completed_record, "fingerprint").unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(response.headers().contains_key(IDEMPOTENT_REPLAYED_HEADER));
+    }
+}
@@ -28,6 +28,7 @@ pub enum Method {
 pub enum ContentType {
     Json,
     FormUrlEncoded,
+    FormData,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -39,6 +40,8 @@ pub struct Request {
     pub content_type: Option<ContentType>,
     pub certificate: Option<String>,
     pub certificate_key: Option<String>,
+    #[serde(skip)]
+    pub form_data: Option<reqwest::multipart::Form>,
 }
 
 impl Request {
@@ -51,6 +54,7 @@ impl Request {
             content_type: None,
             certificate: None,
             certificate_key: None,
+            form_data: None,
         }
     }
 
@@ -84,6 +88,7 @@ pub struct RequestBuilder {
     pub content_type: Option<ContentType>,
     pub certificate: Option<String>,
     pub certificate_key: Option<String>,
+    pub form_data: Option<reqwest::multipart::Form>,
 }
 
 impl RequestBuilder {
@@ -96,6 +101,7 @@ impl RequestBuilder {
             content_type: None,
             certificate: None,
             certificate_key: None,
+            form_data: None,
         }
     }
 
@@ -130,6 +136,11 @@ impl RequestBuilder {
         self
     }
 
+    pub fn form_data(mut self, form_data: Option<reqwest::multipart::Form>) -> Self {
+        self.form_data = form_data;
+        self
+    }
+
     pub fn add_certificate(mut self, certificate: Option<String>) -> Self {
         self.certificate = certificate;
         self
@@ -149,6 +160,7 @@ impl RequestBuilder {
             content_type: self.content_type,
             certificate: self.certificate,
             certificate_key: self.certificate_key,
+            form_data: self.form_data,
         }
     }
 }
@@ -5,7 +5,10 @@ use error_stack::{report, IntoReport, ResultExt};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
 
 use crate::{
-    core::errors::{self, RouterResult, StorageErrorExt},
+    core::{
+        api_keys,
+        errors::{self, RouterResult, StorageErrorExt},
+    },
     db::StorageInterface,
     routes::{app::AppStateInfo, AppState},
     services::api,
@@ -25,6 +28,23 @@ where
     ) -> RouterResult<T>;
 }
 
+/// Merchant on whose behalf an authenticated request is made.
+pub trait AuthInfo {
+    fn get_merchant_id(&self) -> Option<&str>;
+}
+
+impl AuthInfo for () {
+    fn get_merchant_id(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl AuthInfo for storage::MerchantAccount {
+    fn get_merchant_id(&self) -> Option<&str> {
+        Some(&self.merchant_id)
+    }
+}
+
 #[derive(Debug)]
 pub struct ApiKeyAuth;
 
@@ -46,6 +66,29 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for ApiKeyAuth {
     }
 }
 
+/// Authentication through an API key created through the API keys API, which must have been
+/// granted the given scope. The API key of the merchant account is not accepted.
+#[derive(Debug)]
+pub struct ScopedApiKeyAuth(pub api_models::api_keys::ApiKeyScope);
+
+#[async_trait]
+impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for ScopedApiKeyAuth {
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &AppState,
+    ) -> RouterResult<storage::MerchantAccount> {
+        let api_key =
+            get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
+        let stored_api_key = api_keys::verify_api_key_scope(&*state.store, api_key, self.0).await?;
+        state
+            .store
+            .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id)
+            .await
+            .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::Unauthorized))
+    }
+}
+
 #[derive(Debug)]
 pub struct AdminApiAuth;
 
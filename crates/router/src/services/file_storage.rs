@@ -0,0 +1,89 @@
+use std::path::PathBuf;
+
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{
+    configs::settings::{FileStorageBackend, FileUploadConfig},
+    core::errors::{self, CustomResult},
+};
+
+/// A store for the contents of uploaded files, addressed by a key generated by the router.
+#[async_trait::async_trait]
+pub trait FileStorage: Send + Sync {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), errors::FileStorageError>;
+
+    async fn retrieve_file(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Vec<u8>, errors::FileStorageError>;
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::FileStorageError>;
+}
+
+pub fn get_file_storage(config: &FileUploadConfig) -> Box<dyn FileStorage> {
+    match config.file_storage_backend {
+        FileStorageBackend::Local => Box::new(LocalFileStorage::new(&config.local_storage_path)),
+    }
+}
+
+/// Stores files in a directory on the local filesystem of the router.
+pub struct LocalFileStorage {
+    base_path: PathBuf,
+}
+
+impl LocalFileStorage {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: PathBuf::from(base_path),
+        }
+    }
+
+    fn get_file_path(&self, file_key: &str) -> PathBuf {
+        self.base_path.join(file_key)
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorage for LocalFileStorage {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), errors::FileStorageError> {
+        let file_path = self.get_file_path(file_key);
+        if let Some(parent) = file_path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .into_report()
+                .change_context(errors::FileStorageError::DirectoryCreationFailed)?;
+        }
+        tokio::fs::write(&file_path, file)
+            .await
+            .into_report()
+            .change_context(errors::FileStorageError::FileUploadFailed)
+            .attach_printable_lazy(|| format!("Failed to write file with key: {file_key}"))
+    }
+
+    async fn retrieve_file(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Vec<u8>, errors::FileStorageError> {
+        tokio::fs::read(self.get_file_path(file_key))
+            .await
+            .into_report()
+            .change_context(errors::FileStorageError::FileRetrievalFailed)
+            .attach_printable_lazy(|| format!("Failed to read file with key: {file_key}"))
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::FileStorageError> {
+        tokio::fs::remove_file(self.get_file_path(file_key))
+            .await
+            .into_report()
+            .change_context(errors::FileStorageError::FileDeletionFailed)
+            .attach_printable_lazy(|| format!("Failed to delete file with key: {file_key}"))
+    }
+}
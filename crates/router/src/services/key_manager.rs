@@ -0,0 +1,346 @@
+//! Envelope encryption of merchant data: the data of a merchant is encrypted with data keys of the
+//! merchant, which are themselves stored wrapped by a master key. The master key is read from a
+//! local key file, or is a KMS key when the `kms` feature is enabled.
+//!
+//! Unwrapped data keys are cached in memory: a data key never changes once created, as a new key
+//! version is created instead. Replacing the master key is not supported yet, as it requires
+//! re-wrapping the data keys of every merchant. Automatic rotation of a KMS key needs no
+//! re-wrapping, since KMS keeps the previous key material to unwrap keys wrapped before it.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use base64::Engine;
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{
+    configs::settings::{ConnectorCredentials, LocalVault},
+    consts,
+    core::errors::{self, CustomResult},
+    services::encryption,
+    types::storage,
+};
+
+#[derive(Clone)]
+pub struct MasterKeyManager {
+    #[cfg(not(feature = "kms"))]
+    master_key_file: String,
+    #[cfg(not(feature = "kms"))]
+    master_key: Arc<once_cell::sync::OnceCell<Vec<u8>>>,
+    #[cfg(feature = "kms")]
+    kms_key_id: String,
+    #[cfg(feature = "kms")]
+    kms_region: String,
+    #[cfg(feature = "kms")]
+    kms_client: Arc<tokio::sync::OnceCell<aws_sdk_kms::Client>>,
+    /// Unwrapped data keys, by merchant ID and key version
+    data_keys: Arc<RwLock<HashMap<(String, i32), Vec<u8>>>>,
+}
+
+impl MasterKeyManager {
+    pub fn new(conf: &ConnectorCredentials) -> Self {
+        Self {
+            #[cfg(not(feature = "kms"))]
+            master_key_file: conf.master_key_file.clone(),
+            #[cfg(not(feature = "kms"))]
+            master_key: Default::default(),
+            #[cfg(feature = "kms")]
+            kms_key_id: conf.kms_key_id.clone(),
+            #[cfg(feature = "kms")]
+            kms_region: conf.kms_region.clone(),
+            #[cfg(feature = "kms")]
+            kms_client: Default::default(),
+            data_keys: Default::default(),
+        }
+    }
+
+    pub fn get_cached_data_key(&self, merchant_id: &str, key_version: i32) -> Option<Vec<u8>> {
+        self.data_keys
+            .read()
+            .ok()?
+            .get(&(merchant_id.to_string(), key_version))
+            .cloned()
+    }
+
+    /// Unwraps the data key of the key store, caching it for later reads of the same key version
+    pub async fn unwrap_merchant_data_key(
+        &self,
+        key_store: storage::MerchantKeyStore,
+    ) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+        if let Some(data_key) =
+            self.get_cached_data_key(&key_store.merchant_id, key_store.key_version)
+        {
+            return Ok(data_key);
+        }
+
+        let data_key = self.unwrap_data_key(key_store.encrypted_key).await?;
+        if let Ok(mut data_keys) = self.data_keys.write() {
+            data_keys.insert(
+                (key_store.merchant_id, key_store.key_version),
+                data_key.clone(),
+            );
+        }
+        Ok(data_key)
+    }
+}
+
+#[cfg(not(feature = "kms"))]
+impl MasterKeyManager {
+    /// Reads the master key from the key file on first use
+    fn get_master_key(&self) -> CustomResult<&[u8], errors::EncryptionError> {
+        self.master_key
+            .get_or_try_init(|| read_key_file(&self.master_key_file))
+            .map(Vec::as_slice)
+    }
+
+    pub async fn wrap_data_key(
+        &self,
+        data_key: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+        encryption::encrypt(&hex::encode(data_key), self.get_master_key()?)
+    }
+
+    pub async fn unwrap_data_key(
+        &self,
+        wrapped_data_key: Vec<u8>,
+    ) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+        let data_key = encryption::decrypt(wrapped_data_key, self.get_master_key()?)?;
+        hex::decode(data_key)
+            .into_report()
+            .change_context(errors::EncryptionError)
+            .attach_printable("Data key is not hex encoded")
+    }
+}
+
+#[cfg(feature = "kms")]
+mod kms {
+    use aws_config::meta::region::RegionProviderChain;
+    use aws_sdk_kms::{types::Blob, Client, Region};
+    use error_stack::{report, IntoReport, ResultExt};
+
+    use super::MasterKeyManager;
+    use crate::core::errors::{self, CustomResult};
+
+    impl MasterKeyManager {
+        /// Creates the KMS client on first use
+        async fn get_kms_client(&self) -> &Client {
+            self.kms_client
+                .get_or_init(|| async {
+                    let region_provider =
+                        RegionProviderChain::first_try(Region::new(self.kms_region.clone()));
+                    let shared_config = aws_config::from_env().region(region_provider).load().await;
+                    Client::new(&shared_config)
+                })
+                .await
+        }
+
+        pub async fn wrap_data_key(
+            &self,
+            data_key: &[u8],
+        ) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+            let resp = self
+                .get_kms_client()
+                .await
+                .encrypt()
+                .key_id(self.kms_key_id.clone())
+                .plaintext(Blob::new(data_key.to_vec()))
+                .send()
+                .await
+                .into_report()
+                .change_context(errors::EncryptionError)
+                .attach_printable("Error wrapping the data key with kms")?;
+            match resp.ciphertext_blob() {
+                Some(inner) => Ok(inner.as_ref().to_vec()),
+                None => Err(report!(errors::EncryptionError)
+                    .attach_printable("Missing ciphertext blob in response")),
+            }
+        }
+
+        pub async fn unwrap_data_key(
+            &self,
+            wrapped_data_key: Vec<u8>,
+        ) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+            let resp = self
+                .get_kms_client()
+                .await
+                .decrypt()
+                .key_id(self.kms_key_id.clone())
+                .ciphertext_blob(Blob::new(wrapped_data_key))
+                .send()
+                .await
+                .into_report()
+                .change_context(errors::EncryptionError)
+                .attach_printable("Error unwrapping the data key with kms")?;
+            match resp.plaintext() {
+                Some(inner) => Ok(inner.as_ref().to_vec()),
+                None => Err(report!(errors::EncryptionError)
+                    .attach_printable("Missing plaintext in response")),
+            }
+        }
+    }
+}
+
+/// Key of the local vault, kept apart from the master key so that the card data in the vault
+/// cannot be recovered with the master key alone
+#[derive(Clone)]
+pub struct LocalVaultKeyManager {
+    key_file: String,
+    key: Arc<once_cell::sync::OnceCell<Vec<u8>>>,
+}
+
+impl LocalVaultKeyManager {
+    pub fn new(conf: &LocalVault) -> Self {
+        Self {
+            key_file: conf.key_file.clone(),
+            key: Default::default(),
+        }
+    }
+
+    /// Reads the vault key from the key file on first use
+    pub fn get_key(&self) -> CustomResult<&[u8], errors::EncryptionError> {
+        self.key
+            .get_or_try_init(|| read_key_file(&self.key_file))
+            .map(Vec::as_slice)
+    }
+}
+
+/// Reads a key from a local file holding its hex encoding
+pub fn read_key_file(key_file: &str) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+    let key = std::fs::read_to_string(key_file)
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable_lazy(|| format!("Failed to read the key file {key_file}"))?;
+    hex::decode(key.trim())
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable("Key is not hex encoded")
+}
+
+/// Generates a new 256 bit data key
+pub fn generate_data_key() -> [u8; 32] {
+    rand::random()
+}
+
+/// Encrypts the value with the data key, into a JSON string holding the base64 encoded ciphertext
+pub fn encrypt_value(
+    value: &serde_json::Value,
+    data_key: &[u8],
+) -> CustomResult<serde_json::Value, errors::EncryptionError> {
+    let ciphertext = encryption::encrypt(&value.to_string(), data_key)?;
+    Ok(serde_json::Value::String(
+        consts::BASE64_ENGINE.encode(ciphertext),
+    ))
+}
+
+/// Decrypts a value encrypted by [`encrypt_value`] with the same data key
+pub fn decrypt_value(
+    value: serde_json::Value,
+    data_key: &[u8],
+) -> CustomResult<serde_json::Value, errors::EncryptionError> {
+    let ciphertext = value
+        .as_str()
+        .ok_or(errors::EncryptionError)
+        .into_report()
+        .attach_printable("Encrypted value is not a string")?;
+    let ciphertext = consts::BASE64_ENGINE
+        .decode(ciphertext)
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable("Error decoding from base64")?;
+    let plaintext = encryption::decrypt(ciphertext, data_key)?;
+    serde_json::from_str(&plaintext)
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable("Decrypted value is not valid JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_value() {
+        let data_key = generate_data_key();
+        let value = serde_json::json!({
+            "auth_type": "HeaderKey",
+            "api_key": "sk_test_123",
+        });
+
+        let encrypted = encrypt_value(&value, &data_key).unwrap();
+        assert!(encrypted.is_string());
+        assert_ne!(encrypted, value);
+
+        let decrypted = decrypt_value(encrypted, &data_key).unwrap();
+        assert_eq!(decrypted, value);
+    }
+
+    #[test]
+    fn test_decrypt_value_with_another_key_fails() {
+        let value = serde_json::json!({ "api_key": "sk_test_123" });
+        let encrypted = encrypt_value(&value, &generate_data_key()).unwrap();
+
+        assert!(decrypt_value(encrypted, &generate_data_key()).is_err());
+    }
+
+    #[cfg(not(feature = "kms"))]
+    #[actix_rt::test]
+    async fn test_unwrapped_data_keys_are_cached() {
+        let master_key_file = std::env::temp_dir()
+            .join(format!(
+                "master_key_{}.hex",
+                hex::encode(generate_data_key())
+            ))
+            .to_string_lossy()
+            .to_string();
+        std::fs::write(&master_key_file, hex::encode(generate_data_key())).unwrap();
+        let master_key_manager = MasterKeyManager::new(&ConnectorCredentials {
+            master_key_file: master_key_file.clone(),
+        });
+
+        let data_key = generate_data_key();
+        let mut key_store = storage::MerchantKeyStore {
+            id: 1,
+            merchant_id: "merchant_1".to_string(),
+            key_version: 1,
+            encrypted_key: master_key_manager.wrap_data_key(&data_key).await.unwrap(),
+            created_at: common_utils::date_time::now(),
+        };
+        assert!(master_key_manager
+            .get_cached_data_key("merchant_1", 1)
+            .is_none());
+
+        let unwrapped_key = master_key_manager
+            .unwrap_merchant_data_key(key_store.clone())
+            .await
+            .unwrap();
+        assert_eq!(unwrapped_key, data_key);
+        assert_eq!(
+            master_key_manager.get_cached_data_key("merchant_1", 1),
+            Some(data_key.to_vec())
+        );
+        assert!(master_key_manager
+            .get_cached_data_key("merchant_1", 2)
+            .is_none());
+
+        // The cached key is returned without unwrapping the stored key again
+        key_store.encrypted_key = vec![0; 64];
+        let unwrapped_key = master_key_manager
+            .unwrap_merchant_data_key(key_store)
+            .await
+            .unwrap();
+        assert_eq!(unwrapped_key, data_key);
+
+        std::fs::remove_file(master_key_file).unwrap();
+    }
+
+    #[test]
+    fn test_decrypt_plaintext_value_fails() {
+        let value = serde_json::json!({ "api_key": "sk_test_123" });
+
+        assert!(decrypt_value(value, &generate_data_key()).is_err());
+    }
+}
@@ -0,0 +1,369 @@
+//! Locks held in Redis, which serialize the mutations of a resource across all the instances of
+//! the application.
+
+use std::{sync::Arc, time::Duration};
+
+use error_stack::{report, ResultExt};
+use redis_interface::{RedisConnectionPool, SetnxReply};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::LockSettings,
+    core::errors::{self, CustomResult, RouterResult},
+    logger,
+    routes::AppState,
+};
+
+fn get_lock_key(resource_id: &str) -> String {
+    format!("lock_{resource_id}")
+}
+
+fn get_fencing_token_key(lock_key: &str) -> String {
+    format!("{lock_key}_fencing_token")
+}
+
+/// TTL of the fencing token counter of a resource, in seconds. The counter only restarts once the
+/// resource has not been locked for this long, by which time every earlier lock has expired.
+const FENCING_TOKEN_TTL: i64 = 7 * 24 * 60 * 60;
+
+/// Lock held on a resource. The lock expires if it is not extended within its TTL, and is
+/// released in the background if it is dropped without being released.
+pub struct RedisLock {
+    redis_conn: Arc<RedisConnectionPool>,
+    lock_key: String,
+    lock_value: String,
+    fencing_token: i64,
+    ttl: i64,
+    extender: Option<tokio::task::JoinHandle<()>>,
+    is_released: bool,
+}
+
+impl RedisLock {
+    /// Acquires the lock on the resource, waiting for the lock to be released by its current
+    /// holder as many times as allowed by the lock settings.
+    #[instrument(skip(redis_conn, settings))]
+    pub async fn acquire(
+        redis_conn: Arc<RedisConnectionPool>,
+        resource_id: &str,
+        settings: &LockSettings,
+    ) -> CustomResult<Self, errors::LockError> {
+        let lock_key = get_lock_key(resource_id);
+        // The value identifies the holder, so that a lock which has expired and has been acquired
+        // by another holder is neither extended nor released
+        let lock_value = uuid::Uuid::new_v4().to_string();
+
+        for attempt in 0..=settings.lock_retries {
+            if attempt > 0 {
+                tokio::time::sleep(Duration::from_millis(settings.retry_delay)).await;
+            }
+
+            let reply = redis_conn
+                .set_key_if_not_exist(&lock_key, lock_value.as_str())
+                .await
+                .change_context(errors::LockError::AcquireFailed)?;
+
+            if let SetnxReply::KeySet = reply {
+                // The fencing token increases with every acquisition, so that a holder whose lock
+                // has expired can detect that the resource has been locked again since
+                let fencing_token_key = get_fencing_token_key(&lock_key);
+                let fencing_token = redis_conn
+                    .increment_key(&fencing_token_key)
+                    .await
+                    .change_context(errors::LockError::AcquireFailed)
+                    .attach_printable("Failed to generate the fencing token")?;
+                redis_conn
+                    .set_expiry(&fencing_token_key, FENCING_TOKEN_TTL)
+                    .await
+                    .change_context(errors::LockError::AcquireFailed)
+                    .attach_printable("Failed to set the TTL of the fencing token")?;
+
+                let lock = Self {
+                    redis_conn,
+                    lock_key,
+                    lock_value,
+                    fencing_token,
+                    ttl: settings.lock_ttl,
+                    extender: None,
+                    is_released: false,
+                };
+                lock.extend()
+                    .await
+                    .change_context(errors::LockError::AcquireFailed)
+                    .attach_printable("Failed to set the TTL of the lock")?;
+                return Ok(lock);
+            }
+        }
+
+        Err(report!(errors::LockError::ResourceLocked))
+            .attach_printable_lazy(|| format!("Lock {lock_key} is held by another holder"))
+    }
+
+    /// Fencing token of the lock, greater than the tokens of all the earlier holders of the lock.
+    pub fn fencing_token(&self) -> i64 {
+        self.fencing_token
+    }
+
+    /// Checks that the resource has not been locked by another holder since this lock was
+    /// acquired, which happens when the lock expires while being held.
+    pub async fn check_fencing_token(&self) -> CustomResult<(), errors::LockError> {
+        let latest_fencing_token: i64 = self
+            .redis_conn
+            .get_key(&get_fencing_token_key(&self.lock_key))
+            .await
+            .change_context(errors::LockError::CheckFailed)
+            .attach_printable("Failed to get the latest fencing token")?;
+
+        if latest_fencing_token == self.fencing_token {
+            Ok(())
+        } else {
+            Err(report!(errors::LockError::LockLost)).attach_printable_lazy(|| {
+                format!(
+                    "Fencing token {} of lock {} is stale, the latest is {latest_fencing_token}",
+                    self.fencing_token, self.lock_key
+                )
+            })
+        }
+    }
+
+    /// Resets the TTL of the lock, failing if the lock has expired.
+    pub async fn extend(&self) -> CustomResult<(), errors::LockError> {
+        let is_extended = self
+            .redis_conn
+            .set_expiry_if_value_matches(&self.lock_key, &self.lock_value, self.ttl)
+            .await
+            .change_context(errors::LockError::ExtendFailed)?;
+
+        if is_extended {
+            Ok(())
+        } else {
+            Err(report!(errors::LockError::ExtendFailed))
+                .attach_printable_lazy(|| format!("Lock {} has expired", self.lock_key))
+        }
+    }
+
+    /// Extends the lock in the background until it is released, for holders which may keep the
+    /// lock for longer than its TTL.
+    pub fn auto_extend(mut self) -> Self {
+        let redis_conn = self.redis_conn.clone();
+        let lock_key = self.lock_key.clone();
+        let lock_value = self.lock_value.clone();
+        let ttl = self.ttl;
+        let interval = Duration::from_secs(u64::try_from(ttl).unwrap_or(1)) / 3;
+
+        self.extender = Some(tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                match redis_conn
+                    .set_expiry_if_value_matches(&lock_key, &lock_value, ttl)
+                    .await
+                {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        logger::warn!(%lock_key, "Lock expired before being extended");
+                        break;
+                    }
+                    Err(error) => logger::error!(lock_extension_error=?error),
+                }
+            }
+        }));
+        self
+    }
+
+    /// Releases the lock, unless it has expired and has been acquired by another holder.
+    pub async fn release(mut self) -> CustomResult<(), errors::LockError> {
+        self.stop_extender();
+        self.is_released = true;
+
+        let is_released = self
+            .redis_conn
+            .delete_key_if_value_matches(&self.lock_key, &self.lock_value)
+            .await
+            .change_context(errors::LockError::ReleaseFailed)?;
+
+        if !is_released {
+            logger::warn!(lock_key = %self.lock_key, "Lock expired before being released");
+        }
+        Ok(())
+    }
+
+    fn stop_extender(&mut self) {
+        if let Some(extender) = self.extender.take() {
+            extender.abort();
+        }
+    }
+}
+
+impl Drop for RedisLock {
+    fn drop(&mut self) {
+        self.stop_extender();
+        if self.is_released {
+            return;
+        }
+
+        // Locks dropped without being released, such as on early returns, are released in the
+        // background, and otherwise expire after their TTL
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let redis_conn = self.redis_conn.clone();
+            let lock_key = self.lock_key.clone();
+            let lock_value = self.lock_value.clone();
+            runtime.spawn(async move {
+                if let Err(error) = redis_conn
+                    .delete_key_if_value_matches(&lock_key, &lock_value)
+                    .await
+                {
+                    logger::error!(lock_release_error=?error);
+                }
+            });
+        }
+    }
+}
+
+/// Acquires the lock serializing the mutations of a payment, which is extended until released.
+pub async fn lock_payment(
+    state: &AppState,
+    merchant_id: &str,
+    payment_id: &str,
+) -> RouterResult<RedisLock> {
+    RedisLock::acquire(
+        state.store.get_redis_conn(),
+        &format!("payment_{merchant_id}_{payment_id}"),
+        &state.conf.lock_settings,
+    )
+    .await
+    .map(RedisLock::auto_extend)
+    .map_err(|error| {
+        let is_locked = matches!(error.current_context(), errors::LockError::ResourceLocked);
+        if is_locked {
+            error.change_context(errors::ApiErrorResponse::ResourceBusy)
+        } else {
+            error.change_context(errors::ApiErrorResponse::InternalServerError)
+        }
+    })
+}
+
+/// Checks that the lock of a payment is still held, before the payment is mutated.
+pub async fn check_payment_lock(lock: &RedisLock) -> RouterResult<()> {
+    lock.check_fencing_token().await.map_err(|error| {
+        let is_lost = matches!(error.current_context(), errors::LockError::LockLost);
+        if is_lost {
+            error.change_context(errors::ApiErrorResponse::ResourceBusy)
+        } else {
+            error.change_context(errors::ApiErrorResponse::InternalServerError)
+        }
+    })
+}
+
+/// Releases the lock of a payment. Failures are logged, as the lock expires after its TTL.
+pub async fn unlock_payment(lock: RedisLock) {
+    if let Err(error) = lock.release().await {
+        logger::error!(lock_release_error=?error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    // These tests require a running Redis server, and are ignored by default like the other
+    // tests depending on external services
+
+    use redis_interface::RedisSettings;
+
+    use super::*;
+
+    fn lock_settings(lock_retries: u32) -> LockSettings {
+        LockSettings {
+            lock_ttl: 10,
+            lock_retries,
+            retry_delay: 10,
+        }
+    }
+
+    async fn redis_conn() -> Arc<RedisConnectionPool> {
+        Arc::new(
+            RedisConnectionPool::new(&RedisSettings::default())
+                .await
+                .unwrap(),
+        )
+    }
+
+    #[actix_rt::test]
+    #[ignore]
+    async fn test_lock_contention() {
+        let redis_conn = redis_conn().await;
+        let resource_id = format!("test_{}", uuid::Uuid::new_v4());
+
+        let lock = RedisLock::acquire(redis_conn.clone(), &resource_id, &lock_settings(0))
+            .await
+            .unwrap();
+        let error = RedisLock::acquire(redis_conn.clone(), &resource_id, &lock_settings(2))
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error.current_context(),
+            errors::LockError::ResourceLocked
+        ));
+
+        lock.extend().await.unwrap();
+        lock.release().await.unwrap();
+    }
+
+    #[actix_rt::test]
+    #[ignore]
+    async fn test_fencing_token() {
+        let redis_conn = redis_conn().await;
+        let resource_id = format!("test_{}", uuid::Uuid::new_v4());
+
+        let lock = RedisLock::acquire(redis_conn.clone(), &resource_id, &lock_settings(0))
+            .await
+            .unwrap();
+        lock.check_fencing_token().await.unwrap();
+
+        // The lock expires while being held, and the resource is locked by another holder
+        redis_conn
+            .delete_key(&get_lock_key(&resource_id))
+            .await
+            .unwrap();
+        let other_lock = RedisLock::acquire(redis_conn.clone(), &resource_id, &lock_settings(0))
+            .await
+            .unwrap();
+        assert!(other_lock.fencing_token() > lock.fencing_token());
+        let error = lock.check_fencing_token().await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::LockError::LockLost
+        ));
+        other_lock.check_fencing_token().await.unwrap();
+
+        lock.release().await.unwrap();
+        other_lock.release().await.unwrap();
+    }
+
+    #[actix_rt::test]
+    #[ignore]
+    async fn test_lock_release() {
+        let redis_conn = redis_conn().await;
+        let resource_id = format!("test_{}", uuid::Uuid::new_v4());
+
+        let lock = RedisLock::acquire(redis_conn.clone(), &resource_id, &lock_settings(0))
+            .await
+            .unwrap();
+        lock.release().await.unwrap();
+
+        let lock = RedisLock::acquire(redis_conn.clone(), &resource_id, &lock_settings(0))
+            .await
+            .unwrap();
+        // A lock which has expired cannot be extended, nor released on behalf of its new holder
+        redis_conn
+            .delete_key(&get_lock_key(&resource_id))
+            .await
+            .unwrap();
+        let other_lock = RedisLock::acquire(redis_conn.clone(), &resource_id, &lock_settings(0))
+            .await
+            .unwrap();
+        assert!(lock.extend().await.is_err());
+        lock.release().await.unwrap();
+        assert!(other_lock.extend().await.is_ok());
+        other_lock.release().await.unwrap();
+    }
+}
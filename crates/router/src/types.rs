@@ -25,12 +25,27 @@ pub type PaymentsAuthorizeRouterData =
 pub type PaymentsSyncRouterData = RouterData<api::PSync, PaymentsSyncData, PaymentsResponseData>;
 pub type PaymentsCaptureRouterData =
     RouterData<api::Capture, PaymentsCaptureData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationRouterData = RouterData<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 pub type PaymentsCancelRouterData = RouterData<api::Void, PaymentsCancelData, PaymentsResponseData>;
 pub type PaymentsSessionRouterData =
     RouterData<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type RefundsRouterData<F> = RouterData<F, RefundsData, RefundsResponseData>;
 pub type RefundExecuteRouterData = RouterData<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsResponseData>;
+pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
+pub type AcceptDisputeRouterData =
+    RouterData<api::Accept, AcceptDisputeRequestData, AcceptDisputeResponse>;
+pub type SubmitEvidenceRouterData =
+    RouterData<api::Evidence, SubmitEvidenceRequestData, SubmitEvidenceResponse>;
+pub type DefendDisputeRouterData =
+    RouterData<api::Defend, DefendDisputeRequestData, DefendDisputeResponse>;
+pub type UploadFileRouterData = RouterData<api::Upload, UploadFileRequestData, UploadFileResponse>;
+pub type MandateRevokeRouterData =
+    RouterData<api::mandates::MandateRevoke, MandateRevokeRequestData, MandateRevokeResponseData>;
 
 pub type RefreshTokenRouterData =
     RouterData<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
@@ -45,16 +60,44 @@ pub type PaymentsSessionResponseRouterData<R> =
     ResponseRouterData<api::Session, R, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsCaptureResponseRouterData<R> =
     ResponseRouterData<api::Capture, R, PaymentsCaptureData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationResponseRouterData<R> = ResponseRouterData<
+    api::IncrementalAuthorization,
+    R,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 
 pub type RefundsResponseRouterData<F, R> =
     ResponseRouterData<F, R, RefundsData, RefundsResponseData>;
 
+pub type PayoutsResponseRouterData<F, R> =
+    ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
+pub type AcceptDisputeResponseRouterData<R> =
+    ResponseRouterData<api::Accept, R, AcceptDisputeRequestData, AcceptDisputeResponse>;
+pub type SubmitEvidenceResponseRouterData<R> =
+    ResponseRouterData<api::Evidence, R, SubmitEvidenceRequestData, SubmitEvidenceResponse>;
+pub type DefendDisputeResponseRouterData<R> =
+    ResponseRouterData<api::Defend, R, DefendDisputeRequestData, DefendDisputeResponse>;
+pub type UploadFileResponseRouterData<R> =
+    ResponseRouterData<api::Upload, R, UploadFileRequestData, UploadFileResponse>;
+pub type MandateRevokeResponseRouterData<R> = ResponseRouterData<
+    api::mandates::MandateRevoke,
+    R,
+    MandateRevokeRequestData,
+    MandateRevokeResponseData,
+>;
+
 pub type PaymentsAuthorizeType =
     dyn services::ConnectorIntegration<api::Authorize, PaymentsAuthorizeData, PaymentsResponseData>;
 pub type PaymentsSyncType =
     dyn services::ConnectorIntegration<api::PSync, PaymentsSyncData, PaymentsResponseData>;
 pub type PaymentsCaptureType =
     dyn services::ConnectorIntegration<api::Capture, PaymentsCaptureData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationType = dyn services::ConnectorIntegration<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 pub type PaymentsSessionType =
     dyn services::ConnectorIntegration<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsVoidType =
@@ -65,6 +108,38 @@ pub type RefundExecuteType =
 pub type RefundSyncType =
     dyn services::ConnectorIntegration<api::RSync, RefundsData, RefundsResponseData>;
 
+pub type PayoutCreateType =
+    dyn services::ConnectorIntegration<api::PoCreate, PayoutsData, PayoutsResponseData>;
+pub type PayoutFulfillType =
+    dyn services::ConnectorIntegration<api::PoFulfill, PayoutsData, PayoutsResponseData>;
+pub type PayoutCancelType =
+    dyn services::ConnectorIntegration<api::PoCancel, PayoutsData, PayoutsResponseData>;
+pub type PayoutReverseType =
+    dyn services::ConnectorIntegration<api::PoReverse, PayoutsData, PayoutsResponseData>;
+
+pub type AcceptDisputeType = dyn services::ConnectorIntegration<
+    api::Accept,
+    AcceptDisputeRequestData,
+    AcceptDisputeResponse,
+>;
+pub type SubmitEvidenceType = dyn services::ConnectorIntegration<
+    api::Evidence,
+    SubmitEvidenceRequestData,
+    SubmitEvidenceResponse,
+>;
+pub type DefendDisputeType = dyn services::ConnectorIntegration<
+    api::Defend,
+    DefendDisputeRequestData,
+    DefendDisputeResponse,
+>;
+pub type MandateRevokeType = dyn services::ConnectorIntegration<
+    api::mandates::MandateRevoke,
+    MandateRevokeRequestData,
+    MandateRevokeResponseData,
+>;
+pub type UploadFileType =
+    dyn services::ConnectorIntegration<api::Upload, UploadFileRequestData, UploadFileResponse>;
+
 pub type RefreshTokenType =
     dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
@@ -115,6 +190,8 @@ pub struct PaymentsAuthorizeData {
     pub setup_mandate_details: Option<payments::MandateData>,
     pub browser_info: Option<BrowserInformation>,
     pub order_details: Option<api_models::payments::OrderDetails>,
+    /// Whether the authorized amount may be increased later through incremental authorizations
+    pub request_incremental_authorization: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -123,6 +200,19 @@ pub struct PaymentsCaptureData {
     pub currency: storage_enums::Currency,
     pub connector_transaction_id: String,
     pub amount: i64,
+    /// Whether the amount which remains uncaptured after this capture is released
+    pub final_capture: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentsIncrementalAuthorizationData {
+    /// The total amount to be authorized after the increment
+    pub total_amount: i64,
+    /// The amount by which the authorized amount is increased
+    pub additional_amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector_transaction_id: String,
+    pub reason: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -250,6 +340,106 @@ pub struct RefundsResponseData {
     // pub amount_received: Option<i32>, // Calculation for amount received not in place yet
 }
 
+#[derive(Debug, Clone)]
+pub struct PayoutsData {
+    pub payout_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payout_type: storage_enums::PayoutType,
+    pub payout_method_data: Option<api::PayoutMethodData>,
+    /// Identifier of the payout at the connector, available once the payout has been created
+    pub connector_payout_id: Option<String>,
+    pub customer_id: Option<String>,
+    pub email: Option<masking::Secret<String, Email>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsResponseData {
+    pub connector_payout_id: String,
+    pub status: storage_enums::PayoutStatus,
+}
+
+#[derive(Debug, Clone)]
+pub struct MandateRevokeRequestData {
+    pub mandate_id: String,
+    pub connector_mandate_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MandateRevokeResponseData {
+    pub mandate_status: storage_enums::MandateStatus,
+}
+
+#[derive(Debug, Clone)]
+pub struct AcceptDisputeRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct AcceptDisputeResponse {
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub connector_status: Option<String>,
+}
+
+/// Evidence submitted for a dispute, with files referenced by their identifiers at the connector
+#[derive(Debug, Clone, Default)]
+pub struct SubmitEvidenceRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+    pub receipt: Option<String>,
+    pub shipping_documentation: Option<String>,
+    pub customer_communication: Option<String>,
+    pub refund_policy: Option<String>,
+    pub cancellation_policy: Option<String>,
+    pub service_documentation: Option<String>,
+    pub recurring_transaction_agreement: Option<String>,
+    pub invoice_showing_distinct_transactions: Option<String>,
+    pub uncategorized_file: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_email_address: Option<String>,
+    pub product_description: Option<String>,
+    pub shipping_address: Option<String>,
+    pub shipping_carrier: Option<String>,
+    pub shipping_tracking_number: Option<String>,
+    pub shipping_date: Option<String>,
+    pub refund_policy_disclosure: Option<String>,
+    pub refund_refusal_explanation: Option<String>,
+    pub cancellation_rebuttal: Option<String>,
+    pub uncategorized_text: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubmitEvidenceResponse {
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub connector_status: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DefendDisputeRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct DefendDisputeResponse {
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub connector_status: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadFileRequestData {
+    pub file_key: String,
+    pub file: Vec<u8>,
+    pub file_type: mime::Mime,
+    pub file_size: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadFileResponse {
+    pub provider_file_id: String,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Redirection {
     Redirect,
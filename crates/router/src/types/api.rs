@@ -2,11 +2,18 @@ pub mod admin;
 pub mod api_keys;
 pub mod configs;
 pub mod customers;
+pub mod disputes;
 pub mod enums;
+pub mod events;
+pub mod files;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
+pub mod proxy;
 pub mod refunds;
+pub mod subscriptions;
+pub mod tokens;
 pub mod webhooks;
 
 use std::{fmt::Debug, str::FromStr};
@@ -14,7 +21,8 @@ use std::{fmt::Debug, str::FromStr};
 use error_stack::{report, IntoReport, ResultExt};
 
 pub use self::{
-    admin::*, api_keys::*, configs::*, customers::*, payment_methods::*, payments::*, refunds::*,
+    admin::*, api_keys::*, configs::*, customers::*, disputes::*, events::*, files::*,
+    payment_methods::*, payments::*, payouts::*, proxy::*, refunds::*, subscriptions::*, tokens::*,
     webhooks::*,
 };
 use super::ErrorResponse;
@@ -70,6 +78,13 @@ pub trait ConnectorCommon {
             reason: None,
         })
     }
+
+    /// Whether a payment that failed with this error can be retried with another connector.
+    /// Errors are never retried unless the connector identifies them as soft declines: after a
+    /// server error or a timeout, the connector may have processed the payment all the same.
+    fn is_retryable_error(&self, _error: &ErrorResponse) -> bool {
+        false
+    }
 }
 
 /// Extended trait for connector common to allow functions with generic type
@@ -89,7 +104,17 @@ pub trait ConnectorCommonExt<Flow, Req, Resp>:
 pub trait Router {}
 
 pub trait Connector:
-    Send + Refund + Payment + Debug + ConnectorRedirectResponse + IncomingWebhook + ConnectorAccessToken
+    Send
+    + Refund
+    + Payment
+    + Payouts
+    + Debug
+    + ConnectorRedirectResponse
+    + IncomingWebhook
+    + ConnectorAccessToken
+    + Dispute
+    + FileUpload
+    + mandates::ConnectorMandateRevoke
 {
 }
 
@@ -100,11 +125,15 @@ pub struct Pe;
 impl<
         T: Refund
             + Payment
+            + Payouts
             + Debug
             + ConnectorRedirectResponse
             + Send
             + IncomingWebhook
-            + ConnectorAccessToken,
+            + ConnectorAccessToken
+            + Dispute
+            + FileUpload
+            + mandates::ConnectorMandateRevoke,
     > Connector for T
 {
 }
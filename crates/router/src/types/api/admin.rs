@@ -1,8 +1,10 @@
 pub use api_models::admin::{
+    AmountRange, ConnectorCredentialsKeyRotationResponse, ConnectorVolumeSplit,
     CreateMerchantAccount, DeleteMcaResponse, DeleteMerchantAccountResponse,
     MerchantAccountResponse, MerchantConnectorId, MerchantDetails, MerchantId,
-    PaymentConnectorCreate, PaymentMethods, RoutingAlgorithm, ToggleKVRequest, ToggleKVResponse,
-    WebhookDetails,
+    PaymentConnectorCreate, PaymentMethods, RoutingAlgorithm, RoutingConditions, RoutingRule,
+    RuleBasedRoutingAlgorithm, SuccessRateRoutingAlgorithm, ToggleKVRequest, ToggleKVResponse,
+    WebhookDetails, WebhookSigningSecretResponse,
 };
 
 use crate::types::{storage, transformers::Foreign};
@@ -26,6 +28,7 @@ impl From<Foreign<storage::MerchantAccount>> for Foreign<MerchantAccountResponse
             publishable_key: item.publishable_key,
             metadata: item.metadata,
             locker_id: item.locker_id,
+            webhook_signing_secret: item.webhook_signing_secret,
         }
         .into()
     }
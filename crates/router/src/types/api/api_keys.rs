@@ -1,4 +1,4 @@
 pub use api_models::api_keys::{
-    ApiKeyExpiration, CreateApiKeyRequest, CreateApiKeyResponse, ListApiKeyConstraints,
-    RetrieveApiKeyResponse, RevokeApiKeyResponse, UpdateApiKeyRequest,
+    ApiKeyExpiration, ApiKeyScope, CreateApiKeyRequest, CreateApiKeyResponse,
+    ListApiKeyConstraints, RetrieveApiKeyResponse, RevokeApiKeyResponse, UpdateApiKeyRequest,
 };
@@ -1,5 +1,8 @@
 use api_models::customers;
-pub use api_models::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest};
+pub use api_models::customers::{
+    CustomerDeleteResponse, CustomerErasureId, CustomerErasureResponse, CustomerExportResponse,
+    CustomerId, CustomerRequest,
+};
 use error_stack::ResultExt;
 use serde::Serialize;
 
@@ -0,0 +1,33 @@
+pub use api_models::disputes::{
+    DisputeListConstraints, DisputeListResponse, DisputePayload, DisputeResponse,
+    SubmitEvidenceRequest,
+};
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct Accept;
+
+pub trait AcceptDispute:
+    api::ConnectorIntegration<Accept, types::AcceptDisputeRequestData, types::AcceptDisputeResponse>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct Evidence;
+
+pub trait SubmitEvidence:
+    api::ConnectorIntegration<Evidence, types::SubmitEvidenceRequestData, types::SubmitEvidenceResponse>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct Defend;
+
+pub trait DefendDispute:
+    api::ConnectorIntegration<Defend, types::DefendDisputeRequestData, types::DefendDisputeResponse>
+{
+}
+
+pub trait Dispute: ConnectorCommon + AcceptDispute + SubmitEvidence + DefendDispute {}
@@ -0,0 +1,4 @@
+pub use api_models::events::{
+    EventDeliveryAttemptListResponse, EventDeliveryAttemptResponse, EventListConstraints,
+    EventListResponse, EventResponse,
+};
@@ -0,0 +1,23 @@
+pub use api_models::files::CreateFileResponse;
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct CreateFileRequest {
+    pub file: Vec<u8>,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: mime::Mime,
+    pub purpose: api_models::enums::FilePurpose,
+}
+
+#[derive(Debug, Clone)]
+pub struct Upload;
+
+pub trait UploadFile:
+    api::ConnectorIntegration<Upload, types::UploadFileRequestData, types::UploadFileResponse>
+{
+}
+
+pub trait FileUpload: ConnectorCommon + UploadFile {}
@@ -10,8 +10,9 @@ use crate::{
     },
     newtype,
     routes::AppState,
+    services,
     types::{
-        api,
+        self, api,
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
@@ -23,6 +24,23 @@ newtype!(
     derives = (Default, Debug, Deserialize, Serialize)
 );
 
+#[derive(Debug, Clone)]
+pub struct MandateRevoke;
+
+pub trait ConnectorMandateRevoke:
+    services::ConnectorIntegration<
+    MandateRevoke,
+    types::MandateRevokeRequestData,
+    types::MandateRevokeResponseData,
+>
+{
+    /// Whether mandates set up through the connector can be revoked at the connector. Mandates of
+    /// the other connectors are only revoked locally.
+    fn supports_mandate_revoke(&self) -> bool {
+        false
+    }
+}
+
 #[async_trait::async_trait]
 pub(crate) trait MandateResponseExt: Sized {
     async fn from_db_mandate(
@@ -85,6 +103,10 @@ impl MandateResponseExt for MandateResponse {
             status: mandate.mandate_status.foreign_into(),
             payment_method: payment_method.payment_method.to_string(),
             payment_method_id: mandate.payment_method_id,
+            start_date: mandate.start_date,
+            end_date: mandate.end_date,
+            max_usage_count: mandate.max_usage_count,
+            usage_count: mandate.usage_count,
         })
     }
 }
@@ -3,11 +3,11 @@ pub use api_models::payments::{
     CustomerAcceptance, MandateData, MandateTxnType, MandateType, MandateValidationFields,
     NextAction, NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
     PaymentListResponse, PaymentMethod, PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody,
-    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
-    PaymentsRedirectionResponse, PaymentsRequest, PaymentsResponse, PaymentsResponseForm,
-    PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
-    PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken, UrlDetails, VerifyRequest,
-    VerifyResponse, WalletData,
+    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsIncrementalAuthorizationRequest,
+    PaymentsRedirectRequest, PaymentsRedirectionResponse, PaymentsRequest, PaymentsResponse,
+    PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse,
+    PaymentsStartRequest, PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken,
+    UrlDetails, VerifyRequest, VerifyResponse, WalletData,
 };
 use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
@@ -66,6 +66,8 @@ impl super::Router for PaymentsRequest {}
 pub struct Authorize;
 #[derive(Debug, Clone)]
 pub struct Capture;
+#[derive(Debug, Clone)]
+pub struct IncrementalAuthorization;
 
 #[derive(Debug, Clone)]
 pub struct PSync;
@@ -154,6 +156,20 @@ pub trait PaymentCapture:
 {
 }
 
+pub trait PaymentIncrementalAuthorization:
+    api::ConnectorIntegration<
+    IncrementalAuthorization,
+    types::PaymentsIncrementalAuthorizationData,
+    types::PaymentsResponseData,
+>
+{
+    /// Whether the connector can increase the amount authorized on a payment. Connectors which
+    /// cannot are never called for incremental authorizations.
+    fn supports_incremental_authorization(&self) -> bool {
+        false
+    }
+}
+
 pub trait PaymentSession:
     api::ConnectorIntegration<Session, types::PaymentsSessionData, types::PaymentsResponseData>
 {
@@ -169,6 +185,7 @@ pub trait Payment:
     + PaymentAuthorize
     + PaymentSync
     + PaymentCapture
+    + PaymentIncrementalAuthorization
     + PaymentVoid
     + PreVerify
     + PaymentSession
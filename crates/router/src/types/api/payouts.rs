@@ -0,0 +1,50 @@
+pub use api_models::payouts::{
+    BankPayout, CardPayout, PayoutAccount, PayoutAccountsResponse, PayoutCreateRequest,
+    PayoutListRequest, PayoutListResponse, PayoutMethodData, PayoutResponse, PayoutUpdateRequest,
+};
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct PoCreate;
+#[derive(Debug, Clone)]
+pub struct PoFulfill;
+#[derive(Debug, Clone)]
+pub struct PoCancel;
+#[derive(Debug, Clone)]
+pub struct PoReverse;
+
+pub trait PayoutCreate:
+    api::ConnectorIntegration<PoCreate, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutFulfill:
+    api::ConnectorIntegration<PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutCancel:
+    api::ConnectorIntegration<PoCancel, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutReverse:
+    api::ConnectorIntegration<PoReverse, types::PayoutsData, types::PayoutsResponseData>
+{
+    /// Whether payouts which have been paid out can be reversed through the connector
+    fn supports_payout_reverse(&self) -> bool {
+        false
+    }
+}
+
+pub trait Payouts:
+    ConnectorCommon + PayoutCreate + PayoutFulfill + PayoutCancel + PayoutReverse
+{
+    /// Whether the connector implements the payout flows, used to list the connector accounts
+    /// of a merchant through which payouts can be made
+    fn supports_payouts(&self) -> bool {
+        false
+    }
+}
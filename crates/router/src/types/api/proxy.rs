@@ -0,0 +1 @@
+pub use api_models::proxy::{ProxyMethod, ProxyRequest, ProxyResponse};
@@ -0,0 +1,3 @@
+pub use api_models::tokens::{
+    DetokenizeResponse, TokenCreateRequest, TokenDeleteResponse, TokenResponse, TokenUsage,
+};
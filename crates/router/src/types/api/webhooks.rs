@@ -2,9 +2,9 @@ pub use api_models::webhooks::{
     IncomingWebhookDetails, IncomingWebhookEvent, MerchantWebhookConfig, OutgoingWebhook,
     OutgoingWebhookContent, WebhookFlow,
 };
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 
-use super::ConnectorCommon;
+use super::{disputes, ConnectorCommon};
 use crate::{
     core::errors::{self, CustomResult},
     db::StorageInterface,
@@ -142,4 +142,25 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
     {
         Ok(services::api::ApplicationResponse::StatusOk)
     }
+
+    /// Returns the identifier given by the connector to the refund of a refund webhook.
+    fn get_webhook_connector_refund_id(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented(
+            "get_webhook_connector_refund_id method".to_string(),
+        ))
+        .into_report()
+    }
+
+    fn get_dispute_details(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<disputes::DisputePayload, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented(
+            "get_dispute_details method".to_string(),
+        ))
+        .into_report()
+    }
 }
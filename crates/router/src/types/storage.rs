@@ -1,20 +1,32 @@
 pub mod address;
 pub mod api_keys;
+pub mod capture;
+pub mod card_vault;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_erasure;
 pub mod customers;
+pub mod data_token;
+pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
+pub mod event_delivery_attempt;
 pub mod events;
+pub mod file;
+pub mod idempotent_response;
+pub mod incremental_authorization;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod reverse_lookup;
+pub mod subscription;
 
 mod query;
 pub mod refund;
@@ -23,8 +35,10 @@ pub mod refund;
 pub mod kv;
 
 pub use self::{
-    address::*, api_keys::*, configs::*, connector_response::*, customers::*, events::*,
-    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
-    payment_attempt::*, payment_intent::*, payment_method::*, process_tracker::*, refund::*,
-    reverse_lookup::*,
+    address::*, api_keys::*, capture::*, card_vault::*, configs::*, connector_response::*,
+    customer_erasure::*, customers::*, data_token::*, dispute::*, event_delivery_attempt::*,
+    events::*, file::*, idempotent_response::*, incremental_authorization::*, locker_mock_up::*,
+    mandate::*, merchant_account::*, merchant_connector_account::*, merchant_key_store::*,
+    payment_attempt::*, payment_intent::*, payment_method::*, payouts::*, process_tracker::*,
+    refund::*, reverse_lookup::*, subscription::*,
 };
@@ -0,0 +1 @@
+pub use storage_models::card_vault::{CardVault, CardVaultNew, CardVaultUpdate};
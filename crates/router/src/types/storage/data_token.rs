@@ -0,0 +1 @@
+pub use storage_models::data_token::{DataToken, DataTokenNew};
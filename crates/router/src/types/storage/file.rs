@@ -0,0 +1,3 @@
+pub use storage_models::file::{
+    FileMetadata, FileMetadataNew, FileMetadataUpdate, FileMetadataUpdateInternal,
+};
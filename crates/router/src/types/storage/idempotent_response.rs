@@ -0,0 +1,3 @@
+pub use storage_models::idempotent_response::{
+    IdempotencyRecord, IdempotentResponse, IdempotentResponseNew,
+};
@@ -0,0 +1,4 @@
+pub use storage_models::incremental_authorization::{
+    IncrementalAuthorization, IncrementalAuthorizationNew, IncrementalAuthorizationUpdate,
+    IncrementalAuthorizationUpdateInternal,
+};
@@ -1,3 +1,4 @@
 pub use storage_models::mandate::{
-    Mandate, MandateNew, MandateUpdate, MandateUpdateInternal, SingleUseMandate,
+    Mandate, MandateExpiryTrackingData, MandateNew, MandateUpdate, MandateUpdateInternal,
+    SingleUseMandate,
 };
@@ -0,0 +1,3 @@
+pub use storage_models::merchant_key_store::{
+    ConnectorCredentialsReencryptionTrackingData, MerchantKeyStore, MerchantKeyStoreNew,
+};
@@ -0,0 +1,4 @@
+pub use storage_models::subscription::{
+    Subscription, SubscriptionNew, SubscriptionTrackingData, SubscriptionUpdate,
+    SubscriptionUpdateInternal,
+};
@@ -191,8 +191,11 @@ impl From<F<storage_enums::AttemptStatus>> for F<storage_enums::IntentStatus> {
                 storage_enums::IntentStatus::RequiresCustomerAction
             }
 
-            storage_enums::AttemptStatus::PartialCharged
-            | storage_enums::AttemptStatus::Started
+            storage_enums::AttemptStatus::PartialCharged => {
+                storage_enums::IntentStatus::PartiallyCaptured
+            }
+
+            storage_enums::AttemptStatus::Started
             | storage_enums::AttemptStatus::AuthenticationSuccessful
             | storage_enums::AttemptStatus::Authorizing
             | storage_enums::AttemptStatus::CodInitiated
@@ -218,6 +221,7 @@ impl TryFrom<F<api_enums::IntentStatus>> for F<storage_enums::EventType> {
     fn try_from(value: F<api_enums::IntentStatus>) -> Result<Self, Self::Error> {
         match value.0 {
             api_enums::IntentStatus::Succeeded => Ok(storage_enums::EventType::PaymentSucceeded),
+            api_enums::IntentStatus::Failed => Ok(storage_enums::EventType::PaymentFailed),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
                 field_name: "intent_status",
             }),
@@ -226,6 +230,24 @@ impl TryFrom<F<api_enums::IntentStatus>> for F<storage_enums::EventType> {
     }
 }
 
+impl TryFrom<F<storage_enums::RefundStatus>> for F<storage_enums::EventType> {
+    type Error = errors::ValidationError;
+
+    fn try_from(value: F<storage_enums::RefundStatus>) -> Result<Self, Self::Error> {
+        match value.0 {
+            storage_enums::RefundStatus::Success => Ok(storage_enums::EventType::RefundSucceeded),
+            storage_enums::RefundStatus::Failure
+            | storage_enums::RefundStatus::TransactionFailure => {
+                Ok(storage_enums::EventType::RefundFailed)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "refund_status",
+            }),
+        }
+        .map(Into::into)
+    }
+}
+
 impl From<F<storage_enums::EventType>> for F<api_enums::EventType> {
     fn from(event_type: F<storage_enums::EventType>) -> Self {
         Self(frunk::labelled_convert_from(event_type.0))
@@ -285,6 +307,191 @@ impl From<F<storage_enums::Currency>> for F<api_enums::Currency> {
     }
 }
 
+impl From<F<storage_enums::PayoutStatus>> for F<api_enums::PayoutStatus> {
+    fn from(status: F<storage_enums::PayoutStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<api_enums::PayoutType>> for F<storage_enums::PayoutType> {
+    fn from(payout_type: F<api_enums::PayoutType>) -> Self {
+        Self(frunk::labelled_convert_from(payout_type.0))
+    }
+}
+
+impl From<F<storage_enums::PayoutType>> for F<api_enums::PayoutType> {
+    fn from(payout_type: F<storage_enums::PayoutType>) -> Self {
+        Self(frunk::labelled_convert_from(payout_type.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeStage>> for F<api_enums::DisputeStage> {
+    fn from(dispute_stage: F<storage_enums::DisputeStage>) -> Self {
+        Self(frunk::labelled_convert_from(dispute_stage.0))
+    }
+}
+
+impl From<F<api_enums::DisputeStage>> for F<storage_enums::DisputeStage> {
+    fn from(dispute_stage: F<api_enums::DisputeStage>) -> Self {
+        Self(frunk::labelled_convert_from(dispute_stage.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeStatus>> for F<api_enums::DisputeStatus> {
+    fn from(status: F<storage_enums::DisputeStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<api_enums::DisputeStatus>> for F<storage_enums::DisputeStatus> {
+    fn from(status: F<api_enums::DisputeStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<storage_enums::CaptureStatus>> for F<api_enums::CaptureStatus> {
+    fn from(status: F<storage_enums::CaptureStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<storage_enums::AuthorizationStatus>> for F<api_enums::AuthorizationStatus> {
+    fn from(status: F<storage_enums::AuthorizationStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<storage_enums::SubscriptionStatus>> for F<api_enums::SubscriptionStatus> {
+    fn from(status: F<storage_enums::SubscriptionStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<api_enums::SubscriptionInterval>> for F<storage_enums::SubscriptionInterval> {
+    fn from(interval: F<api_enums::SubscriptionInterval>) -> Self {
+        Self(frunk::labelled_convert_from(interval.0))
+    }
+}
+
+impl From<F<storage_enums::SubscriptionInterval>> for F<api_enums::SubscriptionInterval> {
+    fn from(interval: F<storage_enums::SubscriptionInterval>) -> Self {
+        Self(frunk::labelled_convert_from(interval.0))
+    }
+}
+
+impl From<F<storage_enums::CustomerErasureStatus>> for F<api_enums::CustomerErasureStatus> {
+    fn from(status: F<storage_enums::CustomerErasureStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<api_enums::FilePurpose>> for F<storage_enums::FilePurpose> {
+    fn from(purpose: F<api_enums::FilePurpose>) -> Self {
+        Self(frunk::labelled_convert_from(purpose.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeStatus>> for F<storage_enums::EventType> {
+    fn from(status: F<storage_enums::DisputeStatus>) -> Self {
+        match status.0 {
+            storage_enums::DisputeStatus::DisputeOpened => storage_enums::EventType::DisputeOpened,
+            storage_enums::DisputeStatus::DisputeExpired => {
+                storage_enums::EventType::DisputeExpired
+            }
+            storage_enums::DisputeStatus::DisputeAccepted => {
+                storage_enums::EventType::DisputeAccepted
+            }
+            storage_enums::DisputeStatus::DisputeCancelled => {
+                storage_enums::EventType::DisputeCancelled
+            }
+            storage_enums::DisputeStatus::DisputeChallenged => {
+                storage_enums::EventType::DisputeChallenged
+            }
+            storage_enums::DisputeStatus::DisputeWon => storage_enums::EventType::DisputeWon,
+            storage_enums::DisputeStatus::DisputeLost => storage_enums::EventType::DisputeLost,
+        }
+        .into()
+    }
+}
+
+impl TryFrom<F<api_models::webhooks::IncomingWebhookEvent>> for F<storage_enums::DisputeStatus> {
+    type Error = errors::ValidationError;
+
+    fn try_from(value: F<api_models::webhooks::IncomingWebhookEvent>) -> Result<Self, Self::Error> {
+        match value.0 {
+            api_models::webhooks::IncomingWebhookEvent::DisputeOpened => {
+                Ok(storage_enums::DisputeStatus::DisputeOpened)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeExpired => {
+                Ok(storage_enums::DisputeStatus::DisputeExpired)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeAccepted => {
+                Ok(storage_enums::DisputeStatus::DisputeAccepted)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeCancelled => {
+                Ok(storage_enums::DisputeStatus::DisputeCancelled)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeChallenged => {
+                Ok(storage_enums::DisputeStatus::DisputeChallenged)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeWon => {
+                Ok(storage_enums::DisputeStatus::DisputeWon)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeLost => {
+                Ok(storage_enums::DisputeStatus::DisputeLost)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event",
+            }),
+        }
+        .map(Into::into)
+    }
+}
+
+impl TryFrom<F<api_models::webhooks::IncomingWebhookEvent>> for F<storage_enums::PayoutStatus> {
+    type Error = errors::ValidationError;
+
+    fn try_from(value: F<api_models::webhooks::IncomingWebhookEvent>) -> Result<Self, Self::Error> {
+        match value.0 {
+            api_models::webhooks::IncomingWebhookEvent::PayoutSuccess => {
+                Ok(storage_enums::PayoutStatus::Success)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PayoutFailure => {
+                Ok(storage_enums::PayoutStatus::Failed)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PayoutCancelled => {
+                Ok(storage_enums::PayoutStatus::Cancelled)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PayoutReversed => {
+                Ok(storage_enums::PayoutStatus::Reversed)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event",
+            }),
+        }
+        .map(Into::into)
+    }
+}
+
+impl TryFrom<F<api_models::webhooks::IncomingWebhookEvent>> for F<storage_enums::RefundStatus> {
+    type Error = errors::ValidationError;
+
+    fn try_from(value: F<api_models::webhooks::IncomingWebhookEvent>) -> Result<Self, Self::Error> {
+        match value.0 {
+            api_models::webhooks::IncomingWebhookEvent::RefundSuccess => {
+                Ok(storage_enums::RefundStatus::Success)
+            }
+            api_models::webhooks::IncomingWebhookEvent::RefundFailure => {
+                Ok(storage_enums::RefundStatus::Failure)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event",
+            }),
+        }
+        .map(Into::into)
+    }
+}
+
 impl<'a> From<F<&'a api_types::Address>> for F<storage::AddressUpdate> {
     fn from(address: F<&api_types::Address>) -> Self {
         let address = address.0;
@@ -316,6 +523,157 @@ impl From<F<storage::Config>> for F<api_types::Config> {
     }
 }
 
+impl From<F<storage::Capture>> for F<api_models::payments::CaptureResponse> {
+    fn from(capture: F<storage::Capture>) -> Self {
+        let capture = capture.0;
+        api_models::payments::CaptureResponse {
+            capture_id: capture.capture_id,
+            status: capture.status.foreign_into(),
+            amount: capture.amount,
+            currency: capture.currency.map(ForeignInto::foreign_into),
+            final_capture: capture.final_capture,
+            error_message: capture.error_message,
+            error_code: capture.error_code,
+            created_at: capture.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::IncrementalAuthorization>>
+    for F<api_models::payments::IncrementalAuthorizationResponse>
+{
+    fn from(authorization: F<storage::IncrementalAuthorization>) -> Self {
+        let authorization = authorization.0;
+        api_models::payments::IncrementalAuthorizationResponse {
+            authorization_id: authorization.authorization_id,
+            status: authorization.status.foreign_into(),
+            amount: authorization.amount,
+            previously_authorized_amount: authorization.previously_authorized_amount,
+            currency: authorization.currency.map(ForeignInto::foreign_into),
+            reason: authorization.reason,
+            error_message: authorization.error_message,
+            error_code: authorization.error_code,
+            created_at: authorization.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::Subscription>> for F<api_models::subscriptions::SubscriptionResponse> {
+    fn from(subscription: F<storage::Subscription>) -> Self {
+        let subscription = subscription.0;
+        api_models::subscriptions::SubscriptionResponse {
+            subscription_id: subscription.subscription_id,
+            customer_id: subscription.customer_id,
+            mandate_id: subscription.mandate_id,
+            status: subscription.status.foreign_into(),
+            amount: subscription.amount,
+            currency: subscription.currency.foreign_into(),
+            interval: subscription.billing_interval.foreign_into(),
+            interval_count: subscription.interval_count,
+            trial_end: subscription.trial_end,
+            end_date: subscription.end_date,
+            current_period_start: subscription.current_period_start,
+            current_period_end: subscription.current_period_end,
+            failed_payment_attempts: subscription.failed_payment_attempts,
+            latest_payment_id: subscription.latest_payment_id,
+            description: subscription.description,
+            metadata: subscription.metadata,
+            cancelled_at: subscription.cancelled_at,
+            created_at: subscription.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::DataToken>> for F<api_models::tokens::TokenResponse> {
+    fn from(data_token: F<storage::DataToken>) -> Self {
+        let data_token = data_token.0;
+        api_models::tokens::TokenResponse {
+            token_id: data_token.token_id,
+            data_type: data_token.data_type,
+            usage: if data_token.single_use {
+                api_models::tokens::TokenUsage::SingleUse
+            } else {
+                api_models::tokens::TokenUsage::MultiUse
+            },
+            created: data_token.created_at,
+            expires_at: data_token.expires_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::PaymentMethod>> for F<api_models::customers::CustomerPaymentMethodExport> {
+    fn from(payment_method: F<storage::PaymentMethod>) -> Self {
+        let payment_method = payment_method.0;
+        api_models::customers::CustomerPaymentMethodExport {
+            payment_method_id: payment_method.payment_method_id,
+            payment_method: payment_method.payment_method.foreign_into(),
+            payment_method_type: payment_method
+                .payment_method_type
+                .map(ForeignInto::foreign_into),
+            payment_method_issuer: payment_method.payment_method_issuer,
+            scheme: payment_method.scheme,
+            issuer_country: payment_method.issuer_country,
+            metadata: payment_method.metadata,
+            created_at: payment_method.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::Mandate>> for F<api_models::customers::CustomerMandateExport> {
+    fn from(mandate: F<storage::Mandate>) -> Self {
+        let mandate = mandate.0;
+        api_models::customers::CustomerMandateExport {
+            mandate_id: mandate.mandate_id,
+            status: mandate.mandate_status.foreign_into(),
+            payment_method_id: mandate.payment_method_id,
+            customer_accepted_at: mandate.customer_accepted_at,
+            customer_ip_address: mandate.customer_ip_address,
+            customer_user_agent: mandate.customer_user_agent,
+            created_at: mandate.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::PaymentIntent>> for F<api_models::customers::CustomerPaymentExport> {
+    fn from(payment_intent: F<storage::PaymentIntent>) -> Self {
+        let payment_intent = payment_intent.0;
+        api_models::customers::CustomerPaymentExport {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status.foreign_into(),
+            amount: payment_intent.amount,
+            currency: payment_intent.currency.map(ForeignInto::foreign_into),
+            description: payment_intent.description,
+            metadata: payment_intent.metadata,
+            created_at: payment_intent.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::CustomerErasure>> for F<api_models::customers::CustomerErasureResponse> {
+    fn from(erasure: F<storage::CustomerErasure>) -> Self {
+        let erasure = erasure.0;
+        api_models::customers::CustomerErasureResponse {
+            erasure_id: erasure.erasure_id,
+            customer_id: erasure.customer_id,
+            status: erasure.status.foreign_into(),
+            addresses_redacted: erasure.addresses_redacted,
+            payments_redacted: erasure.payments_redacted,
+            payment_methods_deleted: erasure.payment_methods_deleted,
+            failure_reason: erasure.failure_reason,
+            completed_at: erasure.completed_at,
+            created_at: erasure.created_at,
+        }
+        .into()
+    }
+}
+
 impl<'a> From<F<&'a api_types::ConfigUpdate>> for F<storage::ConfigUpdate> {
     fn from(config: F<&api_types::ConfigUpdate>) -> Self {
         let config_update = config.0;
@@ -375,6 +733,7 @@ impl TryFrom<F<storage::MerchantConnectorAccount>>
             disabled: merchant_ca.disabled,
             metadata: merchant_ca.metadata,
             payment_methods_enabled,
+            authorization_expiry_days: merchant_ca.authorization_expiry_days,
         }
         .into())
     }
@@ -428,6 +787,11 @@ impl
             )),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key
+                .scopes
+                .iter()
+                .filter_map(|scope| scope.parse().ok())
+                .collect(),
         }
         .into()
     }
@@ -444,6 +808,11 @@ impl From<F<storage_models::api_keys::ApiKey>> for F<api_models::api_keys::Retri
             prefix: format!("{}-{}", api_key.key_id, api_key.prefix).into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key
+                .scopes
+                .iter()
+                .filter_map(|scope| scope.parse().ok())
+                .collect(),
         }
         .into()
     }
@@ -459,6 +828,9 @@ impl From<F<api_models::api_keys::UpdateApiKeyRequest>>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            scopes: api_key
+                .scopes
+                .map(|scopes| scopes.iter().map(ToString::to_string).collect()),
         }
         .into()
     }
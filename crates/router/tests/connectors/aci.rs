@@ -50,6 +50,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             browser_info: None,
             order_details: None,
             email: None,
+            request_incremental_authorization: false,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
@@ -78,6 +78,7 @@ impl AdyenTest {
             browser_info: None,
             order_details: None,
             email: None,
+            request_incremental_authorization: false,
         })
     }
 }
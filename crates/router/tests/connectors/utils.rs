@@ -469,6 +469,7 @@ impl Default for PaymentAuthorizeType {
             browser_info: Some(BrowserInfoType::default().0),
             order_details: None,
             email: None,
+            request_incremental_authorization: false,
         };
         Self(data)
     }
@@ -481,6 +482,7 @@ impl Default for PaymentCaptureType {
             currency: enums::Currency::USD,
             connector_transaction_id: "".to_string(),
             amount: 100,
+            final_capture: true,
         })
     }
 }
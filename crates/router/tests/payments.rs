@@ -327,6 +327,7 @@ async fn payments_create_core() {
         off_session: None,
         client_secret: None,
         browser_info: None,
+        request_incremental_authorization: None,
     };
 
     let expected_response = api::PaymentsResponse {
@@ -482,6 +483,7 @@ async fn payments_create_core_adyen_no_redirect() {
         off_session: None,
         client_secret: None,
         browser_info: None,
+        request_incremental_authorization: None,
     };
 
     let expected_response = services::ApplicationResponse::Json(api::PaymentsResponse {
@@ -239,6 +239,7 @@ async fn payments_create_core_adyen_no_redirect() {
         mandate_id: None,
         client_secret: None,
         browser_info: None,
+        request_incremental_authorization: None,
     };
 
     let expected_response = services::ApplicationResponse::Json(api::PaymentsResponse {
@@ -345,6 +345,9 @@ pub fn api_error_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 /// - domain
 /// - update_tracker
 ///
+/// Operations which only read the payment are run without holding the lock of the payment, which
+/// is specified with `lock = "false"` in the helper attribute.
+///
 /// ## Example
 /// ```
 /// use router_derive::Operation;
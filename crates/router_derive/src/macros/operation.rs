@@ -16,6 +16,8 @@ enum Derives {
     Syncdata,
     Canceldata,
     Capturedata,
+    IncrementalAuthorization,
+    IncrementalAuthorizationData,
     VerifyData,
     Start,
     Verify,
@@ -34,6 +36,8 @@ impl From<String> for Derives {
             "canceldata" => Self::Canceldata,
             "capture" => Self::Capture,
             "capturedata" => Self::Capturedata,
+            "incrementalauthorization" => Self::IncrementalAuthorization,
+            "incrementalauthorizationdata" => Self::IncrementalAuthorizationData,
             "start" => Self::Start,
             "verify" => Self::Verify,
             "verifydata" => Self::VerifyData,
@@ -110,6 +114,12 @@ impl Conversion {
             Derives::Canceldata => syn::Ident::new("PaymentsCancelData", Span::call_site()),
             Derives::Capture => syn::Ident::new("PaymentsCaptureRequest", Span::call_site()),
             Derives::Capturedata => syn::Ident::new("PaymentsCaptureData", Span::call_site()),
+            Derives::IncrementalAuthorization => {
+                syn::Ident::new("PaymentsIncrementalAuthorizationRequest", Span::call_site())
+            }
+            Derives::IncrementalAuthorizationData => {
+                syn::Ident::new("PaymentsIncrementalAuthorizationData", Span::call_site())
+            }
             Derives::Start => syn::Ident::new("PaymentsStartRequest", Span::call_site()),
             Derives::Verify => syn::Ident::new("VerifyRequest", Span::call_site()),
             Derives::VerifyData => syn::Ident::new("VerifyRequestData", Span::call_site()),
@@ -285,6 +295,20 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
             "Invalid properties. Property 'flow' was not found",
         )
     })?;
+    let lock_fn = match prop.get("lock").map(|lock| lock.join("")).as_deref() {
+        None | Some("true") => quote! {},
+        Some("false") => quote! {
+            fn should_lock_payment(&self) -> bool {
+                false
+            }
+        },
+        Some(lock) => {
+            return Err(helpers::syn_error(
+                op.span(),
+                &format!("Invalid value {lock} for property 'lock', expected true or false"),
+            ))
+        }
+    };
     let current_crate = syn::Ident::new(
         &prop
             .get("crate")
@@ -295,18 +319,24 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
 
     let trait_derive = flow.iter().map(|derive| {
         let derive: Derives = derive.to_owned().into();
-        let fns = ops.iter().map(|t| {
-            let con: Conversion = t.to_owned().into();
-            con.to_function(derive)
-        });
+        let fns = ops
+            .iter()
+            .map(|t| {
+                let con: Conversion = t.to_owned().into();
+                con.to_function(derive)
+            })
+            .chain(std::iter::once(lock_fn.clone()));
         derive.to_operation(fns, struct_name)
     });
     let ref_trait_derive = flow.iter().map(|derive| {
         let derive: Derives = derive.to_owned().into();
-        let fns = ops.iter().map(|t| {
-            let con: Conversion = t.to_owned().into();
-            con.to_ref_function(derive)
-        });
+        let fns = ops
+            .iter()
+            .map(|t| {
+                let con: Conversion = t.to_owned().into();
+                con.to_ref_function(derive)
+            })
+            .chain(std::iter::once(lock_fn.clone()));
         derive.to_ref_operation(fns, struct_name)
     });
     let trait_derive = quote! {
@@ -325,12 +355,14 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
                     VerifyRequestData,
                     PaymentsSyncData,
                     PaymentsCaptureData,
+                    PaymentsIncrementalAuthorizationData,
                     PaymentsCancelData,
                     PaymentsAuthorizeData,
                     PaymentsSessionData,
 
                     api::{
                         PaymentsCaptureRequest,
+                        PaymentsIncrementalAuthorizationRequest,
                         PaymentsCancelRequest,
                         PaymentsRetrieveRequest,
                         PaymentsRequest,
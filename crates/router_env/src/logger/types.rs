@@ -62,6 +62,10 @@ pub enum Flow {
     MerchantsAccountUpdate,
     /// Merchants account delete flow.
     MerchantsAccountDelete,
+    /// Merchants account webhook signing secret rotate flow.
+    MerchantsAccountWebhookSecretRotate,
+    /// Merchants account connector credentials key rotate flow.
+    MerchantsAccountConnectorCredentialsKeyRotate,
     /// Payment connectors create flow.
     PaymentConnectorsCreate,
     /// Payment connectors retrieve flow.
@@ -88,6 +92,12 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers export flow.
+    CustomersExport,
+    /// Customer erasure create flow.
+    CustomerErasureCreate,
+    /// Customer erasure retrieve flow.
+    CustomerErasureRetrieve,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -118,6 +128,8 @@ pub enum Flow {
     PaymentsConfirm,
     /// Payments capture flow.
     PaymentsCapture,
+    /// Payments incremental authorization flow.
+    PaymentsIncrementalAuthorization,
     /// Payments cancel flow.
     PaymentsCancel,
     /// Payments Session Token flow
@@ -132,12 +144,16 @@ pub enum Flow {
     PayoutsRetrieve,
     /// Payouts update flow.
     PayoutsUpdate,
+    /// Payouts fulfill flow.
+    PayoutsFulfill,
     /// Payouts reverse flow.
     PayoutsReverse,
     /// Payouts cancel flow.
     PayoutsCancel,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    /// Payouts list flow.
+    PayoutsList,
     /// Refunds create flow.
     RefundsCreate,
     /// Refunds retrieve flow.
@@ -148,6 +164,42 @@ pub enum Flow {
     RefundsList,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
+    /// Dispute retrieve flow
+    DisputesRetrieve,
+    /// Dispute list flow
+    DisputesList,
+    /// Events list flow
+    EventsList,
+    /// Event delivery attempts list flow
+    EventDeliveryAttemptsList,
+    /// Event delivery retry flow
+    EventDeliveryRetry,
+    /// Dispute accept flow
+    DisputesAccept,
+    /// Dispute evidence submit flow
+    DisputesEvidenceSubmit,
+    /// Subscription create flow
+    SubscriptionsCreate,
+    /// Subscription retrieve flow
+    SubscriptionsRetrieve,
+    /// Subscription cancel flow
+    SubscriptionsCancel,
+    /// Token create flow
+    TokensCreate,
+    /// Token retrieve flow
+    TokensRetrieve,
+    /// Token detokenize flow
+    TokensDetokenize,
+    /// Token delete flow
+    TokensDelete,
+    /// Vault proxy flow
+    VaultProxy,
+    /// File create flow
+    CreateFile,
+    /// File retrieve flow
+    RetrieveFile,
+    /// File delete flow
+    DeleteFile,
     /// Validate payment method flow
     ValidatePaymentMethod,
     /// API Key create flow
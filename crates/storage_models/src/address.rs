@@ -88,6 +88,26 @@ pub struct AddressUpdateInternal {
     modified_at: PrimitiveDateTime,
 }
 
+impl AddressUpdateInternal {
+    pub fn apply_changeset(self, source: Address) -> Address {
+        Address {
+            city: self.city.or(source.city),
+            country: self.country.or(source.country),
+            line1: self.line1.or(source.line1),
+            line2: self.line2.or(source.line2),
+            line3: self.line3.or(source.line3),
+            state: self.state.or(source.state),
+            zip: self.zip.or(source.zip),
+            first_name: self.first_name.or(source.first_name),
+            last_name: self.last_name.or(source.last_name),
+            phone_number: self.phone_number.or(source.phone_number),
+            country_code: self.country_code.or(source.country_code),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
 impl From<AddressUpdate> for AddressUpdateInternal {
     fn from(address_update: AddressUpdate) -> Self {
         match address_update {
@@ -17,6 +17,7 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Vec<String>,
 }
 
 #[derive(Debug, Insertable)]
@@ -32,6 +33,7 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Vec<String>,
 }
 
 #[derive(Debug)]
@@ -41,6 +43,7 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        scopes: Option<Vec<String>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -54,6 +57,7 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Vec<String>>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -64,17 +68,20 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                scopes,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                scopes,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                scopes: None,
             },
         }
     }
@@ -90,6 +97,12 @@ impl From<String> for HashedApiKey {
     }
 }
 
+impl HashedApiKey {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 mod diesel_impl {
     use diesel::{
         backend::Backend,
@@ -0,0 +1,87 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::captures};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = captures)]
+pub struct Capture {
+    pub id: i32,
+    pub capture_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub status: storage_enums::CaptureStatus,
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub connector: String,
+    pub final_capture: bool,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize,
+)]
+#[diesel(table_name = captures)]
+pub struct CaptureNew {
+    pub capture_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub status: storage_enums::CaptureStatus,
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub connector: String,
+    pub final_capture: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CaptureUpdate {
+    StatusUpdate {
+        status: storage_enums::CaptureStatus,
+    },
+    ErrorUpdate {
+        status: storage_enums::CaptureStatus,
+        error_message: Option<String>,
+        error_code: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = captures)]
+pub struct CaptureUpdateInternal {
+    status: Option<storage_enums::CaptureStatus>,
+    error_message: Option<String>,
+    error_code: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<CaptureUpdate> for CaptureUpdateInternal {
+    fn from(capture_update: CaptureUpdate) -> Self {
+        let update = match capture_update {
+            CaptureUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                ..Default::default()
+            },
+            CaptureUpdate::ErrorUpdate {
+                status,
+                error_message,
+                error_code,
+            } => Self {
+                status: Some(status),
+                error_message,
+                error_code,
+                ..Default::default()
+            },
+        };
+
+        Self {
+            modified_at: Some(common_utils::date_time::now()),
+            ..update
+        }
+    }
+}
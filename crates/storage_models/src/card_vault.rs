@@ -0,0 +1,80 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::card_vault;
+
+/// Card saved in the local vault. The card data is encrypted with a data key of its own, which is
+/// stored wrapped by the vault key. The card security code is never stored in the database.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = card_vault)]
+pub struct CardVault {
+    pub id: i32,
+    pub card_id: String,
+    pub external_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub payment_method_id: Option<String>,
+    pub card_fingerprint: String,
+    pub card_global_fingerprint: String,
+    pub encrypted_data_key: Vec<u8>,
+    pub encrypted_card_data: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = card_vault)]
+pub struct CardVaultNew {
+    pub card_id: String,
+    pub external_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub payment_method_id: Option<String>,
+    pub card_fingerprint: String,
+    pub card_global_fingerprint: String,
+    pub encrypted_data_key: Vec<u8>,
+    pub encrypted_card_data: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub enum CardVaultUpdate {
+    CardDataUpdate {
+        encrypted_data_key: Vec<u8>,
+        encrypted_card_data: Vec<u8>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = card_vault)]
+pub struct CardVaultUpdateInternal {
+    encrypted_data_key: Option<Vec<u8>>,
+    encrypted_card_data: Option<Vec<u8>>,
+}
+
+impl CardVaultUpdate {
+    pub fn apply_changeset(self, source: CardVault) -> CardVault {
+        let card_vault_update: CardVaultUpdateInternal = self.into();
+        CardVault {
+            encrypted_data_key: card_vault_update
+                .encrypted_data_key
+                .unwrap_or(source.encrypted_data_key),
+            encrypted_card_data: card_vault_update
+                .encrypted_card_data
+                .unwrap_or(source.encrypted_card_data),
+            ..source
+        }
+    }
+}
+
+impl From<CardVaultUpdate> for CardVaultUpdateInternal {
+    fn from(card_vault_update: CardVaultUpdate) -> Self {
+        match card_vault_update {
+            CardVaultUpdate::CardDataUpdate {
+                encrypted_data_key,
+                encrypted_card_data,
+            } => Self {
+                encrypted_data_key: Some(encrypted_data_key),
+                encrypted_card_data: Some(encrypted_card_data),
+            },
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::customer_erasure};
+
+/// Audit record of the erasure of the personal data of a customer
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = customer_erasure)]
+pub struct CustomerErasure {
+    pub id: i32,
+    pub erasure_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub status: storage_enums::CustomerErasureStatus,
+    pub addresses_redacted: i32,
+    pub payments_redacted: i32,
+    pub payment_methods_deleted: i32,
+    pub failure_reason: Option<String>,
+    pub completed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_erasure)]
+pub struct CustomerErasureNew {
+    pub erasure_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub status: storage_enums::CustomerErasureStatus,
+}
+
+#[derive(Debug)]
+pub enum CustomerErasureUpdate {
+    CompletedUpdate {
+        addresses_redacted: i32,
+        payments_redacted: i32,
+        payment_methods_deleted: i32,
+    },
+    FailedUpdate {
+        failure_reason: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_erasure)]
+pub struct CustomerErasureUpdateInternal {
+    status: Option<storage_enums::CustomerErasureStatus>,
+    addresses_redacted: Option<i32>,
+    payments_redacted: Option<i32>,
+    payment_methods_deleted: Option<i32>,
+    failure_reason: Option<String>,
+    completed_at: Option<PrimitiveDateTime>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<CustomerErasureUpdate> for CustomerErasureUpdateInternal {
+    fn from(customer_erasure_update: CustomerErasureUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        let update = match customer_erasure_update {
+            CustomerErasureUpdate::CompletedUpdate {
+                addresses_redacted,
+                payments_redacted,
+                payment_methods_deleted,
+            } => Self {
+                status: Some(storage_enums::CustomerErasureStatus::Completed),
+                addresses_redacted: Some(addresses_redacted),
+                payments_redacted: Some(payments_redacted),
+                payment_methods_deleted: Some(payment_methods_deleted),
+                completed_at: Some(now),
+                ..Default::default()
+            },
+            CustomerErasureUpdate::FailedUpdate { failure_reason } => Self {
+                status: Some(storage_enums::CustomerErasureStatus::Failed),
+                failure_reason: Some(failure_reason),
+                ..Default::default()
+            },
+        };
+
+        Self {
+            modified_at: Some(now),
+            ..update
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CustomerErasureTrackingData {
+    pub merchant_id: String,
+    pub erasure_id: String,
+}
@@ -56,6 +56,23 @@ pub struct CustomerUpdateInternal {
     metadata: Option<serde_json::Value>,
 }
 
+impl CustomerUpdate {
+    pub fn apply_changeset(self, source: Customer) -> Customer {
+        let customer_update: CustomerUpdateInternal = self.into();
+        Customer {
+            name: customer_update.name.or(source.name),
+            email: customer_update.email.or(source.email),
+            phone: customer_update.phone.or(source.phone),
+            description: customer_update.description.or(source.description),
+            phone_country_code: customer_update
+                .phone_country_code
+                .or(source.phone_country_code),
+            metadata: customer_update.metadata.or(source.metadata),
+            ..source
+        }
+    }
+}
+
 impl From<CustomerUpdate> for CustomerUpdateInternal {
     fn from(customer_update: CustomerUpdate) -> Self {
         match customer_update {
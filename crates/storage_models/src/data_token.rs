@@ -0,0 +1,32 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::data_token;
+
+/// Sensitive data tokenized by a merchant. The data is encrypted with a data key of its own, which
+/// is stored wrapped by the vault key.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = data_token)]
+pub struct DataToken {
+    pub id: i32,
+    pub token_id: String,
+    pub merchant_id: String,
+    pub data_type: Option<String>,
+    pub single_use: bool,
+    pub encrypted_data_key: Vec<u8>,
+    pub encrypted_data: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_token)]
+pub struct DataTokenNew {
+    pub token_id: String,
+    pub merchant_id: String,
+    pub data_type: Option<String>,
+    pub single_use: bool,
+    pub encrypted_data_key: Vec<u8>,
+    pub encrypted_data: Vec<u8>,
+    pub expires_at: PrimitiveDateTime,
+}
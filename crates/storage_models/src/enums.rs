@@ -2,15 +2,20 @@
 pub mod diesel_exports {
     pub use super::{
         DbAttemptStatus as AttemptStatus, DbAuthenticationType as AuthenticationType,
-        DbCaptureMethod as CaptureMethod, DbConnectorType as ConnectorType, DbCurrency as Currency,
-        DbEventClass as EventClass, DbEventObjectType as EventObjectType, DbEventType as EventType,
-        DbFutureUsage as FutureUsage, DbIntentStatus as IntentStatus,
+        DbAuthorizationStatus as AuthorizationStatus, DbCaptureMethod as CaptureMethod,
+        DbCaptureStatus as CaptureStatus, DbConnectorType as ConnectorType, DbCurrency as Currency,
+        DbCustomerErasureStatus as CustomerErasureStatus, DbDisputeStage as DisputeStage,
+        DbDisputeStatus as DisputeStatus, DbEventClass as EventClass,
+        DbEventObjectType as EventObjectType, DbEventType as EventType,
+        DbFilePurpose as FilePurpose, DbFutureUsage as FutureUsage, DbIntentStatus as IntentStatus,
         DbMandateStatus as MandateStatus, DbMandateType as MandateType,
         DbMerchantStorageScheme as MerchantStorageScheme, DbPaymentFlow as PaymentFlow,
         DbPaymentMethodIssuerCode as PaymentMethodIssuerCode,
         DbPaymentMethodSubType as PaymentMethodSubType, DbPaymentMethodType as PaymentMethodType,
+        DbPayoutStatus as PayoutStatus, DbPayoutType as PayoutType,
         DbProcessTrackerStatus as ProcessTrackerStatus, DbRefundStatus as RefundStatus,
         DbRefundType as RefundType, DbRoutingAlgorithm as RoutingAlgorithm,
+        DbSubscriptionInterval as SubscriptionInterval, DbSubscriptionStatus as SubscriptionStatus,
     };
 }
 
@@ -103,6 +108,56 @@ pub enum CaptureMethod {
     Scheduled,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureStatus {
+    #[default]
+    Started,
+    Charged,
+    Pending,
+    Failed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthorizationStatus {
+    #[default]
+    Started,
+    Success,
+    Failure,
+    Processing,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -277,6 +332,9 @@ pub enum Currency {
 #[strum(serialize_all = "snake_case")]
 pub enum EventClass {
     Payments,
+    Refunds,
+    Disputes,
+    Subscriptions,
 }
 
 #[derive(
@@ -296,6 +354,9 @@ pub enum EventClass {
 #[strum(serialize_all = "snake_case")]
 pub enum EventObjectType {
     PaymentDetails,
+    RefundDetails,
+    DisputeDetails,
+    SubscriptionDetails,
 }
 
 #[derive(
@@ -316,6 +377,21 @@ pub enum EventObjectType {
 #[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentSucceeded,
+    PaymentFailed,
+    RefundSucceeded,
+    RefundFailed,
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+    SubscriptionCreated,
+    SubscriptionRenewed,
+    SubscriptionPaymentFailed,
+    SubscriptionCancelled,
+    SubscriptionEnded,
 }
 
 #[derive(
@@ -345,6 +421,7 @@ pub enum IntentStatus {
     #[default]
     RequiresConfirmation,
     RequiresCapture,
+    PartiallyCaptured,
 }
 
 #[derive(
@@ -506,6 +583,128 @@ pub enum PaymentMethodType {
     Paypal,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    Success,
+    Failed,
+    Cancelled,
+    #[default]
+    Pending,
+    RequiresFulfillment,
+    Reversed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    Card,
+    Bank,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStage {
+    PreDispute,
+    #[default]
+    Dispute,
+    PreArbitration,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStatus {
+    #[default]
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FilePurpose {
+    #[default]
+    DisputeEvidence,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -671,4 +870,80 @@ pub enum MandateStatus {
     Inactive,
     Pending,
     Revoked,
+    Expired,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerErasureStatus {
+    #[default]
+    Pending,
+    Completed,
+    Failed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    Trialing,
+    #[default]
+    Active,
+    PastDue,
+    Cancelled,
+    Ended,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionInterval {
+    Day,
+    Week,
+    #[default]
+    Month,
+    Year,
 }
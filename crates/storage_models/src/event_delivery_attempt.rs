@@ -0,0 +1,38 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::event_delivery_attempt;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = event_delivery_attempt)]
+pub struct EventDeliveryAttemptNew {
+    pub attempt_id: String,
+    pub event_id: String,
+    pub merchant_id: String,
+    pub request_body: String,
+    pub response_status_code: Option<i32>,
+    pub response_body: Option<String>,
+    pub latency_ms: Option<i64>,
+    pub is_success: bool,
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = event_delivery_attempt)]
+pub struct EventDeliveryAttempt {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub attempt_id: String,
+    pub event_id: String,
+    pub merchant_id: String,
+    pub request_body: String,
+    pub response_status_code: Option<i32>,
+    pub response_body: Option<String>,
+    pub latency_ms: Option<i64>,
+    pub is_success: bool,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
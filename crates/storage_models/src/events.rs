@@ -1,5 +1,5 @@
 use common_utils::custom_serde;
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -16,6 +16,7 @@ pub struct EventNew {
     pub intent_reference_id: Option<String>,
     pub primary_object_id: String,
     pub primary_object_type: storage_enums::EventObjectType,
+    pub merchant_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
@@ -32,4 +33,36 @@ pub struct Event {
     pub primary_object_type: storage_enums::EventObjectType,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub merchant_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum EventUpdate {
+    UpdateWebhookNotified { is_webhook_notified: Option<bool> },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = events)]
+pub struct EventUpdateInternal {
+    pub is_webhook_notified: Option<bool>,
+}
+
+impl From<EventUpdate> for EventUpdateInternal {
+    fn from(event_update: EventUpdate) -> Self {
+        match event_update {
+            EventUpdate::UpdateWebhookNotified {
+                is_webhook_notified,
+            } => Self {
+                is_webhook_notified,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OutgoingWebhookTrackingData {
+    pub merchant_id: String,
+    pub event_id: String,
+    /// The webhook as sent in the first delivery attempt
+    pub webhook: serde_json::Value,
 }
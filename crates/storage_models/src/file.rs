@@ -0,0 +1,90 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::file_metadata};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = file_metadata)]
+pub struct FileMetadata {
+    pub id: i32,
+    pub file_id: String,
+    pub merchant_id: String,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: String,
+    pub purpose: storage_enums::FilePurpose,
+    pub file_upload_provider: String,
+    pub provider_file_id: Option<String>,
+    pub available: bool,
+    pub connector: Option<String>,
+    pub connector_file_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize,
+)]
+#[diesel(table_name = file_metadata)]
+pub struct FileMetadataNew {
+    pub file_id: String,
+    pub merchant_id: String,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: String,
+    pub purpose: storage_enums::FilePurpose,
+    pub file_upload_provider: String,
+    pub provider_file_id: Option<String>,
+    pub available: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FileMetadataUpdate {
+    StorageUpdate {
+        provider_file_id: String,
+        available: bool,
+    },
+    ConnectorUpdate {
+        connector: String,
+        connector_file_id: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = file_metadata)]
+pub struct FileMetadataUpdateInternal {
+    provider_file_id: Option<String>,
+    available: Option<bool>,
+    connector: Option<String>,
+    connector_file_id: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<FileMetadataUpdate> for FileMetadataUpdateInternal {
+    fn from(file_metadata_update: FileMetadataUpdate) -> Self {
+        let update = match file_metadata_update {
+            FileMetadataUpdate::StorageUpdate {
+                provider_file_id,
+                available,
+            } => Self {
+                provider_file_id: Some(provider_file_id),
+                available: Some(available),
+                ..Default::default()
+            },
+            FileMetadataUpdate::ConnectorUpdate {
+                connector,
+                connector_file_id,
+            } => Self {
+                connector: Some(connector),
+                connector_file_id: Some(connector_file_id),
+                ..Default::default()
+            },
+        };
+
+        Self {
+            modified_at: Some(common_utils::date_time::now()),
+            ..update
+        }
+    }
+}
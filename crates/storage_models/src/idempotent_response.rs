@@ -0,0 +1,59 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::idempotent_response;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = idempotent_response)]
+pub struct IdempotentResponseNew {
+    pub merchant_id: String,
+    pub idempotency_key: String,
+    pub request_fingerprint: String,
+    pub response_status_code: i32,
+    pub response_body: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = idempotent_response)]
+pub struct IdempotentResponse {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub merchant_id: String,
+    pub idempotency_key: String,
+    pub request_fingerprint: String,
+    pub response_status_code: i32,
+    pub response_body: String,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// State of an idempotency key cached in Redis. The response is absent while the first request
+/// made with the key is being processed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IdempotencyRecord {
+    pub request_fingerprint: String,
+    pub response_status_code: Option<i32>,
+    pub response_body: Option<String>,
+}
+
+impl From<IdempotentResponseNew> for IdempotencyRecord {
+    fn from(response: IdempotentResponseNew) -> Self {
+        Self {
+            request_fingerprint: response.request_fingerprint,
+            response_status_code: Some(response.response_status_code),
+            response_body: Some(response.response_body),
+        }
+    }
+}
+
+impl From<IdempotentResponse> for IdempotencyRecord {
+    fn from(response: IdempotentResponse) -> Self {
+        Self {
+            request_fingerprint: response.request_fingerprint,
+            response_status_code: Some(response.response_status_code),
+            response_body: Some(response.response_body),
+        }
+    }
+}
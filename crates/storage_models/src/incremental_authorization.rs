@@ -0,0 +1,89 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::incremental_authorizations};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = incremental_authorizations)]
+pub struct IncrementalAuthorization {
+    pub id: i32,
+    pub authorization_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub status: storage_enums::AuthorizationStatus,
+    pub amount: i64,
+    pub previously_authorized_amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub connector: String,
+    pub reason: Option<String>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize,
+)]
+#[diesel(table_name = incremental_authorizations)]
+pub struct IncrementalAuthorizationNew {
+    pub authorization_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub status: storage_enums::AuthorizationStatus,
+    pub amount: i64,
+    pub previously_authorized_amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub connector: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IncrementalAuthorizationUpdate {
+    StatusUpdate {
+        status: storage_enums::AuthorizationStatus,
+    },
+    ErrorUpdate {
+        status: storage_enums::AuthorizationStatus,
+        error_message: Option<String>,
+        error_code: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incremental_authorizations)]
+pub struct IncrementalAuthorizationUpdateInternal {
+    status: Option<storage_enums::AuthorizationStatus>,
+    error_message: Option<String>,
+    error_code: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<IncrementalAuthorizationUpdate> for IncrementalAuthorizationUpdateInternal {
+    fn from(authorization_update: IncrementalAuthorizationUpdate) -> Self {
+        let update = match authorization_update {
+            IncrementalAuthorizationUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                ..Default::default()
+            },
+            IncrementalAuthorizationUpdate::ErrorUpdate {
+                status,
+                error_message,
+                error_code,
+            } => Self {
+                status: Some(status),
+                error_message,
+                error_code,
+                ..Default::default()
+            },
+        };
+
+        Self {
+            modified_at: Some(common_utils::date_time::now()),
+            ..update
+        }
+    }
+}
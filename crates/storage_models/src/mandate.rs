@@ -1,6 +1,7 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::mandate};
@@ -26,6 +27,10 @@ pub struct Mandate {
     pub amount_captured: Option<i64>,
     pub connector: String,
     pub connector_mandate_id: Option<String>,
+    pub start_date: Option<PrimitiveDateTime>,
+    pub end_date: Option<PrimitiveDateTime>,
+    pub max_usage_count: Option<i32>,
+    pub usage_count: i32,
 }
 
 #[derive(
@@ -50,6 +55,10 @@ pub struct MandateNew {
     pub amount_captured: Option<i64>,
     pub connector: String,
     pub connector_mandate_id: Option<String>,
+    pub start_date: Option<PrimitiveDateTime>,
+    pub end_date: Option<PrimitiveDateTime>,
+    pub max_usage_count: Option<i32>,
+    pub usage_count: Option<i32>,
 }
 
 #[derive(Debug)]
@@ -101,3 +110,9 @@ impl From<MandateUpdate> for MandateUpdateInternal {
         }
     }
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MandateExpiryTrackingData {
+    pub merchant_id: String,
+    pub mandate_id: String,
+}
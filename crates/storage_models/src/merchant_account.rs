@@ -1,5 +1,6 @@
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::StrongSecret;
+use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::merchant_account};
 
@@ -33,6 +34,9 @@ pub struct MerchantAccount {
     pub locker_id: Option<String>,
     pub metadata: Option<serde_json::Value>,
     pub routing_algorithm: Option<serde_json::Value>,
+    pub webhook_signing_secret: Option<StrongSecret<String>>,
+    pub previous_webhook_signing_secret: Option<StrongSecret<String>>,
+    pub previous_webhook_signing_secret_expires_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay)]
@@ -53,6 +57,7 @@ pub struct MerchantAccountNew {
     pub locker_id: Option<String>,
     pub metadata: Option<serde_json::Value>,
     pub routing_algorithm: Option<serde_json::Value>,
+    pub webhook_signing_secret: Option<StrongSecret<String>>,
 }
 
 #[derive(Debug)]
@@ -76,6 +81,11 @@ pub enum MerchantAccountUpdate {
     StorageSchemeUpdate {
         storage_scheme: storage_enums::MerchantStorageScheme,
     },
+    WebhookSigningSecretUpdate {
+        webhook_signing_secret: StrongSecret<String>,
+        previous_webhook_signing_secret: Option<StrongSecret<String>>,
+        previous_webhook_signing_secret_expires_at: PrimitiveDateTime,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -96,6 +106,9 @@ pub struct MerchantAccountUpdateInternal {
     locker_id: Option<String>,
     metadata: Option<serde_json::Value>,
     routing_algorithm: Option<serde_json::Value>,
+    webhook_signing_secret: Option<StrongSecret<String>>,
+    previous_webhook_signing_secret: Option<StrongSecret<String>>,
+    previous_webhook_signing_secret_expires_at: Option<PrimitiveDateTime>,
 }
 
 impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
@@ -137,6 +150,18 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 storage_scheme: Some(storage_scheme),
                 ..Default::default()
             },
+            MerchantAccountUpdate::WebhookSigningSecretUpdate {
+                webhook_signing_secret,
+                previous_webhook_signing_secret,
+                previous_webhook_signing_secret_expires_at,
+            } => Self {
+                webhook_signing_secret: Some(webhook_signing_secret),
+                previous_webhook_signing_secret,
+                previous_webhook_signing_secret_expires_at: Some(
+                    previous_webhook_signing_secret_expires_at,
+                ),
+                ..Default::default()
+            },
         }
     }
 }
@@ -0,0 +1,30 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::merchant_key_store;
+
+/// Data key of a merchant, wrapped by the master key. A merchant has one data key per version,
+/// the latest of which encrypts new data.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = merchant_key_store)]
+pub struct MerchantKeyStore {
+    pub id: i32,
+    pub merchant_id: String,
+    pub key_version: i32,
+    pub encrypted_key: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = merchant_key_store)]
+pub struct MerchantKeyStoreNew {
+    pub merchant_id: String,
+    pub key_version: i32,
+    pub encrypted_key: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorCredentialsReencryptionTrackingData {
+    pub merchant_id: String,
+    pub key_version: i32,
+}
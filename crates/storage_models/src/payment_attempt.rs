@@ -39,6 +39,8 @@ pub struct PaymentAttempt {
     pub error_code: Option<String>,
     pub payment_token: Option<String>,
     pub connector_metadata: Option<serde_json::Value>,
+    pub routing_algorithm_version: Option<String>,
+    pub card_fingerprint: Option<String>,
 }
 
 #[derive(
@@ -78,6 +80,8 @@ pub struct PaymentAttemptNew {
     pub payment_token: Option<String>,
     pub error_code: Option<String>,
     pub connector_metadata: Option<serde_json::Value>,
+    pub routing_algorithm_version: Option<String>,
+    pub card_fingerprint: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,10 +93,14 @@ pub enum PaymentAttemptUpdate {
         authentication_type: Option<storage_enums::AuthenticationType>,
         payment_method: Option<storage_enums::PaymentMethodType>,
         payment_token: Option<String>,
+        capture_method: Option<storage_enums::CaptureMethod>,
+        capture_on: Option<PrimitiveDateTime>,
     },
     UpdateTrackers {
         payment_token: Option<String>,
         connector: Option<String>,
+        routing_algorithm_version: Option<String>,
+        card_fingerprint: Option<String>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -106,6 +114,10 @@ pub enum PaymentAttemptUpdate {
         browser_info: Option<serde_json::Value>,
         connector: Option<String>,
         payment_token: Option<String>,
+        routing_algorithm_version: Option<String>,
+        card_fingerprint: Option<String>,
+        capture_method: Option<storage_enums::CaptureMethod>,
+        capture_on: Option<PrimitiveDateTime>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -124,6 +136,9 @@ pub enum PaymentAttemptUpdate {
     StatusUpdate {
         status: storage_enums::AttemptStatus,
     },
+    AmountUpdate {
+        amount: i64,
+    },
     ErrorUpdate {
         connector: Option<String>,
         status: storage_enums::AttemptStatus,
@@ -152,6 +167,10 @@ pub struct PaymentAttemptUpdateInternal {
     payment_token: Option<String>,
     error_code: Option<String>,
     connector_metadata: Option<serde_json::Value>,
+    routing_algorithm_version: Option<String>,
+    card_fingerprint: Option<String>,
+    capture_method: Option<storage_enums::CaptureMethod>,
+    capture_on: Option<PrimitiveDateTime>,
 }
 
 impl PaymentAttemptUpdate {
@@ -174,6 +193,12 @@ impl PaymentAttemptUpdate {
             browser_info: pa_update.browser_info.or(source.browser_info),
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token.or(source.payment_token),
+            routing_algorithm_version: pa_update
+                .routing_algorithm_version
+                .or(source.routing_algorithm_version),
+            card_fingerprint: pa_update.card_fingerprint.or(source.card_fingerprint),
+            capture_method: pa_update.capture_method.or(source.capture_method),
+            capture_on: pa_update.capture_on.or(source.capture_on),
             ..source
         }
     }
@@ -190,6 +215,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 authentication_type,
                 payment_method,
                 payment_token,
+                capture_method,
+                capture_on,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -198,6 +225,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 authentication_type,
                 payment_method,
                 payment_token,
+                capture_method,
+                capture_on,
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
@@ -217,6 +246,10 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 browser_info,
                 connector,
                 payment_token,
+                routing_algorithm_version,
+                card_fingerprint,
+                capture_method,
+                capture_on,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -227,6 +260,10 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 browser_info,
                 connector,
                 payment_token,
+                routing_algorithm_version,
+                card_fingerprint,
+                capture_method,
+                capture_on,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -275,12 +312,21 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 status: Some(status),
                 ..Default::default()
             },
+            PaymentAttemptUpdate::AmountUpdate { amount } => Self {
+                amount: Some(amount),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
+                routing_algorithm_version,
+                card_fingerprint,
             } => Self {
                 payment_token,
                 connector,
+                routing_algorithm_version,
+                card_fingerprint,
                 ..Default::default()
             },
         }
@@ -29,6 +29,7 @@ pub struct PaymentIntent {
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
     pub client_secret: Option<String>,
+    pub request_incremental_authorization: Option<bool>,
 }
 
 #[derive(
@@ -65,6 +66,7 @@ pub struct PaymentIntentNew {
     pub client_secret: Option<String>,
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
+    pub request_incremental_authorization: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,6 +94,13 @@ pub enum PaymentIntentUpdate {
     PGStatusUpdate {
         status: storage_enums::IntentStatus,
     },
+    AmountUpdate {
+        amount: i64,
+    },
+    PiiRedactionUpdate {
+        description: Option<String>,
+        metadata: Option<serde_json::Value>,
+    },
     Update {
         amount: i64,
         currency: storage_enums::Currency,
@@ -116,6 +125,7 @@ pub struct PaymentIntentUpdateInternal {
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
     pub metadata: Option<serde_json::Value>,
+    pub description: Option<String>,
     pub client_secret: Option<Option<String>>,
     pub billing_address_id: Option<String>,
     pub shipping_address_id: Option<String>,
@@ -137,6 +147,7 @@ impl PaymentIntentUpdate {
                 .or(source.setup_future_usage),
             off_session: internal_update.off_session.or(source.off_session),
             metadata: internal_update.metadata.or(source.metadata),
+            description: internal_update.description.or(source.description),
             client_secret: internal_update
                 .client_secret
                 .unwrap_or(source.client_secret),
@@ -201,6 +212,20 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
+            PaymentIntentUpdate::AmountUpdate { amount } => Self {
+                amount: Some(amount),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentIntentUpdate::PiiRedactionUpdate {
+                description,
+                metadata,
+            } => Self {
+                description,
+                metadata,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
             PaymentIntentUpdate::MerchantStatusUpdate {
                 status,
                 shipping_address_id,
@@ -244,3 +269,9 @@ fn make_client_secret_null_if_success(
         None
     }
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuthorizationExpiryTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+}
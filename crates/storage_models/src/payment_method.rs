@@ -30,6 +30,7 @@ pub struct PaymentMethod {
     pub payment_method_issuer: Option<String>,
     pub payment_method_issuer_code: Option<storage_enums::PaymentMethodIssuerCode>,
     pub metadata: Option<serde_json::Value>,
+    pub card_fingerprint: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable, Queryable, router_derive::DebugAsDisplay)]
@@ -55,6 +56,7 @@ pub struct PaymentMethodNew {
     pub created_at: PrimitiveDateTime,
     pub last_modified: PrimitiveDateTime,
     pub metadata: Option<serde_json::Value>,
+    pub card_fingerprint: Option<String>,
 }
 
 impl Default for PaymentMethodNew {
@@ -82,6 +84,7 @@ impl Default for PaymentMethodNew {
             created_at: now,
             last_modified: now,
             metadata: Option::default(),
+            card_fingerprint: Option::default(),
         }
     }
 }
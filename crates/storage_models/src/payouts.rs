@@ -0,0 +1,113 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payouts};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payouts)]
+pub struct Payouts {
+    pub id: i32,
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub connector_payout_id: Option<String>,
+    pub payout_type: storage_enums::PayoutType,
+    pub status: storage_enums::PayoutStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize,
+)]
+#[diesel(table_name = payouts)]
+pub struct PayoutsNew {
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub connector_payout_id: Option<String>,
+    pub payout_type: storage_enums::PayoutType,
+    pub status: storage_enums::PayoutStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PayoutsUpdate {
+    StatusUpdate {
+        connector_payout_id: Option<String>,
+        status: storage_enums::PayoutStatus,
+    },
+    ErrorUpdate {
+        status: storage_enums::PayoutStatus,
+        error_message: Option<String>,
+        error_code: Option<String>,
+    },
+    MetadataUpdate {
+        description: Option<String>,
+        metadata: Option<serde_json::Value>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payouts)]
+pub struct PayoutsUpdateInternal {
+    connector_payout_id: Option<String>,
+    status: Option<storage_enums::PayoutStatus>,
+    description: Option<String>,
+    metadata: Option<serde_json::Value>,
+    error_message: Option<String>,
+    error_code: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PayoutsUpdate> for PayoutsUpdateInternal {
+    fn from(payout_update: PayoutsUpdate) -> Self {
+        let update = match payout_update {
+            PayoutsUpdate::StatusUpdate {
+                connector_payout_id,
+                status,
+            } => Self {
+                connector_payout_id,
+                status: Some(status),
+                ..Default::default()
+            },
+            PayoutsUpdate::ErrorUpdate {
+                status,
+                error_message,
+                error_code,
+            } => Self {
+                status: Some(status),
+                error_message,
+                error_code,
+                ..Default::default()
+            },
+            PayoutsUpdate::MetadataUpdate {
+                description,
+                metadata,
+            } => Self {
+                description,
+                metadata,
+                ..Default::default()
+            },
+        };
+
+        Self {
+            modified_at: Some(common_utils::date_time::now()),
+            ..update
+        }
+    }
+}
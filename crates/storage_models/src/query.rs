@@ -1,17 +1,29 @@
 pub mod address;
 pub mod api_keys;
+pub mod capture;
+pub mod card_vault;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_erasure;
 pub mod customers;
+pub mod data_token;
+pub mod dispute;
+pub mod event_delivery_attempt;
 pub mod events;
+pub mod file;
 pub mod generics;
+pub mod idempotent_response;
+pub mod incremental_authorization;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod subscription;
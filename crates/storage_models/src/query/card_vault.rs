@@ -0,0 +1,55 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    card_vault::{CardVault, CardVaultNew, CardVaultUpdate, CardVaultUpdateInternal},
+    schema::card_vault::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CardVaultNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CardVault> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CardVault {
+    #[instrument(skip(conn))]
+    pub async fn find_by_card_id(conn: &PgPooledConn, card_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::card_id.eq(card_id.to_owned()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_card_id(
+        conn: &PgPooledConn,
+        card_id: &str,
+        card_vault: CardVaultUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::card_id.eq(card_id.to_owned()),
+            CardVaultUpdateInternal::from(card_vault),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_card_id(conn: &PgPooledConn, card_id: &str) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::card_id.eq(card_id.to_owned()),
+        )
+        .await
+    }
+}
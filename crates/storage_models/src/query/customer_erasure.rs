@@ -0,0 +1,64 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    customer_erasure::{
+        CustomerErasure, CustomerErasureNew, CustomerErasureUpdate, CustomerErasureUpdateInternal,
+    },
+    errors,
+    schema::customer_erasure::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerErasureNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CustomerErasure> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CustomerErasure {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_erasure_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        erasure_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::erasure_id.eq(erasure_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        customer_erasure: CustomerErasureUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::erasure_id
+                .eq(self.erasure_id.to_owned())
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
+            CustomerErasureUpdateInternal::from(customer_erasure),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    data_token::{DataToken, DataTokenNew},
+    errors,
+    schema::data_token::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl DataTokenNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DataToken> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DataToken {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_token_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        token_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::token_id.eq(token_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_merchant_id_token_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        token_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::token_id.eq(token_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_expired_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        now: PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        match generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::expires_at.le(now)),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => Ok(false),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    event_delivery_attempt::{EventDeliveryAttempt, EventDeliveryAttemptNew},
+    schema::event_delivery_attempt::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl EventDeliveryAttemptNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<EventDeliveryAttempt> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl EventDeliveryAttempt {
+    /// Returns the delivery attempts of the event, latest first.
+    #[instrument(skip(conn))]
+    pub async fn filter_by_event_id(
+        conn: &PgPooledConn,
+        event_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::event_id.eq(event_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+}
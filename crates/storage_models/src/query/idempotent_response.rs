@@ -0,0 +1,33 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    idempotent_response::{IdempotentResponse, IdempotentResponseNew},
+    schema::idempotent_response::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IdempotentResponseNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<IdempotentResponse> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl IdempotentResponse {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_idempotency_key(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        idempotency_key: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::idempotency_key.eq(idempotency_key.to_owned())),
+        )
+        .await
+    }
+}
@@ -25,6 +25,23 @@ impl LockerMockUp {
         .await
     }
 
+    /// Fetches the cards with an ID from `id` on, in the order of their IDs
+    #[instrument(skip(conn))]
+    pub async fn find_from_id(
+        conn: &PgPooledConn,
+        id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::id.ge(id),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn delete_by_card_id(conn: &PgPooledConn, card_id: &str) -> StorageResult<Self> {
         generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
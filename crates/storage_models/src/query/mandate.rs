@@ -1,9 +1,12 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use diesel::{
+    associations::HasTable, dsl::sql, sql_types, BoolExpressionMethods, ExpressionMethods,
+    NullableExpressionMethods, Table,
+};
 use error_stack::report;
 use router_env::{instrument, tracing};
 
 use super::generics;
-use crate::{errors, mandate::*, schema::mandate::dsl, PgPooledConn, StorageResult};
+use crate::{enums, errors, mandate::*, schema::mandate::dsl, PgPooledConn, StorageResult};
 
 impl MandateNew {
     #[instrument(skip(conn))]
@@ -70,4 +73,82 @@ impl Mandate {
                 .attach_printable("Error while updating mandate")
         })
     }
+
+    /// Reserves one usage and `amount` of the mandate for a payment, before the payment is made.
+    /// The usage count and amount are incremented in the same statement that checks them against
+    /// the limits of the mandate, so that concurrent payments cannot use the mandate more times or
+    /// for more than allowed. Returns `NotFound` when the mandate has no usage or amount left.
+    pub async fn reserve_usage_by_merchant_id_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::mandate_id.eq(mandate_id.to_owned()))
+                .and(
+                    dsl::max_usage_count
+                        .is_null()
+                        .or(dsl::usage_count.nullable().lt(dsl::max_usage_count)),
+                )
+                .and(
+                    dsl::mandate_amount.is_null().or(sql::<sql_types::Bool>(
+                        "COALESCE(amount_captured, 0) + ",
+                    )
+                    .bind::<sql_types::BigInt, _>(amount)
+                    .sql(" <= mandate_amount")),
+                )
+                .and(
+                    dsl::mandate_type
+                        .eq(enums::MandateType::MultiUse)
+                        .or(dsl::usage_count.eq(0)),
+                ),
+            (
+                dsl::usage_count.eq(dsl::usage_count + 1),
+                dsl::amount_captured.eq(sql::<sql_types::Nullable<sql_types::BigInt>>(
+                    "COALESCE(amount_captured, 0) + ",
+                )
+                .bind::<sql_types::BigInt, _>(amount)),
+            ),
+        )
+        .await
+    }
+
+    /// Releases the usage and `amount` reserved for a payment which failed.
+    pub async fn release_usage_by_merchant_id_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::mandate_id.eq(mandate_id.to_owned()))
+                .and(dsl::usage_count.gt(0)),
+            (
+                dsl::usage_count.eq(dsl::usage_count - 1),
+                dsl::amount_captured.eq(sql::<sql_types::Nullable<sql_types::BigInt>>(
+                    "GREATEST(COALESCE(amount_captured, 0) - ",
+                )
+                .bind::<sql_types::BigInt, _>(amount)
+                .sql(", 0)")),
+            ),
+        )
+        .await
+    }
 }
@@ -0,0 +1,54 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew},
+    schema::merchant_key_store::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl MerchantKeyStoreNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<MerchantKeyStore> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl MerchantKeyStore {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_key_version(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        key_version: i32,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::key_version.eq(key_version)),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_latest_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(1),
+            None,
+            Some(dsl::key_version.desc()),
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(report!(errors::DatabaseError::NotFound))
+        .attach_printable_lazy(|| format!("No data key found for merchant {merchant_id}"))
+    }
+}
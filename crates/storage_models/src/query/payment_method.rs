@@ -97,4 +97,21 @@ impl PaymentMethod {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_customer_id_merchant_id_card_fingerprint(
+        conn: &PgPooledConn,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::customer_id
+                .eq(customer_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                .and(dsl::card_fingerprint.eq(card_fingerprint.to_owned())),
+        )
+        .await
+    }
 }
@@ -40,6 +40,48 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        scopes -> Array<Nullable<Text>>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    captures (id) {
+        id -> Int4,
+        capture_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        status -> CaptureStatus,
+        amount -> Int8,
+        currency -> Nullable<Currency>,
+        connector -> Varchar,
+        final_capture -> Bool,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    card_vault (id) {
+        id -> Int4,
+        card_id -> Varchar,
+        external_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        payment_method_id -> Nullable<Varchar>,
+        card_fingerprint -> Varchar,
+        card_global_fingerprint -> Varchar,
+        encrypted_data_key -> Bytea,
+        encrypted_card_data -> Bytea,
+        created_at -> Timestamp,
     }
 }
 
@@ -72,6 +114,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_erasure (id) {
+        id -> Int4,
+        erasure_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Varchar,
+        status -> CustomerErasureStatus,
+        addresses_redacted -> Int4,
+        payments_redacted -> Int4,
+        payment_methods_deleted -> Int4,
+        failure_reason -> Nullable<Varchar>,
+        completed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -90,6 +152,50 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    data_token (id) {
+        id -> Int4,
+        token_id -> Varchar,
+        merchant_id -> Varchar,
+        data_type -> Nullable<Varchar>,
+        single_use -> Bool,
+        encrypted_data_key -> Bytea,
+        encrypted_data -> Bytea,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    dispute (id) {
+        id -> Int4,
+        dispute_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        dispute_stage -> DisputeStage,
+        dispute_status -> DisputeStatus,
+        payment_id -> Varchar,
+        attempt_id -> Varchar,
+        merchant_id -> Varchar,
+        connector -> Varchar,
+        connector_status -> Varchar,
+        connector_dispute_id -> Varchar,
+        connector_reason -> Nullable<Varchar>,
+        connector_reason_code -> Nullable<Varchar>,
+        challenge_required_by -> Nullable<Timestamp>,
+        dispute_created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -104,6 +210,86 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        merchant_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    event_delivery_attempt (id) {
+        id -> Int4,
+        attempt_id -> Varchar,
+        event_id -> Varchar,
+        merchant_id -> Varchar,
+        request_body -> Text,
+        response_status_code -> Nullable<Int4>,
+        response_body -> Nullable<Text>,
+        latency_ms -> Nullable<Int8>,
+        is_success -> Bool,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    file_metadata (id) {
+        id -> Int4,
+        file_id -> Varchar,
+        merchant_id -> Varchar,
+        file_name -> Nullable<Varchar>,
+        file_size -> Int4,
+        file_type -> Varchar,
+        purpose -> FilePurpose,
+        file_upload_provider -> Varchar,
+        provider_file_id -> Nullable<Varchar>,
+        available -> Bool,
+        connector -> Nullable<Varchar>,
+        connector_file_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    idempotent_response (id) {
+        id -> Int4,
+        merchant_id -> Varchar,
+        idempotency_key -> Varchar,
+        request_fingerprint -> Varchar,
+        response_status_code -> Int4,
+        response_body -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incremental_authorizations (id) {
+        id -> Int4,
+        authorization_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        status -> AuthorizationStatus,
+        amount -> Int8,
+        previously_authorized_amount -> Int8,
+        currency -> Nullable<Currency>,
+        connector -> Varchar,
+        reason -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -153,6 +339,10 @@ diesel::table! {
         amount_captured -> Nullable<Int8>,
         connector -> Varchar,
         connector_mandate_id -> Nullable<Varchar>,
+        start_date -> Nullable<Timestamp>,
+        end_date -> Nullable<Timestamp>,
+        max_usage_count -> Nullable<Int4>,
+        usage_count -> Int4,
     }
 }
 
@@ -178,6 +368,9 @@ diesel::table! {
         locker_id -> Nullable<Varchar>,
         metadata -> Nullable<Jsonb>,
         routing_algorithm -> Nullable<Json>,
+        webhook_signing_secret -> Nullable<Varchar>,
+        previous_webhook_signing_secret -> Nullable<Varchar>,
+        previous_webhook_signing_secret_expires_at -> Nullable<Timestamp>,
     }
 }
 
@@ -196,6 +389,21 @@ diesel::table! {
         payment_methods_enabled -> Nullable<Array<Nullable<Json>>>,
         connector_type -> ConnectorType,
         metadata -> Nullable<Jsonb>,
+        authorization_expiry_days -> Nullable<Int4>,
+        key_version -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    merchant_key_store (id) {
+        id -> Int4,
+        merchant_id -> Varchar,
+        key_version -> Int4,
+        encrypted_key -> Bytea,
+        created_at -> Timestamp,
     }
 }
 
@@ -236,6 +444,8 @@ diesel::table! {
         error_code -> Nullable<Varchar>,
         payment_token -> Nullable<Varchar>,
         connector_metadata -> Nullable<Jsonb>,
+        routing_algorithm_version -> Nullable<Varchar>,
+        card_fingerprint -> Nullable<Varchar>,
     }
 }
 
@@ -266,6 +476,7 @@ diesel::table! {
         setup_future_usage -> Nullable<FutureUsage>,
         off_session -> Nullable<Bool>,
         client_secret -> Nullable<Varchar>,
+        request_incremental_authorization -> Nullable<Bool>,
     }
 }
 
@@ -295,6 +506,31 @@ diesel::table! {
         payment_method_issuer -> Nullable<Varchar>,
         payment_method_issuer_code -> Nullable<PaymentMethodIssuerCode>,
         metadata -> Nullable<Json>,
+        card_fingerprint -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payouts (id) {
+        id -> Int4,
+        payout_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        connector -> Varchar,
+        connector_payout_id -> Nullable<Varchar>,
+        payout_type -> PayoutType,
+        status -> PayoutStatus,
+        amount -> Int8,
+        currency -> Currency,
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Json>,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -363,21 +599,62 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription (id) {
+        id -> Int4,
+        subscription_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Varchar,
+        mandate_id -> Varchar,
+        status -> SubscriptionStatus,
+        amount -> Int8,
+        currency -> Currency,
+        billing_interval -> SubscriptionInterval,
+        interval_count -> Int4,
+        trial_end -> Nullable<Timestamp>,
+        end_date -> Nullable<Timestamp>,
+        current_period_start -> Timestamp,
+        current_period_end -> Timestamp,
+        failed_payment_attempts -> Int4,
+        latest_payment_id -> Nullable<Varchar>,
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Json>,
+        cancelled_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    captures,
+    card_vault,
     configs,
     connector_response,
+    customer_erasure,
     customers,
+    data_token,
+    dispute,
+    event_delivery_attempt,
     events,
+    file_metadata,
+    idempotent_response,
+    incremental_authorizations,
     locker_mock_up,
     mandate,
     merchant_account,
     merchant_connector_account,
+    merchant_key_store,
     payment_attempt,
     payment_intent,
     payment_methods,
+    payouts,
     process_tracker,
     refund,
     reverse_lookup,
+    subscription,
 );
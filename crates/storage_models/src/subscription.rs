@@ -0,0 +1,144 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::subscription};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = subscription)]
+pub struct Subscription {
+    pub id: i32,
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub end_date: Option<PrimitiveDateTime>,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub failed_payment_attempts: i32,
+    pub latest_payment_id: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub end_date: Option<PrimitiveDateTime>,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionUpdate {
+    StatusUpdate {
+        status: storage_enums::SubscriptionStatus,
+        cancelled_at: Option<PrimitiveDateTime>,
+    },
+    CycleUpdate {
+        status: storage_enums::SubscriptionStatus,
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+        latest_payment_id: Option<String>,
+    },
+    PaymentFailedUpdate {
+        status: storage_enums::SubscriptionStatus,
+        failed_payment_attempts: i32,
+        latest_payment_id: Option<String>,
+        cancelled_at: Option<PrimitiveDateTime>,
+    },
+    PaymentPendingUpdate {
+        latest_payment_id: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionUpdateInternal {
+    status: Option<storage_enums::SubscriptionStatus>,
+    current_period_start: Option<PrimitiveDateTime>,
+    current_period_end: Option<PrimitiveDateTime>,
+    failed_payment_attempts: Option<i32>,
+    latest_payment_id: Option<String>,
+    cancelled_at: Option<PrimitiveDateTime>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(subscription_update: SubscriptionUpdate) -> Self {
+        let update = match subscription_update {
+            SubscriptionUpdate::StatusUpdate {
+                status,
+                cancelled_at,
+            } => Self {
+                status: Some(status),
+                cancelled_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::CycleUpdate {
+                status,
+                current_period_start,
+                current_period_end,
+                latest_payment_id,
+            } => Self {
+                status: Some(status),
+                current_period_start: Some(current_period_start),
+                current_period_end: Some(current_period_end),
+                failed_payment_attempts: Some(0),
+                latest_payment_id,
+                ..Default::default()
+            },
+            SubscriptionUpdate::PaymentFailedUpdate {
+                status,
+                failed_payment_attempts,
+                latest_payment_id,
+                cancelled_at,
+            } => Self {
+                status: Some(status),
+                failed_payment_attempts: Some(failed_payment_attempts),
+                latest_payment_id,
+                cancelled_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::PaymentPendingUpdate { latest_payment_id } => Self {
+                latest_payment_id,
+                ..Default::default()
+            },
+        };
+
+        Self {
+            modified_at: Some(common_utils::date_time::now()),
+            ..update
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionTrackingData {
+    pub merchant_id: String,
+    pub subscription_id: String,
+    /// Payment of the current cycle which was still being processed when the task last ran
+    #[serde(default)]
+    pub pending_payment_id: Option<String>,
+}
@@ -0,0 +1,5 @@
+DROP TABLE payouts;
+
+DROP TYPE "PayoutStatus";
+
+DROP TYPE "PayoutType";
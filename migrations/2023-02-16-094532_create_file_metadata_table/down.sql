@@ -0,0 +1,3 @@
+DROP TABLE file_metadata;
+
+DROP TYPE "FilePurpose";
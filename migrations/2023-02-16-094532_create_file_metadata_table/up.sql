@@ -0,0 +1,20 @@
+CREATE TYPE "FilePurpose" AS ENUM ('dispute_evidence');
+
+CREATE TABLE file_metadata (
+    id SERIAL PRIMARY KEY,
+    file_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    file_name VARCHAR(255),
+    file_size INTEGER NOT NULL,
+    file_type VARCHAR(255) NOT NULL,
+    purpose "FilePurpose" NOT NULL,
+    file_upload_provider VARCHAR(255) NOT NULL,
+    provider_file_id VARCHAR(255),
+    available BOOLEAN NOT NULL DEFAULT FALSE,
+    connector VARCHAR(255),
+    connector_file_id VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX file_metadata_merchant_id_file_id_index ON file_metadata (merchant_id, file_id);
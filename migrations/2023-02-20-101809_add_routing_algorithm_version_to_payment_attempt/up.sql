@@ -0,0 +1 @@
+ALTER TABLE payment_attempt ADD COLUMN routing_algorithm_version VARCHAR(64);
@@ -0,0 +1 @@
+ALTER TABLE merchant_account DROP COLUMN webhook_signing_secret;
@@ -0,0 +1 @@
+ALTER TABLE merchant_account ADD COLUMN webhook_signing_secret VARCHAR(255);
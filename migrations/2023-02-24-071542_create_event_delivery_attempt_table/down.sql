@@ -0,0 +1,5 @@
+DROP TABLE event_delivery_attempt;
+
+DROP INDEX events_merchant_id_index;
+
+ALTER TABLE events DROP COLUMN merchant_id;
@@ -0,0 +1,21 @@
+ALTER TABLE events ADD COLUMN merchant_id VARCHAR(255);
+
+CREATE INDEX events_merchant_id_index ON events (merchant_id);
+
+CREATE TABLE event_delivery_attempt (
+    id SERIAL PRIMARY KEY,
+    attempt_id VARCHAR(64) NOT NULL,
+    event_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    request_body TEXT NOT NULL,
+    response_status_code INTEGER,
+    response_body TEXT,
+    latency_ms BIGINT,
+    is_success BOOLEAN NOT NULL,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX event_delivery_attempt_attempt_id_index ON event_delivery_attempt (attempt_id);
+
+CREATE INDEX event_delivery_attempt_event_id_index ON event_delivery_attempt (event_id);
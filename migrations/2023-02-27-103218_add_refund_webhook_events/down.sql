@@ -0,0 +1,20 @@
+DELETE FROM pg_enum
+WHERE enumlabel = 'refunds'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventClass'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'refund_details'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventObjectType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel IN (
+  'refund_succeeded',
+  'refund_failed'
+)
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
@@ -0,0 +1,6 @@
+ALTER TYPE "EventClass" ADD VALUE 'refunds';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'refund_details';
+
+ALTER TYPE "EventType" ADD VALUE 'refund_succeeded';
+ALTER TYPE "EventType" ADD VALUE 'refund_failed';
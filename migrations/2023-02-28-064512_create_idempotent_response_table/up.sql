@@ -0,0 +1,11 @@
+CREATE TABLE idempotent_response (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(255) NOT NULL,
+    idempotency_key VARCHAR(255) NOT NULL,
+    request_fingerprint VARCHAR(128) NOT NULL,
+    response_status_code INTEGER NOT NULL,
+    response_body TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX idempotent_response_merchant_id_idempotency_key_index ON idempotent_response (merchant_id, idempotency_key);
@@ -0,0 +1,7 @@
+DROP TABLE captures;
+
+DROP TYPE "CaptureStatus";
+
+-- Postgres cannot drop a value from an enum type, and payments may still be in the
+-- 'partially_captured' status. The value is left in place, as it is unused once the application
+-- is rolled back.
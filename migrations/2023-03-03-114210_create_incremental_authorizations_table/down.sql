@@ -0,0 +1,5 @@
+ALTER TABLE payment_intent DROP COLUMN request_incremental_authorization;
+
+DROP TABLE incremental_authorizations;
+
+DROP TYPE "AuthorizationStatus";
@@ -0,0 +1 @@
+ALTER TABLE merchant_connector_account DROP COLUMN authorization_expiry_days;
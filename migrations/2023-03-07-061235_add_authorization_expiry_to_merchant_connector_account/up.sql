@@ -0,0 +1 @@
+ALTER TABLE merchant_connector_account ADD COLUMN authorization_expiry_days INTEGER;
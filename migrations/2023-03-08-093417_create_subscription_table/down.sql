@@ -0,0 +1,29 @@
+DELETE FROM pg_enum
+WHERE enumlabel IN (
+  'subscription_created',
+  'subscription_renewed',
+  'subscription_payment_failed',
+  'subscription_cancelled',
+  'subscription_ended'
+)
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'subscription_details'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventObjectType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'subscriptions'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventClass'
+);
+
+DROP TABLE subscription;
+
+DROP TYPE "SubscriptionInterval";
+
+DROP TYPE "SubscriptionStatus";
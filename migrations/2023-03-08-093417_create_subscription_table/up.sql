@@ -0,0 +1,41 @@
+CREATE TYPE "SubscriptionStatus" AS ENUM ('trialing', 'active', 'past_due', 'cancelled', 'ended');
+
+CREATE TYPE "SubscriptionInterval" AS ENUM ('day', 'week', 'month', 'year');
+
+CREATE TABLE subscription (
+    id SERIAL PRIMARY KEY,
+    subscription_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    mandate_id VARCHAR(64) NOT NULL,
+    status "SubscriptionStatus" NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval "SubscriptionInterval" NOT NULL,
+    interval_count INTEGER NOT NULL DEFAULT 1,
+    trial_end TIMESTAMP,
+    end_date TIMESTAMP,
+    current_period_start TIMESTAMP NOT NULL,
+    current_period_end TIMESTAMP NOT NULL,
+    failed_payment_attempts INTEGER NOT NULL DEFAULT 0,
+    latest_payment_id VARCHAR(64),
+    description VARCHAR(255),
+    metadata JSON,
+    cancelled_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX subscription_merchant_id_subscription_id_index ON subscription (merchant_id, subscription_id);
+
+CREATE INDEX subscription_merchant_id_customer_id_index ON subscription (merchant_id, customer_id);
+
+ALTER TYPE "EventClass" ADD VALUE 'subscriptions';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'subscription_details';
+
+ALTER TYPE "EventType" ADD VALUE 'subscription_created';
+ALTER TYPE "EventType" ADD VALUE 'subscription_renewed';
+ALTER TYPE "EventType" ADD VALUE 'subscription_payment_failed';
+ALTER TYPE "EventType" ADD VALUE 'subscription_cancelled';
+ALTER TYPE "EventType" ADD VALUE 'subscription_ended';
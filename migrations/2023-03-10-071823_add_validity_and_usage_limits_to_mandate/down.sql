@@ -0,0 +1,11 @@
+ALTER TABLE mandate
+DROP COLUMN IF EXISTS start_date,
+DROP COLUMN IF EXISTS end_date,
+DROP COLUMN IF EXISTS max_usage_count,
+DROP COLUMN IF EXISTS usage_count;
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'expired'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'MandateStatus'
+);
@@ -0,0 +1,7 @@
+ALTER TABLE mandate
+ADD COLUMN IF NOT EXISTS start_date TIMESTAMP NULL,
+ADD COLUMN IF NOT EXISTS end_date TIMESTAMP NULL,
+ADD COLUMN IF NOT EXISTS max_usage_count INTEGER NULL,
+ADD COLUMN IF NOT EXISTS usage_count INTEGER NOT NULL DEFAULT 0;
+
+ALTER TYPE "MandateStatus" ADD VALUE 'expired';
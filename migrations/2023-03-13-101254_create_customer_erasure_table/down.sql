@@ -0,0 +1,3 @@
+DROP TABLE customer_erasure;
+
+DROP TYPE "CustomerErasureStatus";
@@ -0,0 +1,20 @@
+CREATE TYPE "CustomerErasureStatus" AS ENUM ('pending', 'completed', 'failed');
+
+CREATE TABLE customer_erasure (
+    id SERIAL PRIMARY KEY,
+    erasure_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    status "CustomerErasureStatus" NOT NULL,
+    addresses_redacted INTEGER NOT NULL DEFAULT 0,
+    payments_redacted INTEGER NOT NULL DEFAULT 0,
+    payment_methods_deleted INTEGER NOT NULL DEFAULT 0,
+    failure_reason VARCHAR(255),
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX customer_erasure_merchant_id_erasure_id_index ON customer_erasure (merchant_id, erasure_id);
+
+CREATE INDEX customer_erasure_merchant_id_customer_id_index ON customer_erasure (merchant_id, customer_id);
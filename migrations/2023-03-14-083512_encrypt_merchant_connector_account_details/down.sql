@@ -0,0 +1,3 @@
+ALTER TABLE merchant_connector_account DROP COLUMN key_version;
+
+DROP TABLE merchant_key_store;
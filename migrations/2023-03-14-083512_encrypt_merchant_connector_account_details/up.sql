@@ -0,0 +1,11 @@
+CREATE TABLE merchant_key_store (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    key_version INTEGER NOT NULL,
+    encrypted_key BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX merchant_key_store_merchant_id_key_version_index ON merchant_key_store (merchant_id, key_version);
+
+ALTER TABLE merchant_connector_account ADD COLUMN key_version INTEGER;
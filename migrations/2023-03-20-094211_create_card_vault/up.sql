@@ -0,0 +1,15 @@
+CREATE TABLE card_vault (
+    id SERIAL PRIMARY KEY,
+    card_id VARCHAR(64) NOT NULL,
+    external_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    payment_method_id VARCHAR(64),
+    card_fingerprint VARCHAR(64) NOT NULL,
+    card_global_fingerprint VARCHAR(64) NOT NULL,
+    encrypted_data_key BYTEA NOT NULL,
+    encrypted_card_data BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX card_vault_card_id_index ON card_vault (card_id);
@@ -0,0 +1,5 @@
+ALTER TABLE payment_attempt DROP COLUMN card_fingerprint;
+
+DROP INDEX payment_methods_merchant_id_customer_id_card_fingerprint_index;
+
+ALTER TABLE payment_methods DROP COLUMN card_fingerprint;
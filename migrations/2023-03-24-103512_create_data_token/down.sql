@@ -0,0 +1,3 @@
+ALTER TABLE api_keys DROP COLUMN scopes;
+
+DROP TABLE data_token;
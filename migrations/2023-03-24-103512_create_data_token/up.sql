@@ -0,0 +1,17 @@
+CREATE TABLE data_token (
+    id SERIAL PRIMARY KEY,
+    token_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    data_type VARCHAR(64),
+    single_use BOOLEAN NOT NULL DEFAULT FALSE,
+    encrypted_data_key BYTEA NOT NULL,
+    encrypted_data BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL
+);
+
+CREATE UNIQUE INDEX data_token_token_id_index ON data_token (token_id);
+
+CREATE INDEX data_token_merchant_id_expires_at_index ON data_token (merchant_id, expires_at);
+
+ALTER TABLE api_keys ADD COLUMN scopes TEXT[] NOT NULL DEFAULT '{}';
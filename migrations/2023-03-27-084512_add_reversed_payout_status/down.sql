@@ -0,0 +1,2 @@
+-- Postgres cannot drop a value from an enum type, and payouts may still be in the 'reversed'
+-- status. The value is left in place, as it is unused once the application is rolled back.
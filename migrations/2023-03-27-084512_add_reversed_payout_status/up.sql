@@ -0,0 +1 @@
+ALTER TYPE "PayoutStatus" ADD VALUE 'reversed';
@@ -0,0 +1,20 @@
+-- Cards saved more than once for a customer keep their payment methods, without a fingerprint
+UPDATE payment_methods
+SET card_fingerprint = NULL
+WHERE id IN (
+    SELECT id
+    FROM (
+        SELECT id,
+            ROW_NUMBER() OVER (
+                PARTITION BY merchant_id, customer_id, card_fingerprint
+                ORDER BY created_at, id
+            ) AS row_number
+        FROM payment_methods
+        WHERE card_fingerprint IS NOT NULL
+    ) AS saved_cards
+    WHERE row_number > 1
+);
+
+DROP INDEX payment_methods_merchant_id_customer_id_card_fingerprint_index;
+
+CREATE UNIQUE INDEX payment_methods_merchant_id_customer_id_card_fingerprint_index ON payment_methods (merchant_id, customer_id, card_fingerprint);
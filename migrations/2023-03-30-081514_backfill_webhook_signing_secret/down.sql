@@ -0,0 +1,5 @@
+ALTER TABLE merchant_account DROP COLUMN previous_webhook_signing_secret_expires_at;
+ALTER TABLE merchant_account DROP COLUMN previous_webhook_signing_secret;
+
+-- The backfilled webhook signing secrets are kept, as merchants may already verify webhooks with
+-- them
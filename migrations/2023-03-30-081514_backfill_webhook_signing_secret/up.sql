@@ -0,0 +1,8 @@
+-- Merchants created before outgoing webhooks were signed get a signing secret, as unsigned
+-- webhooks are not sent anymore
+UPDATE merchant_account
+SET webhook_signing_secret = 'whsec_' || replace(gen_random_uuid()::text, '-', '')
+WHERE webhook_signing_secret IS NULL;
+
+ALTER TABLE merchant_account ADD COLUMN previous_webhook_signing_secret VARCHAR(255);
+ALTER TABLE merchant_account ADD COLUMN previous_webhook_signing_secret_expires_at TIMESTAMP;